@@ -365,17 +365,9 @@ export const archiveEntity: ImpureGraphFunction<
   Promise<void>
 > = async ({ graphApi }, params) => {
   const { entity, actorId } = params;
-  await graphApi.updateEntity({
+  await graphApi.archiveEntity({
     entityId: entity.metadata.recordId.entityId,
-    archived: true,
     actorId,
-    /**
-     * @todo: these fields shouldn't be required when archiving an entity
-     *
-     * @see https://app.asana.com/0/1201095311341924/1203285029221330/f
-     * */
-    entityTypeId: entity.metadata.entityTypeId,
-    properties: entity.properties,
   });
 };
 
@@ -173,6 +173,9 @@ pub fn bench_get_entity_by_id(
                             None,
                         ),
                     },
+                    sort_by: Vec::new(),
+                    limit: None,
+                    cursor: None,
                 })
                 .await
                 .expect("failed to read entity from store");
@@ -38,7 +38,7 @@ pub fn bench_get_entity_by_id(
                 .expect("could not choose random entity")
         },
         |entity_uuid| async move {
-            let subgraph = store
+            let (subgraph, _) = store
                 .get_entity(&StructuralQuery {
                     filter: Filter::Equal(
                         Some(FilterExpression::Path(EntityQueryPath::Uuid)),
@@ -51,6 +51,9 @@ pub fn bench_get_entity_by_id(
                         pinned: PinnedTemporalAxisUnresolved::new(None),
                         variable: VariableTemporalAxisUnresolved::new(None, None),
                     },
+                    sort_by: Vec::new(),
+                    limit: None,
+                    cursor: None,
                 })
                 .await
                 .expect("failed to read entity from store");
@@ -80,7 +83,7 @@ pub fn bench_get_entities_by_property(
         filter
             .convert_parameters()
             .expect("failed to convert parameters");
-        let subgraph = store
+        let (subgraph, _) = store
             .get_entity(&StructuralQuery {
                 filter,
                 graph_resolve_depths,
@@ -91,6 +94,9 @@ pub fn bench_get_entities_by_property(
                         None,
                     ),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await
             .expect("failed to read entity from store");
@@ -122,7 +128,7 @@ pub fn bench_get_link_by_target_by_property(
         filter
             .convert_parameters()
             .expect("failed to convert parameters");
-        let subgraph = store
+        let (subgraph, _) = store
             .get_entity(&StructuralQuery {
                 filter,
                 graph_resolve_depths,
@@ -133,6 +139,9 @@ pub fn bench_get_link_by_target_by_property(
                         None,
                     ),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await
             .expect("failed to read entity from store");
@@ -43,6 +43,9 @@ pub fn bench_get_entity_type_by_id(
                             None,
                         ),
                     },
+                    sort_by: Vec::new(),
+                    limit: None,
+                    cursor: None,
                 })
                 .await
                 .expect("failed to read entity type from store");
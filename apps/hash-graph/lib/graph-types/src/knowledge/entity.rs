@@ -1,6 +1,10 @@
 #[cfg(feature = "postgres")]
 use std::error::Error;
-use std::{collections::HashMap, fmt, str::FromStr};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    str::FromStr,
+};
 
 #[cfg(feature = "postgres")]
 use bytes::BytesMut;
@@ -86,6 +90,214 @@ impl EntityProperties {
     pub const fn properties(&self) -> &HashMap<BaseUrl, serde_json::Value> {
         &self.0
     }
+
+    /// Returns the property-level differences from `self` to `other`.
+    ///
+    /// Nested objects and arrays are compared element-wise, so a [`PropertyDiff`] always refers
+    /// to the most specific path at which the values differ. The differences are ordered by their
+    /// path.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<PropertyDiff> {
+        let mut diffs = Vec::new();
+        diff_objects(
+            &self
+                .0
+                .iter()
+                .map(|(base_url, value)| (base_url.as_str(), value))
+                .collect(),
+            &other
+                .0
+                .iter()
+                .map(|(base_url, value)| (base_url.as_str(), value))
+                .collect(),
+            &mut PropertyPath::default(),
+            &mut diffs,
+        );
+        diffs
+    }
+}
+
+/// A single step into the properties of an entity.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum PropertyPathElement {
+    /// The property identified by its base URL.
+    Property(String),
+    /// The element at the specified index of an array.
+    Index(usize),
+}
+
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for PropertyPathElement {
+    fn schema() -> (&'static str, openapi::RefOr<openapi::Schema>) {
+        (
+            "PropertyPathElement",
+            openapi::Schema::OneOf(
+                openapi::OneOfBuilder::new()
+                    .item(openapi::ObjectBuilder::new().schema_type(openapi::SchemaType::String))
+                    .item(
+                        openapi::ObjectBuilder::new()
+                            .schema_type(openapi::SchemaType::Integer)
+                            .minimum(Some(0.0)),
+                    )
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
+/// The location of a value inside of [`EntityProperties`].
+///
+/// The first element is the [`BaseUrl`] of the property, followed by the keys of nested property
+/// objects and the indices of array elements. The path is displayed as a sequence of subscripts,
+/// e.g. `["https://example.com/property-type/email/"][0]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct PropertyPath(Vec<PropertyPathElement>);
+
+impl PropertyPath {
+    #[must_use]
+    pub fn elements(&self) -> &[PropertyPathElement] {
+        &self.0
+    }
+
+    pub fn push(&mut self, element: PropertyPathElement) {
+        self.0.push(element);
+    }
+
+    pub fn pop(&mut self) -> Option<PropertyPathElement> {
+        self.0.pop()
+    }
+
+    /// Returns a new path, which is extended by `element`.
+    #[must_use]
+    pub fn join(&self, element: PropertyPathElement) -> Self {
+        let mut path = self.clone();
+        path.push(element);
+        path
+    }
+}
+
+impl FromIterator<PropertyPathElement> for PropertyPath {
+    fn from_iter<T: IntoIterator<Item = PropertyPathElement>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl fmt::Display for PropertyPath {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for element in &self.0 {
+            match element {
+                PropertyPathElement::Property(base_url) => write!(fmt, "[\"{base_url}\"]")?,
+                PropertyPathElement::Index(index) => write!(fmt, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A single difference between two [`EntityProperties`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum PropertyDiff {
+    #[cfg_attr(feature = "utoipa", schema(title = "AddedPropertyDiff"))]
+    Added {
+        #[cfg_attr(feature = "utoipa", schema(value_type = Vec<PropertyPathElement>))]
+        path: PropertyPath,
+        added: serde_json::Value,
+    },
+    #[cfg_attr(feature = "utoipa", schema(title = "RemovedPropertyDiff"))]
+    Removed {
+        #[cfg_attr(feature = "utoipa", schema(value_type = Vec<PropertyPathElement>))]
+        path: PropertyPath,
+        removed: serde_json::Value,
+    },
+    #[cfg_attr(feature = "utoipa", schema(title = "ChangedPropertyDiff"))]
+    Changed {
+        #[cfg_attr(feature = "utoipa", schema(value_type = Vec<PropertyPathElement>))]
+        path: PropertyPath,
+        old: serde_json::Value,
+        new: serde_json::Value,
+    },
+}
+
+fn diff_objects(
+    old: &BTreeMap<&str, &serde_json::Value>,
+    new: &BTreeMap<&str, &serde_json::Value>,
+    path: &mut PropertyPath,
+    diffs: &mut Vec<PropertyDiff>,
+) {
+    let keys = old
+        .keys()
+        .chain(new.keys())
+        .copied()
+        .collect::<BTreeSet<_>>();
+    for key in keys {
+        path.push(PropertyPathElement::Property(key.to_owned()));
+        match (old.get(key), new.get(key)) {
+            (Some(old), Some(new)) => diff_values(old, new, path, diffs),
+            (Some(removed), None) => diffs.push(PropertyDiff::Removed {
+                path: path.clone(),
+                removed: (*removed).clone(),
+            }),
+            (None, Some(added)) => diffs.push(PropertyDiff::Added {
+                path: path.clone(),
+                added: (*added).clone(),
+            }),
+            (None, None) => unreachable!("key is taken from either of the objects"),
+        }
+        path.pop();
+    }
+}
+
+fn diff_values(
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    path: &mut PropertyPath,
+    diffs: &mut Vec<PropertyDiff>,
+) {
+    match (old, new) {
+        (serde_json::Value::Object(old), serde_json::Value::Object(new)) => {
+            let old = old
+                .iter()
+                .map(|(key, value)| (key.as_str(), value))
+                .collect::<BTreeMap<_, _>>();
+            let new = new
+                .iter()
+                .map(|(key, value)| (key.as_str(), value))
+                .collect::<BTreeMap<_, _>>();
+            diff_objects(&old, &new, path, diffs);
+        }
+        (serde_json::Value::Array(old), serde_json::Value::Array(new)) => {
+            for index in 0..old.len().max(new.len()) {
+                path.push(PropertyPathElement::Index(index));
+                match (old.get(index), new.get(index)) {
+                    (Some(old), Some(new)) => diff_values(old, new, path, diffs),
+                    (Some(removed), None) => diffs.push(PropertyDiff::Removed {
+                        path: path.clone(),
+                        removed: removed.clone(),
+                    }),
+                    (None, Some(added)) => diffs.push(PropertyDiff::Added {
+                        path: path.clone(),
+                        added: added.clone(),
+                    }),
+                    (None, None) => unreachable!("index is within either of the arrays"),
+                }
+                path.pop();
+            }
+        }
+        (old, new) => {
+            if old != new {
+                diffs.push(PropertyDiff::Changed {
+                    path: path.clone(),
+                    old: old.clone(),
+                    new: new.clone(),
+                });
+            }
+        }
+    }
 }
 
 /// The metadata of an [`Entity`] record.
@@ -317,4 +529,74 @@ mod tests {
     fn page() {
         test_entity(graph_test_data::entity::PAGE_V1);
     }
+
+    #[test]
+    fn diff() {
+        let old: EntityProperties = serde_json::from_value(serde_json::json!({
+            "https://example.com/name/": "Alice",
+            "https://example.com/age/": 42,
+            "https://example.com/emails/": ["alice@example.com", "alice@example.org"],
+            "https://example.com/address/": {
+                "https://example.com/street/": "Main Street",
+                "https://example.com/city/": "London",
+            },
+        }))
+        .expect("invalid entity");
+        let new: EntityProperties = serde_json::from_value(serde_json::json!({
+            "https://example.com/name/": "Alice",
+            "https://example.com/emails/": ["alice@example.net"],
+            "https://example.com/address/": {
+                "https://example.com/street/": "Main Street",
+                "https://example.com/city/": "Paris",
+                "https://example.com/postcode/": "75001",
+            },
+            "https://example.com/nickname/": "Al",
+        }))
+        .expect("invalid entity");
+
+        let path = |elements: &[&str]| {
+            elements
+                .iter()
+                .map(|element| {
+                    element.parse().map_or_else(
+                        |_| PropertyPathElement::Property((*element).to_owned()),
+                        PropertyPathElement::Index,
+                    )
+                })
+                .collect::<PropertyPath>()
+        };
+
+        assert_eq!(old.diff(&new), [
+            PropertyDiff::Changed {
+                path: path(&["https://example.com/address/", "https://example.com/city/"]),
+                old: serde_json::json!("London"),
+                new: serde_json::json!("Paris"),
+            },
+            PropertyDiff::Added {
+                path: path(&[
+                    "https://example.com/address/",
+                    "https://example.com/postcode/"
+                ]),
+                added: serde_json::json!("75001"),
+            },
+            PropertyDiff::Removed {
+                path: path(&["https://example.com/age/"]),
+                removed: serde_json::json!(42),
+            },
+            PropertyDiff::Changed {
+                path: path(&["https://example.com/emails/", "0"]),
+                old: serde_json::json!("alice@example.com"),
+                new: serde_json::json!("alice@example.net"),
+            },
+            PropertyDiff::Removed {
+                path: path(&["https://example.com/emails/", "1"]),
+                removed: serde_json::json!("alice@example.org"),
+            },
+            PropertyDiff::Added {
+                path: path(&["https://example.com/nickname/"]),
+                added: serde_json::json!("Al"),
+            },
+        ]);
+        assert!(new.diff(&new).is_empty());
+    }
 }
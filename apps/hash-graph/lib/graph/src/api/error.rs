@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use hash_status::Status as HashStatus;
+use hash_status::{details::BadRequest, Status as HashStatus};
 use serde::{Deserialize, Serialize};
 
 pub(in crate::api) use crate::api::gen::status_payloads::{ErrorInfo, RequestInfo, ResourceInfo};
@@ -10,6 +10,7 @@ pub enum StatusPayloads {
     ErrorInfo(ErrorInfo),
     RequestInfo(RequestInfo),
     ResourceInfo(ResourceInfo),
+    BadRequest(BadRequest),
 }
 
 impl ErrorInfo {
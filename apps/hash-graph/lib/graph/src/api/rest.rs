@@ -13,10 +13,12 @@ mod status;
 mod utoipa_typedef;
 
 mod account;
+mod change;
 mod data_type;
 mod entity;
 mod entity_type;
 mod property_type;
+mod search;
 
 use std::{fs, io, sync::Arc};
 
@@ -136,6 +138,8 @@ where
         property_type::PropertyTypeResource::routes::<P>(),
         entity_type::EntityTypeResource::routes::<P>(),
         entity::EntityResource::routes::<P>(),
+        search::SearchResource::routes::<P>(),
+        change::ChangeResource::routes::<P>(),
     ]
 }
 
@@ -146,6 +150,8 @@ fn api_documentation() -> Vec<openapi::OpenApi> {
         property_type::PropertyTypeResource::documentation(),
         entity_type::EntityTypeResource::documentation(),
         entity::EntityResource::documentation(),
+        search::SearchResource::documentation(),
+        change::ChangeResource::documentation(),
     ]
 }
 
@@ -549,6 +555,82 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("notEqual"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("InFilter"))
+                                .property(
+                                    "in",
+                                    ArrayBuilder::new()
+                                        .items(
+                                            OneOfBuilder::new()
+                                                .item(Ref::from_schema_name("FilterExpression"))
+                                                .item(ArrayBuilder::new().items(Any::schema().1)),
+                                        )
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("in"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("LessFilter"))
+                                .property(
+                                    "less",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("less"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("LessOrEqualFilter"))
+                                .property(
+                                    "lessOrEqual",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("lessOrEqual"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("GreaterFilter"))
+                                .property(
+                                    "greater",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("greater"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("GreaterOrEqualFilter"))
+                                .property(
+                                    "greaterOrEqual",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("greaterOrEqual"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("BetweenFilter"))
+                                .property(
+                                    "between",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(3))
+                                        .max_items(Some(3)),
+                                )
+                                .required("between"),
+                        )
                         .item(
                             ObjectBuilder::new()
                                 .title(Some("StartsWithFilter"))
@@ -585,6 +667,18 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("containsSegment"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ContainsTextFilter"))
+                                .property(
+                                    "containsText",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("containsText"),
+                        )
                         .build(),
                 )
                 .into(),
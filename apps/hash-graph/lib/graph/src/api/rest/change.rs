@@ -0,0 +1,216 @@
+//! Web routes for reading the changes of the graph.
+
+use std::{num::NonZeroUsize, sync::Arc, time::Duration};
+
+use axum::{
+    extract::Query,
+    http::{HeaderMap, StatusCode},
+    response::sse::{Event, KeepAlive, Sse},
+    routing::get,
+    Extension, Router,
+};
+use futures::{stream, Stream, StreamExt};
+use serde::Deserialize;
+use tokio::time::{sleep, Instant};
+use utoipa::OpenApi;
+
+use crate::{
+    api::rest::{api_resource::RoutedResource, json::Json, report_to_status_code},
+    store::{
+        ChangeEvent, ChangeEventOffset, ChangeOperation, ChangeStore, ChangedRecord, StorePool,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        read_change_events,
+        stream_change_events,
+    ),
+    components(
+        schemas(
+            ChangeEvent,
+            ChangeEventOffset,
+            ChangeOperation,
+            ChangedRecord,
+        )
+    ),
+    tags(
+        (name = "Change", description = "Change data capture API")
+    )
+)]
+pub struct ChangeResource;
+
+impl RoutedResource for ChangeResource {
+    /// Create routes for reading the changes of the graph.
+    fn routes<P: StorePool + Send + 'static>() -> Router {
+        // TODO: The URL format here is preliminary and will have to change.
+        Router::new().nest(
+            "/changes",
+            Router::new()
+                .route("/", get(read_change_events::<P>))
+                .route("/stream", get(stream_change_events::<P>)),
+        )
+    }
+}
+
+/// The interval in which the store is polled for new changes while waiting.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The maximum duration a long-polling request waits for new changes.
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+fn default_limit() -> NonZeroUsize {
+    NonZeroUsize::new(100).expect("100 is not zero")
+}
+
+async fn fetch_change_events<P: StorePool + Send>(
+    pool: &P,
+    after: Option<ChangeEventOffset>,
+    limit: NonZeroUsize,
+) -> Result<Vec<ChangeEvent>, StatusCode> {
+    let store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    store
+        .read_change_events(after, limit)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not read change events");
+            report_to_status_code(&report)
+        })
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReadChangeEventsParams {
+    after: Option<ChangeEventOffset>,
+    #[serde(default = "default_limit")]
+    limit: NonZeroUsize,
+    #[serde(default)]
+    wait: u64,
+}
+
+#[utoipa::path(
+    get,
+    path = "/changes",
+    tag = "Change",
+    params(
+        ("after" = Option<ChangeEventOffset>, Query, description = "The offset of the last change already read, the changes are read from the beginning if omitted"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of changes to return, defaults to 100"),
+        ("wait" = Option<u64>, Query, description = "The number of seconds to wait for new changes if there are none yet, at most 60. Defaults to 0, which returns immediately"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", body = [ChangeEvent], description = "The changes after the provided offset, ordered by their offset"),
+        (status = 422, content_type = "text/plain", description = "Provided query parameters are invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(pool))]
+async fn read_change_events<P: StorePool + Send>(
+    Query(ReadChangeEventsParams { after, limit, wait }): Query<ReadChangeEventsParams>,
+    pool: Extension<Arc<P>>,
+) -> Result<Json<Vec<ChangeEvent>>, StatusCode> {
+    let deadline = Instant::now() + Duration::from_secs(wait).min(MAX_WAIT);
+
+    loop {
+        // The store is acquired for every poll, so waiting does not block a connection.
+        let events = fetch_change_events(pool.as_ref(), after, limit).await?;
+
+        let now = Instant::now();
+        if !events.is_empty() || now >= deadline {
+            return Ok(Json(events));
+        }
+
+        sleep(POLL_INTERVAL.min(deadline - now)).await;
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamChangeEventsParams {
+    after: Option<ChangeEventOffset>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/changes/stream",
+    tag = "Change",
+    params(
+        ("after" = Option<ChangeEventOffset>, Query, description = "The offset of the last change already read, the changes are streamed from the beginning if omitted"),
+        ("Last-Event-ID" = Option<ChangeEventOffset>, Header, description = "The offset of the last change already read, takes precedence over `after` when reconnecting"),
+    ),
+    responses(
+        (status = 200, content_type = "text/event-stream", description = "A stream of server-sent `change` events. The data of each event is a `ChangeEvent` and the event ID is its offset. If the changes can't be read anymore, an `error` event with the status code as data is sent before the stream is closed"),
+        (status = 400, content_type = "text/plain", description = "The `Last-Event-ID` header is not a valid offset"),
+        (status = 422, content_type = "text/plain", description = "Provided query parameters are invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(pool, headers))]
+async fn stream_change_events<P: StorePool + Send + 'static>(
+    Query(StreamChangeEventsParams { after }): Query<StreamChangeEventsParams>,
+    headers: HeaderMap,
+    pool: Extension<Arc<P>>,
+) -> Result<Sse<impl Stream<Item = Result<Event, serde_json::Error>>>, StatusCode> {
+    let after = headers
+        .get("Last-Event-ID")
+        .map(|last_event_id| {
+            last_event_id
+                .to_str()
+                .ok()
+                .and_then(|last_event_id| last_event_id.parse().ok())
+                .map(ChangeEventOffset::new)
+                .ok_or(StatusCode::BAD_REQUEST)
+        })
+        .transpose()?
+        .or(after);
+
+    // Reading the first changes before opening the stream reports store errors as status code.
+    let first_events = fetch_change_events(pool.as_ref(), after, default_limit()).await?;
+    let after = first_events.last().map(|event| event.offset).or(after);
+
+    // After a store error, an `error` event is sent and the stream is closed. The client is
+    // expected to reconnect, which resumes at the last received event.
+    let next_events = stream::unfold(Some((pool.0, after)), |state| async move {
+        let (pool, after) = state?;
+        loop {
+            match fetch_change_events(pool.as_ref(), after, default_limit()).await {
+                Ok(events) if events.is_empty() => sleep(POLL_INTERVAL).await,
+                Ok(events) => {
+                    let after = events.last().map(|event| event.offset);
+                    return Some((Ok(events), Some((pool, after))));
+                }
+                Err(status_code) => {
+                    tracing::error!(%status_code, "Closing the stream of change events");
+                    return Some((Err(status_code), None));
+                }
+            }
+        }
+    });
+
+    let events = stream::iter([Ok(first_events)])
+        .chain(next_events)
+        .flat_map(|events| {
+            stream::iter(match events {
+                Ok(events) => events
+                    .into_iter()
+                    .map(|event| {
+                        Event::default()
+                            .event("change")
+                            .id(event.offset.as_i64().to_string())
+                            .json_data(&event)
+                    })
+                    .collect::<Vec<_>>(),
+                Err(status_code) => {
+                    vec![Ok(Event::default()
+                        .event("error")
+                        .data(status_code.to_string()))]
+                }
+            })
+        });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
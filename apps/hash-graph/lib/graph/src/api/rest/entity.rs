@@ -2,31 +2,50 @@
 
 use std::sync::Arc;
 
-use axum::{http::StatusCode, routing::post, Extension, Router};
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post, put},
+    Extension, Router,
+};
+use error_stack::Report;
 use futures::TryFutureExt;
 use graph_types::{
     knowledge::{
         entity::{
             Entity, EntityEditionId, EntityId, EntityMetadata, EntityProperties, EntityRecordId,
-            EntityTemporalMetadata, EntityUuid,
+            EntityTemporalMetadata, EntityUuid, PropertyDiff, PropertyPathElement,
         },
         link::{EntityLinkOrder, LinkData, LinkOrder},
     },
-    provenance::{OwnedById, RecordCreatedById},
+    provenance::{OwnedById, RecordArchivedById, RecordCreatedById},
 };
+use hash_status::details::BadRequest;
 use serde::{Deserialize, Serialize};
 use type_system::url::VersionedUrl;
 use utoipa::{OpenApi, ToSchema};
 
 use crate::{
-    api::rest::{
-        api_resource::RoutedResource, json::Json, report_to_status_code,
-        utoipa_typedef::subgraph::Subgraph,
+    api::{
+        error::{ErrorInfo, Status, StatusPayloads},
+        rest::{
+            api_resource::RoutedResource, json::Json, report_to_status_code,
+            status::status_to_response, utoipa_typedef::subgraph::Subgraph,
+        },
     },
     knowledge::EntityQueryToken,
     store::{
-        error::{EntityDoesNotExist, RaceConditionOnUpdate},
-        EntityStore, StorePool,
+        error::{
+            EntityDoesNotExist, EntityEditionDoesNotExist, EntityValidationError,
+            RaceConditionOnUpdate,
+        },
+        query::{
+            AggregationFunction, AggregationGroup, AggregationQuery, AggregationResult, Cursor,
+            EntityAggregationQuery, Ordering,
+        },
+        BatchMode, BatchOperationIndex, EntityBatchLinkData, EntityBatchOperation,
+        EntityBatchReference, EntityStore, StorePool, UpdateError,
     },
     subgraph::query::{EntityStructuralQuery, StructuralQuery},
 };
@@ -36,14 +55,38 @@ use crate::{
     paths(
         create_entity,
         get_entities_by_query,
+        get_entity_page_by_query,
+        aggregate_entities,
+        get_entity_history,
+        diff_entity_editions,
         update_entity,
+        archive_entity,
+        unarchive_entity,
+        batch_entities,
     ),
     components(
         schemas(
             CreateEntityRequest,
             UpdateEntityRequest,
+            ArchiveEntityRequest,
+            UnarchiveEntityRequest,
+            BatchEntitiesRequest,
+            BatchMode,
+            EntityBatchOperation,
+            EntityBatchReference,
+            EntityBatchLinkData,
+            EntityBatchResult,
             EntityQueryToken,
             EntityStructuralQuery,
+            EntityQueryPageResponse,
+            Ordering,
+            Cursor,
+            EntityAggregationQuery,
+            AggregationFunction,
+            AggregationResult,
+            AggregationGroup,
+            PropertyDiff,
+            PropertyPathElement,
 
             Entity,
             EntityUuid,
@@ -73,7 +116,14 @@ impl RoutedResource for EntityResource {
             "/entities",
             Router::new()
                 .route("/", post(create_entity::<P>).put(update_entity::<P>))
-                .route("/query", post(get_entities_by_query::<P>)),
+                .route("/query", post(get_entities_by_query::<P>))
+                .route("/query/page", post(get_entity_page_by_query::<P>))
+                .route("/aggregate", post(aggregate_entities::<P>))
+                .route("/:entity_id/history", get(get_entity_history::<P>))
+                .route("/:entity_id/diff", get(diff_entity_editions::<P>))
+                .route("/archive", put(archive_entity::<P>))
+                .route("/unarchive", put(unarchive_entity::<P>))
+                .route("/batch", post(batch_entities::<P>)),
         )
     }
 }
@@ -95,6 +145,28 @@ struct CreateEntityRequest {
     link_data: Option<LinkData>,
 }
 
+/// Collects the violations of the entity from the [`BadRequest`]s attached to the report.
+fn entity_violations<C>(report: &Report<C>) -> BadRequest {
+    report
+        .frames()
+        .filter_map(|frame| frame.downcast_ref::<BadRequest>())
+        .flat_map(BadRequest::field_violations)
+        .cloned()
+        .fold(BadRequest::new(), BadRequest::with_field_violation)
+}
+
+/// Returns a response listing the violations of the entity if the report contains an
+/// [`EntityValidationError`].
+fn entity_validation_response<C>(report: &Report<C>) -> Option<Response> {
+    report.contains::<EntityValidationError>().then(|| {
+        status_to_response(Status::new(
+            hash_status::StatusCode::InvalidArgument,
+            Some(EntityValidationError.to_string()),
+            vec![StatusPayloads::BadRequest(entity_violations(report))],
+        ))
+    })
+}
+
 #[utoipa::path(
     post,
     path = "/entities",
@@ -102,6 +174,7 @@ struct CreateEntityRequest {
     tag = "Entity",
     responses(
         (status = 200, content_type = "application/json", description = "The metadata of the created entity", body = EntityMetadata),
+        (status = 400, content_type = "application/json", description = "The entity is not valid with respect to its entity type or the entity type of its left entity", body = VAR_STATUS),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
         (status = 404, description = "Entity Type URL was not found"),
@@ -112,7 +185,7 @@ struct CreateEntityRequest {
 async fn create_entity<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
     body: Json<CreateEntityRequest>,
-) -> Result<Json<EntityMetadata>, StatusCode> {
+) -> Result<Json<EntityMetadata>, Response> {
     let Json(CreateEntityRequest {
         properties,
         entity_type_id,
@@ -124,7 +197,7 @@ async fn create_entity<P: StorePool + Send>(
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     store
@@ -142,8 +215,10 @@ async fn create_entity<P: StorePool + Send>(
         .map_err(|report| {
             tracing::error!(error=?report, "Could not create entity");
 
-            // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            entity_validation_response(&report).unwrap_or_else(|| {
+                // Insertion/update errors are considered internal server errors.
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            })
         })
         .map(Json)
 }
@@ -155,7 +230,7 @@ async fn create_entity<P: StorePool + Send>(
     tag = "Entity",
     responses(
         (status = 200, content_type = "application/json", body = Subgraph, description = "A subgraph rooted at entities that satisfy the given query, each resolved to the requested depth."),
-        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid or requests a page, which is only supported by `/entities/query/page`"),
         (status = 500, description = "Store error occurred"),
     )
 )]
@@ -174,6 +249,11 @@ async fn get_entities_by_query<P: StorePool + Send>(
                 tracing::error!(?error, "Could not deserialize query");
                 StatusCode::INTERNAL_SERVER_ERROR
             })?;
+            // The cursor of the next page can't be returned without the page response
+            if query.limit.is_some() || query.cursor.is_some() {
+                tracing::error!(?query, "Pages can only be read from `/entities/query/page`");
+                return Err(StatusCode::UNPROCESSABLE_ENTITY);
+            }
             query.filter.convert_parameters().map_err(|error| {
                 tracing::error!(?error, "Could not validate query");
                 StatusCode::INTERNAL_SERVER_ERROR
@@ -184,7 +264,188 @@ async fn get_entities_by_query<P: StorePool + Send>(
             })
         })
         .await
-        .map(|subgraph| Json(subgraph.into()))
+        .map(|(subgraph, _)| Json(subgraph.into()))
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct EntityQueryPageResponse {
+    subgraph: Subgraph,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    cursor: Option<Cursor>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/query/page",
+    request_body = EntityStructuralQuery,
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", body = EntityQueryPageResponse, description = "A subgraph rooted at a page of the entities that satisfy the given query, each resolved to the requested depth, and the cursor of the next page if the query is limited and more entities are available."),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(pool))]
+async fn get_entity_page_by_query<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    Json(query): Json<serde_json::Value>,
+) -> Result<Json<EntityQueryPageResponse>, StatusCode> {
+    pool.acquire()
+        .map_err(|error| {
+            tracing::error!(?error, "Could not acquire access to the store");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+        .and_then(|store| async move {
+            let mut query = StructuralQuery::deserialize(&query).map_err(|error| {
+                tracing::error!(?error, "Could not deserialize query");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            query.filter.convert_parameters().map_err(|error| {
+                tracing::error!(?error, "Could not validate query");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            store.get_entity(&query).await.map_err(|report| {
+                tracing::error!(error=?report, ?query, "Could not read entities from the store");
+                report_to_status_code(&report)
+            })
+        })
+        .await
+        .map(|(subgraph, cursor)| {
+            Json(EntityQueryPageResponse {
+                subgraph: subgraph.into(),
+                cursor,
+            })
+        })
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/aggregate",
+    request_body = EntityAggregationQuery,
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", body = [AggregationGroup], description = "The aggregated values of the entities that satisfy the given filter, grouped and ordered by the values at the requested paths"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(pool))]
+async fn aggregate_entities<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    Json(query): Json<serde_json::Value>,
+) -> Result<Json<Vec<AggregationGroup>>, StatusCode> {
+    pool.acquire()
+        .map_err(|error| {
+            tracing::error!(?error, "Could not acquire access to the store");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+        .and_then(|store| async move {
+            let mut query = AggregationQuery::deserialize(&query).map_err(|error| {
+                tracing::error!(?error, "Could not deserialize query");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            query.filter.convert_parameters().map_err(|error| {
+                tracing::error!(?error, "Could not validate query");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            store.aggregate_entities(&query).await.map_err(|report| {
+                tracing::error!(error=?report, ?query, "Could not aggregate entities in the store");
+                report_to_status_code(&report)
+            })
+        })
+        .await
+        .map(Json)
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entityId}/history",
+    tag = "Entity",
+    params(
+        ("entityId" = EntityId, Path, description = "The ID of the entity"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", body = [EntityMetadata], description = "The metadata of every edition of the entity, ordered by the start of their transaction time and decision time"),
+        (status = 400, content_type = "text/plain", description = "Provided entity ID is invalid"),
+
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(pool))]
+async fn get_entity_history<P: StorePool + Send>(
+    Path(entity_id): Path<EntityId>,
+    pool: Extension<Arc<P>>,
+) -> Result<Json<Vec<EntityMetadata>>, StatusCode> {
+    let store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    store
+        .get_entity_history(entity_id)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not read entity history");
+
+            if report.contains::<EntityDoesNotExist>() {
+                StatusCode::NOT_FOUND
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        })
+        .map(Json)
+}
+
+#[derive(Debug, Deserialize)]
+struct DiffEntityEditionsParams {
+    old: EntityEditionId,
+    new: EntityEditionId,
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entityId}/diff",
+    tag = "Entity",
+    params(
+        ("entityId" = EntityId, Path, description = "The ID of the entity"),
+        ("old" = EntityEditionId, Query, description = "The edition to compare from"),
+        ("new" = EntityEditionId, Query, description = "The edition to compare to"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", body = [PropertyDiff], description = "The differences between the properties of the two editions, ordered by their path"),
+        (status = 400, content_type = "text/plain", description = "Provided entity ID or edition IDs are invalid"),
+
+        (status = 404, description = "Either of the editions was not found for the entity"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(pool))]
+async fn diff_entity_editions<P: StorePool + Send>(
+    Path(entity_id): Path<EntityId>,
+    Query(DiffEntityEditionsParams { old, new }): Query<DiffEntityEditionsParams>,
+    pool: Extension<Arc<P>>,
+) -> Result<Json<Vec<PropertyDiff>>, StatusCode> {
+    let store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    store
+        .diff_entity_editions(entity_id, old, new)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not diff entity editions");
+
+            if report.contains::<EntityEditionDoesNotExist>() {
+                StatusCode::NOT_FOUND
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        })
+        .map(Json)
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -206,6 +467,7 @@ struct UpdateEntityRequest {
     tag = "Entity",
     responses(
         (status = 200, content_type = "application/json", description = "The metadata of the updated entity", body = EntityMetadata),
+        (status = 400, content_type = "application/json", description = "The entity is not valid with respect to its entity type or the entity type of its left entity", body = VAR_STATUS),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
         (status = 423, content_type = "text/plain", description = "The entity that should be updated was unexpectedly updated at the same time"),
 
@@ -218,7 +480,7 @@ struct UpdateEntityRequest {
 async fn update_entity<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
     body: Json<UpdateEntityRequest>,
-) -> Result<Json<EntityMetadata>, StatusCode> {
+) -> Result<Json<EntityMetadata>, Response> {
     let Json(UpdateEntityRequest {
         properties,
         entity_id,
@@ -230,7 +492,7 @@ async fn update_entity<P: StorePool + Send>(
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     store
@@ -248,13 +510,271 @@ async fn update_entity<P: StorePool + Send>(
             tracing::error!(error=?report, "Could not update entity");
 
             if report.contains::<EntityDoesNotExist>() {
-                StatusCode::NOT_FOUND
+                StatusCode::NOT_FOUND.into_response()
             } else if report.contains::<RaceConditionOnUpdate>() {
-                StatusCode::LOCKED
+                StatusCode::LOCKED.into_response()
+            } else if let Some(response) = entity_validation_response(&report) {
+                response
             } else {
                 // Insertion/update errors are considered internal server errors.
-                StatusCode::INTERNAL_SERVER_ERROR
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
             }
         })
         .map(Json)
 }
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct ArchiveEntityRequest {
+    entity_id: EntityId,
+    actor_id: RecordArchivedById,
+}
+
+#[utoipa::path(
+    put,
+    path = "/entities/archive",
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of the archived entity", body = EntityMetadata),
+        (status = 400, content_type = "application/json", description = "The entity is a link, which is required by the entity type of its left entity", body = VAR_STATUS),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "The entity that should be archived was unexpectedly updated at the same time"),
+
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = ArchiveEntityRequest,
+)]
+#[tracing::instrument(level = "info", skip(pool))]
+async fn archive_entity<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    body: Json<ArchiveEntityRequest>,
+) -> Result<Json<EntityMetadata>, Response> {
+    let Json(ArchiveEntityRequest {
+        entity_id,
+        actor_id,
+    }) = body;
+
+    let mut store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
+    })?;
+
+    store
+        .archive_entity(entity_id, actor_id)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not archive entity");
+
+            if report.contains::<EntityDoesNotExist>() {
+                StatusCode::NOT_FOUND.into_response()
+            } else if report.contains::<RaceConditionOnUpdate>() {
+                StatusCode::LOCKED.into_response()
+            } else if let Some(response) = entity_validation_response(&report) {
+                response
+            } else {
+                // Insertion/update errors are considered internal server errors.
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        })
+        .map(Json)
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct UnarchiveEntityRequest {
+    entity_id: EntityId,
+    actor_id: RecordCreatedById,
+}
+
+#[utoipa::path(
+    put,
+    path = "/entities/unarchive",
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", description = "The metadata of the restored entity", body = EntityMetadata),
+        (status = 400, content_type = "application/json", description = "The entity is a link, which is not allowed by the entity type of its left entity", body = VAR_STATUS),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "The entity that should be restored was unexpectedly updated at the same time"),
+
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = UnarchiveEntityRequest,
+)]
+#[tracing::instrument(level = "info", skip(pool))]
+async fn unarchive_entity<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    body: Json<UnarchiveEntityRequest>,
+) -> Result<Json<EntityMetadata>, Response> {
+    let Json(UnarchiveEntityRequest {
+        entity_id,
+        actor_id,
+    }) = body;
+
+    let mut store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
+    })?;
+
+    store
+        .unarchive_entity(entity_id, actor_id)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not unarchive entity");
+
+            if report.contains::<EntityDoesNotExist>() {
+                StatusCode::NOT_FOUND.into_response()
+            } else if report.contains::<RaceConditionOnUpdate>() {
+                StatusCode::LOCKED.into_response()
+            } else if let Some(response) = entity_validation_response(&report) {
+                response
+            } else {
+                // Insertion/update errors are considered internal server errors.
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        })
+        .map(Json)
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct BatchEntitiesRequest {
+    operations: Vec<EntityBatchOperation>,
+    actor_id: RecordCreatedById,
+    #[serde(default)]
+    mode: BatchMode,
+}
+
+/// The result of a single operation in a batch.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "status", rename_all = "camelCase")]
+enum EntityBatchResult {
+    #[schema(title = "EntityBatchSuccess")]
+    Success { metadata: EntityMetadata },
+    /// The operation failed with the status code and the error the corresponding single operation
+    /// would have failed with.
+    #[schema(title = "EntityBatchFailure")]
+    Failure {
+        code: u16,
+        #[schema(value_type = VAR_STATUS)]
+        error: Status<StatusPayloads>,
+    },
+}
+
+fn batch_error_status_code(report: &Report<UpdateError>) -> StatusCode {
+    if report.contains::<EntityDoesNotExist>() {
+        StatusCode::NOT_FOUND
+    } else if report.contains::<RaceConditionOnUpdate>() {
+        StatusCode::LOCKED
+    } else if report.contains::<EntityValidationError>() {
+        StatusCode::BAD_REQUEST
+    } else {
+        // Insertion/update errors are considered internal server errors.
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+}
+
+/// Returns the status of a failed batch operation followed by `contents`.
+///
+/// The violations of an invalid entity are listed the same way as for the corresponding single
+/// operation.
+fn batch_error_status(
+    report: &Report<UpdateError>,
+    mut contents: Vec<StatusPayloads>,
+) -> Status<StatusPayloads> {
+    let (code, message) = if report.contains::<EntityValidationError>() {
+        contents.insert(0, StatusPayloads::BadRequest(entity_violations(report)));
+        (
+            hash_status::StatusCode::InvalidArgument,
+            EntityValidationError.to_string(),
+        )
+    } else {
+        let code = match batch_error_status_code(report) {
+            StatusCode::NOT_FOUND => hash_status::StatusCode::NotFound,
+            StatusCode::LOCKED => hash_status::StatusCode::Aborted,
+            _ => hash_status::StatusCode::Internal,
+        };
+        (code, report.current_context().to_string())
+    };
+
+    Status::new(code, Some(message), contents)
+}
+
+/// Returns a response for a failed batch in [`BatchMode::AllOrNothing`] mode.
+///
+/// The response has the status code of the failed operation, the index of the operation is
+/// returned as `operationIndex` in the metadata of the [`ErrorInfo`].
+fn batch_error_response(report: &Report<UpdateError>) -> Response {
+    let metadata = report
+        .frames()
+        .find_map(|frame| frame.downcast_ref::<BatchOperationIndex>())
+        .map(|BatchOperationIndex(index)| ("operationIndex".to_owned(), serde_json::json!(index)));
+    let status = batch_error_status(report, vec![StatusPayloads::ErrorInfo(ErrorInfo::new(
+        metadata.into_iter().collect(),
+        "BATCH_OPERATION_FAILED".to_owned(),
+    ))]);
+
+    // `LOCKED` has no corresponding `StatusCode`, so the status code of the response is set
+    // explicitly
+    let mut response = status_to_response(status);
+    *response.status_mut() = batch_error_status_code(report);
+    response
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/batch",
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", description = "The result of each operation in the order of the operations", body = [EntityBatchResult]),
+        (status = 400, content_type = "application/json", description = "In `allOrNothing` mode, an entity is not valid with respect to its entity type or the entity type of its left entity", body = VAR_STATUS),
+        (status = 404, content_type = "application/json", description = "In `allOrNothing` mode, an entity, an entity type, or a referenced operation was not found", body = VAR_STATUS),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "application/json", description = "In `allOrNothing` mode, an entity that should be updated was unexpectedly updated at the same time", body = VAR_STATUS),
+
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = BatchEntitiesRequest,
+)]
+#[tracing::instrument(level = "info", skip(pool, body))]
+async fn batch_entities<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    body: Json<BatchEntitiesRequest>,
+) -> Result<Json<Vec<EntityBatchResult>>, Response> {
+    let Json(BatchEntitiesRequest {
+        operations,
+        actor_id,
+        mode,
+    }) = body;
+
+    let mut store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
+    })?;
+
+    store
+        .batch_entities(operations, actor_id, mode)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not apply batch of entity operations");
+            batch_error_response(&report)
+        })
+        .map(|results| {
+            Json(
+                results
+                    .into_iter()
+                    .map(|result| match result {
+                        Ok(metadata) => EntityBatchResult::Success { metadata },
+                        Err(report) => {
+                            tracing::warn!(error=?report, "Batch operation failed");
+                            EntityBatchResult::Failure {
+                                code: batch_error_status_code(&report).as_u16(),
+                                error: batch_error_status(&report, Vec::new()),
+                            }
+                        }
+                    })
+                    .collect(),
+            )
+        })
+}
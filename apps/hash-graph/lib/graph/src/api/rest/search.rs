@@ -0,0 +1,85 @@
+//! Web routes for full-text search.
+
+use std::{num::NonZeroUsize, sync::Arc};
+
+use axum::{http::StatusCode, routing::post, Extension, Router};
+use serde::Deserialize;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::rest::{api_resource::RoutedResource, json::Json, report_to_status_code},
+    store::{EntitySearchResult, OntologyTypeSearchResult, SearchResults, SearchStore, StorePool},
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        search,
+    ),
+    components(
+        schemas(
+            SearchRequest,
+            SearchResults,
+            EntitySearchResult,
+            OntologyTypeSearchResult,
+        )
+    ),
+    tags(
+        (name = "Search", description = "Full-text search API")
+    )
+)]
+pub struct SearchResource;
+
+impl RoutedResource for SearchResource {
+    /// Create routes for searching the graph.
+    fn routes<P: StorePool + Send + 'static>() -> Router {
+        // TODO: The URL format here is preliminary and will have to change.
+        Router::new().route("/search", post(search::<P>))
+    }
+}
+
+fn default_limit() -> NonZeroUsize {
+    NonZeroUsize::new(10).expect("10 is not zero")
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct SearchRequest {
+    /// The words to search for. Quoted phrases, `or` and `-` to exclude words are supported.
+    query: String,
+    /// The maximum number of results for each kind of graph element, defaults to 10.
+    #[serde(default = "default_limit")]
+    #[schema(value_type = u32, minimum = 1)]
+    limit: NonZeroUsize,
+}
+
+#[utoipa::path(
+    post,
+    path = "/search",
+    request_body = SearchRequest,
+    tag = "Search",
+    responses(
+        (status = 200, content_type = "application/json", body = SearchResults, description = "The current entities and ontology types matching the query, ordered by relevance, with the matching words highlighted"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(pool))]
+async fn search<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    Json(SearchRequest { query, limit }): Json<SearchRequest>,
+) -> Result<Json<SearchResults>, StatusCode> {
+    let store = pool.acquire().await.map_err(|report| {
+        tracing::error!(error=?report, "Could not acquire store");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    store
+        .search(&query, limit)
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not search the store");
+            report_to_status_code(&report)
+        })
+        .map(Json)
+}
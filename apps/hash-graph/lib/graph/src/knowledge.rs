@@ -2,5 +2,12 @@
 //!  `ontology` module, i.e you have Ontologies and Knowledge-Graphs
 
 mod query;
+mod validation;
 
-pub use self::query::{EntityQueryPath, EntityQueryPathVisitor, EntityQueryToken};
+pub use self::{
+    query::{EntityQueryPath, EntityQueryPathVisitor, EntityQueryToken},
+    validation::{
+        EntityValidator, Link, LinkValidationError, LinkValidator, PropertyValidationError,
+        PropertyValidationErrorKind,
+    },
+};
@@ -0,0 +1,655 @@
+mod link;
+
+use std::{collections::HashMap, fmt};
+
+use graph_types::knowledge::entity::{EntityProperties, PropertyPath, PropertyPathElement};
+use hash_status::details::FieldViolation;
+use serde::Serialize;
+use serde_json::Value;
+
+pub use self::link::{Link, LinkValidationError, LinkValidator};
+
+/// The reason why a value is not valid with respect to its schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PropertyValidationErrorKind {
+    /// A property listed as `required` is not present.
+    MissingRequiredProperty,
+    /// A property is present, which is not specified by the schema.
+    UnexpectedProperty,
+    /// The JSON type of the value does not match the type expected by the schema.
+    #[serde(rename_all = "camelCase")]
+    InvalidType {
+        expected: String,
+        actual: &'static str,
+    },
+    /// The value does not equal the constant value specified by the data type.
+    #[serde(rename_all = "camelCase")]
+    InvalidConstant { expected: Value },
+    /// An array contains fewer elements than specified by `minItems`.
+    #[serde(rename_all = "camelCase")]
+    TooFewItems { min_items: usize, actual: usize },
+    /// An array contains more elements than specified by `maxItems`.
+    #[serde(rename_all = "camelCase")]
+    TooManyItems { max_items: usize, actual: usize },
+    /// The value is not valid with respect to any of the `oneOf` variants.
+    NoMatchingVariant,
+    /// The schema refers to a type, which could not be resolved.
+    #[serde(rename_all = "camelCase")]
+    UnresolvedReference { url: String },
+}
+
+impl fmt::Display for PropertyValidationErrorKind {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRequiredProperty => fmt.write_str("the property is required"),
+            Self::UnexpectedProperty => fmt.write_str("the property is not allowed by the schema"),
+            Self::InvalidType { expected, actual } => {
+                write!(
+                    fmt,
+                    "expected a value of type `{expected}` but got `{actual}`"
+                )
+            }
+            Self::InvalidConstant { expected } => write!(fmt, "expected the value `{expected}`"),
+            Self::TooFewItems { min_items, actual } => write!(
+                fmt,
+                "expected at least {min_items} items but got {actual} items"
+            ),
+            Self::TooManyItems { max_items, actual } => write!(
+                fmt,
+                "expected at most {max_items} items but got {actual} items"
+            ),
+            Self::NoMatchingVariant => {
+                fmt.write_str("the value does not match any of the possible variants")
+            }
+            Self::UnresolvedReference { url } => write!(fmt, "could not resolve the type `{url}`"),
+        }
+    }
+}
+
+/// A violation of the schema at a specific [`PropertyPath`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyValidationError {
+    pub path: PropertyPath,
+    #[serde(flatten)]
+    pub kind: PropertyValidationErrorKind,
+}
+
+impl fmt::Display for PropertyValidationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "properties{}: {}", self.path, self.kind)
+    }
+}
+
+impl From<&PropertyValidationError> for FieldViolation {
+    fn from(error: &PropertyValidationError) -> Self {
+        Self::new(format!("properties{}", error.path), error.kind.to_string())
+    }
+}
+
+const fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn reference(schema: &Value) -> Option<&str> {
+    schema.get("$ref").and_then(Value::as_str)
+}
+
+fn required(schema: &Value) -> impl Iterator<Item = &str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+}
+
+/// Looks up the type identified by `url` and reports an [`UnresolvedReference`] if it's not found.
+///
+/// [`UnresolvedReference`]: PropertyValidationErrorKind::UnresolvedReference
+fn resolve<'t>(
+    types: &'t HashMap<String, Value>,
+    url: &str,
+    path: &PropertyPath,
+    errors: &mut Vec<PropertyValidationError>,
+) -> Option<&'t Value> {
+    let resolved = types.get(url);
+    if resolved.is_none() {
+        errors.push(PropertyValidationError {
+            path: path.clone(),
+            kind: PropertyValidationErrorKind::UnresolvedReference {
+                url: url.to_owned(),
+            },
+        });
+    }
+    resolved
+}
+
+/// Validates [`EntityProperties`] against the schema of an entity type.
+///
+/// The validator operates on the JSON representation of the types. It has to be provided with the
+/// schema of the entity type itself, all entity types it inherits from, and all property types and
+/// data types which are referenced (directly or indirectly) by these entity types. Types are looked
+/// up by their `$id`.
+///
+/// As entity types are closed, all properties of an entity have to be specified by the entity type
+/// or one of its parents. The `required` properties of all of these types are required as well.
+#[derive(Debug, Default)]
+pub struct EntityValidator {
+    entity_types: Vec<Value>,
+    property_types: HashMap<String, Value>,
+    data_types: HashMap<String, Value>,
+}
+
+impl EntityValidator {
+    #[must_use]
+    pub fn new(
+        entity_types: impl IntoIterator<Item = Value>,
+        property_types: impl IntoIterator<Item = Value>,
+        data_types: impl IntoIterator<Item = Value>,
+    ) -> Self {
+        fn by_id(schemas: impl IntoIterator<Item = Value>) -> HashMap<String, Value> {
+            schemas
+                .into_iter()
+                .filter_map(|schema| {
+                    let id = schema.get("$id")?.as_str()?.to_owned();
+                    Some((id, schema))
+                })
+                .collect()
+        }
+
+        Self {
+            entity_types: entity_types.into_iter().collect(),
+            property_types: by_id(property_types),
+            data_types: by_id(data_types),
+        }
+    }
+
+    /// Validates `properties` and returns every violation of the schema.
+    ///
+    /// An empty list is returned if the properties are valid.
+    #[must_use]
+    pub fn validate_properties(
+        &self,
+        properties: &EntityProperties,
+    ) -> Vec<PropertyValidationError> {
+        let mut errors = Vec::new();
+
+        let property_schemas = self
+            .entity_types
+            .iter()
+            .filter_map(|entity_type| entity_type.get("properties")?.as_object())
+            .flatten()
+            .map(|(base_url, schema)| (base_url.as_str(), schema))
+            .collect::<HashMap<_, _>>();
+
+        for base_url in self.entity_types.iter().flat_map(required) {
+            if !properties
+                .properties()
+                .keys()
+                .any(|property| property.as_str() == base_url)
+            {
+                errors.push(PropertyValidationError {
+                    path: PropertyPath::default()
+                        .join(PropertyPathElement::Property(base_url.to_owned())),
+                    kind: PropertyValidationErrorKind::MissingRequiredProperty,
+                });
+            }
+        }
+
+        for (base_url, value) in properties.properties() {
+            let path = PropertyPath::default()
+                .join(PropertyPathElement::Property(base_url.as_str().to_owned()));
+
+            let Some(schema) = property_schemas.get(base_url.as_str()) else {
+                errors.push(PropertyValidationError {
+                    path,
+                    kind: PropertyValidationErrorKind::UnexpectedProperty,
+                });
+                continue;
+            };
+
+            self.validate_property(schema, value, &path, &mut errors);
+        }
+
+        errors
+    }
+
+    /// Validates a value against a reference to a property type or an array of these.
+    fn validate_property(
+        &self,
+        schema: &Value,
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        if let Some(url) = reference(schema) {
+            if let Some(property_type) = resolve(&self.property_types, url, path, errors) {
+                self.validate_one_of(property_type, value, path, errors);
+            }
+        } else if let Some(items) = schema.get("items") {
+            Self::validate_array(schema, value, path, errors, |index, item, errors| {
+                self.validate_property(items, item, &path.join(index), errors);
+            });
+        }
+    }
+
+    /// Validates a value against the `oneOf` variants of `schema`.
+    ///
+    /// If no variant matches, the errors of the variant are reported if there is only a single
+    /// variant or only a single variant is applicable to the JSON type of the value. Otherwise,
+    /// [`NoMatchingVariant`] is reported.
+    ///
+    /// [`NoMatchingVariant`]: PropertyValidationErrorKind::NoMatchingVariant
+    fn validate_one_of(
+        &self,
+        schema: &Value,
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        let mut variant_errors = Vec::new();
+
+        for variant in schema
+            .get("oneOf")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let mut current_errors = Vec::new();
+            self.validate_property_values(variant, value, path, &mut current_errors);
+
+            if current_errors.is_empty() {
+                return;
+            }
+            variant_errors.push(current_errors);
+        }
+
+        if variant_errors.len() > 1 {
+            variant_errors.retain(|current_errors| {
+                !current_errors.iter().any(|error| {
+                    error.path == *path
+                        && matches!(error.kind, PropertyValidationErrorKind::InvalidType { .. })
+                })
+            });
+        }
+
+        if let [variant_errors] = variant_errors.as_mut_slice() {
+            errors.append(variant_errors);
+        } else {
+            errors.push(PropertyValidationError {
+                path: path.clone(),
+                kind: PropertyValidationErrorKind::NoMatchingVariant,
+            });
+        }
+    }
+
+    /// Validates a value against a single variant of a property type, which is either a reference
+    /// to a data type, a property object, or an array of property values.
+    fn validate_property_values(
+        &self,
+        schema: &Value,
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        if let Some(url) = reference(schema) {
+            if let Some(data_type) = resolve(&self.data_types, url, path, errors) {
+                Self::validate_data_type(data_type, value, path, errors);
+            }
+            return;
+        }
+
+        match schema.get("type").and_then(Value::as_str) {
+            Some("object") => self.validate_object(schema, value, path, errors),
+            Some("array") => {
+                let items = schema.get("items").unwrap_or(&Value::Null);
+                Self::validate_array(schema, value, path, errors, |index, item, errors| {
+                    self.validate_one_of(items, item, &path.join(index), errors);
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_object(
+        &self,
+        schema: &Value,
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        let Some(object) = value.as_object() else {
+            errors.push(PropertyValidationError {
+                path: path.clone(),
+                kind: PropertyValidationErrorKind::InvalidType {
+                    expected: "object".to_owned(),
+                    actual: json_type(value),
+                },
+            });
+            return;
+        };
+
+        let property_schemas = schema.get("properties").and_then(Value::as_object);
+
+        for base_url in required(schema) {
+            if !object.contains_key(base_url) {
+                errors.push(PropertyValidationError {
+                    path: path.join(PropertyPathElement::Property(base_url.to_owned())),
+                    kind: PropertyValidationErrorKind::MissingRequiredProperty,
+                });
+            }
+        }
+
+        for (base_url, value) in object {
+            let path = path.join(PropertyPathElement::Property(base_url.clone()));
+
+            let Some(schema) = property_schemas.and_then(|schemas| schemas.get(base_url)) else {
+                errors.push(PropertyValidationError {
+                    path,
+                    kind: PropertyValidationErrorKind::UnexpectedProperty,
+                });
+                continue;
+            };
+
+            self.validate_property(schema, value, &path, errors);
+        }
+    }
+
+    /// Checks that `value` is an array, which respects `minItems` and `maxItems` of `schema`, and
+    /// validates each item using `validate_item`.
+    fn validate_array(
+        schema: &Value,
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+        mut validate_item: impl FnMut(PropertyPathElement, &Value, &mut Vec<PropertyValidationError>),
+    ) {
+        let Some(array) = value.as_array() else {
+            errors.push(PropertyValidationError {
+                path: path.clone(),
+                kind: PropertyValidationErrorKind::InvalidType {
+                    expected: "array".to_owned(),
+                    actual: json_type(value),
+                },
+            });
+            return;
+        };
+
+        let bound = |keyword| {
+            schema
+                .get(keyword)
+                .and_then(Value::as_u64)
+                .and_then(|bound| usize::try_from(bound).ok())
+        };
+
+        if let Some(min_items) = bound("minItems") {
+            if array.len() < min_items {
+                errors.push(PropertyValidationError {
+                    path: path.clone(),
+                    kind: PropertyValidationErrorKind::TooFewItems {
+                        min_items,
+                        actual: array.len(),
+                    },
+                });
+            }
+        }
+        if let Some(max_items) = bound("maxItems") {
+            if array.len() > max_items {
+                errors.push(PropertyValidationError {
+                    path: path.clone(),
+                    kind: PropertyValidationErrorKind::TooManyItems {
+                        max_items,
+                        actual: array.len(),
+                    },
+                });
+            }
+        }
+
+        for (index, item) in array.iter().enumerate() {
+            validate_item(PropertyPathElement::Index(index), item, errors);
+        }
+    }
+
+    fn validate_data_type(
+        schema: &Value,
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+            let actual = json_type(value);
+            if expected != actual {
+                errors.push(PropertyValidationError {
+                    path: path.clone(),
+                    kind: PropertyValidationErrorKind::InvalidType {
+                        expected: expected.to_owned(),
+                        actual,
+                    },
+                });
+                return;
+            }
+        }
+
+        if let Some(expected) = schema.get("const") {
+            if expected != value {
+                errors.push(PropertyValidationError {
+                    path: path.clone(),
+                    kind: PropertyValidationErrorKind::InvalidConstant {
+                        expected: expected.clone(),
+                    },
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graph_test_data::{data_type, property_type};
+    use serde_json::json;
+
+    use super::*;
+
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const AGE: &str = "https://blockprotocol.org/@alice/types/property-type/age/";
+    const CONTACT_INFORMATION: &str =
+        "https://blockprotocol.org/@alice/types/property-type/contact-information/";
+    const EMAIL: &str = "https://blockprotocol.org/@alice/types/property-type/email/";
+    const CONTRIVED_PROPERTY: &str =
+        "https://blockprotocol.org/@alice/types/property-type/contrived-property/";
+
+    fn validator() -> EntityValidator {
+        let parent = json!({
+            "kind": "entityType",
+            "$id": "https://example.com/entity-type/parent/v/1",
+            "type": "object",
+            "title": "Parent",
+            "properties": {
+                AGE: { "$ref": format!("{AGE}v/1") },
+            },
+            "required": [AGE],
+        });
+        let child = json!({
+            "kind": "entityType",
+            "$id": "https://example.com/entity-type/child/v/1",
+            "type": "object",
+            "title": "Child",
+            "allOf": [{ "$ref": "https://example.com/entity-type/parent/v/1" }],
+            "properties": {
+                NAME: {
+                    "type": "array",
+                    "items": { "$ref": format!("{NAME}v/1") },
+                    "minItems": 1,
+                    "maxItems": 2,
+                },
+                CONTACT_INFORMATION: { "$ref": format!("{CONTACT_INFORMATION}v/1") },
+                CONTRIVED_PROPERTY: { "$ref": format!("{CONTRIVED_PROPERTY}v/1") },
+            },
+        });
+
+        let parse = |schema: &str| serde_json::from_str::<Value>(schema).expect("invalid schema");
+
+        EntityValidator::new(
+            [child, parent],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::CONTACT_INFORMATION_V1,
+                property_type::EMAIL_V1,
+                property_type::PHONE_NUMBER_V1,
+                property_type::CONTRIVED_PROPERTY_V1,
+            ]
+            .map(parse),
+            [data_type::TEXT_V1, data_type::NUMBER_V1].map(parse),
+        )
+    }
+
+    fn validate(properties: Value) -> Vec<PropertyValidationError> {
+        validator().validate_properties(
+            &serde_json::from_value(properties).expect("invalid entity properties"),
+        )
+    }
+
+    fn path(elements: impl IntoIterator<Item = PropertyPathElement>) -> PropertyPath {
+        elements.into_iter().collect()
+    }
+
+    fn property(base_url: &str) -> PropertyPathElement {
+        PropertyPathElement::Property(base_url.to_owned())
+    }
+
+    #[test]
+    fn valid_properties() {
+        assert_eq!(
+            validate(json!({
+                AGE: 42,
+                NAME: ["Alice", "Ally"],
+                CONTACT_INFORMATION: { EMAIL: "alice@example.com" },
+                CONTRIVED_PROPERTY: [1, 2, 3],
+            })),
+            []
+        );
+        assert_eq!(validate(json!({ AGE: 42, CONTRIVED_PROPERTY: 5 })), []);
+    }
+
+    #[test]
+    fn inherited_required_property() {
+        assert_eq!(validate(json!({ NAME: ["Alice"] })), [
+            PropertyValidationError {
+                path: path([property(AGE)]),
+                kind: PropertyValidationErrorKind::MissingRequiredProperty,
+            }
+        ]);
+    }
+
+    #[test]
+    fn unexpected_property() {
+        assert_eq!(validate(json!({ AGE: 42, EMAIL: "alice@example.com" })), [
+            PropertyValidationError {
+                path: path([property(EMAIL)]),
+                kind: PropertyValidationErrorKind::UnexpectedProperty,
+            }
+        ]);
+    }
+
+    #[test]
+    fn invalid_type() {
+        assert_eq!(
+            validate(json!({ AGE: "42", NAME: ["Alice", 5] })).len(),
+            2,
+            "expected one error for each invalid value"
+        );
+        assert_eq!(validate(json!({ AGE: 42, NAME: ["Alice", 5] })), [
+            PropertyValidationError {
+                path: path([property(NAME), PropertyPathElement::Index(1)]),
+                kind: PropertyValidationErrorKind::InvalidType {
+                    expected: "string".to_owned(),
+                    actual: "number",
+                },
+            }
+        ]);
+    }
+
+    #[test]
+    fn nested_object() {
+        assert_eq!(validate(json!({ AGE: 42, CONTACT_INFORMATION: {} })), [
+            PropertyValidationError {
+                path: path([property(CONTACT_INFORMATION), property(EMAIL)]),
+                kind: PropertyValidationErrorKind::MissingRequiredProperty,
+            }
+        ]);
+    }
+
+    #[test]
+    fn array_bounds() {
+        assert_eq!(validate(json!({ AGE: 42, NAME: [] })), [
+            PropertyValidationError {
+                path: path([property(NAME)]),
+                kind: PropertyValidationErrorKind::TooFewItems {
+                    min_items: 1,
+                    actual: 0,
+                },
+            }
+        ]);
+        assert_eq!(
+            validate(json!({ AGE: 42, CONTRIVED_PROPERTY: [1, 2, 3, 4, 5] })),
+            [PropertyValidationError {
+                path: path([property(CONTRIVED_PROPERTY)]),
+                kind: PropertyValidationErrorKind::TooManyItems {
+                    max_items: 4,
+                    actual: 5,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn no_matching_variant() {
+        assert_eq!(validate(json!({ AGE: 42, CONTRIVED_PROPERTY: "five" })), [
+            PropertyValidationError {
+                path: path([property(CONTRIVED_PROPERTY)]),
+                kind: PropertyValidationErrorKind::NoMatchingVariant,
+            }
+        ]);
+    }
+
+    #[test]
+    fn display() {
+        let error = PropertyValidationError {
+            path: path([property(NAME), PropertyPathElement::Index(0)]),
+            kind: PropertyValidationErrorKind::TooFewItems {
+                min_items: 1,
+                actual: 0,
+            },
+        };
+
+        assert_eq!(
+            error.to_string(),
+            format!("properties[\"{NAME}\"][0]: expected at least 1 items but got 0 items")
+        );
+    }
+
+    #[test]
+    fn field_violation() {
+        let [error] = validate(json!({ AGE: 42, NAME: [] }))
+            .try_into()
+            .expect("one error");
+
+        assert_eq!(
+            FieldViolation::from(&error),
+            FieldViolation::new(
+                format!("properties[\"{NAME}\"]"),
+                "expected at least 1 items but got 0 items"
+            )
+        );
+    }
+}
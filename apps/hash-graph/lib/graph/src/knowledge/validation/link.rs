@@ -0,0 +1,413 @@
+use std::fmt;
+
+use graph_types::knowledge::entity::EntityId;
+use hash_status::details::FieldViolation;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The reason why a link entity is not valid with respect to the entity type of its left entity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum LinkValidationError {
+    /// The entity at one end of the link does not exist or is archived.
+    #[serde(rename_all = "camelCase")]
+    MissingEntity { entity_id: EntityId },
+    /// The entity type of the left entity does not allow links of this type.
+    #[serde(rename_all = "camelCase")]
+    LinkNotAllowed { link_entity_type_id: String },
+    /// The right entity is not of any entity type allowed as destination of the link.
+    #[serde(rename_all = "camelCase")]
+    InvalidDestination {
+        link_entity_type_id: String,
+        expected: Vec<String>,
+    },
+    /// The link specifies an order, but links of this type are not ordered.
+    #[serde(rename_all = "camelCase")]
+    UnorderedLink { link_entity_type_id: String },
+    /// The left entity would have fewer links of this type than specified by `minItems`.
+    #[serde(rename_all = "camelCase")]
+    TooFewLinks {
+        link_entity_type_id: String,
+        min_items: usize,
+        actual: usize,
+    },
+    /// The left entity would have more links of this type than specified by `maxItems`.
+    #[serde(rename_all = "camelCase")]
+    TooManyLinks {
+        link_entity_type_id: String,
+        max_items: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for LinkValidationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEntity { entity_id } => {
+                write!(fmt, "the linked entity `{entity_id}` does not exist")
+            }
+            Self::LinkNotAllowed {
+                link_entity_type_id,
+            } => write!(
+                fmt,
+                "links of type `{link_entity_type_id}` are not allowed on the left entity"
+            ),
+            Self::InvalidDestination {
+                link_entity_type_id,
+                expected,
+            } => write!(
+                fmt,
+                "links of type `{link_entity_type_id}` have to point to an entity of type `{}`",
+                expected.join("`, `")
+            ),
+            Self::UnorderedLink {
+                link_entity_type_id,
+            } => write!(
+                fmt,
+                "links of type `{link_entity_type_id}` are unordered but an order was specified"
+            ),
+            Self::TooFewLinks {
+                link_entity_type_id,
+                min_items,
+                actual,
+            } => write!(
+                fmt,
+                "expected at least {min_items} links of type `{link_entity_type_id}` but got \
+                 {actual} links"
+            ),
+            Self::TooManyLinks {
+                link_entity_type_id,
+                max_items,
+                actual,
+            } => write!(
+                fmt,
+                "expected at most {max_items} links of type `{link_entity_type_id}` but got \
+                 {actual} links"
+            ),
+        }
+    }
+}
+
+/// Link violations are reported for the link data as a whole.
+impl From<&LinkValidationError> for FieldViolation {
+    fn from(error: &LinkValidationError) -> Self {
+        Self::new("linkData", error.to_string())
+    }
+}
+
+/// A link entity to be validated by a [`LinkValidator`].
+///
+/// Entity types are identified by their `$id`. The entity types of the link entity and the right
+/// entity include all entity types they inherit from, so a link (or destination) of an entity type
+/// inheriting from an allowed entity type is allowed as well.
+#[derive(Debug)]
+pub struct Link<'a> {
+    /// The entity types of the link entity, starting with the type of the entity itself.
+    pub link_entity_types: &'a [String],
+    /// The entity types of the right entity.
+    pub right_entity_types: &'a [String],
+    /// `true`, if an order was specified for the link relative to the other outgoing links of the
+    /// left entity.
+    pub ordered: bool,
+    /// `true`, if the link entity is archived.
+    pub archived: bool,
+    /// The number of other, non-archived links of the same type originating from the left entity.
+    pub other_links: usize,
+}
+
+/// Validates link entities against the `links` of the entity type of their left entity.
+///
+/// The validator has to be provided with the schema of the entity type of the left entity and all
+/// entity types it inherits from.
+///
+/// As links are only added or removed one at a time, `minItems` is only checked when a link is
+/// archived and `maxItems` only when a non-archived link is added.
+#[derive(Debug, Default)]
+pub struct LinkValidator {
+    left_entity_types: Vec<Value>,
+}
+
+impl LinkValidator {
+    #[must_use]
+    pub fn new(left_entity_types: impl IntoIterator<Item = Value>) -> Self {
+        Self {
+            left_entity_types: left_entity_types.into_iter().collect(),
+        }
+    }
+
+    fn constraint<'s>(&'s self, link_entity_types: &[String]) -> Option<(&'s str, &'s Value)> {
+        link_entity_types.iter().find_map(|link_entity_type| {
+            self.left_entity_types.iter().find_map(|entity_type| {
+                let (id, schema) = entity_type
+                    .get("links")?
+                    .as_object()?
+                    .get_key_value(link_entity_type)?;
+                Some((id.as_str(), schema))
+            })
+        })
+    }
+
+    /// Returns the entity type, which the link is constrained by in the entity type of the left
+    /// entity.
+    ///
+    /// This is either the entity type of the link entity or one of its parents. Returns [`None`]
+    /// if links of this type are not allowed at all.
+    #[must_use]
+    pub fn link_entity_type_id(&self, link_entity_types: &[String]) -> Option<&str> {
+        self.constraint(link_entity_types).map(|(id, _)| id)
+    }
+
+    /// Validates `link` and returns every violation of the constraints.
+    ///
+    /// An empty list is returned if the link is valid.
+    #[must_use]
+    pub fn validate_link(&self, link: &Link<'_>) -> Vec<LinkValidationError> {
+        let Some((link_entity_type_id, schema)) = self.constraint(link.link_entity_types) else {
+            return vec![LinkValidationError::LinkNotAllowed {
+                link_entity_type_id: link.link_entity_types.first().cloned().unwrap_or_default(),
+            }];
+        };
+
+        let mut errors = Vec::new();
+
+        // If `oneOf` is not specified, the link may point to an entity of any type
+        if let Some(destinations) = schema
+            .get("items")
+            .and_then(|items| items.get("oneOf"))
+            .and_then(Value::as_array)
+        {
+            let expected = destinations
+                .iter()
+                .filter_map(|destination| destination.get("$ref")?.as_str())
+                .map(ToOwned::to_owned)
+                .collect::<Vec<_>>();
+
+            if !expected
+                .iter()
+                .any(|destination| link.right_entity_types.contains(destination))
+            {
+                errors.push(LinkValidationError::InvalidDestination {
+                    link_entity_type_id: link_entity_type_id.to_owned(),
+                    expected,
+                });
+            }
+        }
+
+        if link.ordered
+            && !schema
+                .get("ordered")
+                .and_then(Value::as_bool)
+                .unwrap_or(false)
+        {
+            errors.push(LinkValidationError::UnorderedLink {
+                link_entity_type_id: link_entity_type_id.to_owned(),
+            });
+        }
+
+        let bound = |keyword| {
+            schema
+                .get(keyword)
+                .and_then(Value::as_u64)
+                .and_then(|bound| usize::try_from(bound).ok())
+        };
+
+        if link.archived {
+            if let Some(min_items) = bound("minItems") {
+                if link.other_links < min_items {
+                    errors.push(LinkValidationError::TooFewLinks {
+                        link_entity_type_id: link_entity_type_id.to_owned(),
+                        min_items,
+                        actual: link.other_links,
+                    });
+                }
+            }
+        } else if let Some(max_items) = bound("maxItems") {
+            if link.other_links + 1 > max_items {
+                errors.push(LinkValidationError::TooManyLinks {
+                    link_entity_type_id: link_entity_type_id.to_owned(),
+                    max_items,
+                    actual: link.other_links + 1,
+                });
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graph_test_data::entity_type;
+    use serde_json::json;
+
+    use super::*;
+
+    const FRIEND_OF: &str = "https://blockprotocol.org/@alice/types/entity-type/friend-of/v/1";
+    const ACQUAINTANCE_OF: &str =
+        "https://blockprotocol.org/@alice/types/entity-type/acquaintance-of/v/1";
+    const LINK: &str = "https://blockprotocol.org/@blockprotocol/types/entity-type/link/v/1";
+    const PERSON: &str = "https://blockprotocol.org/@alice/types/entity-type/person/v/1";
+    const BLOCK: &str = "https://blockprotocol.org/@alice/types/entity-type/block/v/1";
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|&id| id.to_owned()).collect()
+    }
+
+    fn person() -> LinkValidator {
+        LinkValidator::new([
+            serde_json::from_str(entity_type::PERSON_V1).expect("invalid entity type")
+        ])
+    }
+
+    #[test]
+    fn valid_link() {
+        let link_entity_types = ids(&[FRIEND_OF, LINK]);
+        let right_entity_types = ids(&[PERSON]);
+
+        assert_eq!(
+            person().validate_link(&Link {
+                link_entity_types: &link_entity_types,
+                right_entity_types: &right_entity_types,
+                ordered: false,
+                archived: false,
+                other_links: 3,
+            }),
+            []
+        );
+        assert_eq!(
+            person().link_entity_type_id(&link_entity_types),
+            Some(FRIEND_OF)
+        );
+    }
+
+    #[test]
+    fn inherited_link() {
+        let parent = json!({
+            "kind": "entityType",
+            "$id": "https://example.com/entity-type/parent/v/1",
+            "type": "object",
+            "title": "Parent",
+            "properties": {},
+            "links": {
+                LINK: { "type": "array", "items": {} },
+            },
+        });
+        let validator = LinkValidator::new([
+            json!({
+                "kind": "entityType",
+                "$id": "https://example.com/entity-type/child/v/1",
+                "type": "object",
+                "title": "Child",
+                "allOf": [{ "$ref": "https://example.com/entity-type/parent/v/1" }],
+                "properties": {},
+            }),
+            parent,
+        ]);
+
+        let link_entity_types = ids(&[ACQUAINTANCE_OF, LINK]);
+        let right_entity_types = ids(&[BLOCK]);
+
+        assert_eq!(
+            validator.validate_link(&Link {
+                link_entity_types: &link_entity_types,
+                right_entity_types: &right_entity_types,
+                ordered: false,
+                archived: false,
+                other_links: 0,
+            }),
+            []
+        );
+        assert_eq!(
+            validator.link_entity_type_id(&link_entity_types),
+            Some(LINK)
+        );
+    }
+
+    #[test]
+    fn link_not_allowed() {
+        let link_entity_types = ids(&[ACQUAINTANCE_OF, LINK]);
+        let right_entity_types = ids(&[PERSON]);
+
+        assert_eq!(
+            person().validate_link(&Link {
+                link_entity_types: &link_entity_types,
+                right_entity_types: &right_entity_types,
+                ordered: false,
+                archived: false,
+                other_links: 0,
+            }),
+            [LinkValidationError::LinkNotAllowed {
+                link_entity_type_id: ACQUAINTANCE_OF.to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_destination() {
+        let link_entity_types = ids(&[FRIEND_OF, LINK]);
+        let right_entity_types = ids(&[BLOCK]);
+
+        assert_eq!(
+            person().validate_link(&Link {
+                link_entity_types: &link_entity_types,
+                right_entity_types: &right_entity_types,
+                ordered: true,
+                archived: false,
+                other_links: 0,
+            }),
+            [
+                LinkValidationError::InvalidDestination {
+                    link_entity_type_id: FRIEND_OF.to_owned(),
+                    expected: ids(&[PERSON]),
+                },
+                LinkValidationError::UnorderedLink {
+                    link_entity_type_id: FRIEND_OF.to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn cardinality() {
+        let validator = LinkValidator::new([json!({
+            "kind": "entityType",
+            "$id": "https://example.com/entity-type/person/v/1",
+            "type": "object",
+            "title": "Person",
+            "properties": {},
+            "links": {
+                FRIEND_OF: {
+                    "type": "array",
+                    "items": { "oneOf": [{ "$ref": PERSON }] },
+                    "minItems": 1,
+                    "maxItems": 2,
+                },
+            },
+        })]);
+        let link_entity_types = ids(&[FRIEND_OF, LINK]);
+        let right_entity_types = ids(&[PERSON]);
+
+        let validate = |archived, other_links| {
+            validator.validate_link(&Link {
+                link_entity_types: &link_entity_types,
+                right_entity_types: &right_entity_types,
+                ordered: false,
+                archived,
+                other_links,
+            })
+        };
+
+        assert_eq!(validate(false, 1), []);
+        assert_eq!(validate(false, 2), [LinkValidationError::TooManyLinks {
+            link_entity_type_id: FRIEND_OF.to_owned(),
+            max_items: 2,
+            actual: 3,
+        }]);
+        assert_eq!(validate(true, 1), []);
+        assert_eq!(validate(true, 0), [LinkValidationError::TooFewLinks {
+            link_entity_type_id: FRIEND_OF.to_owned(),
+            min_items: 1,
+            actual: 0,
+        }]);
+    }
+}
@@ -35,6 +35,7 @@ impl<C: AsClient> WriteBatch<C> for EntityRowBatch {
                         left_to_right_order INTEGER,
                         right_to_left_order INTEGER,
                         record_created_by_id UUID NOT NULL,
+                        record_archived_by_id UUID,
                         archived BOOLEAN NOT NULL,
                         entity_type_base_url TEXT NOT NULL,
                         entity_type_version INT8 NOT NULL
@@ -151,7 +152,8 @@ impl<C: AsClient> WriteBatch<C> for EntityRowBatch {
                             left_to_right_order INT4,
                             right_to_left_order INT4,
                             record_created_by_id UUID,
-                            archived BOOLEAN
+                            archived BOOLEAN,
+                            record_archived_by_id UUID
                         FROM entity_editions_tmp;
 
                     INSERT INTO entity_temporal_metadata SELECT * FROM entity_temporal_metadata_tmp;
@@ -9,7 +9,10 @@ use futures::{
     stream::{select_all, BoxStream, SelectAll},
     Sink, SinkExt, Stream, StreamExt,
 };
-use graph_types::{ontology::OntologyTypeVersion, provenance::RecordCreatedById};
+use graph_types::{
+    ontology::OntologyTypeVersion,
+    provenance::{RecordArchivedById, RecordCreatedById},
+};
 use temporal_versioning::{
     ClosedTemporalBound, LeftClosedTemporalInterval, OpenTemporalBound, Timestamp,
 };
@@ -83,6 +86,31 @@ impl Sink<EntitySnapshotRecord> for EntitySender {
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not send entity id")?;
 
+        let record_created_by_id = entity.metadata.custom.provenance.map_or_else(
+            || {
+                RecordCreatedById::new(
+                    entity
+                        .metadata
+                        .record_id
+                        .entity_id
+                        .owned_by_id
+                        .as_account_id(),
+                )
+            },
+            |provenance| provenance.record_created_by_id,
+        );
+        let archived = entity.metadata.custom.archived.unwrap_or(false);
+        // Archived editions without an archiving account are considered to be archived by their
+        // creator, the same way as existing editions were migrated.
+        let record_archived_by_id = archived.then(|| {
+            entity
+                .metadata
+                .custom
+                .provenance
+                .and_then(|provenance| provenance.record_archived_by_id)
+                .unwrap_or_else(|| RecordArchivedById::new(record_created_by_id.as_account_id()))
+        });
+
         self.edition
             .start_send_unpin(EntityEditionRow {
                 entity_edition_id: entity.metadata.record_id.edition_id,
@@ -93,20 +121,9 @@ impl Sink<EntitySnapshotRecord> for EntitySender {
                 right_to_left_order: entity
                     .link_data
                     .and_then(|link_data| link_data.order.right_to_left),
-                record_created_by_id: entity.metadata.custom.provenance.map_or_else(
-                    || {
-                        RecordCreatedById::new(
-                            entity
-                                .metadata
-                                .record_id
-                                .entity_id
-                                .owned_by_id
-                                .as_account_id(),
-                        )
-                    },
-                    |p| p.record_created_by_id,
-                ),
-                archived: entity.metadata.custom.archived.unwrap_or(false),
+                record_created_by_id,
+                record_archived_by_id,
+                archived,
                 entity_type_base_url: entity.metadata.entity_type_id.base_url.as_str().to_owned(),
                 entity_type_version: OntologyTypeVersion::new(
                     entity.metadata.entity_type_id.version,
@@ -4,7 +4,7 @@ use graph_types::{
         link::LinkOrder,
     },
     ontology::OntologyTypeVersion,
-    provenance::{OwnedById, RecordCreatedById},
+    provenance::{OwnedById, RecordArchivedById, RecordCreatedById},
 };
 use postgres_types::ToSql;
 use temporal_versioning::{DecisionTime, LeftClosedTemporalInterval, TransactionTime};
@@ -24,6 +24,7 @@ pub struct EntityEditionRow {
     pub left_to_right_order: Option<LinkOrder>,
     pub right_to_left_order: Option<LinkOrder>,
     pub record_created_by_id: RecordCreatedById,
+    pub record_archived_by_id: Option<RecordArchivedById>,
     pub archived: bool,
     pub entity_type_base_url: String,
     pub entity_type_version: OntologyTypeVersion,
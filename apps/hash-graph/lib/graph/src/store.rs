@@ -3,12 +3,14 @@ pub mod error;
 pub mod query;
 
 mod account;
+mod change;
 mod config;
 mod knowledge;
 mod migration;
 mod ontology;
 mod pool;
 mod record;
+mod search;
 
 mod fetcher;
 mod postgres;
@@ -17,18 +19,23 @@ use async_trait::async_trait;
 
 pub use self::{
     account::AccountStore,
+    change::{ChangeEvent, ChangeEventOffset, ChangeOperation, ChangeStore, ChangedRecord},
     config::{DatabaseConnectionInfo, DatabaseType},
     error::{
         BaseUrlAlreadyExists, InsertionError, OntologyVersionDoesNotExist, QueryError, StoreError,
         UpdateError,
     },
     fetcher::{FetchingPool, TypeFetcher},
-    knowledge::EntityStore,
+    knowledge::{
+        BatchMode, BatchOperationIndex, EntityBatchLinkData, EntityBatchOperation,
+        EntityBatchReference, EntityStore,
+    },
     migration::{Migration, MigrationState, StoreMigration},
     ontology::{DataTypeStore, EntityTypeStore, PropertyTypeStore},
     pool::StorePool,
     postgres::{AsClient, PostgresStore, PostgresStorePool},
     record::Record,
+    search::{EntitySearchResult, OntologyTypeSearchResult, SearchResults, SearchStore},
 };
 
 /// Describes the API of a store implementation.
@@ -39,11 +46,23 @@ pub use self::{
 /// raised depending on the implementation, e.g. connection issues.
 #[async_trait]
 pub trait Store:
-    AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore
+    AccountStore
+    + DataTypeStore
+    + PropertyTypeStore
+    + EntityTypeStore
+    + EntityStore
+    + SearchStore
+    + ChangeStore
 {
 }
 impl<S> Store for S where
-    S: AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore
+    S: AccountStore
+        + DataTypeStore
+        + PropertyTypeStore
+        + EntityTypeStore
+        + EntityStore
+        + SearchStore
+        + ChangeStore
 {
 }
 
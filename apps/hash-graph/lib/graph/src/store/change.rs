@@ -0,0 +1,100 @@
+use std::num::NonZeroUsize;
+
+use async_trait::async_trait;
+use error_stack::Result;
+use graph_types::{
+    knowledge::entity::EntityRecordId, ontology::OntologyTypeRecordId,
+    provenance::ProvenanceMetadata,
+};
+use postgres_types::FromSql;
+use serde::{Deserialize, Serialize};
+use temporal_versioning::{Timestamp, TransactionTime};
+use utoipa::ToSchema;
+
+use crate::store::QueryError;
+
+/// The position of a [`ChangeEvent`] in the stream of changes.
+///
+/// Offsets are strictly increasing in the order the changes were committed, but they are not
+/// necessarily consecutive.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema,
+)]
+#[repr(transparent)]
+pub struct ChangeEventOffset(i64);
+
+impl ChangeEventOffset {
+    #[must_use]
+    pub const fn new(offset: i64) -> Self {
+        Self(offset)
+    }
+
+    #[must_use]
+    pub const fn as_i64(self) -> i64 {
+        self.0
+    }
+}
+
+/// The kind of mutation a [`ChangeEvent`] is describing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, FromSql)]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "change_operation")]
+pub enum ChangeOperation {
+    #[postgres(name = "created")]
+    Created,
+    #[postgres(name = "updated")]
+    Updated,
+    #[postgres(name = "archived")]
+    Archived,
+    #[postgres(name = "unarchived")]
+    Unarchived,
+}
+
+/// The record which was changed by a mutation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ChangedRecord {
+    #[schema(title = "ChangedEntity")]
+    #[serde(rename_all = "camelCase")]
+    Entity { record_id: EntityRecordId },
+    #[schema(title = "ChangedDataType")]
+    #[serde(rename_all = "camelCase")]
+    DataType { record_id: OntologyTypeRecordId },
+    #[schema(title = "ChangedPropertyType")]
+    #[serde(rename_all = "camelCase")]
+    PropertyType { record_id: OntologyTypeRecordId },
+    #[schema(title = "ChangedEntityType")]
+    #[serde(rename_all = "camelCase")]
+    EntityType { record_id: OntologyTypeRecordId },
+}
+
+/// A committed mutation of the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeEvent {
+    pub offset: ChangeEventOffset,
+    pub operation: ChangeOperation,
+    pub record: ChangedRecord,
+    /// The transaction time at which the change became effective.
+    pub transaction_time: Timestamp<TransactionTime>,
+    pub provenance: ProvenanceMetadata,
+}
+
+/// Describes the API of a store implementation for reading the changes of the graph.
+#[async_trait]
+pub trait ChangeStore {
+    /// Reads the committed changes ordered by their offset, starting directly after `after` or
+    /// from the first change if `after` is `None`.
+    ///
+    /// At most `limit` changes are returned. Passing the offset of the last returned change as
+    /// `after` resumes reading where the previous call stopped.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the changes failed
+    async fn read_change_events(
+        &self,
+        after: Option<ChangeEventOffset>,
+        limit: NonZeroUsize,
+    ) -> Result<Vec<ChangeEvent>, QueryError>;
+}
@@ -85,6 +85,18 @@ impl fmt::Display for EntityDoesNotExist {
 
 impl Context for EntityDoesNotExist {}
 
+#[derive(Debug)]
+#[must_use]
+pub struct EntityEditionDoesNotExist;
+
+impl fmt::Display for EntityEditionDoesNotExist {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("Entity edition does not exist")
+    }
+}
+
+impl Context for EntityEditionDoesNotExist {}
+
 #[derive(Debug)]
 #[must_use]
 pub struct RaceConditionOnUpdate;
@@ -97,6 +109,18 @@ impl fmt::Display for RaceConditionOnUpdate {
 
 impl Context for RaceConditionOnUpdate {}
 
+#[derive(Debug)]
+#[must_use]
+pub struct EntityValidationError;
+
+impl fmt::Display for EntityValidationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The entity is not valid with respect to its entity type")
+    }
+}
+
+impl Context for EntityValidationError {}
+
 #[derive(Debug)]
 #[must_use]
 pub struct VersionedUrlAlreadyExists;
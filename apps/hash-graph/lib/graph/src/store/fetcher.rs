@@ -2,6 +2,7 @@ use std::{
     collections::{HashMap, HashSet},
     iter::once,
     mem,
+    num::NonZeroUsize,
 };
 
 use async_trait::async_trait;
@@ -9,7 +10,10 @@ use error_stack::{Report, Result, ResultExt};
 use graph_types::{
     account::AccountId,
     knowledge::{
-        entity::{Entity, EntityId, EntityMetadata, EntityProperties, EntityUuid},
+        entity::{
+            Entity, EntityEditionId, EntityId, EntityMetadata, EntityProperties, EntityUuid,
+            PropertyDiff,
+        },
         link::{EntityLinkOrder, LinkData},
     },
     ontology::{
@@ -34,9 +38,11 @@ use crate::{
     ontology::domain_validator::DomainValidator,
     store::{
         crud::Read,
-        query::{Filter, OntologyQueryPath},
-        AccountStore, ConflictBehavior, DataTypeStore, EntityStore, EntityTypeStore,
-        InsertionError, PropertyTypeStore, QueryError, Record, StoreError, StorePool, UpdateError,
+        query::{AggregationGroup, AggregationQuery, Cursor, Filter, OntologyQueryPath},
+        AccountStore, BatchMode, ChangeEvent, ChangeEventOffset, ChangeStore, ConflictBehavior,
+        DataTypeStore, EntityBatchOperation, EntityStore, EntityTypeStore, InsertionError,
+        PropertyTypeStore, QueryError, Record, SearchResults, SearchStore, StoreError, StorePool,
+        UpdateError,
     },
     subgraph::{
         edges::GraphResolveDepths,
@@ -191,6 +197,9 @@ where
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             }
         }
 
@@ -547,6 +556,32 @@ where
     }
 }
 
+#[async_trait]
+impl<S, A> SearchStore for FetchingStore<S, A>
+where
+    S: SearchStore + Send + Sync,
+    A: Send + Sync,
+{
+    async fn search(&self, query: &str, limit: NonZeroUsize) -> Result<SearchResults, QueryError> {
+        self.store.search(query, limit).await
+    }
+}
+
+#[async_trait]
+impl<S, A> ChangeStore for FetchingStore<S, A>
+where
+    S: ChangeStore + Send + Sync,
+    A: Send + Sync,
+{
+    async fn read_change_events(
+        &self,
+        after: Option<ChangeEventOffset>,
+        limit: NonZeroUsize,
+    ) -> Result<Vec<ChangeEvent>, QueryError> {
+        self.store.read_change_events(after, limit).await
+    }
+}
+
 #[async_trait]
 impl<S, A> DataTypeStore for FetchingStore<S, A>
 where
@@ -809,10 +844,36 @@ where
             .await
     }
 
-    async fn get_entity(&self, query: &StructuralQuery<Entity>) -> Result<Subgraph, QueryError> {
+    async fn get_entity(
+        &self,
+        query: &StructuralQuery<Entity>,
+    ) -> Result<(Subgraph, Option<Cursor>), QueryError> {
         self.store.get_entity(query).await
     }
 
+    async fn aggregate_entities(
+        &self,
+        query: &AggregationQuery<'_, Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        self.store.aggregate_entities(query).await
+    }
+
+    async fn get_entity_history(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityMetadata>, QueryError> {
+        self.store.get_entity_history(entity_id).await
+    }
+
+    async fn diff_entity_editions(
+        &self,
+        entity_id: EntityId,
+        old: EntityEditionId,
+        new: EntityEditionId,
+    ) -> Result<Vec<PropertyDiff>, QueryError> {
+        self.store.diff_entity_editions(entity_id, old, new).await
+    }
+
     async fn update_entity(
         &mut self,
         entity_id: EntityId,
@@ -845,4 +906,43 @@ where
             )
             .await
     }
+
+    async fn archive_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: RecordArchivedById,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.store.archive_entity(entity_id, actor_id).await
+    }
+
+    async fn unarchive_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: RecordCreatedById,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.store.unarchive_entity(entity_id, actor_id).await
+    }
+
+    async fn batch_entities(
+        &mut self,
+        operations: Vec<EntityBatchOperation>,
+        actor_id: RecordCreatedById,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<EntityMetadata, UpdateError>>, UpdateError> {
+        for operation in &operations {
+            let (EntityBatchOperation::Create { entity_type_id, .. }
+            | EntityBatchOperation::Update { entity_type_id, .. }) = operation;
+            let entity_type_reference = EntityTypeReference::new(entity_type_id.clone());
+            self.insert_external_types_by_reference(
+                OntologyTypeReference::EntityTypeReference(&entity_type_reference),
+                actor_id,
+                ConflictBehavior::Skip,
+                FetchBehavior::ExcludeProvidedReferences,
+            )
+            .await
+            .change_context(UpdateError)?;
+        }
+
+        self.store.batch_entities(operations, actor_id, mode).await
+    }
 }
@@ -1,20 +1,157 @@
+use std::fmt;
+
 use async_trait::async_trait;
-use error_stack::Result;
+use error_stack::{Report, Result, ResultExt};
 use graph_types::{
     knowledge::{
-        entity::{Entity, EntityId, EntityMetadata, EntityProperties, EntityUuid},
+        entity::{
+            Entity, EntityEditionId, EntityId, EntityMetadata, EntityProperties, EntityUuid,
+            PropertyDiff,
+        },
         link::{EntityLinkOrder, LinkData},
     },
-    provenance::{OwnedById, RecordCreatedById},
+    provenance::{OwnedById, RecordArchivedById, RecordCreatedById},
 };
+use serde::Deserialize;
 use temporal_versioning::{DecisionTime, Timestamp};
 use type_system::url::VersionedUrl;
+use utoipa::ToSchema;
 
 use crate::{
-    store::{crud, InsertionError, QueryError, UpdateError},
+    store::{
+        crud,
+        error::EntityDoesNotExist,
+        query::{AggregationGroup, AggregationQuery, Cursor},
+        InsertionError, QueryError, UpdateError,
+    },
     subgraph::{query::StructuralQuery, Subgraph},
 };
 
+/// Specifies how a batch of [`EntityBatchOperation`]s handles failing operations.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum BatchMode {
+    /// If any operation fails, none of the operations are applied.
+    #[default]
+    AllOrNothing,
+    /// A failing operation is skipped while the other operations are still applied.
+    BestEffort,
+}
+
+/// The index of the operation in a batch an error of [`EntityStore::batch_entities`] originated
+/// from.
+///
+/// It's attached to the error of every failing operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BatchOperationIndex(pub usize);
+
+impl fmt::Display for BatchOperationIndex {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "batch operation {} failed", self.0)
+    }
+}
+
+/// Refers to an entity, which either already exists or is the result of an earlier operation in
+/// the same batch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum EntityBatchReference {
+    EntityId(EntityId),
+    /// The index of an earlier operation in the batch.
+    Operation(usize),
+}
+
+impl EntityBatchReference {
+    /// Returns the [`EntityId`] this reference refers to.
+    ///
+    /// `results` are the results of the operations in the batch which were already applied.
+    ///
+    /// # Errors
+    ///
+    /// - if the referenced operation was not applied before or failed
+    pub fn resolve(
+        self,
+        results: &[Result<EntityMetadata, UpdateError>],
+    ) -> Result<EntityId, UpdateError> {
+        match self {
+            Self::EntityId(entity_id) => Ok(entity_id),
+            Self::Operation(index) => results
+                .get(index)
+                .and_then(|result| result.as_ref().ok())
+                .map(|metadata| metadata.record_id().entity_id)
+                .ok_or_else(|| {
+                    Report::new(EntityDoesNotExist)
+                        .attach_printable(format!(
+                            "batch operation {index} did not result in an entity"
+                        ))
+                        .change_context(UpdateError)
+                }),
+        }
+    }
+}
+
+/// The link data of an entity created in a batch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct EntityBatchLinkData {
+    pub left_entity: EntityBatchReference,
+    pub right_entity: EntityBatchReference,
+    #[serde(flatten)]
+    pub order: EntityLinkOrder,
+}
+
+impl EntityBatchLinkData {
+    /// Resolves the references to the left and right entity into [`LinkData`].
+    ///
+    /// # Errors
+    ///
+    /// - if either of the references could not be resolved
+    pub fn resolve(
+        self,
+        results: &[Result<EntityMetadata, UpdateError>],
+    ) -> Result<LinkData, UpdateError> {
+        Ok(LinkData {
+            left_entity_id: self.left_entity.resolve(results)?,
+            right_entity_id: self.right_entity.resolve(results)?,
+            order: self.order,
+        })
+    }
+}
+
+/// A single operation of a batch passed to [`EntityStore::batch_entities`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum EntityBatchOperation {
+    /// Creates a new entity, see [`EntityStore::create_entity`].
+    #[serde(rename_all = "camelCase")]
+    #[schema(title = "CreateEntityBatchOperation")]
+    Create {
+        owned_by_id: OwnedById,
+        #[serde(default)]
+        #[schema(nullable = false)]
+        entity_uuid: Option<EntityUuid>,
+        #[schema(value_type = SHARED_VersionedUrl)]
+        entity_type_id: VersionedUrl,
+        properties: EntityProperties,
+        #[serde(default)]
+        #[schema(nullable = false)]
+        link_data: Option<EntityBatchLinkData>,
+    },
+    /// Updates an existing entity, see [`EntityStore::update_entity`].
+    #[serde(rename_all = "camelCase")]
+    #[schema(title = "UpdateEntityBatchOperation")]
+    Update {
+        entity: EntityBatchReference,
+        #[schema(value_type = SHARED_VersionedUrl)]
+        entity_type_id: VersionedUrl,
+        properties: EntityProperties,
+        #[serde(flatten)]
+        link_order: EntityLinkOrder,
+        #[serde(default)]
+        archived: bool,
+    },
+}
+
 /// Describes the API of a store implementation for [Entities].
 ///
 /// [Entities]: Entity
@@ -26,6 +163,8 @@ pub trait EntityStore: crud::Read<Entity> {
     ///
     /// - if the [`EntityType`] doesn't exist
     /// - if the [`EntityProperties`] is not valid with respect to the specified [`EntityType`]
+    /// - if `link_data` refers to an entity which doesn't exist or is archived
+    /// - if the link is not allowed by the [`EntityType`] of the left entity
     /// - if the account referred to by `owned_by_id` does not exist
     /// - if an [`EntityUuid`] was supplied and already exists in the store
     ///
@@ -80,10 +219,65 @@ pub trait EntityStore: crud::Read<Entity> {
 
     /// Get the [`Subgraph`]s specified by the [`StructuralQuery`].
     ///
+    /// If the query is limited and more root entities are available, a [`Cursor`] is returned to
+    /// read the next page.
+    ///
     /// # Errors
     ///
     /// - if the requested [`Entity`] doesn't exist
-    async fn get_entity(&self, query: &StructuralQuery<Entity>) -> Result<Subgraph, QueryError>;
+    /// - if the cursor is not valid for the query
+    /// - if a path to sort by can have multiple values for an entity, e.g. a path through incoming
+    ///   links or inherited entity types
+    async fn get_entity(
+        &self,
+        query: &StructuralQuery<Entity>,
+    ) -> Result<(Subgraph, Option<Cursor>), QueryError>;
+
+    /// Aggregates the entities specified by the [`AggregationQuery`].
+    ///
+    /// The groups are ordered by their values.
+    ///
+    /// # Errors
+    ///
+    /// - if no aggregation was specified
+    /// - if a path to group by or to aggregate can have multiple values for an entity, e.g. a path
+    ///   through incoming links or inherited entity types
+    /// - if an aggregation other than [`Count`] doesn't specify a path
+    /// - if an [`Average`] is requested over values which are not numbers
+    ///
+    /// [`Count`]: crate::store::query::AggregationFunction::Count
+    /// [`Average`]: crate::store::query::AggregationFunction::Average
+    async fn aggregate_entities(
+        &self,
+        query: &AggregationQuery<'_, Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError>;
+
+    /// Returns the metadata of every edition of the [`Entity`] across both time axes.
+    ///
+    /// The editions are ordered by the start of their transaction time and then by the start of
+    /// their decision time. An edition is returned once for every interval it's valid in, e.g.
+    /// when a later edition was only inserted for a part of its decision time.
+    ///
+    /// # Errors
+    ///
+    /// - if the [`Entity`] doesn't exist
+    async fn get_entity_history(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityMetadata>, QueryError>;
+
+    /// Returns the property-level differences from the `old` to the `new` edition of an
+    /// [`Entity`].
+    ///
+    /// # Errors
+    ///
+    /// - if either of the editions doesn't exist or is not an edition of the [`Entity`]
+    async fn diff_entity_editions(
+        &self,
+        entity_id: EntityId,
+        old: EntityEditionId,
+        new: EntityEditionId,
+    ) -> Result<Vec<PropertyDiff>, QueryError>;
 
     /// Update an existing [`Entity`].
     ///
@@ -92,6 +286,9 @@ pub trait EntityStore: crud::Read<Entity> {
     /// - if the [`Entity`] doesn't exist
     /// - if the [`EntityType`] doesn't exist
     /// - if the [`Entity`] is not valid with respect to its [`EntityType`]
+    /// - if the [`Entity`] is a link, which is not allowed by the [`EntityType`] of its left entity
+    /// - if the [`Entity`] is a link, which is restored or reordered, and one of the linked
+    ///   entities is archived
     /// - if the account referred to by `actor_id` does not exist
     ///
     /// [`EntityType`]: type_system::EntityType
@@ -106,4 +303,65 @@ pub trait EntityStore: crud::Read<Entity> {
         properties: EntityProperties,
         link_order: EntityLinkOrder,
     ) -> Result<EntityMetadata, UpdateError>;
+
+    /// Applies the `operations` in order inside of a single transaction.
+    ///
+    /// An operation may refer to the entity created or updated by an earlier operation in the
+    /// batch by its index. The result of each operation is returned in the order of the
+    /// operations. With [`BatchMode::BestEffort`], failing operations don't affect the other
+    /// operations and their error is returned as their result instead. The error of a failing
+    /// operation has its [`BatchOperationIndex`] attached.
+    ///
+    /// # Errors
+    ///
+    /// - with [`BatchMode::AllOrNothing`], if any of the operations failed, see [`create_entity`]
+    ///   and [`update_entity`]
+    /// - if an operation refers to an operation which is not earlier in the batch or failed
+    ///
+    /// [`create_entity`]: Self::create_entity
+    /// [`update_entity`]: Self::update_entity
+    async fn batch_entities(
+        &mut self,
+        operations: Vec<EntityBatchOperation>,
+        actor_id: RecordCreatedById,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<EntityMetadata, UpdateError>>, UpdateError>;
+
+    /// Archives an existing [`Entity`].
+    ///
+    /// A new edition with the properties of the current edition is created, which is archived by
+    /// `actor_id`. If the [`Entity`] is already archived, the metadata of its current edition is
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// - if the [`Entity`] doesn't exist
+    /// - if the [`Entity`] is a link, which is required by the [`EntityType`] of its left entity
+    /// - if the account referred to by `actor_id` does not exist
+    ///
+    /// [`EntityType`]: type_system::EntityType
+    async fn archive_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: RecordArchivedById,
+    ) -> Result<EntityMetadata, UpdateError>;
+
+    /// Restores an archived [`Entity`].
+    ///
+    /// A new edition with the properties of the current edition is created by `actor_id`. If the
+    /// [`Entity`] is not archived, the metadata of its current edition is returned.
+    ///
+    /// # Errors
+    ///
+    /// - if the [`Entity`] doesn't exist
+    /// - if the [`Entity`] is a link, which is not allowed by the [`EntityType`] of its left entity
+    /// - if the [`Entity`] is a link and one of the linked entities is archived
+    /// - if the account referred to by `actor_id` does not exist
+    ///
+    /// [`EntityType`]: type_system::EntityType
+    async fn unarchive_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: RecordCreatedById,
+    ) -> Result<EntityMetadata, UpdateError>;
 }
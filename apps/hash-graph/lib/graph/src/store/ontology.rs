@@ -63,6 +63,7 @@ pub trait DataTypeStore: crud::Read<DataTypeWithMetadata> {
     /// # Errors
     ///
     /// - if the requested [`DataType`] doesn't exist.
+    /// - if the query requests sorting or pagination
     async fn get_data_type(
         &self,
         query: &StructuralQuery<DataTypeWithMetadata>,
@@ -147,6 +148,7 @@ pub trait PropertyTypeStore: crud::Read<PropertyTypeWithMetadata> {
     /// # Errors
     ///
     /// - if the requested [`PropertyType`] doesn't exist.
+    /// - if the query requests sorting or pagination
     async fn get_property_type(
         &self,
         query: &StructuralQuery<PropertyTypeWithMetadata>,
@@ -229,6 +231,7 @@ pub trait EntityTypeStore: crud::Read<EntityTypeWithMetadata> {
     /// # Errors
     ///
     /// - if the requested [`EntityType`] doesn't exist.
+    /// - if the query requests sorting or pagination
     async fn get_entity_type(
         &self,
         query: &StructuralQuery<EntityTypeWithMetadata>,
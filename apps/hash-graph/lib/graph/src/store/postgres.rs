@@ -1,9 +1,11 @@
+mod change;
 mod knowledge;
 mod ontology;
 
 mod migration;
 mod pool;
 mod query;
+mod search;
 mod traversal_context;
 
 use async_trait::async_trait;
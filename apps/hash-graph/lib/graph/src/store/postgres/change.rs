@@ -0,0 +1,110 @@
+use std::num::NonZeroUsize;
+
+use async_trait::async_trait;
+use error_stack::{Result, ResultExt};
+use graph_types::{
+    knowledge::entity::{EntityId, EntityRecordId},
+    ontology::OntologyTypeRecordId,
+    provenance::ProvenanceMetadata,
+};
+use postgres_types::FromSql;
+use temporal_versioning::Timestamp;
+use tokio_postgres::GenericClient;
+use type_system::url::BaseUrl;
+
+use crate::store::{
+    AsClient, ChangeEvent, ChangeEventOffset, ChangeStore, ChangedRecord, PostgresStore, QueryError,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, FromSql)]
+#[postgres(name = "change_record_kind")]
+enum ChangeRecordKind {
+    #[postgres(name = "entity")]
+    Entity,
+    #[postgres(name = "data_type")]
+    DataType,
+    #[postgres(name = "property_type")]
+    PropertyType,
+    #[postgres(name = "entity_type")]
+    EntityType,
+}
+
+#[async_trait]
+impl<C: AsClient> ChangeStore for PostgresStore<C> {
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn read_change_events(
+        &self,
+        after: Option<ChangeEventOffset>,
+        limit: NonZeroUsize,
+    ) -> Result<Vec<ChangeEvent>, QueryError> {
+        let after = after.map(ChangeEventOffset::as_i64);
+        let limit = i64::try_from(limit.get()).unwrap_or(i64::MAX);
+
+        self.as_client()
+            .query(
+                r#"
+                    SELECT
+                        "offset",
+                        operation,
+                        record_kind,
+                        transaction_time,
+                        owned_by_id,
+                        entity_uuid,
+                        entity_edition_id,
+                        base_url,
+                        version,
+                        record_created_by_id,
+                        record_archived_by_id
+                    FROM change_events
+                    WHERE $1::BIGINT IS NULL OR "offset" > $1
+                    ORDER BY "offset"
+                    LIMIT $2;
+                "#,
+                &[&after, &limit],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                let ontology_record_id = || -> Result<_, QueryError> {
+                    Ok(OntologyTypeRecordId {
+                        base_url: BaseUrl::new(row.get(7)).change_context(QueryError)?,
+                        version: row.get(8),
+                    })
+                };
+
+                let record = match row.get(2) {
+                    ChangeRecordKind::Entity => ChangedRecord::Entity {
+                        record_id: EntityRecordId {
+                            entity_id: EntityId {
+                                owned_by_id: row.get(4),
+                                entity_uuid: row.get(5),
+                            },
+                            edition_id: row.get(6),
+                        },
+                    },
+                    ChangeRecordKind::DataType => ChangedRecord::DataType {
+                        record_id: ontology_record_id()?,
+                    },
+                    ChangeRecordKind::PropertyType => ChangedRecord::PropertyType {
+                        record_id: ontology_record_id()?,
+                    },
+                    ChangeRecordKind::EntityType => ChangedRecord::EntityType {
+                        record_id: ontology_record_id()?,
+                    },
+                };
+
+                Ok(ChangeEvent {
+                    offset: ChangeEventOffset::new(row.get(0)),
+                    operation: row.get(1),
+                    record,
+                    transaction_time: row.get::<_, Timestamp<()>>(3).cast(),
+                    provenance: ProvenanceMetadata {
+                        record_created_by_id: row.get(9),
+                        record_archived_by_id: row.get(10),
+                    },
+                })
+            })
+            .collect()
+    }
+}
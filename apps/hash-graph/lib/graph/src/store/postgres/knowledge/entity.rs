@@ -1,18 +1,20 @@
 mod read;
+mod validation;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
 use async_trait::async_trait;
 use error_stack::{Report, Result, ResultExt};
 use graph_types::{
+    account::AccountId,
     knowledge::{
         entity::{
             Entity, EntityEditionId, EntityId, EntityMetadata, EntityProperties, EntityRecordId,
-            EntityTemporalMetadata, EntityUuid,
+            EntityTemporalMetadata, EntityUuid, PropertyDiff,
         },
         link::{EntityLinkOrder, LinkData},
     },
-    provenance::{OwnedById, ProvenanceMetadata, RecordCreatedById},
+    provenance::{OwnedById, ProvenanceMetadata, RecordArchivedById, RecordCreatedById},
 };
 use temporal_versioning::{DecisionTime, RightBoundedTemporalInterval, Timestamp};
 use tokio_postgres::GenericClient;
@@ -24,12 +26,15 @@ use crate::store::error::DeletionError;
 use crate::{
     store::{
         crud::Read,
-        error::{EntityDoesNotExist, RaceConditionOnUpdate},
+        error::{EntityDoesNotExist, EntityEditionDoesNotExist, RaceConditionOnUpdate},
         postgres::{
-            knowledge::entity::read::EntityEdgeTraversalData, query::ReferenceTable,
+            knowledge::entity::{read::EntityEdgeTraversalData, validation::ensure_valid},
+            query::ReferenceTable,
             TraversalContext,
         },
-        AsClient, EntityStore, InsertionError, PostgresStore, QueryError, Record, UpdateError,
+        query::{AggregationGroup, AggregationQuery, Cursor},
+        AsClient, BatchMode, BatchOperationIndex, EntityBatchOperation, EntityStore,
+        InsertionError, PostgresStore, QueryError, Record, UpdateError,
     },
     subgraph::{
         edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, SharedEdgeKind},
@@ -209,6 +214,178 @@ impl<C: AsClient> PostgresStore<C> {
 
         Ok(())
     }
+
+    /// Reads the properties of an edition, which must be an edition of `entity_id`.
+    async fn read_entity_edition_properties(
+        &self,
+        entity_id: EntityId,
+        edition_id: EntityEditionId,
+    ) -> Result<EntityProperties, QueryError> {
+        Ok(self
+            .as_client()
+            .query_opt(
+                r#"
+                    SELECT properties
+                    FROM entity_editions
+                    WHERE entity_edition_id = $3
+                      AND EXISTS (
+                        SELECT 1
+                        FROM entity_temporal_metadata
+                        WHERE entity_edition_id = $3
+                          AND owned_by_id = $1
+                          AND entity_uuid = $2
+                      );
+                "#,
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid, &edition_id],
+            )
+            .await
+            .change_context(QueryError)?
+            .ok_or_else(|| {
+                Report::new(EntityEditionDoesNotExist)
+                    .attach(entity_id)
+                    .attach(edition_id)
+                    .change_context(QueryError)
+            })?
+            .get(0))
+    }
+
+    /// Inserts a copy of the current edition of the entity, which is archived by
+    /// `record_archived_by_id` or not archived if it's `None`.
+    ///
+    /// If the entity is already in the requested state, no edition is inserted and the metadata of
+    /// the current edition is returned.
+    async fn set_entity_archival(
+        &mut self,
+        entity_id: EntityId,
+        record_created_by_id: RecordCreatedById,
+        record_archived_by_id: Option<RecordArchivedById>,
+    ) -> Result<EntityMetadata, UpdateError> {
+        let archived = record_archived_by_id.is_some();
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let row = transaction
+            .as_client()
+            .query_opt(
+                r#"
+                    SELECT
+                        entity_editions.archived,
+                        entity_editions.properties,
+                        entity_editions.left_to_right_order,
+                        entity_editions.right_to_left_order,
+                        entity_types.schema ->> '$id',
+                        entity_editions.entity_edition_id,
+                        entity_editions.record_created_by_id,
+                        entity_editions.record_archived_by_id,
+                        entity_temporal_metadata.decision_time,
+                        entity_temporal_metadata.transaction_time
+                    FROM entity_temporal_metadata
+                    JOIN entity_editions
+                      ON entity_editions.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    JOIN entity_is_of_type
+                      ON entity_is_of_type.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    JOIN entity_types
+                      ON entity_types.ontology_id = entity_is_of_type.entity_type_ontology_id
+                    WHERE entity_temporal_metadata.owned_by_id = $1
+                      AND entity_temporal_metadata.entity_uuid = $2
+                      AND entity_temporal_metadata.decision_time @> now()
+                      AND entity_temporal_metadata.transaction_time @> now();
+                "#,
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid],
+            )
+            .await
+            .change_context(UpdateError)?
+            .ok_or_else(|| {
+                Report::new(EntityDoesNotExist)
+                    .attach(entity_id)
+                    .change_context(UpdateError)
+            })?;
+
+        let entity_type_id = VersionedUrl::from_str(row.get(4)).change_context(UpdateError)?;
+        if row.get::<_, bool>(0) == archived {
+            return Ok(EntityMetadata::new(
+                EntityRecordId {
+                    entity_id,
+                    edition_id: row.get(5),
+                },
+                EntityTemporalMetadata {
+                    decision_time: row.get(8),
+                    transaction_time: row.get(9),
+                },
+                entity_type_id,
+                ProvenanceMetadata {
+                    record_created_by_id: RecordCreatedById::new(row.get(6)),
+                    record_archived_by_id: row
+                        .get::<_, Option<AccountId>>(7)
+                        .map(RecordArchivedById::new),
+                },
+                archived,
+            ));
+        }
+
+        let properties: EntityProperties = row.get(1);
+        let link_order = EntityLinkOrder {
+            left_to_right: row.get(2),
+            right_to_left: row.get(3),
+        };
+
+        // An archived link doesn't count towards the links of its left entity anymore, so
+        // archiving a link is validated against `minItems` and restoring it against `maxItems`.
+        // Only a restored link has to connect entities, which are not archived.
+        if let Some((left_entity_id, right_entity_id)) = transaction
+            .linked_entities(entity_id)
+            .await
+            .change_context(UpdateError)?
+        {
+            let link_data = LinkData {
+                left_entity_id,
+                right_entity_id,
+                order: link_order,
+            };
+            let errors = transaction
+                .validate_link(
+                    Some(entity_id),
+                    &entity_type_id,
+                    &link_data,
+                    archived,
+                    !archived,
+                )
+                .await
+                .change_context(UpdateError)?;
+            ensure_valid(&entity_type_id, errors).change_context(UpdateError)?;
+        }
+
+        let edition_id = transaction
+            .insert_entity_edition(
+                record_created_by_id,
+                record_archived_by_id,
+                &entity_type_id,
+                properties,
+                link_order,
+            )
+            .await
+            .change_context(UpdateError)?;
+        let temporal_versioning = transaction
+            .update_entity_temporal_metadata(entity_id, edition_id, None)
+            .await?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(EntityMetadata::new(
+            EntityRecordId {
+                entity_id,
+                edition_id,
+            },
+            temporal_versioning,
+            entity_type_id,
+            ProvenanceMetadata {
+                record_created_by_id,
+                record_archived_by_id,
+            },
+            archived,
+        ))
+    }
 }
 
 #[async_trait]
@@ -232,6 +409,21 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
 
         let transaction = self.transaction().await.change_context(InsertionError)?;
 
+        let validator = transaction
+            .entity_validator(&entity_type_id)
+            .await
+            .change_context(InsertionError)?;
+        ensure_valid(&entity_type_id, validator.validate_properties(&properties))
+            .change_context(InsertionError)?;
+
+        if let Some(link_data) = &link_data {
+            let errors = transaction
+                .validate_link(None, &entity_type_id, link_data, archived, true)
+                .await
+                .change_context(InsertionError)?;
+            ensure_valid(&entity_type_id, errors).change_context(InsertionError)?;
+        }
+
         transaction
             .as_client()
             .query(
@@ -295,10 +487,13 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             }
         };
 
+        let record_archived_by_id = archived.then_some(RecordArchivedById::new(
+            record_created_by_id.as_account_id(),
+        ));
         let edition_id = transaction
             .insert_entity_edition(
                 record_created_by_id,
-                archived,
+                record_archived_by_id,
                 &entity_type_id,
                 properties,
                 link_order,
@@ -372,7 +567,7 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             entity_type_id,
             ProvenanceMetadata {
                 record_created_by_id,
-                record_archived_by_id: None,
+                record_archived_by_id,
             },
             archived,
         ))
@@ -503,18 +698,33 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
     }
 
     #[tracing::instrument(level = "info", skip(self))]
-    async fn get_entity(&self, query: &StructuralQuery<Entity>) -> Result<Subgraph, QueryError> {
+    async fn get_entity(
+        &self,
+        query: &StructuralQuery<Entity>,
+    ) -> Result<(Subgraph, Option<Cursor>), QueryError> {
         let StructuralQuery {
             ref filter,
             graph_resolve_depths,
             temporal_axes: ref unresolved_temporal_axes,
+            ref sort_by,
+            limit,
+            ref cursor,
         } = *query;
 
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
         let time_axis = temporal_axes.variable_time_axis();
 
-        let entities = Read::<Entity>::read_vec(self, filter, Some(&temporal_axes))
-            .await?
+        let (entities, cursor) = if query.is_paginated() {
+            self.read_entity_page(filter, &temporal_axes, sort_by, cursor.as_ref(), limit)
+                .await?
+        } else {
+            (
+                Read::<Entity>::read_vec(self, filter, Some(&temporal_axes)).await?,
+                None,
+            )
+        };
+
+        let entities = entities
             .into_iter()
             .map(|entity| (entity.vertex_id(time_axis), entity))
             .collect();
@@ -556,7 +766,96 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             .read_traversed_vertices(self, &mut subgraph)
             .await?;
 
-        Ok(subgraph)
+        Ok((subgraph, cursor))
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn aggregate_entities(
+        &self,
+        query: &AggregationQuery<'_, Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        let temporal_axes = query.temporal_axes.clone().resolve();
+        self.aggregate_entity_rows(query, &temporal_axes).await
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_entity_history(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityMetadata>, QueryError> {
+        let history = self
+            .as_client()
+            .query(
+                r#"
+                    SELECT
+                        entity_temporal_metadata.entity_edition_id,
+                        entity_temporal_metadata.decision_time,
+                        entity_temporal_metadata.transaction_time,
+                        entity_types.schema ->> '$id',
+                        entity_editions.record_created_by_id,
+                        entity_editions.record_archived_by_id,
+                        entity_editions.archived
+                    FROM entity_temporal_metadata
+                    JOIN entity_editions
+                      ON entity_editions.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    JOIN entity_is_of_type
+                      ON entity_is_of_type.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    JOIN entity_types
+                      ON entity_types.ontology_id = entity_is_of_type.entity_type_ontology_id
+                    WHERE entity_temporal_metadata.owned_by_id = $1
+                      AND entity_temporal_metadata.entity_uuid = $2
+                    ORDER BY lower(entity_temporal_metadata.transaction_time),
+                             lower(entity_temporal_metadata.decision_time);
+                "#,
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                Ok(EntityMetadata::new(
+                    EntityRecordId {
+                        entity_id,
+                        edition_id: row.get(0),
+                    },
+                    EntityTemporalMetadata {
+                        decision_time: row.get(1),
+                        transaction_time: row.get(2),
+                    },
+                    VersionedUrl::from_str(row.get(3)).change_context(QueryError)?,
+                    ProvenanceMetadata {
+                        record_created_by_id: RecordCreatedById::new(row.get(4)),
+                        record_archived_by_id: row
+                            .get::<_, Option<AccountId>>(5)
+                            .map(RecordArchivedById::new),
+                    },
+                    row.get(6),
+                ))
+            })
+            .collect::<Result<Vec<_>, QueryError>>()?;
+
+        if history.is_empty() {
+            return Err(Report::new(EntityDoesNotExist)
+                .attach(entity_id)
+                .change_context(QueryError));
+        }
+
+        Ok(history)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn diff_entity_editions(
+        &self,
+        entity_id: EntityId,
+        old: EntityEditionId,
+        new: EntityEditionId,
+    ) -> Result<Vec<PropertyDiff>, QueryError> {
+        let old = self.read_entity_edition_properties(entity_id, old).await?;
+        let new = self.read_entity_edition_properties(entity_id, new).await?;
+
+        Ok(old.diff(&new))
     }
 
     #[tracing::instrument(level = "info", skip(self, properties))]
@@ -590,10 +889,53 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                 .change_context(UpdateError));
         }
 
+        let validator = transaction
+            .entity_validator(&entity_type_id)
+            .await
+            .change_context(UpdateError)?;
+        ensure_valid(&entity_type_id, validator.validate_properties(&properties))
+            .change_context(UpdateError)?;
+
+        if let Some((left_entity_id, right_entity_id)) = transaction
+            .linked_entities(entity_id)
+            .await
+            .change_context(UpdateError)?
+        {
+            // The linked entities only have to be live if the link is restored or its order is
+            // changed, so an unchanged link can still be updated or archived after them.
+            let require_live_entities = !archived
+                && transaction
+                    .current_link_order(entity_id)
+                    .await
+                    .change_context(UpdateError)?
+                    .map_or(true, |(current_order, current_archived)| {
+                        current_archived || current_order != link_order
+                    });
+            let link_data = LinkData {
+                left_entity_id,
+                right_entity_id,
+                order: link_order,
+            };
+            let errors = transaction
+                .validate_link(
+                    Some(entity_id),
+                    &entity_type_id,
+                    &link_data,
+                    archived,
+                    require_live_entities,
+                )
+                .await
+                .change_context(UpdateError)?;
+            ensure_valid(&entity_type_id, errors).change_context(UpdateError)?;
+        }
+
+        let record_archived_by_id = archived.then_some(RecordArchivedById::new(
+            record_created_by_id.as_account_id(),
+        ));
         let edition_id = transaction
             .insert_entity_edition(
                 record_created_by_id,
-                archived,
+                record_archived_by_id,
                 &entity_type_id,
                 properties,
                 link_order,
@@ -601,56 +943,9 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             .await
             .change_context(UpdateError)?;
 
-        // Calling `UPDATE` on `entity_temporal_metadata` will invoke a trigger that properly
-        // updates the temporal versioning of the entity.
-        let optional_row = if let Some(decision_time) = decision_time {
-            transaction
-                .as_client()
-                .query_opt(
-                    r#"
-                        UPDATE entity_temporal_metadata
-                        SET decision_time = tstzrange($4, upper(decision_time), '[)'),
-                            transaction_time = tstzrange(now(), NULL, '[)'),
-                            entity_edition_id = $3
-                        WHERE owned_by_id = $1
-                          AND entity_uuid = $2
-                          AND decision_time @> $4::TIMESTAMPTZ
-                          AND transaction_time @> now()
-                        RETURNING decision_time, transaction_time;
-                    "#,
-                    &[
-                        &entity_id.owned_by_id,
-                        &entity_id.entity_uuid,
-                        &edition_id,
-                        &decision_time,
-                    ],
-                )
-                .await
-        } else {
-            transaction
-                .as_client()
-                .query_opt(
-                    r#"
-                        UPDATE entity_temporal_metadata
-                        SET decision_time = tstzrange(now(), upper(decision_time), '[)'),
-                            transaction_time = tstzrange(now(), NULL, '[)'),
-                            entity_edition_id = $3
-                        WHERE owned_by_id = $1
-                          AND entity_uuid = $2
-                          AND decision_time @> now()
-                          AND transaction_time @> now()
-                        RETURNING decision_time, transaction_time;
-                    "#,
-                    &[&entity_id.owned_by_id, &entity_id.entity_uuid, &edition_id],
-                )
-                .await
-        }
-        .change_context(UpdateError)?;
-        let row = optional_row.ok_or_else(|| {
-            Report::new(RaceConditionOnUpdate)
-                .attach(entity_id)
-                .change_context(UpdateError)
-        })?;
+        let temporal_versioning = transaction
+            .update_entity_temporal_metadata(entity_id, edition_id, decision_time)
+            .await?;
 
         transaction.commit().await.change_context(UpdateError)?;
 
@@ -659,25 +954,126 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                 entity_id,
                 edition_id,
             },
-            EntityTemporalMetadata {
-                decision_time: row.get(0),
-                transaction_time: row.get(1),
-            },
+            temporal_versioning,
             entity_type_id,
             ProvenanceMetadata {
                 record_created_by_id,
-                record_archived_by_id: None,
+                record_archived_by_id,
             },
             archived,
         ))
     }
+
+    #[tracing::instrument(level = "info", skip(self, operations))]
+    async fn batch_entities(
+        &mut self,
+        operations: Vec<EntityBatchOperation>,
+        actor_id: RecordCreatedById,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<EntityMetadata, UpdateError>>, UpdateError> {
+        let mut transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let mut results = Vec::with_capacity(operations.len());
+        for (index, operation) in operations.into_iter().enumerate() {
+            // Creating and updating an entity runs in a nested transaction, so a failing operation
+            // is rolled back without affecting the other operations.
+            let result = transaction
+                .apply_entity_batch_operation(operation, actor_id, &results)
+                .await
+                .attach_printable_lazy(|| BatchOperationIndex(index));
+            match (result, mode) {
+                (Err(report), BatchMode::AllOrNothing) => return Err(report),
+                (result, _) => results.push(result),
+            }
+        }
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(results)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn archive_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: RecordArchivedById,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.set_entity_archival(
+            entity_id,
+            RecordCreatedById::new(actor_id.as_account_id()),
+            Some(actor_id),
+        )
+        .await
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn unarchive_entity(
+        &mut self,
+        entity_id: EntityId,
+        actor_id: RecordCreatedById,
+    ) -> Result<EntityMetadata, UpdateError> {
+        self.set_entity_archival(entity_id, actor_id, None).await
+    }
 }
 
 impl PostgresStore<tokio_postgres::Transaction<'_>> {
+    /// Applies a single operation of a batch, where `results` are the results of the earlier
+    /// operations in the batch.
+    async fn apply_entity_batch_operation(
+        &mut self,
+        operation: EntityBatchOperation,
+        actor_id: RecordCreatedById,
+        results: &[Result<EntityMetadata, UpdateError>],
+    ) -> Result<EntityMetadata, UpdateError> {
+        match operation {
+            EntityBatchOperation::Create {
+                owned_by_id,
+                entity_uuid,
+                entity_type_id,
+                properties,
+                link_data,
+            } => {
+                let link_data = link_data
+                    .map(|link_data| link_data.resolve(results))
+                    .transpose()?;
+                self.create_entity(
+                    owned_by_id,
+                    entity_uuid,
+                    None,
+                    actor_id,
+                    false,
+                    entity_type_id,
+                    properties,
+                    link_data,
+                )
+                .await
+                .change_context(UpdateError)
+            }
+            EntityBatchOperation::Update {
+                entity,
+                entity_type_id,
+                properties,
+                link_order,
+                archived,
+            } => {
+                self.update_entity(
+                    entity.resolve(results)?,
+                    None,
+                    actor_id,
+                    archived,
+                    entity_type_id,
+                    properties,
+                    link_order,
+                )
+                .await
+            }
+        }
+    }
+
     async fn insert_entity_edition(
         &self,
         record_created_by_id: RecordCreatedById,
-        archived: bool,
+        record_archived_by_id: Option<RecordArchivedById>,
         entity_type_id: &VersionedUrl,
         properties: EntityProperties,
         link_order: EntityLinkOrder,
@@ -689,16 +1085,17 @@ impl PostgresStore<tokio_postgres::Transaction<'_>> {
                     INSERT INTO entity_editions (
                         entity_edition_id,
                         record_created_by_id,
+                        record_archived_by_id,
                         archived,
                         properties,
                         left_to_right_order,
                         right_to_left_order
-                    ) VALUES (gen_random_uuid(), $1, $2, $3, $4, $5)
+                    ) VALUES (gen_random_uuid(), $1, $2, $2 IS NOT NULL, $3, $4, $5)
                     RETURNING entity_edition_id;
                 "#,
                 &[
                     &record_created_by_id,
-                    &archived,
+                    &record_archived_by_id,
                     &properties,
                     &link_order.left_to_right,
                     &link_order.right_to_left,
@@ -728,4 +1125,67 @@ impl PostgresStore<tokio_postgres::Transaction<'_>> {
 
         Ok(edition_id)
     }
+
+    /// Makes `edition_id` the current edition of the entity starting at `decision_time`, or now if
+    /// no decision time is specified.
+    async fn update_entity_temporal_metadata(
+        &self,
+        entity_id: EntityId,
+        edition_id: EntityEditionId,
+        decision_time: Option<Timestamp<DecisionTime>>,
+    ) -> Result<EntityTemporalMetadata, UpdateError> {
+        // Calling `UPDATE` on `entity_temporal_metadata` will invoke a trigger that properly
+        // updates the temporal versioning of the entity.
+        let optional_row = if let Some(decision_time) = decision_time {
+            self.as_client()
+                .query_opt(
+                    r#"
+                        UPDATE entity_temporal_metadata
+                        SET decision_time = tstzrange($4, upper(decision_time), '[)'),
+                            transaction_time = tstzrange(now(), NULL, '[)'),
+                            entity_edition_id = $3
+                        WHERE owned_by_id = $1
+                          AND entity_uuid = $2
+                          AND decision_time @> $4::TIMESTAMPTZ
+                          AND transaction_time @> now()
+                        RETURNING decision_time, transaction_time;
+                    "#,
+                    &[
+                        &entity_id.owned_by_id,
+                        &entity_id.entity_uuid,
+                        &edition_id,
+                        &decision_time,
+                    ],
+                )
+                .await
+        } else {
+            self.as_client()
+                .query_opt(
+                    r#"
+                        UPDATE entity_temporal_metadata
+                        SET decision_time = tstzrange(now(), upper(decision_time), '[)'),
+                            transaction_time = tstzrange(now(), NULL, '[)'),
+                            entity_edition_id = $3
+                        WHERE owned_by_id = $1
+                          AND entity_uuid = $2
+                          AND decision_time @> now()
+                          AND transaction_time @> now()
+                        RETURNING decision_time, transaction_time;
+                    "#,
+                    &[&entity_id.owned_by_id, &entity_id.entity_uuid, &edition_id],
+                )
+                .await
+        }
+        .change_context(UpdateError)?;
+        let row = optional_row.ok_or_else(|| {
+            Report::new(RaceConditionOnUpdate)
+                .attach(entity_id)
+                .change_context(UpdateError)
+        })?;
+
+        Ok(EntityTemporalMetadata {
+            decision_time: row.get(0),
+            transaction_time: row.get(1),
+        })
+    }
 }
@@ -1,8 +1,8 @@
-use std::{borrow::Cow, mem::swap, str::FromStr};
+use std::{borrow::Cow, mem::swap, num::NonZeroUsize, str::FromStr};
 
 use async_trait::async_trait;
-use error_stack::{Result, ResultExt};
-use futures::{StreamExt, TryStreamExt};
+use error_stack::{Report, Result, ResultExt};
+use futures::StreamExt;
 use graph_types::{
     account::AccountId,
     knowledge::{
@@ -12,12 +12,15 @@ use graph_types::{
         },
         link::{EntityLinkOrder, LinkData},
     },
-    provenance::{OwnedById, ProvenanceMetadata, RecordCreatedById},
+    provenance::{OwnedById, ProvenanceMetadata, RecordArchivedById, RecordCreatedById},
 };
+use postgres_types::ToSql;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use temporal_versioning::{
     LeftClosedTemporalInterval, RightBoundedTemporalInterval, TemporalTagged, TimeAxis, Timestamp,
 };
-use tokio_postgres::GenericClient;
+use tokio_postgres::{GenericClient, Row};
 use type_system::url::{BaseUrl, VersionedUrl};
 use uuid::Uuid;
 
@@ -29,10 +32,14 @@ use crate::{
         postgres::{
             ontology::OntologyId,
             query::{
-                Distinctness, ForeignKeyReference, ReferenceTable, SelectCompiler, Table, Transpile,
+                Distinctness, ForeignKeyReference, Ordering, PostgresQueryPath, ReferenceTable,
+                SelectCompiler, Table, Transpile,
             },
         },
-        query::Filter,
+        query::{
+            AggregationFunction, AggregationGroup, AggregationQuery, AggregationResult, Cursor,
+            Filter, Sorting,
+        },
         AsClient, PostgresStore, QueryError,
     },
     subgraph::{
@@ -42,6 +49,182 @@ use crate::{
     },
 };
 
+/// The paths of the left and the right entity of a link entity.
+///
+/// These have to outlive the [`SelectCompiler`] they are selected with.
+struct LinkDataPaths {
+    left_entity_uuid: EntityQueryPath<'static>,
+    left_owned_by_id: EntityQueryPath<'static>,
+    right_entity_uuid: EntityQueryPath<'static>,
+    right_owned_by_id: EntityQueryPath<'static>,
+}
+
+impl LinkDataPaths {
+    fn new() -> Self {
+        let edge = |edge_kind, path| EntityQueryPath::EntityEdge {
+            edge_kind,
+            path: Box::new(path),
+            direction: EdgeDirection::Outgoing,
+        };
+
+        Self {
+            left_entity_uuid: edge(KnowledgeGraphEdgeKind::HasLeftEntity, EntityQueryPath::Uuid),
+            left_owned_by_id: edge(
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                EntityQueryPath::OwnedById,
+            ),
+            right_entity_uuid: edge(
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                EntityQueryPath::Uuid,
+            ),
+            right_owned_by_id: edge(
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                EntityQueryPath::OwnedById,
+            ),
+        }
+    }
+}
+
+/// Adds the selections required to read an [`Entity`] and returns a function, which converts a
+/// resulting row into an [`Entity`].
+///
+/// The selection is distinct on the record of the entity. If `ordering` is provided, the selection
+/// is also ordered by the record, which makes the order of the rows deterministic.
+#[expect(clippy::too_many_lines)]
+fn select_entity<'p>(
+    compiler: &mut SelectCompiler<'p, Entity>,
+    link_data_paths: &'p LinkDataPaths,
+    ordering: Option<Ordering>,
+) -> impl Fn(&Row) -> Result<Entity, QueryError> + Copy + Send + Sync + 'static {
+    let owned_by_id_index = compiler.add_distinct_selection_with_ordering(
+        &EntityQueryPath::OwnedById,
+        Distinctness::Distinct,
+        ordering,
+    );
+    let entity_uuid_index = compiler.add_distinct_selection_with_ordering(
+        &EntityQueryPath::Uuid,
+        Distinctness::Distinct,
+        ordering,
+    );
+    let decision_time_index = compiler.add_distinct_selection_with_ordering(
+        &EntityQueryPath::DecisionTime,
+        Distinctness::Distinct,
+        ordering,
+    );
+    let transaction_time_index = compiler.add_distinct_selection_with_ordering(
+        &EntityQueryPath::TransactionTime,
+        Distinctness::Distinct,
+        ordering,
+    );
+
+    let edition_id_index = compiler.add_selection_path(&EntityQueryPath::EditionId);
+    let type_id_index = compiler.add_selection_path(&EntityQueryPath::EntityTypeEdge {
+        edge_kind: SharedEdgeKind::IsOfType,
+        path: EntityTypeQueryPath::VersionedUrl,
+        inheritance_depth: Some(0),
+    });
+
+    let properties_index = compiler.add_selection_path(&EntityQueryPath::Properties(None));
+
+    let left_entity_uuid_index = compiler.add_selection_path(&link_data_paths.left_entity_uuid);
+    let left_entity_owned_by_id_index =
+        compiler.add_selection_path(&link_data_paths.left_owned_by_id);
+    let right_entity_uuid_index = compiler.add_selection_path(&link_data_paths.right_entity_uuid);
+    let right_entity_owned_by_id_index =
+        compiler.add_selection_path(&link_data_paths.right_owned_by_id);
+    let left_to_right_order_index = compiler.add_selection_path(&EntityQueryPath::LeftToRightOrder);
+    let right_to_left_order_index = compiler.add_selection_path(&EntityQueryPath::RightToLeftOrder);
+
+    let record_created_by_id_index =
+        compiler.add_selection_path(&EntityQueryPath::RecordCreatedById);
+    let record_archived_by_id_index =
+        compiler.add_selection_path(&EntityQueryPath::RecordArchivedById);
+
+    let archived_index = compiler.add_selection_path(&EntityQueryPath::Archived);
+
+    move |row| {
+        let entity_type_id =
+            VersionedUrl::from_str(row.get(type_id_index)).change_context(QueryError)?;
+
+        let link_data = {
+            let left_owned_by_id: Option<AccountId> = row.get(left_entity_owned_by_id_index);
+            let left_entity_uuid: Option<Uuid> = row.get(left_entity_uuid_index);
+            let right_owned_by_id: Option<AccountId> = row.get(right_entity_owned_by_id_index);
+            let right_entity_uuid: Option<Uuid> = row.get(right_entity_uuid_index);
+            match (
+                left_owned_by_id,
+                left_entity_uuid,
+                right_owned_by_id,
+                right_entity_uuid,
+            ) {
+                (
+                    Some(left_owned_by_id),
+                    Some(left_entity_uuid),
+                    Some(right_owned_by_id),
+                    Some(right_entity_uuid),
+                ) => Some(LinkData {
+                    left_entity_id: EntityId {
+                        owned_by_id: OwnedById::new(left_owned_by_id),
+                        entity_uuid: EntityUuid::new(left_entity_uuid),
+                    },
+                    right_entity_id: EntityId {
+                        owned_by_id: OwnedById::new(right_owned_by_id),
+                        entity_uuid: EntityUuid::new(right_entity_uuid),
+                    },
+                    order: EntityLinkOrder {
+                        left_to_right: row.get(left_to_right_order_index),
+                        right_to_left: row.get(right_to_left_order_index),
+                    },
+                }),
+                (None, None, None, None) => None,
+                _ => unreachable!(
+                    "It's not possible to have a link entity with the left entityId or right \
+                     entityId unspecified"
+                ),
+            }
+        };
+
+        let record_created_by_id = RecordCreatedById::new(row.get(record_created_by_id_index));
+        let record_archived_by_id = row
+            .get::<_, Option<AccountId>>(record_archived_by_id_index)
+            .map(RecordArchivedById::new);
+
+        Ok(Entity {
+            properties: row.get(properties_index),
+            link_data,
+            metadata: EntityMetadata::new(
+                EntityRecordId {
+                    entity_id: EntityId {
+                        owned_by_id: row.get(owned_by_id_index),
+                        entity_uuid: row.get(entity_uuid_index),
+                    },
+                    edition_id: row.get(edition_id_index),
+                },
+                EntityTemporalMetadata {
+                    decision_time: row.get(decision_time_index),
+                    transaction_time: row.get(transaction_time_index),
+                },
+                entity_type_id,
+                ProvenanceMetadata {
+                    record_created_by_id,
+                    record_archived_by_id,
+                },
+                row.get(archived_index),
+            ),
+        })
+    }
+}
+
+/// The position of an [`Entity`] in a sorted selection, which is encoded into a [`Cursor`].
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct EntityPosition {
+    sorting: Vec<String>,
+    sort_values: Vec<Value>,
+    entity_id: EntityId,
+    temporal_versioning: EntityTemporalMetadata,
+}
+
 #[async_trait]
 impl<C: AsClient> crud::Read<Entity> for PostgresStore<C> {
     type Record = Entity;
@@ -54,75 +237,10 @@ impl<C: AsClient> crud::Read<Entity> for PostgresStore<C> {
         filter: &Filter<Entity>,
         temporal_axes: Option<&QueryTemporalAxes>,
     ) -> Result<Self::ReadStream, QueryError> {
-        // We can't define these inline otherwise we'll drop while borrowed
-        let left_entity_uuid_path = EntityQueryPath::EntityEdge {
-            edge_kind: KnowledgeGraphEdgeKind::HasLeftEntity,
-            path: Box::new(EntityQueryPath::Uuid),
-            direction: EdgeDirection::Outgoing,
-        };
-        let left_owned_by_id_query_path = EntityQueryPath::EntityEdge {
-            edge_kind: KnowledgeGraphEdgeKind::HasLeftEntity,
-            path: Box::new(EntityQueryPath::OwnedById),
-            direction: EdgeDirection::Outgoing,
-        };
-        let right_entity_uuid_path = EntityQueryPath::EntityEdge {
-            edge_kind: KnowledgeGraphEdgeKind::HasRightEntity,
-            path: Box::new(EntityQueryPath::Uuid),
-            direction: EdgeDirection::Outgoing,
-        };
-        let right_owned_by_id_query_path = EntityQueryPath::EntityEdge {
-            edge_kind: KnowledgeGraphEdgeKind::HasRightEntity,
-            path: Box::new(EntityQueryPath::OwnedById),
-            direction: EdgeDirection::Outgoing,
-        };
+        let link_data_paths = LinkDataPaths::new();
 
         let mut compiler = SelectCompiler::new(temporal_axes);
-
-        let owned_by_id_index = compiler.add_distinct_selection_with_ordering(
-            &EntityQueryPath::OwnedById,
-            Distinctness::Distinct,
-            None,
-        );
-        let entity_uuid_index = compiler.add_distinct_selection_with_ordering(
-            &EntityQueryPath::Uuid,
-            Distinctness::Distinct,
-            None,
-        );
-        let decision_time_index = compiler.add_distinct_selection_with_ordering(
-            &EntityQueryPath::DecisionTime,
-            Distinctness::Distinct,
-            None,
-        );
-        let transaction_time_index = compiler.add_distinct_selection_with_ordering(
-            &EntityQueryPath::TransactionTime,
-            Distinctness::Distinct,
-            None,
-        );
-
-        let edition_id_index = compiler.add_selection_path(&EntityQueryPath::EditionId);
-        let type_id_index = compiler.add_selection_path(&EntityQueryPath::EntityTypeEdge {
-            edge_kind: SharedEdgeKind::IsOfType,
-            path: EntityTypeQueryPath::VersionedUrl,
-            inheritance_depth: Some(0),
-        });
-
-        let properties_index = compiler.add_selection_path(&EntityQueryPath::Properties(None));
-
-        let left_entity_uuid_index = compiler.add_selection_path(&left_entity_uuid_path);
-        let left_entity_owned_by_id_index =
-            compiler.add_selection_path(&left_owned_by_id_query_path);
-        let right_entity_uuid_index = compiler.add_selection_path(&right_entity_uuid_path);
-        let right_entity_owned_by_id_index =
-            compiler.add_selection_path(&right_owned_by_id_query_path);
-        let left_to_right_order_index =
-            compiler.add_selection_path(&EntityQueryPath::LeftToRightOrder);
-        let right_to_left_order_index =
-            compiler.add_selection_path(&EntityQueryPath::RightToLeftOrder);
-
-        let record_created_by_id_index =
-            compiler.add_selection_path(&EntityQueryPath::RecordCreatedById);
-
-        let archived_index = compiler.add_selection_path(&EntityQueryPath::Archived);
+        let to_entity = select_entity(&mut compiler, &link_data_paths, None);
 
         compiler.add_filter(filter);
         let (statement, parameters) = compiler.compile();
@@ -132,79 +250,219 @@ impl<C: AsClient> crud::Read<Entity> for PostgresStore<C> {
             .query_raw(&statement, parameters.iter().copied())
             .await
             .change_context(QueryError)?
-            .map(|row| row.change_context(QueryError))
-            .and_then(move |row| async move {
-                let entity_type_id =
-                    VersionedUrl::from_str(row.get(type_id_index)).change_context(QueryError)?;
-
-                let link_data = {
-                    let left_owned_by_id: Option<AccountId> =
-                        row.get(left_entity_owned_by_id_index);
-                    let left_entity_uuid: Option<Uuid> = row.get(left_entity_uuid_index);
-                    let right_owned_by_id: Option<AccountId> =
-                        row.get(right_entity_owned_by_id_index);
-                    let right_entity_uuid: Option<Uuid> = row.get(right_entity_uuid_index);
-                    match (
-                        left_owned_by_id,
-                        left_entity_uuid,
-                        right_owned_by_id,
-                        right_entity_uuid,
-                    ) {
-                        (
-                            Some(left_owned_by_id),
-                            Some(left_entity_uuid),
-                            Some(right_owned_by_id),
-                            Some(right_entity_uuid),
-                        ) => Some(LinkData {
-                            left_entity_id: EntityId {
-                                owned_by_id: OwnedById::new(left_owned_by_id),
-                                entity_uuid: EntityUuid::new(left_entity_uuid),
-                            },
-                            right_entity_id: EntityId {
-                                owned_by_id: OwnedById::new(right_owned_by_id),
-                                entity_uuid: EntityUuid::new(right_entity_uuid),
-                            },
-                            order: EntityLinkOrder {
-                                left_to_right: row.get(left_to_right_order_index),
-                                right_to_left: row.get(right_to_left_order_index),
-                            },
-                        }),
-                        (None, None, None, None) => None,
-                        _ => unreachable!(
-                            "It's not possible to have a link entity with the left entityId or \
-                             right entityId unspecified"
-                        ),
+            .map(move |row| to_entity(&row.change_context(QueryError)?));
+        Ok(stream)
+    }
+}
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Reads the entities matching `filter`, sorted by `sorting`.
+    ///
+    /// If a `cursor` is provided, only the entities sorted after the position of the cursor are
+    /// read. If a `limit` is provided, at most `limit` entities are read, and if more entities are
+    /// available, the cursor to read the next page is returned as well. A cursor can only be used
+    /// with the sorting it was created for.
+    ///
+    /// Entities with equal sorting values are sorted by their record, so the order is total. The
+    /// paths to sort by have to resolve to at most one value for every entity, otherwise an entity
+    /// could be read on multiple pages or be skipped.
+    #[tracing::instrument(level = "info", skip(self))]
+    pub(crate) async fn read_entity_page(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_axes: &QueryTemporalAxes,
+        sorting: &[Sorting<'_, Entity>],
+        cursor: Option<&Cursor>,
+        limit: Option<NonZeroUsize>,
+    ) -> Result<(Vec<Entity>, Option<Cursor>), QueryError> {
+        if let Some(sorting) = sorting
+            .iter()
+            .find(|sorting| !sorting.path.is_single_valued())
+        {
+            return Err(Report::new(QueryError).attach_printable(format!(
+                "`{}` can have multiple values per entity",
+                sorting.path
+            )));
+        }
+
+        let sorting_key = sorting
+            .iter()
+            .map(|sorting| match sorting.ordering {
+                Ordering::Ascending => sorting.path.to_string(),
+                Ordering::Descending => format!("-{}", sorting.path),
+            })
+            .collect::<Vec<_>>();
+
+        let position = cursor.map(Cursor::decode::<EntityPosition>).transpose()?;
+        if position
+            .as_ref()
+            .is_some_and(|position| position.sorting != sorting_key)
+        {
+            return Err(Report::new(QueryError)
+                .attach_printable("the cursor was created for a different sorting"));
+        }
+
+        let link_data_paths = LinkDataPaths::new();
+
+        let mut compiler = SelectCompiler::new(Some(temporal_axes));
+        let sort_value_indices = sorting
+            .iter()
+            .map(|sorting| {
+                compiler.add_sorting(&sorting.path, sorting.ordering, Distinctness::Distinct)
+            })
+            .collect::<Vec<_>>();
+        let to_entity = select_entity(&mut compiler, &link_data_paths, Some(Ordering::Ascending));
+
+        compiler.add_filter(filter);
+        if let Some(position) = &position {
+            let record: [&(dyn ToSql + Sync); 4] = [
+                &position.entity_id.owned_by_id,
+                &position.entity_id.entity_uuid,
+                &position.temporal_versioning.decision_time,
+                &position.temporal_versioning.transaction_time,
+            ];
+            compiler.add_cursor(
+                position
+                    .sort_values
+                    .iter()
+                    .map(|value| (!value.is_null()).then_some(value as &(dyn ToSql + Sync)))
+                    .chain(record.map(Some)),
+            );
+        }
+        // One additional row is read to know if there is a next page
+        if let Some(limit) = limit {
+            compiler.set_limit(limit.get() + 1);
+        }
+        let (statement, parameters) = compiler.compile();
+
+        let mut rows = self
+            .as_client()
+            .query(&statement, parameters)
+            .await
+            .change_context(QueryError)?;
+
+        let next_position = match limit {
+            Some(limit) if rows.len() > limit.get() => {
+                rows.truncate(limit.get());
+                rows.last().map(|row| {
+                    let sort_values = sort_value_indices
+                        .iter()
+                        .map(|index| row.get::<_, Option<Value>>(*index).unwrap_or(Value::Null))
+                        .collect();
+                    to_entity(row).map(|entity| EntityPosition {
+                        sorting: sorting_key,
+                        sort_values,
+                        entity_id: entity.metadata.record_id().entity_id,
+                        temporal_versioning: entity.metadata.temporal_versioning().clone(),
+                    })
+                })
+            }
+            _ => None,
+        }
+        .transpose()?;
+
+        Ok((
+            rows.iter().map(to_entity).collect::<Result<_, _>>()?,
+            next_position
+                .map(|position| Cursor::encode(&position))
+                .transpose()?,
+        ))
+    }
+
+    /// Groups and aggregates the entities matching the filter of `query`.
+    ///
+    /// Every entity edition matching the filter is aggregated exactly once, even if the filter
+    /// joins multiple rows for it. As only one of the joined rows is kept, the paths to group by
+    /// and to aggregate have to resolve to at most one value for every entity.
+    pub(crate) async fn aggregate_entity_rows(
+        &self,
+        query: &AggregationQuery<'_, Entity>,
+        temporal_axes: &QueryTemporalAxes,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        if query.aggregations.is_empty() {
+            return Err(Report::new(QueryError).attach_printable("no aggregation was specified"));
+        }
+        if let Some(path) = query
+            .group_by
+            .iter()
+            .chain(
+                query
+                    .aggregations
+                    .iter()
+                    .filter_map(|aggregation| aggregation.path.as_ref()),
+            )
+            .find(|path| !path.is_single_valued())
+        {
+            return Err(Report::new(QueryError)
+                .attach_printable(format!("`{path}` can have multiple values per entity")));
+        }
+
+        let mut compiler = SelectCompiler::new(Some(temporal_axes));
+        compiler.add_distinct_path(&EntityQueryPath::OwnedById);
+        compiler.add_distinct_path(&EntityQueryPath::Uuid);
+        compiler.add_distinct_path(&EntityQueryPath::DecisionTime);
+        compiler.add_distinct_path(&EntityQueryPath::TransactionTime);
+
+        let group_indices = query
+            .group_by
+            .iter()
+            .map(|path| compiler.add_grouping(path))
+            .collect::<Vec<_>>();
+        let aggregation_indices = query
+            .aggregations
+            .iter()
+            .map(|aggregation| {
+                let index = match (&aggregation.path, aggregation.function) {
+                    (Some(path), function) => compiler.add_aggregation(path, function),
+                    // Every entity has a UUID, so counting it counts all entities
+                    (None, AggregationFunction::Count) => {
+                        compiler.add_aggregation(&EntityQueryPath::Uuid, AggregationFunction::Count)
+                    }
+                    (None, function) => {
+                        return Err(Report::new(QueryError).attach_printable(format!(
+                            "a path is required to aggregate with `{function:?}`"
+                        )));
                     }
                 };
+                Ok((index, aggregation.function))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
-                let record_created_by_id =
-                    RecordCreatedById::new(row.get(record_created_by_id_index));
-
-                Ok(Entity {
-                    properties: row.get(properties_index),
-                    link_data,
-                    metadata: EntityMetadata::new(
-                        EntityRecordId {
-                            entity_id: EntityId {
-                                owned_by_id: row.get(owned_by_id_index),
-                                entity_uuid: row.get(entity_uuid_index),
-                            },
-                            edition_id: row.get(edition_id_index),
-                        },
-                        EntityTemporalMetadata {
-                            decision_time: row.get(decision_time_index),
-                            transaction_time: row.get(transaction_time_index),
-                        },
-                        entity_type_id,
-                        ProvenanceMetadata {
-                            record_created_by_id,
-                            record_archived_by_id: None,
-                        },
-                        row.get(archived_index),
-                    ),
+        compiler.add_filter(&query.filter);
+        let (statement, parameters) = compiler.compile_aggregation();
+
+        self.as_client()
+            .query(&statement, parameters)
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                Ok(AggregationGroup {
+                    group: group_indices
+                        .iter()
+                        .map(|index| row.get::<_, Option<Value>>(*index).unwrap_or(Value::Null))
+                        .collect(),
+                    results: aggregation_indices
+                        .iter()
+                        .map(|(index, function)| {
+                            Ok(match function {
+                                AggregationFunction::Count => AggregationResult::Count(
+                                    usize::try_from(row.get::<_, i64>(*index))
+                                        .change_context(QueryError)?,
+                                ),
+                                AggregationFunction::Min => AggregationResult::Min(row.get(*index)),
+                                AggregationFunction::Max => AggregationResult::Max(row.get(*index)),
+                                AggregationFunction::Average => AggregationResult::Average(
+                                    row.get::<_, Option<Value>>(*index)
+                                        .as_ref()
+                                        .and_then(Value::as_f64),
+                                ),
+                            })
+                        })
+                        .collect::<Result<_, _>>()?,
                 })
-            });
-        Ok(stream)
+            })
+            .collect()
     }
 }
 
@@ -0,0 +1,402 @@
+use std::fmt;
+
+use error_stack::{Report, Result, ResultExt};
+use graph_types::knowledge::{
+    entity::EntityId,
+    link::{EntityLinkOrder, LinkData},
+};
+use hash_status::details::{BadRequest, FieldViolation};
+use serde_json::Value;
+use tokio_postgres::GenericClient;
+use type_system::url::VersionedUrl;
+
+use crate::{
+    knowledge::{EntityValidator, Link, LinkValidationError, LinkValidator},
+    store::{
+        error::EntityValidationError, postgres::ontology::OntologyId, AsClient, PostgresStore,
+        QueryError,
+    },
+};
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Reads the schemas of the specified entity type and all entity types it inherits from.
+    async fn entity_type_closure(
+        &self,
+        entity_type_ontology_id: OntologyId,
+    ) -> Result<Vec<Value>, QueryError> {
+        Ok(self
+            .as_client()
+            .query(
+                r#"
+                    SELECT schema
+                    FROM entity_types
+                    WHERE ontology_id = $1
+                       OR ontology_id IN (
+                           SELECT target_entity_type_ontology_id
+                           FROM closed_entity_type_inherits_from
+                           WHERE source_entity_type_ontology_id = $1
+                       );
+                "#,
+                &[&entity_type_ontology_id],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| row.get(0))
+            .collect())
+    }
+
+    /// Reads the schemas required to validate an entity of the specified entity type.
+    ///
+    /// This includes the entity type itself, all entity types it inherits from, and all property
+    /// types and data types, which are (directly or indirectly) referenced by these.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) async fn entity_validator(
+        &self,
+        entity_type_id: &VersionedUrl,
+    ) -> Result<EntityValidator, QueryError> {
+        let entity_type_ontology_id = self.ontology_id_by_url(entity_type_id).await?;
+
+        let entity_types = self.entity_type_closure(entity_type_ontology_id).await?;
+
+        let mut property_types = Vec::new();
+        let mut data_types = Vec::new();
+        for row in self
+            .as_client()
+            .query(
+                r#"
+                    WITH RECURSIVE property_type_ids AS (
+                        SELECT target_property_type_ontology_id AS ontology_id
+                        FROM closed_entity_type_constrains_properties_on
+                        WHERE source_entity_type_ontology_id = $1
+                      UNION
+                        SELECT property_type_constrains_properties_on.target_property_type_ontology_id
+                        FROM property_type_constrains_properties_on
+                        JOIN property_type_ids
+                          ON property_type_constrains_properties_on.source_property_type_ontology_id
+                             = property_type_ids.ontology_id
+                    )
+                    SELECT schema, FALSE
+                    FROM property_types
+                    WHERE ontology_id IN (SELECT ontology_id FROM property_type_ids)
+                    UNION ALL
+                    SELECT schema, TRUE
+                    FROM data_types
+                    WHERE ontology_id IN (
+                        SELECT target_data_type_ontology_id
+                        FROM property_type_constrains_values_on
+                        JOIN property_type_ids
+                          ON property_type_constrains_values_on.source_property_type_ontology_id
+                             = property_type_ids.ontology_id
+                    );
+                "#,
+                &[&entity_type_ontology_id],
+            )
+            .await
+            .change_context(QueryError)?
+        {
+            let schema: Value = row.get(0);
+            if row.get::<_, bool>(1) {
+                data_types.push(schema);
+            } else {
+                property_types.push(schema);
+            }
+        }
+
+        Ok(EntityValidator::new(
+            entity_types,
+            property_types,
+            data_types,
+        ))
+    }
+
+    /// Returns the entity type of the current edition of the specified entity and whether it's
+    /// archived.
+    async fn current_entity_type(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Option<(OntologyId, bool)>, QueryError> {
+        Ok(self
+            .as_client()
+            .query_opt(
+                r#"
+                    SELECT entity_is_of_type.entity_type_ontology_id, entity_editions.archived
+                    FROM entity_temporal_metadata
+                    JOIN entity_editions
+                      ON entity_editions.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    JOIN entity_is_of_type
+                      ON entity_is_of_type.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    WHERE entity_temporal_metadata.owned_by_id = $1
+                      AND entity_temporal_metadata.entity_uuid = $2
+                      AND entity_temporal_metadata.decision_time @> now()
+                      AND entity_temporal_metadata.transaction_time @> now();
+                "#,
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid],
+            )
+            .await
+            .change_context(QueryError)?
+            .map(|row| (row.get(0), row.get(1))))
+    }
+
+    /// Returns the link order of the current edition of the specified entity and whether it's
+    /// archived.
+    pub(crate) async fn current_link_order(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Option<(EntityLinkOrder, bool)>, QueryError> {
+        Ok(self
+            .as_client()
+            .query_opt(
+                r#"
+                    SELECT
+                        entity_editions.left_to_right_order,
+                        entity_editions.right_to_left_order,
+                        entity_editions.archived
+                    FROM entity_temporal_metadata
+                    JOIN entity_editions
+                      ON entity_editions.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    WHERE entity_temporal_metadata.owned_by_id = $1
+                      AND entity_temporal_metadata.entity_uuid = $2
+                      AND entity_temporal_metadata.decision_time @> now()
+                      AND entity_temporal_metadata.transaction_time @> now();
+                "#,
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid],
+            )
+            .await
+            .change_context(QueryError)?
+            .map(|row| {
+                (
+                    EntityLinkOrder {
+                        left_to_right: row.get(0),
+                        right_to_left: row.get(1),
+                    },
+                    row.get(2),
+                )
+            }))
+    }
+
+    /// Returns the left and the right entity of the specified entity if it's a link entity.
+    pub(crate) async fn linked_entities(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Option<(EntityId, EntityId)>, QueryError> {
+        Ok(self
+            .as_client()
+            .query_opt(
+                r#"
+                    SELECT
+                        entity_has_left_entity.left_owned_by_id,
+                        entity_has_left_entity.left_entity_uuid,
+                        entity_has_right_entity.right_owned_by_id,
+                        entity_has_right_entity.right_entity_uuid
+                    FROM entity_has_left_entity
+                    JOIN entity_has_right_entity
+                      ON entity_has_right_entity.owned_by_id = entity_has_left_entity.owned_by_id
+                     AND entity_has_right_entity.entity_uuid = entity_has_left_entity.entity_uuid
+                    WHERE entity_has_left_entity.owned_by_id = $1
+                      AND entity_has_left_entity.entity_uuid = $2;
+                "#,
+                &[&entity_id.owned_by_id, &entity_id.entity_uuid],
+            )
+            .await
+            .change_context(QueryError)?
+            .map(|row| {
+                (
+                    EntityId {
+                        owned_by_id: row.get(0),
+                        entity_uuid: row.get(1),
+                    },
+                    EntityId {
+                        owned_by_id: row.get(2),
+                        entity_uuid: row.get(3),
+                    },
+                )
+            }))
+    }
+
+    /// Counts the non-archived links originating from `left_entity_id`, which are of the entity
+    /// type identified by `link_entity_type_id` or of a type inheriting from it.
+    ///
+    /// The link entity identified by `excluded_entity_id` is not counted.
+    ///
+    /// Before counting, a transaction-level advisory lock keyed on `left_entity_id` is acquired.
+    /// Concurrent transactions adding links to the same left entity are serialized this way, so
+    /// each of them counts the links committed by the others. This relies on the store being a
+    /// transaction, otherwise the lock is released right away.
+    async fn count_links(
+        &self,
+        left_entity_id: EntityId,
+        link_entity_type_id: &str,
+        excluded_entity_id: Option<EntityId>,
+    ) -> Result<usize, QueryError> {
+        self.as_client()
+            .execute(
+                r#"
+                    SELECT pg_advisory_xact_lock(
+                        hashtextextended($1::UUID::TEXT || $2::UUID::TEXT, 0)
+                    );
+                "#,
+                &[&left_entity_id.owned_by_id, &left_entity_id.entity_uuid],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let count: i64 = self
+            .as_client()
+            .query_one(
+                r#"
+                    SELECT COUNT(DISTINCT (
+                        entity_has_left_entity.owned_by_id,
+                        entity_has_left_entity.entity_uuid
+                    ))
+                    FROM entity_has_left_entity
+                    JOIN entity_temporal_metadata
+                      ON entity_temporal_metadata.owned_by_id = entity_has_left_entity.owned_by_id
+                     AND entity_temporal_metadata.entity_uuid = entity_has_left_entity.entity_uuid
+                    JOIN entity_editions
+                      ON entity_editions.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    JOIN closed_entity_is_of_type
+                      ON closed_entity_is_of_type.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    JOIN entity_types
+                      ON entity_types.ontology_id = closed_entity_is_of_type.entity_type_ontology_id
+                    WHERE entity_has_left_entity.left_owned_by_id = $1
+                      AND entity_has_left_entity.left_entity_uuid = $2
+                      AND (entity_has_left_entity.owned_by_id, entity_has_left_entity.entity_uuid)
+                          IS DISTINCT FROM ($4::UUID, $5::UUID)
+                      AND entity_temporal_metadata.decision_time @> now()
+                      AND entity_temporal_metadata.transaction_time @> now()
+                      AND NOT entity_editions.archived
+                      AND entity_types.schema ->> '$id' = $3;
+                "#,
+                &[
+                    &left_entity_id.owned_by_id,
+                    &left_entity_id.entity_uuid,
+                    &link_entity_type_id,
+                    &excluded_entity_id.map(|entity_id| entity_id.owned_by_id),
+                    &excluded_entity_id.map(|entity_id| entity_id.entity_uuid),
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .get(0);
+
+        usize::try_from(count).change_context(QueryError)
+    }
+
+    /// Validates a link entity against the links of the entity type of its left entity.
+    ///
+    /// `entity_id` is the link entity itself if it already exists, so it's not counted as another
+    /// link of the left entity.
+    ///
+    /// If `require_live_entities` is `true`, an archived left or right entity is reported as
+    /// missing. This is only required when a link is created or restored, so links can still be
+    /// updated and archived after the entities they connect have been archived. The links of an
+    /// archived left entity are not validated against its entity type in this case.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) async fn validate_link(
+        &self,
+        entity_id: Option<EntityId>,
+        entity_type_id: &VersionedUrl,
+        link_data: &LinkData,
+        archived: bool,
+        require_live_entities: bool,
+    ) -> Result<Vec<LinkValidationError>, QueryError> {
+        let left_entity = self.current_entity_type(link_data.left_entity_id).await?;
+        let right_entity = self.current_entity_type(link_data.right_entity_id).await?;
+
+        let missing_entities = [
+            (link_data.left_entity_id, left_entity),
+            (link_data.right_entity_id, right_entity),
+        ]
+        .into_iter()
+        .filter(|(_, entity)| {
+            entity.map_or(true, |(_, entity_archived)| {
+                entity_archived && require_live_entities
+            })
+        })
+        .map(|(entity_id, _)| LinkValidationError::MissingEntity { entity_id })
+        .collect::<Vec<_>>();
+
+        let (Some((left_entity_type, left_entity_archived)), Some((right_entity_type, _))) =
+            (left_entity, right_entity)
+        else {
+            return Ok(missing_entities);
+        };
+        if !missing_entities.is_empty() {
+            return Ok(missing_entities);
+        }
+        if left_entity_archived {
+            return Ok(Vec::new());
+        }
+
+        let type_ids = |schemas: Vec<Value>| {
+            schemas
+                .iter()
+                .filter_map(|schema| schema.get("$id")?.as_str().map(ToOwned::to_owned))
+                .collect::<Vec<_>>()
+        };
+
+        // The entity type of the link entity itself has to be the first one
+        let link_entity_type_id = entity_type_id.to_string();
+        let mut link_entity_types = type_ids(
+            self.entity_type_closure(self.ontology_id_by_url(entity_type_id).await?)
+                .await?,
+        );
+        link_entity_types.retain(|id| *id != link_entity_type_id);
+        link_entity_types.insert(0, link_entity_type_id);
+
+        let right_entity_types = type_ids(self.entity_type_closure(right_entity_type).await?);
+
+        let validator = LinkValidator::new(self.entity_type_closure(left_entity_type).await?);
+        let other_links = match validator.link_entity_type_id(&link_entity_types) {
+            Some(constraint) => {
+                self.count_links(link_data.left_entity_id, constraint, entity_id)
+                    .await?
+            }
+            None => 0,
+        };
+
+        Ok(validator.validate_link(&Link {
+            link_entity_types: &link_entity_types,
+            right_entity_types: &right_entity_types,
+            ordered: link_data.order.left_to_right.is_some(),
+            archived,
+            other_links,
+        }))
+    }
+}
+
+/// Returns an [`EntityValidationError`] with every violation attached if `errors` is not empty.
+///
+/// Besides the printable violations, a [`BadRequest`] listing the path and the reason of each
+/// violation is attached, which is returned to the client.
+pub(crate) fn ensure_valid<E>(
+    entity_type_id: &VersionedUrl,
+    errors: Vec<E>,
+) -> Result<(), EntityValidationError>
+where
+    E: fmt::Display + fmt::Debug + Send + Sync + 'static,
+    for<'e> FieldViolation: From<&'e E>,
+{
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let bad_request = errors
+        .iter()
+        .map(FieldViolation::from)
+        .fold(BadRequest::new(), BadRequest::with_field_violation);
+
+    Err(errors
+        .into_iter()
+        .fold(
+            Report::new(EntityValidationError).attach_printable(entity_type_id.clone()),
+            Report::attach_printable,
+        )
+        .attach(bad_request))
+}
@@ -114,10 +114,16 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
         &self,
         query: &StructuralQuery<DataTypeWithMetadata>,
     ) -> Result<Subgraph, QueryError> {
+        if query.is_paginated() {
+            return Err(Report::new(QueryError)
+                .attach_printable("sorting and pagination are only supported for entities"));
+        }
+
         let StructuralQuery {
             ref filter,
             graph_resolve_depths,
             temporal_axes: ref unresolved_temporal_axes,
+            ..
         } = *query;
 
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
@@ -19,13 +19,14 @@ pub use self::{
     condition::{Condition, EqualityOperator},
     expression::{
         CommonTableExpression, Constant, Expression, Function, JoinExpression, OrderByExpression,
-        Ordering, SelectExpression, WhereExpression, WithExpression,
+        PostgresType, SelectExpression, WhereExpression, WithExpression,
     },
-    statement::{Distinctness, SelectStatement, Statement, WindowStatement},
+    statement::{AggregationStatement, Distinctness, SelectStatement, Statement, WindowStatement},
     table::{
         Alias, AliasedColumn, AliasedTable, Column, ForeignKeyReference, ReferenceTable, Table,
     },
 };
+pub use crate::store::query::Ordering;
 use crate::store::{postgres::query::table::Relation, Record};
 
 pub trait PostgresRecord: Record {
@@ -40,6 +41,11 @@ pub trait PostgresQueryPath {
 
     /// The [`Column`] where this path ends.
     fn terminating_column(&self) -> Column;
+
+    /// Returns if this path resolves to at most one value for every record.
+    fn is_single_valued(&self) -> bool {
+        self.relations().into_iter().all(Relation::is_single_valued)
+    }
 }
 
 /// Renders the object into a Postgres compatible format.
@@ -1,19 +1,24 @@
-use std::{collections::HashSet, fmt::Display, marker::PhantomData};
+use std::{borrow::Cow, collections::HashSet, fmt::Display, marker::PhantomData};
 
 use postgres_types::ToSql;
+use serde_json::Value;
 use temporal_versioning::TimeAxis;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 use tokio_postgres::row::RowIndex;
 
 use crate::{
     store::{
         postgres::query::{
             table::{EntityTemporalMetadata, OntologyIds, OntologyTemporalMetadata},
-            Alias, AliasedColumn, AliasedTable, Column, Condition, Distinctness, EqualityOperator,
-            Expression, Function, JoinExpression, OrderByExpression, Ordering, PostgresQueryPath,
-            PostgresRecord, SelectExpression, SelectStatement, Table, Transpile, WhereExpression,
-            WindowStatement, WithExpression,
+            AggregationStatement, Alias, AliasedColumn, AliasedTable, Column, Condition, Constant,
+            Distinctness, EqualityOperator, Expression, Function, JoinExpression,
+            OrderByExpression, Ordering, PostgresQueryPath, PostgresRecord, PostgresType,
+            SelectExpression, SelectStatement, Table, Transpile, WhereExpression, WindowStatement,
+            WithExpression,
+        },
+        query::{
+            AggregationFunction, Filter, FilterExpression, Parameter, ParameterList, ParameterType,
         },
-        query::{Filter, FilterExpression, Parameter, ParameterList, ParameterType},
     },
     subgraph::temporal_axes::QueryTemporalAxes,
 };
@@ -36,10 +41,31 @@ pub struct CompilerArtifacts<'p> {
     temporal_tables: TemporalTableInfo,
 }
 
+/// The type JSON values are compared as in an ordering comparison.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum JsonComparisonType {
+    Number,
+    Timestamp,
+    Text,
+}
+
+impl JsonComparisonType {
+    /// Strings are compared as timestamps if they are RFC 3339 timestamps, as these are the only
+    /// strings converted to timestamps by the database.
+    fn from_text(text: &str) -> Self {
+        if OffsetDateTime::parse(text, &Rfc3339).is_ok() {
+            Self::Timestamp
+        } else {
+            Self::Text
+        }
+    }
+}
+
 pub struct SelectCompiler<'p, T> {
     statement: SelectStatement,
     artifacts: CompilerArtifacts<'p>,
     temporal_axes: Option<&'p QueryTemporalAxes>,
+    aggregations: Vec<Option<AggregationFunction>>,
     _marker: PhantomData<fn(*const T)>,
 }
 
@@ -59,6 +85,7 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
                 joins: Vec::new(),
                 where_expression: WhereExpression::default(),
                 order_by_expression: OrderByExpression::default(),
+                limit: None,
             },
             artifacts: CompilerArtifacts {
                 parameters: Vec::new(),
@@ -71,6 +98,7 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
                 },
             },
             temporal_axes,
+            aggregations: Vec::new(),
             _marker: PhantomData,
         }
     }
@@ -240,10 +268,12 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
     {
         let column = self.compile_path_column(path);
         if distinctness == Distinctness::Distinct {
-            self.statement.distinct.push(column);
+            self.statement.distinct.push(Expression::Column(column));
         }
         if let Some(ordering) = ordering {
-            self.statement.order_by_expression.push(column, ordering);
+            self.statement
+                .order_by_expression
+                .push(Expression::Column(column), ordering);
         }
         self.statement
             .selects
@@ -251,6 +281,160 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
         self.statement.selects.len() - 1
     }
 
+    /// Returns the value of `column` as JSON value, where a JSON `null` is converted to `NULL`.
+    fn json_value(column: AliasedColumn) -> Expression {
+        Expression::Function(Function::NullIf(
+            Box::new(Expression::Function(Function::ToJson(Box::new(
+                Expression::Column(column),
+            )))),
+            Box::new(Expression::Cast(
+                Box::new(Expression::Constant(Constant::String("null"))),
+                PostgresType::JsonB,
+            )),
+        ))
+    }
+
+    /// Sorts the selection by the value at `path` and adds the value to the selection.
+    ///
+    /// The value is converted to JSON, so values of any column can be sorted and compared in the
+    /// same way. A JSON `null` is treated like a missing value, so the selected value is either a
+    /// non-`null` JSON value or `NULL`.
+    ///
+    /// Sortings are applied in the order they are added. If the selection is distinct, the value
+    /// has to be distinct as well, as `DISTINCT ON` requires the leftmost ordering expressions.
+    pub fn add_sorting<'q>(
+        &mut self,
+        path: &'p R::QueryPath<'q>,
+        ordering: Ordering,
+        distinctness: Distinctness,
+    ) -> impl RowIndex + Display + Copy
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let column = self.compile_path_column(path);
+        let expression = Self::json_value(column);
+        if distinctness == Distinctness::Distinct {
+            self.statement.distinct.push(expression.clone());
+        }
+        self.statement
+            .order_by_expression
+            .push(expression.clone(), ordering);
+        self.statement
+            .selects
+            .push(SelectExpression::new(expression, None));
+        self.statement.selects.len() - 1
+    }
+
+    /// Restricts the selection to the rows, which are ordered after the row with the provided
+    /// `values`.
+    ///
+    /// `values` contains the value of every expression the selection is ordered by, in the same
+    /// order as the orderings were added. `None` represents `NULL`, which is sorted last in
+    /// ascending order and first in descending order.
+    pub fn add_cursor(&mut self, values: impl IntoIterator<Item = Option<&'p (dyn ToSql + Sync)>>) {
+        let keys = self
+            .statement
+            .order_by_expression
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // A row is ordered after the cursor if it's ordered after the cursor by one expression and
+        // is equal to the cursor by all previous expressions.
+        let mut conditions = Vec::new();
+        let mut equalities = Vec::new();
+        for ((expression, ordering), value) in keys.into_iter().zip(values) {
+            let value = value.map(|value| {
+                self.artifacts.parameters.push(value);
+                Expression::Parameter(self.artifacts.parameters.len())
+            });
+
+            let after = match (ordering, &value) {
+                (Ordering::Ascending, None) => None,
+                (Ordering::Ascending, Some(value)) => Some(Condition::Any(vec![
+                    Condition::Greater(expression.clone(), value.clone()),
+                    Condition::Equal(Some(expression.clone()), None),
+                ])),
+                (Ordering::Descending, None) => {
+                    Some(Condition::NotEqual(Some(expression.clone()), None))
+                }
+                (Ordering::Descending, Some(value)) => {
+                    Some(Condition::Less(expression.clone(), value.clone()))
+                }
+            };
+            if let Some(after) = after {
+                conditions.push(Condition::All(
+                    equalities.iter().cloned().chain([after]).collect(),
+                ));
+            }
+            equalities.push(Condition::Equal(Some(expression), value));
+        }
+
+        self.statement
+            .where_expression
+            .add_condition(Condition::Any(conditions));
+    }
+
+    /// Makes the selection distinct by the value at `path` without adding it to the selection.
+    pub fn add_distinct_path<'q>(&mut self, path: &'p R::QueryPath<'q>)
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let column = self.compile_path_column(path);
+        self.statement.distinct.push(Expression::Column(column));
+    }
+
+    /// Groups the aggregation by the value at `path`.
+    ///
+    /// The value is selected as JSON value in the same way as in [`add_sorting`]. Only
+    /// groupings and aggregations are selected by [`compile_aggregation`].
+    ///
+    /// [`add_sorting`]: Self::add_sorting
+    /// [`compile_aggregation`]: Self::compile_aggregation
+    pub fn add_grouping<'q>(&mut self, path: &'p R::QueryPath<'q>) -> impl RowIndex + Display + Copy
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        self.add_aggregation_value(path, None)
+    }
+
+    /// Aggregates the values at `path` with the specified [`AggregationFunction`].
+    ///
+    /// See [`add_grouping`] for how the values are selected.
+    ///
+    /// [`add_grouping`]: Self::add_grouping
+    pub fn add_aggregation<'q>(
+        &mut self,
+        path: &'p R::QueryPath<'q>,
+        function: AggregationFunction,
+    ) -> impl RowIndex + Display + Copy
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        self.add_aggregation_value(path, Some(function))
+    }
+
+    fn add_aggregation_value<'q>(
+        &mut self,
+        path: &'p R::QueryPath<'q>,
+        function: Option<AggregationFunction>,
+    ) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let column = self.compile_path_column(path);
+        self.statement
+            .selects
+            .push(SelectExpression::new(Self::json_value(column), None));
+        self.aggregations.push(function);
+        self.statement.selects.len() - 1
+    }
+
+    /// Limits the selection to at most `limit` rows.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.statement.limit = Some(limit);
+    }
+
     /// Adds a new filter to the selection.
     pub fn add_filter<'f: 'p>(&mut self, filter: &'p Filter<'f, R>)
     where
@@ -269,7 +453,27 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
         )
     }
 
+    /// Transpiles the aggregation of the statement into SQL and the parameter to be passed to a
+    /// prepared statement.
+    ///
+    /// The rows of the statement are grouped and aggregated as specified by [`add_grouping`] and
+    /// [`add_aggregation`].
+    ///
+    /// [`add_grouping`]: Self::add_grouping
+    /// [`add_aggregation`]: Self::add_aggregation
+    pub fn compile_aggregation(&self) -> (String, &[&'p (dyn ToSql + Sync)]) {
+        (
+            AggregationStatement {
+                statement: &self.statement,
+                aggregations: &self.aggregations,
+            }
+            .transpile_to_string(),
+            &self.artifacts.parameters,
+        )
+    }
+
     /// Compiles a [`Filter`] to a `Condition`.
+    #[expect(clippy::too_many_lines)]
     pub fn compile_filter<'f: 'p>(&mut self, filter: &'p Filter<'f, R>) -> Condition
     where
         R::QueryPath<'f>: PostgresQueryPath,
@@ -308,6 +512,26 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
                 self.compile_filter_expression(lhs).0,
                 self.compile_parameter_list(rhs).0,
             ),
+            Filter::Less(lhs, rhs) => {
+                let [lhs, rhs] = self.compile_comparison_operands([lhs, rhs]);
+                Condition::Less(lhs, rhs)
+            }
+            Filter::LessOrEqual(lhs, rhs) => {
+                let [lhs, rhs] = self.compile_comparison_operands([lhs, rhs]);
+                Condition::LessOrEqual(lhs, rhs)
+            }
+            Filter::Greater(lhs, rhs) => {
+                let [lhs, rhs] = self.compile_comparison_operands([lhs, rhs]);
+                Condition::Greater(lhs, rhs)
+            }
+            Filter::GreaterOrEqual(lhs, rhs) => {
+                let [lhs, rhs] = self.compile_comparison_operands([lhs, rhs]);
+                Condition::GreaterOrEqual(lhs, rhs)
+            }
+            Filter::Between(value, lower, upper) => {
+                let [value, lower, upper] = self.compile_comparison_operands([value, lower, upper]);
+                Condition::Between(value, lower, upper)
+            }
             Filter::StartsWith(lhs, rhs) => {
                 let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
                 let left_filter = if left_parameter == ParameterType::Any {
@@ -359,6 +583,19 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
 
                 Condition::ContainsSegment(left_filter, right_filter)
             }
+            Filter::ContainsText(lhs, rhs) => {
+                // JSON values are searched directly, so all strings contained in them are found
+                let (left_filter, _) = self.compile_filter_expression(lhs);
+
+                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
+                let right_filter = if right_parameter == ParameterType::Any {
+                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
+                } else {
+                    right_filter
+                };
+
+                Condition::ContainsText(left_filter, right_filter)
+            }
         }
     }
 
@@ -405,6 +642,7 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
                 joins: vec![],
                 where_expression: WhereExpression::default(),
                 order_by_expression: OrderByExpression::default(),
+                limit: None,
             });
 
         let alias = self.add_join_statements(path);
@@ -539,18 +777,75 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
         &mut self,
         parameters: &'p ParameterList<'f>,
     ) -> (Expression, ParameterType) {
-        let parameter_type = match parameters {
-            ParameterList::Uuid(uuids) => {
-                self.artifacts.parameters.push(uuids);
-                ParameterType::Uuid
+        match parameters {
+            ParameterList::Boolean(booleans) => self.artifacts.parameters.push(booleans),
+            ParameterList::Number(numbers) => self.artifacts.parameters.push(numbers),
+            ParameterList::Text(texts) => self.artifacts.parameters.push(texts),
+            ParameterList::Any(values) => self.artifacts.parameters.push(values),
+            ParameterList::Uuid(Cow::Borrowed(uuids)) => self.artifacts.parameters.push(uuids),
+            ParameterList::Uuid(Cow::Owned(uuids)) => self.artifacts.parameters.push(uuids),
+            ParameterList::OntologyTypeVersion(versions) => {
+                self.artifacts.parameters.push(versions);
             }
-        };
+        }
         (
             Expression::Parameter(self.artifacts.parameters.len()),
-            parameter_type,
+            parameters.parameter_type(),
         )
     }
 
+    /// Compiles the operands of an ordering comparison, e.g. `<` or `BETWEEN`.
+    ///
+    /// JSON values are ordered by their type first, so they are converted to the type of the
+    /// compared parameter: Numbers are compared numerically, RFC 3339 timestamps chronologically,
+    /// and other strings lexicographically. Values of a different type are converted to `NULL`, so
+    /// they never match the comparison, e.g. comparing against a timestamp does not match strings,
+    /// which are not timestamps.
+    fn compile_comparison_operands<'f: 'p, const N: usize>(
+        &mut self,
+        operands: [&'p FilterExpression<'f, R>; N],
+    ) -> [Expression; N]
+    where
+        R::QueryPath<'f>: PostgresQueryPath,
+    {
+        let comparison_type = operands.iter().find_map(|operand| match operand {
+            FilterExpression::Parameter(
+                Parameter::Number(_) | Parameter::Any(Value::Number(_)),
+            ) => Some(JsonComparisonType::Number),
+            FilterExpression::Parameter(Parameter::Text(text)) => {
+                Some(JsonComparisonType::from_text(text))
+            }
+            FilterExpression::Parameter(Parameter::Any(Value::String(text))) => {
+                Some(JsonComparisonType::from_text(text))
+            }
+            FilterExpression::Path(_) | FilterExpression::Parameter(_) => None,
+        });
+
+        operands.map(|operand| {
+            let (expression, parameter_type) = self.compile_filter_expression(operand);
+            let Some(comparison_type) = comparison_type else {
+                return expression;
+            };
+            if parameter_type != ParameterType::Any {
+                return expression;
+            }
+
+            // Postgres has to know that the parameter is a JSON value before it can be cast
+            let expression = if matches!(expression, Expression::Parameter(_)) {
+                Expression::Cast(Box::new(expression), PostgresType::JsonB)
+            } else {
+                expression
+            };
+
+            let expression = Box::new(expression);
+            Expression::Function(match comparison_type {
+                JsonComparisonType::Number => Function::JsonToNumeric(expression),
+                JsonComparisonType::Timestamp => Function::JsonToTimestamp(expression),
+                JsonComparisonType::Text => Function::JsonToText(expression),
+            })
+        })
+    }
+
     /// Joins a chain of [`Relation`]s and returns the table name of the last joined table.
     ///
     /// Joining the tables attempts to deduplicate [`JoinExpression`]s. As soon as a new filter was
@@ -5,7 +5,7 @@ use crate::store::postgres::query::{Expression, Transpile};
 /// A [`Filter`], which can be transpiled.
 ///
 /// [`Filter`]: crate::store::query::Filter
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Condition {
     All(Vec<Self>),
     Any(Vec<Self>),
@@ -16,12 +16,14 @@ pub enum Condition {
     LessOrEqual(Expression, Expression),
     Greater(Expression, Expression),
     GreaterOrEqual(Expression, Expression),
+    Between(Expression, Expression, Expression),
     In(Expression, Expression),
     TimeIntervalContainsTimestamp(Expression, Expression),
     Overlap(Expression, Expression),
     StartsWith(Expression, Expression),
     EndsWith(Expression, Expression),
     ContainsSegment(Expression, Expression),
+    ContainsText(Expression, Expression),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -107,6 +109,13 @@ impl Transpile for Condition {
                 fmt.write_str(" >= ")?;
                 rhs.transpile(fmt)
             }
+            Self::Between(value, lower, upper) => {
+                value.transpile(fmt)?;
+                fmt.write_str(" BETWEEN ")?;
+                lower.transpile(fmt)?;
+                fmt.write_str(" AND ")?;
+                upper.transpile(fmt)
+            }
             Self::In(lhs, rhs) => {
                 lhs.transpile(fmt)?;
                 fmt.write_str(" = ANY(")?;
@@ -141,6 +150,13 @@ impl Transpile for Condition {
                 rhs.transpile(fmt)?;
                 fmt.write_str(" || '%'")
             }
+            Self::ContainsText(lhs, rhs) => {
+                fmt.write_str("to_tsvector('simple', ")?;
+                lhs.transpile(fmt)?;
+                fmt.write_str(") @@ websearch_to_tsquery('simple', ")?;
+                rhs.transpile(fmt)?;
+                fmt.write_char(')')
+            }
         }
     }
 }
@@ -315,6 +331,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transpile_contains_text_condition() {
+        test_condition(
+            &Filter::ContainsText(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("plain text"))),
+            ),
+            r#"to_tsvector('simple', "data_types_0_1_0"."schema"->>'title') @@ websearch_to_tsquery('simple', $1)"#,
+            &[&"plain text"],
+        );
+    }
+
     #[test]
     fn render_without_parameters() {
         test_condition(
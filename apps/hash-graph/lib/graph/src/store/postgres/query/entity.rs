@@ -32,6 +32,7 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
             | Self::LeftToRightOrder
             | Self::RightToLeftOrder
             | Self::RecordCreatedById
+            | Self::RecordArchivedById
             | Self::Archived => vec![Relation::EntityEditions],
             Self::EntityTypeEdge {
                 edge_kind: SharedEdgeKind::IsOfType,
@@ -95,6 +96,7 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
             Self::Archived => Column::EntityEditions(EntityEditions::Archived),
             Self::OwnedById => Column::EntityTemporalMetadata(EntityTemporalMetadata::OwnedById),
             Self::RecordCreatedById => Column::EntityEditions(EntityEditions::RecordCreatedById),
+            Self::RecordArchivedById => Column::EntityEditions(EntityEditions::RecordArchivedById),
             Self::EntityTypeEdge { path, .. } => path.terminating_column(),
             Self::EntityEdge {
                 edge_kind: KnowledgeGraphEdgeKind::HasLeftEntity,
@@ -6,9 +6,9 @@ mod where_clause;
 mod with_clause;
 
 pub use self::{
-    conditional::{Constant, Expression, Function},
+    conditional::{Constant, Expression, Function, PostgresType},
     join_clause::JoinExpression,
-    order_clause::{OrderByExpression, Ordering},
+    order_clause::OrderByExpression,
     select_clause::SelectExpression,
     where_clause::WhereExpression,
     with_clause::{CommonTableExpression, WithExpression},
@@ -2,7 +2,7 @@ use std::fmt::{self, Write};
 
 use crate::store::postgres::query::{AliasedColumn, Transpile, WindowStatement};
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Function {
     Min(Box<Expression>),
     Max(Box<Expression>),
@@ -13,6 +13,15 @@ pub enum Function {
     JsonBuildObject(Vec<(Expression, Expression)>),
     Lower(Box<Expression>),
     Upper(Box<Expression>),
+    ToJson(Box<Expression>),
+    NullIf(Box<Expression>, Box<Expression>),
+    /// Converts a JSON number to `NUMERIC`, other values are converted to `NULL`.
+    JsonToNumeric(Box<Expression>),
+    /// Converts a JSON string to `TEXT`, other values are converted to `NULL`.
+    JsonToText(Box<Expression>),
+    /// Converts a JSON string containing an RFC 3339 timestamp to `TIMESTAMPTZ`, other values are
+    /// converted to `NULL`.
+    JsonToTimestamp(Box<Expression>),
     Now,
 }
 
@@ -84,11 +93,38 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::ToJson(expression) => {
+                fmt.write_str("to_jsonb(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::NullIf(value, null_value) => {
+                fmt.write_str("NULLIF(")?;
+                value.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                null_value.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::JsonToNumeric(expression) => {
+                fmt.write_str("jsonb_to_numeric(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::JsonToText(expression) => {
+                fmt.write_str("jsonb_to_text(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::JsonToTimestamp(expression) => {
+                fmt.write_str("jsonb_to_timestamptz(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Constant {
     Boolean(bool),
     String(&'static str),
@@ -105,8 +141,22 @@ impl Transpile for Constant {
     }
 }
 
+/// A Postgres type, which an [`Expression`] can be cast to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PostgresType {
+    JsonB,
+}
+
+impl Transpile for PostgresType {
+    fn transpile(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::JsonB => fmt.write_str("JSONB"),
+        }
+    }
+}
+
 /// A compiled expression in Postgres.
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Expression {
     Asterisk,
     Column(AliasedColumn),
@@ -117,6 +167,7 @@ pub enum Expression {
     Constant(Constant),
     Function(Function),
     Window(Box<Self>, WindowStatement),
+    Cast(Box<Self>, PostgresType),
 }
 
 impl Transpile for Expression {
@@ -133,6 +184,12 @@ impl Transpile for Expression {
                 window.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::Cast(expression, cast_type) => {
+                fmt.write_char('(')?;
+                expression.transpile(fmt)?;
+                fmt.write_str(")::")?;
+                cast_type.transpile(fmt)
+            }
         }
     }
 }
@@ -178,4 +235,16 @@ mod tests {
             r#"MIN("ontology_ids_1_2_3"."version")"#
         );
     }
+
+    #[test]
+    fn transpile_cast_expression() {
+        assert_eq!(
+            Expression::Function(Function::JsonToNumeric(Box::new(Expression::Cast(
+                Box::new(Expression::Parameter(1)),
+                PostgresType::JsonB
+            ))))
+            .transpile_to_string(),
+            "jsonb_to_numeric(($1)::JSONB)"
+        );
+    }
 }
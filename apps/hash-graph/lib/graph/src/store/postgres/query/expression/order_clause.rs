@@ -1,21 +1,20 @@
 use std::fmt;
 
-use crate::store::postgres::query::{AliasedColumn, Transpile};
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub enum Ordering {
-    Ascending,
-    Descending,
-}
+use crate::store::postgres::query::{Expression, Ordering, Transpile};
 
 #[derive(Debug, Default, PartialEq, Eq, Hash)]
 pub struct OrderByExpression {
-    columns: Vec<(AliasedColumn, Ordering)>,
+    columns: Vec<(Expression, Ordering)>,
 }
 
 impl OrderByExpression {
-    pub fn push(&mut self, column: AliasedColumn, ordering: Ordering) {
-        self.columns.push((column, ordering));
+    pub fn push(&mut self, expression: Expression, ordering: Ordering) {
+        self.columns.push((expression, ordering));
+    }
+
+    /// Returns the expressions the statement is ordered by, starting with the most significant one.
+    pub fn iter(&self) -> impl Iterator<Item = &(Expression, Ordering)> {
+        self.columns.iter()
     }
 
     pub fn is_empty(&self) -> bool {
@@ -30,11 +29,11 @@ impl Transpile for OrderByExpression {
         }
 
         fmt.write_str("ORDER BY ")?;
-        for (idx, (column, ordering)) in self.columns.iter().enumerate() {
+        for (idx, (expression, ordering)) in self.columns.iter().enumerate() {
             if idx > 0 {
                 fmt.write_str(", ")?;
             }
-            column.transpile(fmt)?;
+            expression.transpile(fmt)?;
             match ordering {
                 Ordering::Ascending => write!(fmt, " ASC")?,
                 Ordering::Descending => write!(fmt, " DESC")?,
@@ -50,20 +49,24 @@ mod tests {
     use super::*;
     use crate::{
         ontology::DataTypeQueryPath,
-        store::postgres::query::{test_helper::trim_whitespace, Alias, PostgresQueryPath},
+        store::postgres::query::{
+            test_helper::trim_whitespace, Alias, Function, PostgresQueryPath,
+        },
     };
 
     #[test]
     fn order_one() {
         let mut order_by_expression = OrderByExpression::default();
         order_by_expression.push(
-            DataTypeQueryPath::Version
-                .terminating_column()
-                .aliased(Alias {
-                    condition_index: 1,
-                    chain_depth: 2,
-                    number: 3,
-                }),
+            Expression::Column(
+                DataTypeQueryPath::Version
+                    .terminating_column()
+                    .aliased(Alias {
+                        condition_index: 1,
+                        chain_depth: 2,
+                        number: 3,
+                    }),
+            ),
             Ordering::Ascending,
         );
         assert_eq!(
@@ -76,21 +79,23 @@ mod tests {
     fn order_multiple() {
         let mut order_by_expression = OrderByExpression::default();
         order_by_expression.push(
-            DataTypeQueryPath::BaseUrl
-                .terminating_column()
-                .aliased(Alias {
-                    condition_index: 1,
-                    chain_depth: 2,
-                    number: 3,
-                }),
+            Expression::Column(
+                DataTypeQueryPath::BaseUrl
+                    .terminating_column()
+                    .aliased(Alias {
+                        condition_index: 1,
+                        chain_depth: 2,
+                        number: 3,
+                    }),
+            ),
             Ordering::Ascending,
         );
         order_by_expression.push(
-            DataTypeQueryPath::Type.terminating_column().aliased(Alias {
+            Expression::Column(DataTypeQueryPath::Type.terminating_column().aliased(Alias {
                 condition_index: 4,
                 chain_depth: 5,
                 number: 6,
-            }),
+            })),
             Ordering::Descending,
         );
 
@@ -102,4 +107,26 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn order_expression() {
+        let mut order_by_expression = OrderByExpression::default();
+        order_by_expression.push(
+            Expression::Function(Function::ToJson(Box::new(Expression::Column(
+                DataTypeQueryPath::Title
+                    .terminating_column()
+                    .aliased(Alias {
+                        condition_index: 0,
+                        chain_depth: 0,
+                        number: 0,
+                    }),
+            )))),
+            Ordering::Descending,
+        );
+
+        assert_eq!(
+            order_by_expression.transpile_to_string(),
+            r#"ORDER BY to_jsonb("data_types_0_0_0"."schema"->>'title') DESC"#
+        );
+    }
 }
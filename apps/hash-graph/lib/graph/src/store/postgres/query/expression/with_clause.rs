@@ -80,6 +80,7 @@ mod tests {
             joins: vec![],
             where_expression: WhereExpression::default(),
             order_by_expression: OrderByExpression::default(),
+            limit: None,
         });
 
         assert_eq!(
@@ -102,6 +103,7 @@ mod tests {
             joins: vec![],
             where_expression: WhereExpression::default(),
             order_by_expression: OrderByExpression::default(),
+            limit: None,
         });
 
         assert_eq!(
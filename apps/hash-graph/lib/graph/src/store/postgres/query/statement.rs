@@ -1,9 +1,11 @@
+mod aggregation;
 mod select;
 mod window;
 
 use std::fmt;
 
 pub use self::{
+    aggregation::AggregationStatement,
     select::{Distinctness, SelectStatement},
     window::WindowStatement,
 };
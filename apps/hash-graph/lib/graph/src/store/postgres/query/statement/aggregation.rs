@@ -0,0 +1,83 @@
+use std::fmt::{self, Write};
+
+use crate::store::{
+    postgres::query::{SelectStatement, Transpile},
+    query::AggregationFunction,
+};
+
+/// Groups and aggregates the rows of a [`SelectStatement`].
+///
+/// Each expression selected by the inner statement is either grouped by, if the corresponding
+/// aggregation is `None`, or aggregated by the specified [`AggregationFunction`]. The resulting
+/// columns are in the same order as the selected expressions of the inner statement.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct AggregationStatement<'s> {
+    pub statement: &'s SelectStatement,
+    pub aggregations: &'s [Option<AggregationFunction>],
+}
+
+impl AggregationStatement<'_> {
+    fn transpile_groups(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let groups = self
+            .aggregations
+            .iter()
+            .enumerate()
+            .filter(|(_, aggregation)| aggregation.is_none());
+        for (idx, (value, _)) in groups.enumerate() {
+            if idx > 0 {
+                fmt.write_str(", ")?;
+            }
+            write!(fmt, r#""aggregation"."value_{value}""#)?;
+        }
+        Ok(())
+    }
+}
+
+impl Transpile for AggregationStatement<'_> {
+    fn transpile(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("SELECT ")?;
+        for (idx, aggregation) in self.aggregations.iter().enumerate() {
+            if idx > 0 {
+                fmt.write_str(", ")?;
+            }
+            match aggregation {
+                None => write!(fmt, r#""aggregation"."value_{idx}""#)?,
+                Some(AggregationFunction::Count) => {
+                    write!(fmt, r#"COUNT("aggregation"."value_{idx}")"#)?;
+                }
+                Some(AggregationFunction::Min) => {
+                    write!(fmt, r#"MIN("aggregation"."value_{idx}")"#)?;
+                }
+                Some(AggregationFunction::Max) => {
+                    write!(fmt, r#"MAX("aggregation"."value_{idx}")"#)?;
+                }
+                Some(AggregationFunction::Average) => {
+                    write!(
+                        fmt,
+                        r#"to_jsonb(AVG(("aggregation"."value_{idx})::NUMERIC))"#
+                    )?;
+                }
+            }
+        }
+
+        fmt.write_str("\nFROM (")?;
+        self.statement.transpile(fmt)?;
+        fmt.write_str(r#") AS "aggregation"("#)?;
+        for idx in 0..self.aggregations.len() {
+            if idx > 0 {
+                fmt.write_str(", ")?;
+            }
+            write!(fmt, r#""value_{idx}""#)?;
+        }
+        fmt.write_char(')')?;
+
+        if self.aggregations.iter().any(Option::is_none) {
+            fmt.write_str("\nGROUP BY ")?;
+            self.transpile_groups(fmt)?;
+            fmt.write_str("\nORDER BY ")?;
+            self.transpile_groups(fmt)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,19 +1,20 @@
 use std::fmt::{self, Write};
 
 use crate::store::postgres::query::{
-    expression::OrderByExpression, AliasedColumn, AliasedTable, JoinExpression, SelectExpression,
+    expression::OrderByExpression, AliasedTable, Expression, JoinExpression, SelectExpression,
     Transpile, WhereExpression, WithExpression,
 };
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SelectStatement {
     pub with: WithExpression,
-    pub distinct: Vec<AliasedColumn>,
+    pub distinct: Vec<Expression>,
     pub selects: Vec<SelectExpression>,
     pub from: AliasedTable,
     pub joins: Vec<JoinExpression>,
     pub where_expression: WhereExpression,
     pub order_by_expression: OrderByExpression,
+    pub limit: Option<usize>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -34,11 +35,11 @@ impl Transpile for SelectStatement {
         if !self.distinct.is_empty() {
             fmt.write_str("DISTINCT ON(")?;
 
-            for (idx, column) in self.distinct.iter().enumerate() {
+            for (idx, expression) in self.distinct.iter().enumerate() {
                 if idx > 0 {
                     fmt.write_str(", ")?;
                 }
-                column.transpile(fmt)?;
+                expression.transpile(fmt)?;
             }
             fmt.write_str(") ")?;
         }
@@ -69,6 +70,10 @@ impl Transpile for SelectStatement {
             self.order_by_expression.transpile(fmt)?;
         }
 
+        if let Some(limit) = self.limit {
+            write!(fmt, "\nLIMIT {limit}")?;
+        }
+
         Ok(())
     }
 }
@@ -82,6 +87,7 @@ mod tests {
         ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
     };
     use postgres_types::ToSql;
+    use serde_json::json;
     use uuid::Uuid;
 
     use crate::{
@@ -92,7 +98,9 @@ mod tests {
                 test_helper::trim_whitespace, Distinctness, Ordering, PostgresRecord,
                 SelectCompiler,
             },
-            query::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
+            query::{
+                AggregationFunction, Filter, FilterExpression, JsonPath, Parameter, PathToken,
+            },
         },
         subgraph::{
             edges::{EdgeDirection, KnowledgeGraphEdgeKind, OntologyEdgeKind, SharedEdgeKind},
@@ -653,6 +661,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_aggregation() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes));
+        compiler.add_distinct_path(&EntityQueryPath::OwnedById);
+        compiler.add_distinct_path(&EntityQueryPath::Uuid);
+        compiler.add_grouping(&EntityQueryPath::OwnedById);
+        compiler.add_aggregation(&EntityQueryPath::Uuid, AggregationFunction::Count);
+
+        let filter = Filter::Equal(
+            Some(FilterExpression::Path(EntityQueryPath::RecordCreatedById)),
+            Some(FilterExpression::Parameter(Parameter::Uuid(Uuid::nil()))),
+        );
+        compiler.add_filter(&filter);
+
+        let (compiled_statement, compiled_parameters) = compiler.compile_aggregation();
+        assert_eq!(
+            trim_whitespace(compiled_statement),
+            trim_whitespace(
+                r#"
+                SELECT "aggregation"."value_0", COUNT("aggregation"."value_1")
+                FROM (SELECT
+                        DISTINCT ON("entity_temporal_metadata_0_0_0"."owned_by_id", "entity_temporal_metadata_0_0_0"."entity_uuid")
+                        NULLIF(to_jsonb("entity_temporal_metadata_0_0_0"."owned_by_id"), ('null')::JSONB),
+                        NULLIF(to_jsonb("entity_temporal_metadata_0_0_0"."entity_uuid"), ('null')::JSONB)
+                    FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+                    INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+                      ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+                    WHERE "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+                      AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+                      AND "entity_editions_0_1_0"."record_created_by_id" = $3) AS "aggregation"("value_0", "value_1")
+                GROUP BY "aggregation"."value_0"
+                ORDER BY "aggregation"."value_0"
+                "#
+            )
+        );
+        assert_eq!(
+            compiled_parameters
+                .iter()
+                .map(|parameter| format!("{parameter:?}"))
+                .collect::<Vec<_>>(),
+            [
+                format!("{pinned_timestamp:?}"),
+                format!("{:?}", temporal_axes.variable_interval()),
+                format!("{:?}", Uuid::nil()),
+            ]
+        );
+    }
+
     #[test]
     fn entity_property_query() {
         let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
@@ -728,6 +786,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_property_range_query() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(Some(&temporal_axes));
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/age/""#,
+        ))]);
+
+        let filter = Filter::Between(
+            FilterExpression::Path(EntityQueryPath::Properties(Some(json_path.clone()))),
+            FilterExpression::Parameter(Parameter::Any(json!(18))),
+            FilterExpression::Parameter(Parameter::Any(json!(65.5))),
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."transaction_time" @> $2::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $3
+              AND jsonb_to_numeric(jsonb_path_query_first("entity_editions_0_1_0"."properties", $1::text::jsonpath))
+                  BETWEEN jsonb_to_numeric(($4)::JSONB) AND jsonb_to_numeric(($5)::JSONB)
+            "#,
+            &[
+                &json_path,
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &json!(18),
+                &json!(65.5),
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_property_timestamp_query() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(Some(&temporal_axes));
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/created-at/""#,
+        ))]);
+
+        let filter = Filter::Greater(
+            FilterExpression::Path(EntityQueryPath::Properties(Some(json_path.clone()))),
+            FilterExpression::Parameter(Parameter::Any(json!("2023-01-01T00:00:00Z"))),
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."transaction_time" @> $2::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $3
+              AND jsonb_to_timestamptz(jsonb_path_query_first("entity_editions_0_1_0"."properties", $1::text::jsonpath))
+                  > jsonb_to_timestamptz(($4)::JSONB)
+            "#,
+            &[
+                &json_path,
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &json!("2023-01-01T00:00:00Z"),
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_property_text_query() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(Some(&temporal_axes));
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/published-on/""#,
+        ))]);
+
+        // A date without a time is not an RFC 3339 timestamp, so it's compared as text
+        let filter = Filter::Less(
+            FilterExpression::Path(EntityQueryPath::Properties(Some(json_path.clone()))),
+            FilterExpression::Parameter(Parameter::Any(json!("2023-01-01"))),
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."transaction_time" @> $2::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $3
+              AND jsonb_to_text(jsonb_path_query_first("entity_editions_0_1_0"."properties", $1::text::jsonpath))
+                  < jsonb_to_text(($4)::JSONB)
+            "#,
+            &[
+                &json_path,
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &json!("2023-01-01"),
+            ],
+        );
+    }
+
     #[test]
     fn entity_outgoing_link_query() {
         let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
@@ -664,6 +664,7 @@ pub enum EntityEditions<'p> {
     LeftToRightOrder,
     RightToLeftOrder,
     RecordCreatedById,
+    RecordArchivedById,
     Archived,
 }
 
@@ -671,7 +672,10 @@ impl<'p> EntityEditions<'p> {
     pub const fn nullable(self) -> bool {
         match self {
             Self::EditionId | Self::Archived | Self::RecordCreatedById => false,
-            Self::Properties(_) | Self::LeftToRightOrder | Self::RightToLeftOrder => true,
+            Self::Properties(_)
+            | Self::LeftToRightOrder
+            | Self::RightToLeftOrder
+            | Self::RecordArchivedById => true,
         }
     }
 
@@ -684,6 +688,7 @@ impl<'p> EntityEditions<'p> {
             Self::LeftToRightOrder => (EntityEditions::LeftToRightOrder, None),
             Self::RightToLeftOrder => (EntityEditions::RightToLeftOrder, None),
             Self::RecordCreatedById => (EntityEditions::RecordCreatedById, None),
+            Self::RecordArchivedById => (EntityEditions::RecordArchivedById, None),
             Self::Archived => (EntityEditions::Archived, None),
             Self::Properties(None) => (EntityEditions::Properties(None), None),
             Self::Properties(Some(path)) => {
@@ -705,6 +710,7 @@ impl EntityEditions<'static> {
             Self::LeftToRightOrder => "left_to_right_order",
             Self::RightToLeftOrder => "right_to_left_order",
             Self::RecordCreatedById => "record_created_by_id",
+            Self::RecordArchivedById => "record_archived_by_id",
             Self::Archived => "archived",
         };
         table.transpile(fmt)?;
@@ -713,7 +719,9 @@ impl EntityEditions<'static> {
 
     pub const fn parameter_type(self) -> ParameterType {
         match self {
-            Self::EditionId | Self::RecordCreatedById => ParameterType::Uuid,
+            Self::EditionId | Self::RecordCreatedById | Self::RecordArchivedById => {
+                ParameterType::Uuid
+            }
             Self::Properties(_) => ParameterType::Any,
             Self::LeftToRightOrder | Self::RightToLeftOrder => ParameterType::Number,
             Self::Archived => ParameterType::Boolean,
@@ -1334,6 +1342,35 @@ impl Iterator for ForeignKeyJoin {
 }
 
 impl Relation {
+    /// Returns if joining this relation results in at most one row for every row it's joined on.
+    ///
+    /// Joins through links in [`EdgeDirection::Incoming`] direction, through inherited entity
+    /// types, or through the types referenced by an ontology type can result in multiple rows.
+    pub const fn is_single_valued(self) -> bool {
+        match self {
+            Self::Reference {
+                table:
+                    ReferenceTable::EntityIsOfType {
+                        inheritance_depth: Some(0),
+                    }
+                    | ReferenceTable::EntityHasLeftEntity
+                    | ReferenceTable::EntityHasRightEntity,
+                direction: EdgeDirection::Outgoing,
+            } => true,
+            Self::Reference { .. } => false,
+            Self::OntologyIds
+            | Self::OntologyOwnedMetadata
+            | Self::OntologyExternalMetadata
+            | Self::OntologyAdditionalMetadata
+            | Self::DataTypeIds
+            | Self::PropertyTypeIds
+            | Self::EntityTypeIds
+            | Self::EntityEditions
+            | Self::LeftEntity
+            | Self::RightEntity => true,
+        }
+    }
+
     pub fn joins(self) -> ForeignKeyJoin {
         match self {
             Self::OntologyIds => ForeignKeyJoin::from_reference(ForeignKeyReference::Single {
@@ -1431,7 +1468,12 @@ impl Relation {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{ontology::DataTypeQueryPath, store::postgres::query::PostgresQueryPath};
+    use crate::{
+        knowledge::EntityQueryPath,
+        ontology::{DataTypeQueryPath, EntityTypeQueryPath},
+        store::postgres::query::PostgresQueryPath,
+        subgraph::edges::{KnowledgeGraphEdgeKind, SharedEdgeKind},
+    };
 
     #[test]
     fn transpile_table() {
@@ -1495,4 +1537,52 @@ mod tests {
             r#""data_types_1_2_3"."schema"->>'title'"#
         );
     }
+
+    #[test]
+    fn single_valued_paths() {
+        let entity_type = |inheritance_depth| EntityQueryPath::EntityTypeEdge {
+            edge_kind: SharedEdgeKind::IsOfType,
+            path: EntityTypeQueryPath::BaseUrl,
+            inheritance_depth,
+        };
+        let link = |edge_kind, direction| EntityQueryPath::EntityEdge {
+            edge_kind,
+            path: Box::new(EntityQueryPath::Archived),
+            direction,
+        };
+
+        assert!(EntityQueryPath::Uuid.is_single_valued());
+        assert!(EntityQueryPath::Properties(None).is_single_valued());
+        assert!(entity_type(Some(0)).is_single_valued());
+        assert!(!entity_type(Some(1)).is_single_valued());
+        assert!(!entity_type(None).is_single_valued());
+        assert!(
+            link(
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                EdgeDirection::Outgoing
+            )
+            .is_single_valued()
+        );
+        assert!(
+            link(
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                EdgeDirection::Outgoing
+            )
+            .is_single_valued()
+        );
+        assert!(
+            !link(
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                EdgeDirection::Incoming
+            )
+            .is_single_valued()
+        );
+        assert!(
+            !link(
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                EdgeDirection::Incoming
+            )
+            .is_single_valued()
+        );
+    }
 }
@@ -0,0 +1,163 @@
+use std::{num::NonZeroUsize, str::FromStr};
+
+use async_trait::async_trait;
+use error_stack::{Result, ResultExt};
+use graph_types::knowledge::entity::EntityId;
+use tokio_postgres::GenericClient;
+use type_system::{url::VersionedUrl, DataType, EntityType, PropertyType};
+
+use crate::store::{
+    postgres::ontology::OntologyDatabaseType, AsClient, EntitySearchResult,
+    OntologyTypeSearchResult, PostgresStore, QueryError, SearchResults, SearchStore,
+};
+
+impl<C: AsClient> PostgresStore<C> {
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn search_entities(
+        &self,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<EntitySearchResult>, QueryError> {
+        // The expression searched by has to match the index on `entity_editions.properties`
+        Ok(self
+            .as_client()
+            .query(
+                r#"
+                    SELECT
+                        entity_temporal_metadata.owned_by_id,
+                        entity_temporal_metadata.entity_uuid,
+                        ts_rank(to_tsvector('simple', entity_editions.properties), query),
+                        ts_headline(
+                            'simple',
+                            (
+                                SELECT string_agg(value #>> '{}', ' ')
+                                FROM jsonb_path_query(
+                                    entity_editions.properties,
+                                    'strict $.** ? (@.type() == "string")'
+                                ) AS value
+                            ),
+                            query
+                        )
+                    FROM entity_temporal_metadata
+                    JOIN entity_editions
+                      ON entity_editions.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id,
+                    websearch_to_tsquery('simple', $1) AS query
+                    WHERE to_tsvector('simple', entity_editions.properties) @@ query
+                      AND entity_temporal_metadata.decision_time @> now()
+                      AND entity_temporal_metadata.transaction_time @> now()
+                      AND NOT entity_editions.archived
+                    ORDER BY 3 DESC
+                    LIMIT $2;
+                "#,
+                &[&query, &limit],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| EntitySearchResult {
+                entity_id: EntityId {
+                    owned_by_id: row.get(0),
+                    entity_uuid: row.get(1),
+                },
+                rank: row.get(2),
+                // Entities without any string values don't have a highlight
+                highlight: row.get::<_, Option<String>>(3).unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn search_ontology_types<T: OntologyDatabaseType>(
+        &self,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<OntologyTypeSearchResult>, QueryError> {
+        // `T::table()` is well-defined, so it's safe to use it in the statement. The expressions
+        // searched by have to match the indices on the title and the description. Only the latest
+        // version of a type is searched, so a type is returned at most once.
+        self.as_client()
+            .query(
+                &format!(
+                    r#"
+                        SELECT
+                            {table}.schema ->> '$id',
+                            ts_rank(
+                                setweight(
+                                    to_tsvector('simple', coalesce({table}.schema ->> 'title', '')),
+                                    'A'
+                                ) || setweight(
+                                    to_tsvector(
+                                        'simple',
+                                        coalesce({table}.schema ->> 'description', '')
+                                    ),
+                                    'B'
+                                ),
+                                query
+                            ),
+                            ts_headline(
+                                'simple',
+                                concat_ws(
+                                    ' ',
+                                    {table}.schema ->> 'title',
+                                    {table}.schema ->> 'description'
+                                ),
+                                query
+                            )
+                        FROM {table}
+                        JOIN ontology_temporal_metadata
+                          ON ontology_temporal_metadata.ontology_id = {table}.ontology_id
+                        JOIN ontology_ids
+                          ON ontology_ids.ontology_id = {table}.ontology_id
+                         AND ontology_ids.version = (
+                                SELECT MAX(versions.version)
+                                FROM ontology_ids AS versions
+                                WHERE versions.base_url = ontology_ids.base_url
+                             ),
+                        websearch_to_tsquery('simple', $1) AS query
+                        WHERE (
+                                to_tsvector('simple', {table}.schema ->> 'title') @@ query
+                                OR to_tsvector('simple', {table}.schema ->> 'description') @@ query
+                            )
+                          AND ontology_temporal_metadata.transaction_time @> now()
+                        ORDER BY 2 DESC
+                        LIMIT $2;
+                    "#,
+                    table = T::table()
+                ),
+                &[&query, &limit],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                let ontology_type_id: String = row.get(0);
+                Ok(OntologyTypeSearchResult {
+                    ontology_type_id: VersionedUrl::from_str(&ontology_type_id)
+                        .change_context(QueryError)?,
+                    rank: row.get(1),
+                    highlight: row.get(2),
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<C: AsClient> SearchStore for PostgresStore<C> {
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn search(&self, query: &str, limit: NonZeroUsize) -> Result<SearchResults, QueryError> {
+        let limit = i64::try_from(limit.get()).change_context(QueryError)?;
+
+        Ok(SearchResults {
+            entities: self.search_entities(query, limit).await?,
+            data_types: self.search_ontology_types::<DataType>(query, limit).await?,
+            property_types: self
+                .search_ontology_types::<PropertyType>(query, limit)
+                .await?,
+            entity_types: self
+                .search_ontology_types::<EntityType>(query, limit)
+                .await?,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, hash::Hash};
+use std::{borrow::Cow, collections::HashMap, hash::Hash};
 
 use error_stack::Result;
 use graph_types::{
@@ -34,7 +34,7 @@ impl<C: AsClient> PostgresStore<C> {
             self,
             &Filter::<DataTypeWithMetadata>::In(
                 FilterExpression::Path(DataTypeQueryPath::OntologyId),
-                ParameterList::Uuid(&ids),
+                ParameterList::Uuid(Cow::Borrowed(&ids)),
             ),
             Some(&subgraph.temporal_axes.resolved),
         )
@@ -63,7 +63,7 @@ impl<C: AsClient> PostgresStore<C> {
             self,
             &Filter::<PropertyTypeWithMetadata>::In(
                 FilterExpression::Path(PropertyTypeQueryPath::OntologyId),
-                ParameterList::Uuid(&ids),
+                ParameterList::Uuid(Cow::Borrowed(&ids)),
             ),
             Some(&subgraph.temporal_axes.resolved),
         )
@@ -92,7 +92,7 @@ impl<C: AsClient> PostgresStore<C> {
             self,
             &Filter::<EntityTypeWithMetadata>::In(
                 FilterExpression::Path(EntityTypeQueryPath::OntologyId),
-                ParameterList::Uuid(&ids),
+                ParameterList::Uuid(Cow::Borrowed(&ids)),
             ),
             Some(&subgraph.temporal_axes.resolved),
         )
@@ -121,7 +121,7 @@ impl<C: AsClient> PostgresStore<C> {
             self,
             &Filter::<Entity>::In(
                 FilterExpression::Path(EntityQueryPath::EditionId),
-                ParameterList::Uuid(&ids),
+                ParameterList::Uuid(Cow::Borrowed(&ids)),
             ),
             Some(&subgraph.temporal_axes.resolved),
         )
@@ -1,5 +1,8 @@
+mod aggregation;
+mod cursor;
 mod filter;
 mod path;
+mod sorting;
 
 use std::{collections::HashMap, fmt};
 
@@ -9,8 +12,14 @@ use serde::{
 };
 
 pub use self::{
+    aggregation::{
+        Aggregation, AggregationFunction, AggregationGroup, AggregationQuery, AggregationResult,
+        EntityAggregationQuery,
+    },
+    cursor::Cursor,
     filter::{Filter, FilterExpression, Parameter, ParameterConversionError, ParameterList},
     path::{JsonPath, PathToken},
+    sorting::{Ordering, Sorting},
 };
 
 pub trait QueryPath {
@@ -0,0 +1,207 @@
+use std::fmt;
+
+use derivative::Derivative;
+use graph_types::knowledge::entity::Entity;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{
+    openapi::{ArrayBuilder, Object, ObjectBuilder, OneOfBuilder, Ref, RefOr, Schema, SchemaType},
+    ToSchema,
+};
+
+use crate::{
+    store::{query::Filter, Record},
+    subgraph::temporal_axes::QueryTemporalAxesUnresolved,
+};
+
+/// A function to aggregate the values at a path with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum AggregationFunction {
+    /// The number of records with a value at the path. If no path is specified, all records are
+    /// counted.
+    Count,
+    /// The smallest value. Numbers and strings are compared by their value, values of different
+    /// JSON types are compared by their type.
+    Min,
+    /// The largest value. Numbers and strings are compared by their value, values of different
+    /// JSON types are compared by their type.
+    Max,
+    /// The arithmetic mean of the values, which have to be numbers.
+    Average,
+}
+
+/// Applies an [`AggregationFunction`] to the values at `path`.
+///
+/// `null` values and missing values are ignored.
+#[derive(Derivative, Deserialize)]
+#[derivative(
+    Debug(bound = "R::QueryPath<'p>: fmt::Debug"),
+    PartialEq(bound = "R::QueryPath<'p>: PartialEq")
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "camelCase",
+    bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>"
+)]
+pub struct Aggregation<'p, R: Record + ?Sized> {
+    pub function: AggregationFunction,
+    #[serde(default)]
+    pub path: Option<R::QueryPath<'p>>,
+}
+
+/// Aggregates the records matching `filter`.
+///
+/// The records are grouped by the values at the `group_by` paths, every [`Aggregation`] is applied
+/// to each group. Without any `group_by` path, all records form a single group. Paths which can
+/// have multiple values for a record, e.g. paths through inherited types, cannot be used to group
+/// or aggregate.
+///
+/// # Examples
+///
+/// This query counts the entities of each type for every owner:
+///
+/// ```json
+/// {
+///   "filter": { "all": [] },
+///   "temporalAxes": { ... },
+///   "groupBy": [["ownedById"], ["type(inheritanceDepth = 0)", "versionedUrl"]],
+///   "aggregations": [{ "function": "count" }]
+/// }
+/// ```
+#[derive(Derivative, Deserialize)]
+#[derivative(Debug(bound = "R::QueryPath<'p>: fmt::Debug"))]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct AggregationQuery<'p, R: Record> {
+    #[serde(bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>")]
+    pub filter: Filter<'p, R>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    #[serde(default, bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>")]
+    pub group_by: Vec<R::QueryPath<'p>>,
+    #[serde(bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>")]
+    pub aggregations: Vec<Aggregation<'p, R>>,
+}
+
+pub type EntityAggregationQuery = AggregationQuery<'static, Entity>;
+
+impl<'p> ToSchema<'_> for AggregationQuery<'p, Entity> {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        let path = || {
+            ArrayBuilder::new().items(
+                OneOfBuilder::new()
+                    .item(Ref::from_schema_name("EntityQueryToken"))
+                    .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                    .item(ObjectBuilder::new().schema_type(SchemaType::Number)),
+            )
+        };
+
+        (
+            "EntityAggregationQuery",
+            ObjectBuilder::new()
+                .property("filter", Ref::from_schema_name("Filter"))
+                .required("filter")
+                .property(
+                    "temporalAxes",
+                    Ref::from_schema_name(QueryTemporalAxesUnresolved::schema().0),
+                )
+                .required("temporalAxes")
+                .property("groupBy", ArrayBuilder::new().items(path()))
+                .property(
+                    "aggregations",
+                    ArrayBuilder::new().items(
+                        ObjectBuilder::new()
+                            .property(
+                                "function",
+                                Ref::from_schema_name(AggregationFunction::schema().0),
+                            )
+                            .required("function")
+                            .property("path", path()),
+                    ),
+                )
+                .required("aggregations")
+                .into(),
+        )
+    }
+}
+
+/// The result of an [`AggregationFunction`].
+///
+/// [`Min`], [`Max`], and [`Average`] are `None` if there are no values to aggregate.
+///
+/// [`Min`]: AggregationFunction::Min
+/// [`Max`]: AggregationFunction::Max
+/// [`Average`]: AggregationFunction::Average
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AggregationResult {
+    Count(usize),
+    Min(Option<Value>),
+    Max(Option<Value>),
+    Average(Option<f64>),
+}
+
+impl ToSchema<'_> for AggregationResult {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        let result = |name: &'static str, schema: Object| {
+            ObjectBuilder::new()
+                .title(Some(name))
+                .property(name, schema)
+                .required(name)
+        };
+
+        (
+            "AggregationResult",
+            Schema::OneOf(
+                OneOfBuilder::new()
+                    .item(result(
+                        "count",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::Integer)
+                            .minimum(Some(0.0))
+                            .build(),
+                    ))
+                    .item(result("min", Object::with_type(SchemaType::Value)))
+                    .item(result("max", Object::with_type(SchemaType::Value)))
+                    .item(result(
+                        "average",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::Number)
+                            .nullable(true)
+                            .build(),
+                    ))
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
+/// The aggregated values of the records sharing the same values at the `group_by` paths.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregationGroup {
+    /// The values at the `group_by` paths, `null` if the records don't have a value.
+    pub group: Vec<Value>,
+    /// The results of the aggregations in the order they were requested.
+    pub results: Vec<AggregationResult>,
+}
+
+impl ToSchema<'_> for AggregationGroup {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        (
+            "AggregationGroup",
+            ObjectBuilder::new()
+                .property(
+                    "group",
+                    ArrayBuilder::new().items(Object::with_type(SchemaType::Value)),
+                )
+                .required("group")
+                .property(
+                    "results",
+                    ArrayBuilder::new().items(Ref::from_schema_name(AggregationResult::schema().0)),
+                )
+                .required("results")
+                .into(),
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use error_stack::{Result, ResultExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::store::QueryError;
+
+/// An opaque position in the results of a query.
+///
+/// A cursor is returned alongside a page of results if more results are available and can be
+/// passed to the same query again to continue reading after the last result of the page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Encodes `position` into a cursor.
+    ///
+    /// # Errors
+    ///
+    /// - if `position` cannot be serialized
+    pub fn encode<T: Serialize>(position: &T) -> Result<Self, QueryError> {
+        let position = serde_json::to_vec(position).change_context(QueryError)?;
+        Ok(Self(URL_SAFE_NO_PAD.encode(position)))
+    }
+
+    /// Decodes the position this cursor is pointing to.
+    ///
+    /// # Errors
+    ///
+    /// - if the cursor was not created by [`Cursor::encode`] with a position of type `T`
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, QueryError> {
+        let position = URL_SAFE_NO_PAD
+            .decode(&self.0)
+            .change_context(QueryError)
+            .attach_printable("invalid cursor")?;
+        serde_json::from_slice(&position)
+            .change_context(QueryError)
+            .attach_printable("invalid cursor")
+    }
+}
@@ -42,11 +42,29 @@ pub enum Filter<'p, R: Record + ?Sized> {
         Option<FilterExpression<'p, R>>,
         Option<FilterExpression<'p, R>>,
     ),
-    #[serde(skip)]
     In(FilterExpression<'p, R>, ParameterList<'p>),
+    Less(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    LessOrEqual(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    Greater(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    GreaterOrEqual(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Checks if the first expression is between the second and the third expression
+    /// (inclusive).
+    Between(
+        FilterExpression<'p, R>,
+        FilterExpression<'p, R>,
+        FilterExpression<'p, R>,
+    ),
     StartsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     EndsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     ContainsSegment(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Checks if the text of the first expression matches the full-text search query of the
+    /// second expression.
+    ///
+    /// The query uses the syntax of web search engines: Unquoted words have to occur, quoted
+    /// phrases have to occur in the given order, `or` matches either side and words prefixed by
+    /// `-` must not occur. If the first expression is a JSON value, all strings contained in it
+    /// are searched. Words are compared case-insensitively, but are not stemmed.
+    ContainsText(FilterExpression<'p, R>, FilterExpression<'p, R>),
 }
 
 impl<'p, R> Filter<'p, R>
@@ -122,18 +140,37 @@ where
                 ) => parameter.convert_to_parameter_type(path.expected_type())?,
                 (..) => {}
             },
-            Self::In(lhs, rhs) => {
-                if let FilterExpression::Parameter(parameter) = lhs {
-                    match rhs {
-                        ParameterList::Uuid(_) => {
-                            parameter.convert_to_parameter_type(ParameterType::Uuid)?;
+            Self::In(lhs, rhs) => match lhs {
+                FilterExpression::Parameter(parameter) => {
+                    parameter.convert_to_parameter_type(rhs.parameter_type())?;
+                }
+                FilterExpression::Path(path) => {
+                    rhs.convert_to_parameter_type(path.expected_type())?;
+                }
+            },
+            Self::Less(lhs, rhs)
+            | Self::LessOrEqual(lhs, rhs)
+            | Self::Greater(lhs, rhs)
+            | Self::GreaterOrEqual(lhs, rhs) => match (lhs, rhs) {
+                (FilterExpression::Parameter(parameter), FilterExpression::Path(path))
+                | (FilterExpression::Path(path), FilterExpression::Parameter(parameter)) => {
+                    parameter.convert_to_parameter_type(path.expected_type())?;
+                }
+                (..) => {}
+            },
+            Self::Between(value, lower, upper) => {
+                if let FilterExpression::Path(path) = value {
+                    for bound in [lower, upper] {
+                        if let FilterExpression::Parameter(parameter) = bound {
+                            parameter.convert_to_parameter_type(path.expected_type())?;
                         }
                     }
                 }
             }
             Self::StartsWith(lhs, rhs)
             | Self::EndsWith(lhs, rhs)
-            | Self::ContainsSegment(lhs, rhs) => {
+            | Self::ContainsSegment(lhs, rhs)
+            | Self::ContainsText(lhs, rhs) => {
                 // TODO: We need to find a way to support lists in addition to strings as well
                 if let FilterExpression::Parameter(parameter) = lhs {
                     parameter.convert_to_parameter_type(ParameterType::Text)?;
@@ -176,9 +213,127 @@ pub enum Parameter<'p> {
     OntologyTypeVersion(OntologyTypeVersion),
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A list of values used in an [`In`] filter.
+///
+/// When deserialized, the list contains arbitrary JSON values, which are converted to the type of
+/// the compared path by [`Filter::convert_parameters`].
+///
+/// [`In`]: Filter::In
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
 pub enum ParameterList<'p> {
-    Uuid(&'p [Uuid]),
+    #[serde(skip)]
+    Boolean(Vec<bool>),
+    #[serde(skip)]
+    Number(Vec<i32>),
+    #[serde(skip)]
+    Text(Vec<String>),
+    Any(Vec<Value>),
+    #[serde(skip)]
+    Uuid(Cow<'p, [Uuid]>),
+    #[serde(skip)]
+    OntologyTypeVersion(Vec<OntologyTypeVersion>),
+}
+
+impl ParameterList<'_> {
+    /// Returns the type of the values in this list.
+    #[must_use]
+    pub const fn parameter_type(&self) -> ParameterType {
+        match self {
+            Self::Boolean(_) => ParameterType::Boolean,
+            Self::Number(_) => ParameterType::Number,
+            Self::Text(_) => ParameterType::Text,
+            Self::Any(_) => ParameterType::Any,
+            Self::Uuid(_) => ParameterType::Uuid,
+            Self::OntologyTypeVersion(_) => ParameterType::OntologyTypeVersion,
+        }
+    }
+
+    fn convert_to_parameter_type(
+        &mut self,
+        expected: ParameterType,
+    ) -> Result<(), Report<ParameterConversionError>> {
+        // Only lists of arbitrary values, i.e. deserialized lists, are converted
+        let Self::Any(values) = self else {
+            return Ok(());
+        };
+
+        if expected == ParameterType::Any {
+            return Ok(());
+        }
+
+        let parameters = values
+            .iter()
+            .map(|value| {
+                let mut parameter = match value {
+                    Value::Bool(bool) => Parameter::Boolean(*bool),
+                    Value::String(string) => Parameter::Text(Cow::Owned(string.clone())),
+                    Value::Number(number) => number
+                        .as_i64()
+                        .and_then(|number| i32::try_from(number).ok())
+                        .map_or_else(|| Parameter::Any(value.clone()), Parameter::Number),
+                    Value::Null | Value::Array(_) | Value::Object(_) => {
+                        Parameter::Any(value.clone())
+                    }
+                };
+                parameter.convert_to_parameter_type(expected)?;
+                Ok(parameter)
+            })
+            .collect::<Result<Vec<_>, Report<ParameterConversionError>>>()?;
+
+        *self =
+            match expected {
+                ParameterType::Boolean => {
+                    Self::Boolean(collect_parameters(parameters, expected, |parameter| {
+                        match parameter {
+                            Parameter::Boolean(bool) => Ok(bool),
+                            parameter => Err(parameter),
+                        }
+                    })?)
+                }
+                ParameterType::Number => {
+                    Self::Number(collect_parameters(parameters, expected, |parameter| {
+                        match parameter {
+                            Parameter::Number(number) => Ok(number),
+                            parameter => Err(parameter),
+                        }
+                    })?)
+                }
+                ParameterType::Text | ParameterType::BaseUrl | ParameterType::VersionedUrl => {
+                    Self::Text(collect_parameters(
+                        parameters,
+                        expected,
+                        |parameter| match parameter {
+                            Parameter::Text(text) => Ok(text.into_owned()),
+                            parameter => Err(parameter),
+                        },
+                    )?)
+                }
+                ParameterType::Uuid => Self::Uuid(Cow::Owned(collect_parameters(
+                    parameters,
+                    expected,
+                    |parameter| match parameter {
+                        Parameter::Uuid(uuid) => Ok(uuid),
+                        parameter => Err(parameter),
+                    },
+                )?)),
+                ParameterType::OntologyTypeVersion => Self::OntologyTypeVersion(
+                    collect_parameters(parameters, expected, |parameter| match parameter {
+                        Parameter::OntologyTypeVersion(version) => Ok(version),
+                        parameter => Err(parameter),
+                    })?,
+                ),
+                ParameterType::Any
+                | ParameterType::TimeInterval
+                | ParameterType::Timestamp
+                | ParameterType::Object => bail!(ParameterConversionError {
+                    actual: Parameter::Any(Value::Array(values.clone())),
+                    expected,
+                }),
+            };
+
+        Ok(())
+    }
 }
 
 impl Parameter<'_> {
@@ -194,6 +349,25 @@ impl Parameter<'_> {
     }
 }
 
+/// Extracts the values of the converted `parameters` of a [`ParameterList`].
+fn collect_parameters<'p, T>(
+    parameters: Vec<Parameter<'p>>,
+    expected: ParameterType,
+    extract: impl Fn(Parameter<'p>) -> Result<T, Parameter<'p>>,
+) -> Result<Vec<T>, Report<ParameterConversionError>> {
+    parameters
+        .into_iter()
+        .map(|parameter| {
+            extract(parameter).map_err(|actual| {
+                Report::new(ParameterConversionError {
+                    actual: actual.to_owned(),
+                    expected,
+                })
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 #[must_use]
 pub struct ParameterConversionError {
@@ -406,4 +580,61 @@ mod tests {
             &expected,
         );
     }
+
+    #[test]
+    fn in_list() {
+        let expected = json!({
+          "in": [
+            { "path": ["title"] },
+            ["Text", "Number"]
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::In(
+                FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::Title),
+                ParameterList::Text(vec!["Text".to_owned(), "Number".to_owned()]),
+            ),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn in_list_conversion_error() {
+        let mut filter = Filter::<DataTypeWithMetadata>::deserialize(&json!({
+          "in": [
+            { "path": ["version"] },
+            [1, "latest"]
+          ]
+        }))
+        .expect("Could not deserialize filter");
+
+        filter
+            .convert_parameters()
+            .expect_err("`latest` is not a valid version in a list");
+    }
+
+    #[test]
+    fn between() {
+        let expected = json!({
+          "between": [
+            { "path": ["version"] },
+            { "parameter": 1 },
+            { "parameter": 3 }
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::Between(
+                FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::Version),
+                FilterExpression::Parameter(Parameter::OntologyTypeVersion(
+                    OntologyTypeVersion::new(1),
+                )),
+                FilterExpression::Parameter(Parameter::OntologyTypeVersion(
+                    OntologyTypeVersion::new(3),
+                )),
+            ),
+            &expected,
+        );
+    }
 }
@@ -0,0 +1,36 @@
+use std::fmt;
+
+use derivative::Derivative;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::store::Record;
+
+/// The direction in which values are sorted.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum Ordering {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Sorts the results of a query by the value at `path`.
+///
+/// Values are compared as JSON values. `null` values and missing values are sorted last in
+/// [`Ordering::Ascending`] order and first in [`Ordering::Descending`] order.
+#[derive(Derivative, Deserialize)]
+#[derivative(
+    Debug(bound = "R::QueryPath<'p>: fmt::Debug"),
+    PartialEq(bound = "R::QueryPath<'p>: PartialEq")
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "camelCase",
+    bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>"
+)]
+pub struct Sorting<'p, R: Record + ?Sized> {
+    pub path: R::QueryPath<'p>,
+    #[serde(default)]
+    pub ordering: Ordering,
+}
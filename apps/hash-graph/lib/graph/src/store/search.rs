@@ -0,0 +1,63 @@
+use std::num::NonZeroUsize;
+
+use async_trait::async_trait;
+use error_stack::Result;
+use graph_types::knowledge::entity::EntityId;
+use serde::Serialize;
+use type_system::url::VersionedUrl;
+use utoipa::ToSchema;
+
+use crate::store::QueryError;
+
+/// An entity matching a full-text search query.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EntitySearchResult {
+    pub entity_id: EntityId,
+    /// The relevance of the entity for the query, higher values are more relevant.
+    pub rank: f32,
+    /// The string values of the properties of the entity, reduced to the most relevant fragments.
+    /// Words matching the query are surrounded by `<b>` and `</b>`.
+    pub highlight: String,
+}
+
+/// An ontology type matching a full-text search query.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OntologyTypeSearchResult {
+    #[schema(value_type = SHARED_VersionedUrl)]
+    pub ontology_type_id: VersionedUrl,
+    /// The relevance of the type for the query, higher values are more relevant. Matches in the
+    /// title are ranked higher than matches in the description.
+    pub rank: f32,
+    /// The title and the description of the type, reduced to the most relevant fragments. Words
+    /// matching the query are surrounded by `<b>` and `</b>`.
+    pub highlight: String,
+}
+
+/// The results of a full-text search, ordered by descending rank.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResults {
+    pub entities: Vec<EntitySearchResult>,
+    pub data_types: Vec<OntologyTypeSearchResult>,
+    pub property_types: Vec<OntologyTypeSearchResult>,
+    pub entity_types: Vec<OntologyTypeSearchResult>,
+}
+
+/// Describes the API of a store implementation for full-text search.
+#[async_trait]
+pub trait SearchStore {
+    /// Searches the properties of the current, non-archived entities and the titles and
+    /// descriptions of the current, non-archived ontology types.
+    ///
+    /// The `query` uses the syntax described in [`Filter::ContainsText`]. At most `limit` results
+    /// are returned for each kind of graph element.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the search results failed
+    ///
+    /// [`Filter::ContainsText`]: crate::store::query::Filter::ContainsText
+    async fn search(&self, query: &str, limit: NonZeroUsize) -> Result<SearchResults, QueryError>;
+}
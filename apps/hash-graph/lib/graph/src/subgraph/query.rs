@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, num::NonZeroUsize};
 
 use derivative::Derivative;
 use graph_types::{
@@ -7,12 +7,15 @@ use graph_types::{
 };
 use serde::Deserialize;
 use utoipa::{
-    openapi::{ObjectBuilder, Ref, RefOr, Schema},
+    openapi::{ArrayBuilder, ObjectBuilder, OneOfBuilder, Ref, RefOr, Schema, SchemaType},
     ToSchema,
 };
 
 use crate::{
-    store::{query::Filter, Record},
+    store::{
+        query::{Cursor, Filter, Sorting},
+        Record,
+    },
     subgraph::{edges::GraphResolveDepths, temporal_axes::QueryTemporalAxesUnresolved},
 };
 
@@ -47,6 +50,19 @@ use crate::{
 /// an in-depth explanation of the depth of a query, please see the documentation on
 /// [`GraphResolveDepths`].
 ///
+/// # Sorting and pagination
+///
+/// The root vertices can be sorted by any number of [`Sorting`]s, which consist of a
+/// [`RecordPath`] and an [`Ordering`]. Vertices, which are equal in all sortings, are sorted by
+/// their record, so the order is always deterministic.
+///
+/// If a `limit` is specified, at most `limit` root vertices are returned. When more root vertices
+/// are available, a [`Cursor`] is returned alongside the [`Subgraph`]. Passing this cursor to the
+/// same query returns the next page of root vertices. As the cursor has to be returned, a `limit`
+/// or a `cursor` is only accepted when requesting a page of entities.
+///
+/// Sorting and pagination are currently only supported for entities.
+///
 /// # Examples
 ///
 /// Typically, a structural will be deserialized from a JSON request. The following examples assume,
@@ -154,8 +170,29 @@ use crate::{
 /// }
 /// ```
 ///
+/// This query will return the first 20 entities sorted by the `name` property in descending order.
+/// The [`Cursor`] returned alongside the first page can be passed as `"cursor"` to request the next
+/// page:
+///
+/// ```json
+/// {
+///   "filter": { "all": [] },
+///   "graphResolveDepths": { ... },
+///   "temporalAxes": { ... },
+///   "sortBy": [
+///     {
+///       "path": ["properties", "https://blockprotocol.org/@alice/types/property-type/name/"],
+///       "ordering": "descending"
+///     }
+///   ],
+///   "limit": 20
+/// }
+/// ```
+///
 /// [`RecordPath`]: crate::store::query::QueryPath
 /// [`Parameter`]: crate::store::query::Parameter
+/// [`Ordering`]: crate::store::query::Ordering
+/// [`Subgraph`]: crate::subgraph::Subgraph
 #[derive(Deserialize, Derivative)]
 #[derivative(Debug(bound = "R::QueryPath<'p>: Debug"))]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
@@ -164,10 +201,22 @@ pub struct StructuralQuery<'p, R: Record> {
     pub filter: Filter<'p, R>,
     pub graph_resolve_depths: GraphResolveDepths,
     pub temporal_axes: QueryTemporalAxesUnresolved,
+    #[serde(default, bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>")]
+    pub sort_by: Vec<Sorting<'p, R>>,
+    #[serde(default)]
+    pub limit: Option<NonZeroUsize>,
+    #[serde(default)]
+    pub cursor: Option<Cursor>,
 }
 
 impl<'p, R: Record> StructuralQuery<'p, R> {
-    fn generate_schema() -> RefOr<Schema> {
+    /// Returns `true` if the query requests sorting or pagination.
+    #[must_use]
+    pub fn is_paginated(&self) -> bool {
+        !self.sort_by.is_empty() || self.limit.is_some() || self.cursor.is_some()
+    }
+
+    fn generate_schema() -> ObjectBuilder {
         ObjectBuilder::new()
             .property("filter", Ref::from_schema_name("Filter"))
             .required("filter")
@@ -181,7 +230,6 @@ impl<'p, R: Record> StructuralQuery<'p, R> {
                 Ref::from_schema_name(QueryTemporalAxesUnresolved::schema().0),
             )
             .required("temporalAxes")
-            .into()
     }
 }
 
@@ -194,7 +242,7 @@ impl<'p> ToSchema<'_> for StructuralQuery<'p, DataTypeWithMetadata> {
     fn schema() -> (&'static str, RefOr<Schema>) {
         (
             "DataTypeStructuralQuery",
-            StructuralQuery::<'p, DataTypeWithMetadata>::generate_schema(),
+            StructuralQuery::<'p, DataTypeWithMetadata>::generate_schema().into(),
         )
     }
 }
@@ -203,7 +251,7 @@ impl<'p> ToSchema<'_> for StructuralQuery<'p, PropertyTypeWithMetadata> {
     fn schema() -> (&'static str, RefOr<Schema>) {
         (
             "PropertyTypeStructuralQuery",
-            StructuralQuery::<'p, PropertyTypeWithMetadata>::generate_schema(),
+            StructuralQuery::<'p, PropertyTypeWithMetadata>::generate_schema().into(),
         )
     }
 }
@@ -212,7 +260,7 @@ impl<'p> ToSchema<'_> for StructuralQuery<'p, EntityTypeWithMetadata> {
     fn schema() -> (&'static str, RefOr<Schema>) {
         (
             "EntityTypeStructuralQuery",
-            StructuralQuery::<'p, EntityTypeWithMetadata>::generate_schema(),
+            StructuralQuery::<'p, EntityTypeWithMetadata>::generate_schema().into(),
         )
     }
 }
@@ -221,7 +269,32 @@ impl<'p> ToSchema<'_> for StructuralQuery<'p, Entity> {
     fn schema() -> (&'static str, RefOr<Schema>) {
         (
             "EntityStructuralQuery",
-            StructuralQuery::<'p, Entity>::generate_schema(),
+            StructuralQuery::<'p, Entity>::generate_schema()
+                .property(
+                    "sortBy",
+                    ArrayBuilder::new().items(
+                        ObjectBuilder::new()
+                            .property(
+                                "path",
+                                ArrayBuilder::new().items(
+                                    OneOfBuilder::new()
+                                        .item(Ref::from_schema_name("EntityQueryToken"))
+                                        .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                                        .item(ObjectBuilder::new().schema_type(SchemaType::Number)),
+                                ),
+                            )
+                            .required("path")
+                            .property("ordering", Ref::from_schema_name("Ordering")),
+                    ),
+                )
+                .property(
+                    "limit",
+                    ObjectBuilder::new()
+                        .schema_type(SchemaType::Integer)
+                        .minimum(Some(1.0)),
+                )
+                .property("cursor", Ref::from_schema_name("Cursor"))
+                .into(),
         )
     }
 }
@@ -0,0 +1,29 @@
+-- Converts JSON values for ordering comparisons. A value of a different type is converted to
+-- `NULL`, so it never matches a comparison instead of failing the query.
+CREATE
+OR REPLACE FUNCTION jsonb_to_numeric ("value" JSONB) RETURNS NUMERIC AS $jsonb_to_numeric$
+  SELECT CASE WHEN jsonb_typeof(value) = 'number' THEN value::NUMERIC END;
+$jsonb_to_numeric$ LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE;
+
+CREATE
+OR REPLACE FUNCTION jsonb_to_text ("value" JSONB) RETURNS TEXT AS $jsonb_to_text$
+  SELECT CASE WHEN jsonb_typeof(value) = 'string' THEN value #>> '{}' END;
+$jsonb_to_text$ LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE;
+
+-- Only RFC 3339 timestamps are converted. They always contain an offset, so the conversion does not
+-- depend on the time zone of the session.
+CREATE
+OR REPLACE FUNCTION jsonb_to_timestamptz ("value" JSONB) RETURNS TIMESTAMPTZ AS $jsonb_to_timestamptz$
+BEGIN
+  IF jsonb_typeof(value) <> 'string'
+    OR (value #>> '{}') !~ '^\d{4}-\d{2}-\d{2}[Tt]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$'
+  THEN
+    RETURN NULL;
+  END IF;
+
+  RETURN (value #>> '{}')::TIMESTAMPTZ;
+EXCEPTION
+  WHEN datetime_field_overflow OR invalid_datetime_format THEN
+    RETURN NULL;
+END
+$jsonb_to_timestamptz$ LANGUAGE plpgsql IMMUTABLE STRICT PARALLEL SAFE;
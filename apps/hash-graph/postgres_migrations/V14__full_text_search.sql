@@ -0,0 +1,20 @@
+CREATE INDEX
+  "entity_editions_properties_search_idx" ON "entity_editions" USING gin (TO_TSVECTOR('simple', "properties"));
+
+CREATE INDEX
+  "data_types_title_search_idx" ON "data_types" USING gin (TO_TSVECTOR('simple', "schema" ->> 'title'));
+
+CREATE INDEX
+  "data_types_description_search_idx" ON "data_types" USING gin (TO_TSVECTOR('simple', "schema" ->> 'description'));
+
+CREATE INDEX
+  "property_types_title_search_idx" ON "property_types" USING gin (TO_TSVECTOR('simple', "schema" ->> 'title'));
+
+CREATE INDEX
+  "property_types_description_search_idx" ON "property_types" USING gin (TO_TSVECTOR('simple', "schema" ->> 'description'));
+
+CREATE INDEX
+  "entity_types_title_search_idx" ON "entity_types" USING gin (TO_TSVECTOR('simple', "schema" ->> 'title'));
+
+CREATE INDEX
+  "entity_types_description_search_idx" ON "entity_types" USING gin (TO_TSVECTOR('simple', "schema" ->> 'description'));
@@ -0,0 +1,25 @@
+CREATE
+OR REPLACE FUNCTION jsonb_smaller ("lhs" JSONB, "rhs" JSONB) RETURNS JSONB AS $jsonb_smaller$
+  SELECT CASE WHEN lhs <= rhs THEN lhs ELSE rhs END;
+$jsonb_smaller$ LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE;
+
+CREATE
+OR REPLACE FUNCTION jsonb_larger ("lhs" JSONB, "rhs" JSONB) RETURNS JSONB AS $jsonb_larger$
+  SELECT CASE WHEN lhs >= rhs THEN lhs ELSE rhs END;
+$jsonb_larger$ LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE;
+
+CREATE AGGREGATE
+  MIN (JSONB) (
+    SFUNC = jsonb_smaller,
+    STYPE = JSONB,
+    COMBINEFUNC = jsonb_smaller,
+    PARALLEL = SAFE
+  );
+
+CREATE AGGREGATE
+  MAX (JSONB) (
+    SFUNC = jsonb_larger,
+    STYPE = JSONB,
+    COMBINEFUNC = jsonb_larger,
+    PARALLEL = SAFE
+  );
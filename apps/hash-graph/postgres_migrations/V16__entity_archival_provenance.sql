@@ -0,0 +1,18 @@
+ALTER TABLE
+  "entity_editions"
+ADD COLUMN
+  "record_archived_by_id" UUID REFERENCES "accounts";
+
+UPDATE
+  "entity_editions"
+SET
+  "record_archived_by_id" = "record_created_by_id"
+WHERE
+  "archived";
+
+ALTER TABLE
+  "entity_editions"
+ADD
+  CONSTRAINT "record_archived_check" CHECK (
+    ("record_archived_by_id" IS NULL) = (NOT "archived")
+  );
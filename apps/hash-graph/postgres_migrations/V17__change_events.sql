@@ -0,0 +1,220 @@
+CREATE TYPE
+  "change_operation" AS ENUM('created', 'updated', 'archived', 'unarchived');
+
+CREATE TYPE
+  "change_record_kind" AS ENUM(
+    'entity',
+    'data_type',
+    'property_type',
+    'entity_type'
+  );
+
+-- The change events are not referencing the changed records, so deleting records does not
+-- require rewriting the history of changes.
+CREATE TABLE IF NOT EXISTS
+  "change_events" (
+    "offset" BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    "operation" "change_operation" NOT NULL,
+    "record_kind" "change_record_kind" NOT NULL,
+    "transaction_time" TIMESTAMP WITH TIME ZONE NOT NULL,
+    "owned_by_id" UUID,
+    "entity_uuid" UUID,
+    "entity_edition_id" UUID,
+    "base_url" TEXT,
+    "version" BIGINT,
+    "record_created_by_id" UUID NOT NULL,
+    "record_archived_by_id" UUID,
+    CONSTRAINT "change_events_record_check" CHECK (
+      CASE
+        WHEN "record_kind" = 'entity' THEN "owned_by_id" IS NOT NULL
+        AND "entity_uuid" IS NOT NULL
+        AND "entity_edition_id" IS NOT NULL
+        AND "base_url" IS NULL
+        AND "version" IS NULL
+        ELSE "owned_by_id" IS NULL
+        AND "entity_uuid" IS NULL
+        AND "entity_edition_id" IS NULL
+        AND "base_url" IS NOT NULL
+        AND "version" IS NOT NULL
+      END
+    )
+  );
+
+CREATE
+OR REPLACE FUNCTION "record_entity_change_trigger" () RETURNS TRIGGER AS $pga$
+    DECLARE
+      _operation change_operation;
+      _archived BOOLEAN;
+      _previously_archived BOOLEAN;
+      _record_created_by_id UUID;
+      _record_archived_by_id UUID;
+    BEGIN
+      SELECT archived, record_created_by_id, record_archived_by_id
+      INTO _archived, _record_created_by_id, _record_archived_by_id
+      FROM entity_editions
+      WHERE entity_edition_id = NEW.entity_edition_id;
+
+      -- The record was deleted in the same transaction, so there is no change to record.
+      IF NOT FOUND THEN
+        RETURN NULL;
+      END IF;
+
+      IF TG_OP = 'INSERT' THEN
+        _operation := 'created';
+      ELSE
+        SELECT archived
+        INTO _previously_archived
+        FROM entity_editions
+        WHERE entity_edition_id = OLD.entity_edition_id;
+
+        IF _archived AND NOT _previously_archived THEN
+          _operation := 'archived';
+        ELSIF NOT _archived AND _previously_archived THEN
+          _operation := 'unarchived';
+        ELSE
+          _operation := 'updated';
+        END IF;
+      END IF;
+
+      -- The trigger is deferred until the transaction commits. Holding the lock until the commit
+      -- is finished assigns the offsets in the order the transactions become visible.
+      PERFORM pg_advisory_xact_lock(hashtext('change_events'));
+
+      INSERT INTO change_events (
+        operation,
+        record_kind,
+        transaction_time,
+        owned_by_id,
+        entity_uuid,
+        entity_edition_id,
+        record_created_by_id,
+        record_archived_by_id
+      ) VALUES (
+        _operation,
+        'entity',
+        lower(NEW.transaction_time),
+        NEW.owned_by_id,
+        NEW.entity_uuid,
+        NEW.entity_edition_id,
+        _record_created_by_id,
+        _record_archived_by_id
+      );
+
+      RETURN NULL;
+    END$pga$ VOLATILE LANGUAGE plpgsql;
+
+-- Rows inserted by `update_entity_version_trigger` are keeping the history of an entity and are
+-- not changes on their own, so only top-level inserts are recorded.
+CREATE CONSTRAINT TRIGGER
+  "record_entity_creation_trigger"
+AFTER
+INSERT
+  ON "entity_temporal_metadata" DEFERRABLE INITIALLY DEFERRED FOR EACH ROW
+  WHEN (PG_TRIGGER_DEPTH() = 0)
+EXECUTE
+  PROCEDURE "record_entity_change_trigger" ();
+
+CREATE CONSTRAINT TRIGGER
+  "record_entity_update_trigger"
+AFTER
+UPDATE
+  ON "entity_temporal_metadata" DEFERRABLE INITIALLY DEFERRED FOR EACH ROW
+  WHEN (OLD.entity_edition_id <> NEW.entity_edition_id)
+EXECUTE
+  PROCEDURE "record_entity_change_trigger" ();
+
+CREATE
+OR REPLACE FUNCTION "record_ontology_change_trigger" () RETURNS TRIGGER AS $pga$
+    DECLARE
+      _operation change_operation;
+      _record_kind change_record_kind;
+      _transaction_time TIMESTAMP WITH TIME ZONE;
+      _base_url TEXT;
+      _version BIGINT;
+    BEGIN
+      SELECT base_url, version
+      INTO _base_url, _version
+      FROM ontology_ids
+      WHERE ontology_id = NEW.ontology_id;
+
+      -- See `record_entity_change_trigger`
+      IF NOT FOUND THEN
+        RETURN NULL;
+      END IF;
+
+      IF TG_OP = 'UPDATE' THEN
+        _operation := 'archived';
+        _transaction_time := upper(NEW.transaction_time);
+      ELSE
+        _transaction_time := lower(NEW.transaction_time);
+
+        IF EXISTS (
+          SELECT 1
+          FROM ontology_temporal_metadata
+          WHERE ontology_id = NEW.ontology_id
+            AND lower(transaction_time) < lower(NEW.transaction_time)
+        ) THEN
+          _operation := 'unarchived';
+        ELSIF EXISTS (
+          SELECT 1
+          FROM ontology_ids
+          WHERE base_url = _base_url
+            AND version < _version
+        ) THEN
+          _operation := 'updated';
+        ELSE
+          _operation := 'created';
+        END IF;
+      END IF;
+
+      IF EXISTS (SELECT 1 FROM data_types WHERE ontology_id = NEW.ontology_id) THEN
+        _record_kind := 'data_type';
+      ELSIF EXISTS (SELECT 1 FROM property_types WHERE ontology_id = NEW.ontology_id) THEN
+        _record_kind := 'property_type';
+      ELSE
+        _record_kind := 'entity_type';
+      END IF;
+
+      -- See `record_entity_change_trigger`
+      PERFORM pg_advisory_xact_lock(hashtext('change_events'));
+
+      INSERT INTO change_events (
+        operation,
+        record_kind,
+        transaction_time,
+        base_url,
+        version,
+        record_created_by_id,
+        record_archived_by_id
+      ) VALUES (
+        _operation,
+        _record_kind,
+        _transaction_time,
+        _base_url,
+        _version,
+        NEW.record_created_by_id,
+        NEW.record_archived_by_id
+      );
+
+      RETURN NULL;
+    END$pga$ VOLATILE LANGUAGE plpgsql;
+
+CREATE CONSTRAINT TRIGGER
+  "record_ontology_creation_trigger"
+AFTER
+INSERT
+  ON "ontology_temporal_metadata" DEFERRABLE INITIALLY DEFERRED FOR EACH ROW
+EXECUTE
+  PROCEDURE "record_ontology_change_trigger" ();
+
+CREATE CONSTRAINT TRIGGER
+  "record_ontology_archival_trigger"
+AFTER
+UPDATE
+  ON "ontology_temporal_metadata" DEFERRABLE INITIALLY DEFERRED FOR EACH ROW
+  WHEN (
+    OLD.record_archived_by_id IS NULL
+    AND NEW.record_archived_by_id IS NOT NULL
+  )
+EXECUTE
+  PROCEDURE "record_ontology_change_trigger" ();
@@ -1,9 +1,33 @@
+use std::{borrow::Cow, collections::HashSet, num::NonZeroUsize};
+
+use graph::{
+    knowledge::EntityQueryPath,
+    ontology::EntityTypeQueryPath,
+    store::{
+        error::{EntityDoesNotExist, EntityEditionDoesNotExist},
+        query::{Filter, FilterExpression, JsonPath, Ordering, Parameter, PathToken, Sorting},
+        BatchMode, BatchOperationIndex, ChangeOperation, ChangedRecord, EntityBatchLinkData,
+        EntityBatchOperation, EntityBatchReference,
+    },
+    subgraph::edges::SharedEdgeKind,
+};
 use graph_test_data::{data_type, entity, entity_type, property_type};
-use graph_types::knowledge::{entity::EntityProperties, link::EntityLinkOrder};
+use graph_types::{
+    knowledge::{
+        entity::{
+            Entity, EntityId, EntityProperties, EntityUuid, PropertyDiff, PropertyPath,
+            PropertyPathElement,
+        },
+        link::EntityLinkOrder,
+    },
+    provenance::OwnedById,
+};
+use serde_json::{json, Value};
 use temporal_versioning::ClosedTemporalBound;
 use type_system::url::{BaseUrl, VersionedUrl};
+use uuid::Uuid;
 
-use crate::DatabaseTestWrapper;
+use crate::{DatabaseApi, DatabaseTestWrapper};
 
 #[tokio::test]
 async fn insert() {
@@ -161,3 +185,829 @@ async fn update() {
 
     assert_eq!(entity_v2.properties, page_v2);
 }
+
+#[tokio::test]
+async fn history() {
+    let page_v1: EntityProperties =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: EntityProperties =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database:");
+
+    let entity_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    let v1_metadata = api
+        .create_entity(page_v1.clone(), entity_type_id.clone(), None)
+        .await
+        .expect("could not create entity");
+    let entity_id = v1_metadata.record_id().entity_id;
+
+    let v2_metadata = api
+        .update_entity(
+            entity_id,
+            page_v2.clone(),
+            entity_type_id,
+            EntityLinkOrder {
+                left_to_right: None,
+                right_to_left: None,
+            },
+        )
+        .await
+        .expect("could not update entity");
+
+    let history = api
+        .get_entity_history(entity_id)
+        .await
+        .expect("could not get entity history");
+    let mut edition_ids = history
+        .iter()
+        .map(|metadata| metadata.record_id().edition_id)
+        .collect::<Vec<_>>();
+    edition_ids.sort();
+    edition_ids.dedup();
+    let mut expected_edition_ids = vec![
+        v1_metadata.record_id().edition_id,
+        v2_metadata.record_id().edition_id,
+    ];
+    expected_edition_ids.sort();
+    assert_eq!(edition_ids, expected_edition_ids);
+    assert!(
+        history
+            .iter()
+            .all(|metadata| metadata.record_id().entity_id == entity_id)
+    );
+
+    let text_path = PropertyPath::default().join(PropertyPathElement::Property(
+        "https://blockprotocol.org/@alice/types/property-type/text/".to_owned(),
+    ));
+    assert_eq!(
+        api.diff_entity_editions(
+            entity_id,
+            v1_metadata.record_id().edition_id,
+            v2_metadata.record_id().edition_id,
+        )
+        .await
+        .expect("could not diff entity editions"),
+        [PropertyDiff::Changed {
+            path: text_path,
+            old: serde_json::json!("Intentionally blank page"),
+            new: serde_json::json!("This page was intentionally left blank"),
+        }]
+    );
+    assert!(
+        api.diff_entity_editions(
+            entity_id,
+            v2_metadata.record_id().edition_id,
+            v2_metadata.record_id().edition_id,
+        )
+        .await
+        .expect("could not diff entity editions")
+        .is_empty()
+    );
+
+    let unknown_entity_id = EntityId {
+        owned_by_id: entity_id.owned_by_id,
+        entity_uuid: EntityUuid::new(Uuid::new_v4()),
+    };
+    assert!(
+        api.get_entity_history(unknown_entity_id)
+            .await
+            .expect_err("could get history of unknown entity")
+            .contains::<EntityDoesNotExist>()
+    );
+    assert!(
+        api.diff_entity_editions(
+            unknown_entity_id,
+            v1_metadata.record_id().edition_id,
+            v2_metadata.record_id().edition_id,
+        )
+        .await
+        .expect_err("could diff editions of another entity")
+        .contains::<EntityEditionDoesNotExist>()
+    );
+}
+
+#[tokio::test]
+async fn batch() {
+    let page_v1: EntityProperties =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: EntityProperties =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database:");
+
+    let entity_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+    let owned_by_id = OwnedById::new(api.account_id);
+    let create = |entity_uuid: Option<EntityUuid>| EntityBatchOperation::Create {
+        owned_by_id,
+        entity_uuid,
+        entity_type_id: entity_type_id.clone(),
+        properties: page_v1.clone(),
+        link_data: None,
+    };
+    let update = |entity: EntityBatchReference| EntityBatchOperation::Update {
+        entity,
+        entity_type_id: entity_type_id.clone(),
+        properties: page_v2.clone(),
+        link_order: EntityLinkOrder {
+            left_to_right: None,
+            right_to_left: None,
+        },
+        archived: false,
+    };
+
+    let results = api
+        .batch_entities(
+            vec![
+                create(None),
+                update(EntityBatchReference::Operation(0)),
+                update(EntityBatchReference::Operation(5)),
+            ],
+            BatchMode::BestEffort,
+        )
+        .await
+        .expect("could not apply batch");
+    assert_eq!(results.len(), 3);
+
+    let created = results[0].as_ref().expect("could not create entity");
+    let updated = results[1].as_ref().expect("could not update entity");
+    assert_eq!(created.record_id().entity_id, updated.record_id().entity_id);
+    assert!(
+        results[2]
+            .as_ref()
+            .expect_err("could update entity of unknown operation")
+            .contains::<EntityDoesNotExist>()
+    );
+
+    let entity = api
+        .get_latest_entity(updated.record_id().entity_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity.properties, page_v2);
+
+    let entity_uuid = EntityUuid::new(Uuid::new_v4());
+    let report = api
+        .batch_entities(
+            vec![
+                create(Some(entity_uuid)),
+                update(EntityBatchReference::Operation(2)),
+            ],
+            BatchMode::AllOrNothing,
+        )
+        .await
+        .expect_err("could apply batch with failing operation");
+    assert!(report.contains::<EntityDoesNotExist>());
+    assert_eq!(
+        report
+            .frames()
+            .find_map(|frame| frame.downcast_ref::<BatchOperationIndex>()),
+        Some(&BatchOperationIndex(1))
+    );
+
+    // The entity created by the first operation is rolled back.
+    assert!(
+        api.get_entity_history(EntityId {
+            owned_by_id,
+            entity_uuid,
+        })
+        .await
+        .expect_err("could get history of rolled back entity")
+        .contains::<EntityDoesNotExist>()
+    );
+}
+
+#[tokio::test]
+async fn batch_link() {
+    let alice: EntityProperties =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob: EntityProperties =
+        serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+    let friend_of_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+    let owned_by_id = OwnedById::new(api.account_id);
+    let create = |entity_type_id: &VersionedUrl,
+                  properties: EntityProperties,
+                  link_data: Option<EntityBatchLinkData>| {
+        EntityBatchOperation::Create {
+            owned_by_id,
+            entity_uuid: None,
+            entity_type_id: entity_type_id.clone(),
+            properties,
+            link_data,
+        }
+    };
+
+    // The link refers to the entities created by the earlier operations of the batch
+    let results = api
+        .batch_entities(
+            vec![
+                create(&person_type_id, alice, None),
+                create(&person_type_id, bob, None),
+                create(
+                    &friend_of_type_id,
+                    EntityProperties::empty(),
+                    Some(EntityBatchLinkData {
+                        left_entity: EntityBatchReference::Operation(0),
+                        right_entity: EntityBatchReference::Operation(1),
+                        order: EntityLinkOrder {
+                            left_to_right: None,
+                            right_to_left: None,
+                        },
+                    }),
+                ),
+            ],
+            BatchMode::AllOrNothing,
+        )
+        .await
+        .expect("could not apply batch");
+    let entity_ids = results
+        .iter()
+        .map(|result| {
+            result
+                .as_ref()
+                .map(|metadata| metadata.record_id().entity_id)
+                .expect("could not apply operation")
+        })
+        .collect::<Vec<_>>();
+
+    let link_data = api
+        .get_latest_entity(entity_ids[2])
+        .await
+        .expect("could not get entity")
+        .link_data
+        .expect("entity is not a link");
+    assert_eq!(link_data.left_entity_id, entity_ids[0]);
+    assert_eq!(link_data.right_entity_id, entity_ids[1]);
+}
+
+#[tokio::test]
+async fn change_events() {
+    let page_v1: EntityProperties =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: EntityProperties =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database:");
+
+    let entity_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    api.record_changes_immediately().await;
+    let offset = api.latest_change_event_offset().await;
+
+    let created = api
+        .create_entity(page_v1, entity_type_id.clone(), None)
+        .await
+        .expect("could not create entity");
+    let entity_id = created.record_id().entity_id;
+
+    let updated = api
+        .update_entity(entity_id, page_v2, entity_type_id, EntityLinkOrder {
+            left_to_right: None,
+            right_to_left: None,
+        })
+        .await
+        .expect("could not update entity");
+
+    let archived = api
+        .archive_entity(entity_id)
+        .await
+        .expect("could not archive entity");
+
+    let events = api
+        .read_change_events(offset)
+        .await
+        .expect("could not read change events");
+
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| (event.operation, event.record.clone(), event.provenance))
+            .collect::<Vec<_>>(),
+        [
+            (
+                ChangeOperation::Created,
+                ChangedRecord::Entity {
+                    record_id: created.record_id(),
+                },
+                created.provenance(),
+            ),
+            (
+                ChangeOperation::Updated,
+                ChangedRecord::Entity {
+                    record_id: updated.record_id(),
+                },
+                updated.provenance(),
+            ),
+            (
+                ChangeOperation::Archived,
+                ChangedRecord::Entity {
+                    record_id: archived.record_id(),
+                },
+                archived.provenance(),
+            ),
+        ]
+    );
+
+    // Reading after the last change resumes at the end of the stream.
+    assert!(
+        api.read_change_events(events.last().map(|event| event.offset))
+            .await
+            .expect("could not read change events")
+            .is_empty()
+    );
+}
+
+fn property_path(base_url: &str) -> FilterExpression<'_, Entity> {
+    FilterExpression::Path(EntityQueryPath::Properties(Some(
+        JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(base_url))]),
+    )))
+}
+
+/// Returns the values of the property at `base_url` of `entities` in a stable order.
+fn property_values(entities: Vec<Entity>, base_url: &str) -> Vec<Value> {
+    let mut values = entities
+        .into_iter()
+        .filter_map(|entity| {
+            entity
+                .properties
+                .properties()
+                .iter()
+                .find(|(url, _)| url.as_str() == base_url)
+                .map(|(_, value)| value.clone())
+        })
+        .collect::<Vec<_>>();
+    values.sort_by_key(ToString::to_string);
+    values
+}
+
+#[tokio::test]
+async fn compare_timestamps_with_mixed_values() {
+    const TEXT: &str = "https://blockprotocol.org/@alice/types/property-type/text/";
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let page_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    for text in [
+        "2023-01-01T00:00:00Z",
+        "2023-06-01T12:00:00+02:00",
+        // Looks like a timestamp, but the month is out of range
+        "2023-13-01T00:00:00Z",
+        // Accepted by Postgres as timestamp, but not an RFC 3339 timestamp
+        "tomorrow",
+        "Hello World",
+    ] {
+        api.create_entity(
+            serde_json::from_value(json!({ TEXT: text })).expect("could not parse properties"),
+            page_type_id.clone(),
+            None,
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    // Strings, which are not timestamps, never match a timestamp comparison
+    let later = api
+        .get_latest_entities_by_filter(Filter::Greater(
+            property_path(TEXT),
+            FilterExpression::Parameter(Parameter::Any(json!("2023-03-01T00:00:00Z"))),
+        ))
+        .await
+        .expect("could not query entities");
+    assert_eq!(property_values(later, TEXT), [json!(
+        "2023-06-01T12:00:00+02:00"
+    )]);
+
+    let earlier = api
+        .get_latest_entities_by_filter(Filter::LessOrEqual(
+            property_path(TEXT),
+            FilterExpression::Parameter(Parameter::Any(json!("2023-06-01T10:00:00Z"))),
+        ))
+        .await
+        .expect("could not query entities");
+    assert_eq!(property_values(earlier, TEXT), [
+        json!("2023-01-01T00:00:00Z"),
+        json!("2023-06-01T12:00:00+02:00"),
+    ]);
+
+    // Other strings are compared lexicographically with every string
+    let text = api
+        .get_latest_entities_by_filter(Filter::Greater(
+            property_path(TEXT),
+            FilterExpression::Parameter(Parameter::Any(json!("H"))),
+        ))
+        .await
+        .expect("could not query entities");
+    assert_eq!(property_values(text, TEXT), [
+        json!("Hello World"),
+        json!("tomorrow"),
+    ]);
+}
+
+#[tokio::test]
+async fn compare_numbers_with_mixed_types() {
+    const USER_ID: &str = "https://blockprotocol.org/@alice/types/property-type/user-id/";
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1, data_type::NULL_V1],
+            [property_type::USER_ID_V2, property_type::NICKNAME_V1],
+            [entity_type::USER_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let user_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/user/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    for user_id in [json!(5), json!(42), json!("7"), json!("alice")] {
+        api.create_entity(
+            serde_json::from_value(json!({ USER_ID: user_id }))
+                .expect("could not parse properties"),
+            user_type_id.clone(),
+            None,
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    // Strings are not compared numerically, even if they contain a number
+    let greater = api
+        .get_latest_entities_by_filter(Filter::Greater(
+            property_path(USER_ID),
+            FilterExpression::Parameter(Parameter::Any(json!(6))),
+        ))
+        .await
+        .expect("could not query entities");
+    assert_eq!(property_values(greater, USER_ID), [json!(42)]);
+
+    let between = api
+        .get_latest_entities_by_filter(Filter::Between(
+            property_path(USER_ID),
+            FilterExpression::Parameter(Parameter::Any(json!(0))),
+            FilterExpression::Parameter(Parameter::Any(json!(100))),
+        ))
+        .await
+        .expect("could not query entities");
+    assert_eq!(property_values(between, USER_ID), [json!(42), json!(5)]);
+
+    // Numbers are not compared lexicographically
+    let text = api
+        .get_latest_entities_by_filter(Filter::Less(
+            property_path(USER_ID),
+            FilterExpression::Parameter(Parameter::Any(json!("b"))),
+        ))
+        .await
+        .expect("could not query entities");
+    assert_eq!(property_values(text, USER_ID), [json!("7"), json!("alice")]);
+}
+
+const NICKNAME: &str = "https://blockprotocol.org/@alice/types/property-type/nickname/";
+
+fn user_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/user/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    }
+}
+
+fn sort_by_nickname(ordering: Ordering) -> Vec<Sorting<'static, Entity>> {
+    vec![Sorting {
+        path: EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![
+            PathToken::Field(Cow::Borrowed(NICKNAME)),
+        ]))),
+        ordering,
+    }]
+}
+
+/// Creates a user for every value in `nicknames`. `None` creates a user without a nickname.
+async fn create_users(
+    api: &mut DatabaseApi<'_>,
+    nicknames: impl IntoIterator<Item = Option<Value>>,
+) {
+    for nickname in nicknames {
+        let properties =
+            nickname.map_or_else(|| json!({}), |nickname| json!({ NICKNAME: nickname }));
+        api.create_entity(
+            serde_json::from_value(properties).expect("could not parse properties"),
+            user_type_id(),
+            None,
+        )
+        .await
+        .expect("could not create entity");
+    }
+}
+
+/// Reads the pages sorted by `sort_by` with a page size of `limit` and returns the entities of
+/// every page.
+async fn read_pages(
+    api: &DatabaseApi<'_>,
+    sort_by: impl Fn() -> Vec<Sorting<'static, Entity>>,
+    limit: usize,
+) -> Vec<Vec<Entity>> {
+    let mut pages = Vec::new();
+    let mut cursor = None;
+    loop {
+        let (entities, next_cursor) = api
+            .get_entity_page(sort_by(), NonZeroUsize::new(limit), cursor)
+            .await
+            .expect("could not read page");
+        pages.push(entities);
+        match next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => return pages,
+        }
+    }
+}
+
+#[tokio::test]
+async fn paginate_with_ties() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1, data_type::NULL_V1],
+            [property_type::USER_ID_V2, property_type::NICKNAME_V1],
+            [entity_type::USER_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    create_users(
+        &mut api,
+        ["a", "b", "b", "b", "c"].map(|nickname| Some(json!(nickname))),
+    )
+    .await;
+
+    let pages = read_pages(&api, || sort_by_nickname(Ordering::Ascending), 2).await;
+
+    // Entities with the same nickname are neither skipped nor repeated across pages
+    let entity_ids = pages
+        .iter()
+        .flatten()
+        .map(|entity| entity.metadata.record_id().entity_id)
+        .collect::<HashSet<_>>();
+    assert_eq!(entity_ids.len(), 5);
+
+    assert_eq!(
+        pages
+            .into_iter()
+            .map(|page| property_values(page, NICKNAME))
+            .collect::<Vec<_>>(),
+        [
+            vec![json!("a"), json!("b")],
+            vec![json!("b"), json!("b")],
+            vec![json!("c")],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn paginate_with_null_values() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1, data_type::NULL_V1],
+            [property_type::USER_ID_V2, property_type::NICKNAME_V1],
+            [entity_type::USER_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    create_users(&mut api, [
+        Some(json!("a")),
+        Some(json!("b")),
+        Some(Value::Null),
+        None,
+    ])
+    .await;
+
+    let nicknames = |pages: Vec<Vec<Entity>>| {
+        pages
+            .into_iter()
+            .map(|page| property_values(page, NICKNAME))
+            .collect::<Vec<_>>()
+    };
+
+    // `null` and missing values are equal and sorted last, so their relative order is not defined
+    let mut ascending =
+        nicknames(read_pages(&api, || sort_by_nickname(Ordering::Ascending), 1).await);
+    assert_eq!(ascending.len(), 4);
+    ascending[2..].sort_by_key(Vec::len);
+    assert_eq!(ascending, [
+        vec![json!("a")],
+        vec![json!("b")],
+        vec![],
+        vec![Value::Null],
+    ]);
+
+    // ... and first in descending order
+    let mut descending =
+        nicknames(read_pages(&api, || sort_by_nickname(Ordering::Descending), 1).await);
+    assert_eq!(descending.len(), 4);
+    descending[..2].sort_by_key(Vec::len);
+    assert_eq!(descending, [
+        vec![],
+        vec![Value::Null],
+        vec![json!("b")],
+        vec![json!("a")],
+    ]);
+}
+
+#[tokio::test]
+async fn cursor_requires_same_sorting() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1, data_type::NULL_V1],
+            [property_type::USER_ID_V2, property_type::NICKNAME_V1],
+            [entity_type::USER_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    create_users(
+        &mut api,
+        ["a", "b", "c"].map(|nickname| Some(json!(nickname))),
+    )
+    .await;
+
+    let (_, cursor) = api
+        .get_entity_page(
+            sort_by_nickname(Ordering::Ascending),
+            NonZeroUsize::new(1),
+            None,
+        )
+        .await
+        .expect("could not read page");
+    let cursor = cursor.expect("there should be a next page");
+
+    api.get_entity_page(
+        sort_by_nickname(Ordering::Ascending),
+        NonZeroUsize::new(1),
+        Some(cursor.clone()),
+    )
+    .await
+    .expect("cursor should be valid for the same sorting");
+
+    api.get_entity_page(
+        sort_by_nickname(Ordering::Descending),
+        NonZeroUsize::new(1),
+        Some(cursor.clone()),
+    )
+    .await
+    .expect_err("cursor should be rejected for a different ordering");
+
+    api.get_entity_page(Vec::new(), NonZeroUsize::new(1), Some(cursor))
+        .await
+        .expect_err("cursor should be rejected without sorting");
+}
+
+#[tokio::test]
+async fn sort_by_multi_valued_path() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1, data_type::NULL_V1],
+            [property_type::USER_ID_V2, property_type::NICKNAME_V1],
+            [entity_type::USER_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    create_users(
+        &mut api,
+        ["a", "b", "c"].map(|nickname| Some(json!(nickname))),
+    )
+    .await;
+
+    let sort_by_entity_type = |inheritance_depth| {
+        vec![Sorting {
+            path: EntityQueryPath::EntityTypeEdge {
+                edge_kind: SharedEdgeKind::IsOfType,
+                path: EntityTypeQueryPath::BaseUrl,
+                inheritance_depth,
+            },
+            ordering: Ordering::Ascending,
+        }]
+    };
+
+    // The entity type itself is a single value for every entity
+    api.get_entity_page(sort_by_entity_type(Some(0)), NonZeroUsize::new(1), None)
+        .await
+        .expect("could not sort by the entity type");
+
+    // An entity may have several inherited entity types
+    api.get_entity_page(sort_by_entity_type(None), NonZeroUsize::new(1), None)
+        .await
+        .expect_err("could sort by the inherited entity types");
+}
+
+#[tokio::test]
+async fn paginate_without_sorting() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1, data_type::NULL_V1],
+            [property_type::USER_ID_V2, property_type::NICKNAME_V1],
+            [entity_type::USER_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    create_users(
+        &mut api,
+        ["a", "b", "c"].map(|nickname| Some(json!(nickname))),
+    )
+    .await;
+
+    let pages = read_pages(&api, Vec::new, 2).await;
+    assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), [2, 1]);
+
+    let entity_ids = pages
+        .iter()
+        .flatten()
+        .map(|entity| entity.metadata.record_id().entity_id)
+        .collect::<HashSet<_>>();
+    assert_eq!(entity_ids.len(), 3);
+}
@@ -11,18 +11,21 @@ mod entity;
 mod entity_type;
 mod links;
 mod property_type;
+mod search;
 
-use std::{borrow::Cow, str::FromStr};
+use std::{borrow::Cow, num::NonZeroUsize, str::FromStr};
 
 use error_stack::Result;
 use graph::{
     knowledge::EntityQueryPath,
     ontology::EntityTypeQueryPath,
     store::{
-        query::{Filter, FilterExpression, Parameter},
-        AccountStore, ConflictBehavior, DataTypeStore, DatabaseConnectionInfo, DatabaseType,
-        EntityStore, EntityTypeStore, InsertionError, PostgresStore, PostgresStorePool,
-        PropertyTypeStore, QueryError, StorePool, UpdateError,
+        query::{Cursor, Filter, FilterExpression, Parameter, Sorting},
+        AccountStore, AsClient, BatchMode, ChangeEvent, ChangeEventOffset, ChangeStore,
+        ConflictBehavior, DataTypeStore, DatabaseConnectionInfo, DatabaseType,
+        EntityBatchOperation, EntityStore, EntityTypeStore, InsertionError, PostgresStore,
+        PostgresStorePool, PropertyTypeStore, QueryError, SearchResults, SearchStore, StorePool,
+        UpdateError,
     },
     subgraph::{
         edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, SharedEdgeKind},
@@ -39,7 +42,10 @@ use graph::{
 use graph_types::{
     account::AccountId,
     knowledge::{
-        entity::{Entity, EntityId, EntityMetadata, EntityProperties, EntityUuid},
+        entity::{
+            Entity, EntityEditionId, EntityId, EntityMetadata, EntityProperties, EntityUuid,
+            PropertyDiff,
+        },
         link::{EntityLinkOrder, LinkData},
     },
     ontology::{
@@ -47,11 +53,11 @@ use graph_types::{
         OntologyTypeVersion, PartialCustomEntityTypeMetadata, PartialCustomOntologyMetadata,
         PartialEntityTypeMetadata, PartialOntologyElementMetadata, PropertyTypeWithMetadata,
     },
-    provenance::{OwnedById, ProvenanceMetadata, RecordCreatedById},
+    provenance::{OwnedById, ProvenanceMetadata, RecordArchivedById, RecordCreatedById},
 };
 use temporal_versioning::{DecisionTime, LimitedTemporalBound, TemporalBound, Timestamp};
 use time::{format_description::well_known::Iso8601, Duration, OffsetDateTime};
-use tokio_postgres::{NoTls, Transaction};
+use tokio_postgres::{GenericClient, NoTls, Transaction};
 use type_system::{repr, url::VersionedUrl, DataType, EntityType, PropertyType};
 use uuid::Uuid;
 
@@ -266,6 +272,9 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await?
             .vertices
@@ -319,6 +328,9 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await?
             .vertices
@@ -373,6 +385,9 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await?
             .vertices
@@ -423,8 +438,12 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await?
+            .0
             .vertices
             .entities
             .into_values()
@@ -448,8 +467,12 @@ impl DatabaseApi<'_> {
                         Some(LimitedTemporalBound::Inclusive(timestamp)),
                     ),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await?
+            .0
             .vertices
             .entities
             .into_values()
@@ -468,8 +491,12 @@ impl DatabaseApi<'_> {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await?
+            .0
             .vertices
             .entities
             .into_values()
@@ -478,6 +505,77 @@ impl DatabaseApi<'_> {
         Ok(entities.into_iter().next().unwrap())
     }
 
+    pub async fn get_latest_entities_by_filter(
+        &self,
+        filter: Filter<'_, Entity>,
+    ) -> Result<Vec<Entity>, QueryError> {
+        Ok(self
+            .store
+            .get_entity(&StructuralQuery {
+                filter,
+                graph_resolve_depths: GraphResolveDepths::default(),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
+            })
+            .await?
+            .0
+            .vertices
+            .entities
+            .into_values()
+            .collect())
+    }
+
+    /// Reads a page of the latest entities owned by the account of this API.
+    pub async fn get_entity_page(
+        &self,
+        sort_by: Vec<Sorting<'_, Entity>>,
+        limit: Option<NonZeroUsize>,
+        cursor: Option<Cursor>,
+    ) -> Result<(Vec<Entity>, Option<Cursor>), QueryError> {
+        let (subgraph, cursor) = self
+            .store
+            .get_entity(&StructuralQuery {
+                filter: Filter::Equal(
+                    Some(FilterExpression::Path(EntityQueryPath::OwnedById)),
+                    Some(FilterExpression::Parameter(Parameter::Uuid(
+                        self.account_id.as_uuid(),
+                    ))),
+                ),
+                graph_resolve_depths: GraphResolveDepths::default(),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sort_by,
+                limit,
+                cursor,
+            })
+            .await?;
+
+        Ok((subgraph.vertices.entities.into_values().collect(), cursor))
+    }
+
+    pub async fn get_entity_history(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Vec<EntityMetadata>, QueryError> {
+        self.store.get_entity_history(entity_id).await
+    }
+
+    pub async fn diff_entity_editions(
+        &self,
+        entity_id: EntityId,
+        old: EntityEditionId,
+        new: EntityEditionId,
+    ) -> Result<Vec<PropertyDiff>, QueryError> {
+        self.store.diff_entity_editions(entity_id, old, new).await
+    }
+
     pub async fn update_entity(
         &mut self,
         entity_id: EntityId,
@@ -498,6 +596,63 @@ impl DatabaseApi<'_> {
             .await
     }
 
+    pub async fn batch_entities(
+        &mut self,
+        operations: Vec<EntityBatchOperation>,
+        mode: BatchMode,
+    ) -> Result<Vec<Result<EntityMetadata, UpdateError>>, UpdateError> {
+        self.store
+            .batch_entities(operations, RecordCreatedById::new(self.account_id), mode)
+            .await
+    }
+
+    pub async fn search(
+        &self,
+        query: &str,
+        limit: NonZeroUsize,
+    ) -> Result<SearchResults, QueryError> {
+        self.store.search(query, limit).await
+    }
+
+    /// Records the changes of the test transaction when they happen instead of on commit, as the
+    /// test transaction is never committed.
+    pub async fn record_changes_immediately(&self) {
+        self.store
+            .as_client()
+            .execute(
+                "
+                    SET CONSTRAINTS
+                        record_entity_creation_trigger,
+                        record_entity_update_trigger,
+                        record_ontology_creation_trigger,
+                        record_ontology_archival_trigger
+                    IMMEDIATE;
+                ",
+                &[],
+            )
+            .await
+            .expect("could not record changes immediately");
+    }
+
+    pub async fn latest_change_event_offset(&self) -> Option<ChangeEventOffset> {
+        self.store
+            .as_client()
+            .query_one(r#"SELECT max("offset") FROM change_events"#, &[])
+            .await
+            .expect("could not read the latest change event offset")
+            .get::<_, Option<i64>>(0)
+            .map(ChangeEventOffset::new)
+    }
+
+    pub async fn read_change_events(
+        &self,
+        after: Option<ChangeEventOffset>,
+    ) -> Result<Vec<ChangeEvent>, QueryError> {
+        self.store
+            .read_change_events(after, NonZeroUsize::new(100).expect("100 is not zero"))
+            .await
+    }
+
     async fn create_link_entity(
         &mut self,
         properties: EntityProperties,
@@ -575,7 +730,7 @@ impl DatabaseApi<'_> {
             ),
         ]);
 
-        let mut subgraph = self
+        let (mut subgraph, _) = self
             .store
             .get_entity(&StructuralQuery {
                 filter,
@@ -587,6 +742,9 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await?;
 
@@ -638,7 +796,7 @@ impl DatabaseApi<'_> {
             ),
         ]);
 
-        let mut subgraph = self
+        let (mut subgraph, _) = self
             .store
             .get_entity(&StructuralQuery {
                 filter,
@@ -647,6 +805,9 @@ impl DatabaseApi<'_> {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
+                sort_by: Vec::new(),
+                limit: None,
+                cursor: None,
             })
             .await?;
 
@@ -662,23 +823,18 @@ impl DatabaseApi<'_> {
             .collect())
     }
 
-    async fn archive_entity(
+    async fn archive_entity(&mut self, entity_id: EntityId) -> Result<EntityMetadata, UpdateError> {
+        self.store
+            .archive_entity(entity_id, RecordArchivedById::new(self.account_id))
+            .await
+    }
+
+    async fn unarchive_entity(
         &mut self,
         entity_id: EntityId,
-        properties: EntityProperties,
-        entity_type_id: VersionedUrl,
-        link_order: EntityLinkOrder,
     ) -> Result<EntityMetadata, UpdateError> {
         self.store
-            .update_entity(
-                entity_id,
-                None,
-                RecordCreatedById::new(self.account_id),
-                true,
-                entity_type_id,
-                properties,
-                link_order,
-            )
+            .unarchive_entity(entity_id, RecordCreatedById::new(self.account_id))
             .await
     }
 }
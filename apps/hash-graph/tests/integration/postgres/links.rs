@@ -1,5 +1,9 @@
+use graph::store::error::EntityValidationError;
 use graph_test_data::{data_type, entity, entity_type, property_type};
-use graph_types::knowledge::{entity::EntityProperties, link::EntityLinkOrder};
+use graph_types::{
+    knowledge::{entity::EntityProperties, link::EntityLinkOrder},
+    provenance::RecordArchivedById,
+};
 use type_system::url::{BaseUrl, VersionedUrl};
 
 use crate::DatabaseTestWrapper;
@@ -245,22 +249,229 @@ async fn remove_link() {
             .is_empty()
     );
 
-    api.archive_entity(
-        link_entity_metadata.record_id().entity_id,
+    let archived_metadata = api
+        .archive_entity(link_entity_metadata.record_id().entity_id)
+        .await
+        .expect("could not remove link");
+    assert!(archived_metadata.archived());
+    assert_eq!(
+        archived_metadata.provenance().record_archived_by_id,
+        Some(RecordArchivedById::new(
+            archived_metadata
+                .provenance()
+                .record_created_by_id
+                .as_account_id()
+        ))
+    );
+
+    assert!(
+        api.get_latest_entity_links(alice_metadata.record_id().entity_id)
+            .await
+            .expect("could not fetch links")
+            .is_empty()
+    );
+
+    let rearchived_metadata = api
+        .archive_entity(link_entity_metadata.record_id().entity_id)
+        .await
+        .expect("could not archive an archived link");
+    assert_eq!(rearchived_metadata, archived_metadata);
+
+    let restored_metadata = api
+        .unarchive_entity(link_entity_metadata.record_id().entity_id)
+        .await
+        .expect("could not restore link");
+    assert!(!restored_metadata.archived());
+    assert_eq!(restored_metadata.provenance().record_archived_by_id, None);
+
+    let rerestored_metadata = api
+        .unarchive_entity(link_entity_metadata.record_id().entity_id)
+        .await
+        .expect("could not restore a restored link");
+    assert_eq!(rerestored_metadata, restored_metadata);
+
+    let links = api
+        .get_latest_entity_links(alice_metadata.record_id().entity_id)
+        .await
+        .expect("could not fetch links");
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].metadata.entity_type_id(), &friend_link_type_id);
+}
+
+#[tokio::test]
+async fn remove_required_link() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::WRITTEN_BY_V1,
+            entity_type::PERSON_V1,
+            entity_type::ARTICLE_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    let article_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/article/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    let written_by_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/written-by/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    let article_metadata = api
+        .create_entity(EntityProperties::empty(), article_type_id, None)
+        .await
+        .expect("could not create entity");
+
+    let mut link_entity_ids = Vec::new();
+    for author in [alice, bob] {
+        let author_metadata = api
+            .create_entity(author, person_type_id.clone(), None)
+            .await
+            .expect("could not create entity");
+
+        let link_entity_metadata = api
+            .create_link_entity(
+                EntityProperties::empty(),
+                written_by_type_id.clone(),
+                None,
+                article_metadata.record_id().entity_id,
+                author_metadata.record_id().entity_id,
+            )
+            .await
+            .expect("could not create link");
+        link_entity_ids.push(link_entity_metadata.record_id().entity_id);
+    }
+
+    api.archive_entity(link_entity_ids[0])
+        .await
+        .expect("could not remove link");
+
+    // The article requires at least one author
+    let report = api
+        .archive_entity(link_entity_ids[1])
+        .await
+        .expect_err("could remove the last required link");
+    assert!(report.contains::<EntityValidationError>());
+
+    let links = api
+        .get_latest_entity_links(article_metadata.record_id().entity_id)
+        .await
+        .expect("could not fetch links");
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].metadata.record_id().entity_id, link_entity_ids[1]);
+}
+
+#[tokio::test]
+async fn link_archived_entities() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::WRITTEN_BY_V1,
+            entity_type::PERSON_V1,
+            entity_type::ARTICLE_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    let article_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/article/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    let written_by_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/written-by/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    };
+
+    let article_metadata = api
+        .create_entity(EntityProperties::empty(), article_type_id, None)
+        .await
+        .expect("could not create entity");
+
+    let alice_metadata = api
+        .create_entity(alice, person_type_id, None)
+        .await
+        .expect("could not create entity");
+
+    let link_entity_id = api
+        .create_link_entity(
+            EntityProperties::empty(),
+            written_by_type_id.clone(),
+            None,
+            article_metadata.record_id().entity_id,
+            alice_metadata.record_id().entity_id,
+        )
+        .await
+        .expect("could not create link")
+        .record_id()
+        .entity_id;
+
+    api.archive_entity(alice_metadata.record_id().entity_id)
+        .await
+        .expect("could not archive entity");
+    api.archive_entity(article_metadata.record_id().entity_id)
+        .await
+        .expect("could not archive entity");
+
+    // An unchanged link can be updated after the linked entities were archived
+    api.update_entity(
+        link_entity_id,
         EntityProperties::empty(),
-        friend_link_type_id,
+        written_by_type_id,
         EntityLinkOrder {
             left_to_right: None,
             right_to_left: None,
         },
     )
     .await
-    .expect("could not remove link");
+    .expect("could not update link");
 
-    assert!(
-        api.get_latest_entity_links(alice_metadata.record_id().entity_id)
-            .await
-            .expect("could not fetch links")
-            .is_empty()
-    );
+    // The article is archived, so it doesn't require an author anymore
+    api.archive_entity(link_entity_id)
+        .await
+        .expect("could not remove link");
+
+    let report = api
+        .unarchive_entity(link_entity_id)
+        .await
+        .expect_err("could restore a link between archived entities");
+    assert!(report.contains::<EntityValidationError>());
 }
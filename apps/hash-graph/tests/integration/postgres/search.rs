@@ -0,0 +1,217 @@
+use std::num::NonZeroUsize;
+
+use graph_test_data::{data_type, entity_type, property_type};
+use graph_types::knowledge::{
+    entity::{EntityId, EntityProperties},
+    link::EntityLinkOrder,
+};
+use serde_json::json;
+use type_system::{
+    repr,
+    url::{BaseUrl, VersionedUrl},
+    DataType,
+};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+const TEXT: &str = "https://blockprotocol.org/@alice/types/property-type/text/";
+
+fn limit() -> NonZeroUsize {
+    NonZeroUsize::new(10).expect("limit should not be zero")
+}
+
+fn page_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    }
+}
+
+fn page(text: &str) -> EntityProperties {
+    serde_json::from_value(json!({ TEXT: text })).expect("could not parse properties")
+}
+
+async fn create_page(api: &mut DatabaseApi<'_>, text: &str) -> EntityId {
+    api.create_entity(page(text), page_type_id(), None)
+        .await
+        .expect("could not create entity")
+        .record_id()
+        .entity_id
+}
+
+#[tokio::test]
+async fn entities_are_ranked() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let few = create_page(&mut api, "a graph database").await;
+    let many = create_page(&mut api, "graph theory: a graph is made of graph vertices").await;
+    create_page(&mut api, "a relational database").await;
+
+    let results = api
+        .search("graph", limit())
+        .await
+        .expect("could not search");
+
+    assert_eq!(
+        results
+            .entities
+            .iter()
+            .map(|result| result.entity_id)
+            .collect::<Vec<_>>(),
+        [many, few]
+    );
+    assert!(results.entities[0].rank > results.entities[1].rank);
+}
+
+#[tokio::test]
+async fn entity_highlight() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    create_page(&mut api, "Intentionally blank page").await;
+
+    let results = api
+        .search("blank", limit())
+        .await
+        .expect("could not search");
+
+    assert_eq!(results.entities.len(), 1);
+    assert_eq!(
+        results.entities[0].highlight,
+        "Intentionally <b>blank</b> page"
+    );
+}
+
+#[tokio::test]
+async fn archived_and_superseded_editions_are_excluded() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::TEXT_V1], [
+            entity_type::PAGE_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    let updated = create_page(&mut api, "draft").await;
+    api.update_entity(updated, page("final"), page_type_id(), EntityLinkOrder {
+        left_to_right: None,
+        right_to_left: None,
+    })
+    .await
+    .expect("could not update entity");
+
+    let archived = create_page(&mut api, "obsolete").await;
+    api.archive_entity(archived)
+        .await
+        .expect("could not archive entity");
+
+    for (query, expected) in [
+        ("draft", vec![]),
+        ("final", vec![updated]),
+        ("obsolete", vec![]),
+    ] {
+        let results = api.search(query, limit()).await.expect("could not search");
+        assert_eq!(
+            results
+                .entities
+                .iter()
+                .map(|result| result.entity_id)
+                .collect::<Vec<_>>(),
+            expected,
+            "unexpected results for `{query}`"
+        );
+    }
+}
+
+#[tokio::test]
+async fn ontology_types() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    // "text" is in the title of the text data type, "arithmetical" is in the description of the
+    // number data type, and matches in the title are ranked higher
+    let results = api
+        .search("text or arithmetical", limit())
+        .await
+        .expect("could not search");
+
+    assert_eq!(
+        results
+            .data_types
+            .iter()
+            .map(|result| result.ontology_type_id.to_string())
+            .collect::<Vec<_>>(),
+        [
+            "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1",
+            "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1",
+        ]
+    );
+    assert!(results.data_types[0].rank > results.data_types[1].rank);
+    assert_eq!(
+        results.data_types[0].highlight,
+        "<b>Text</b> An ordered sequence of characters"
+    );
+
+    assert_eq!(
+        results
+            .property_types
+            .iter()
+            .map(|result| result.ontology_type_id.to_string())
+            .collect::<Vec<_>>(),
+        ["https://blockprotocol.org/@alice/types/property-type/text/v/1"]
+    );
+    assert!(results.entity_types.is_empty());
+    assert!(results.entities.is_empty());
+}
+
+#[tokio::test]
+async fn only_latest_ontology_type_versions() {
+    let object_dt_v2_repr: repr::DataType = serde_json::from_str(data_type::OBJECT_V2)
+        .expect("could not parse data type representation");
+    let object_dt_v2 = DataType::try_from(object_dt_v2_repr).expect("could not parse data type");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::OBJECT_V1], [], [])
+        .await
+        .expect("could not seed database");
+
+    api.update_data_type(object_dt_v2)
+        .await
+        .expect("could not update data type");
+
+    let results = api
+        .search("object", limit())
+        .await
+        .expect("could not search");
+
+    assert_eq!(
+        results
+            .data_types
+            .iter()
+            .map(|result| result.ontology_type_id.to_string())
+            .collect::<Vec<_>>(),
+        ["https://blockprotocol.org/@blockprotocol/types/data-type/object/v/2"]
+    );
+}
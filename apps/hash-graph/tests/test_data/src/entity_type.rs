@@ -3,6 +3,7 @@ pub mod link;
 pub const LINK_V1: &str = include_str!("entity_type/link.json");
 
 pub const UK_ADDRESS_V1: &str = include_str!("entity_type/uk_address.json");
+pub const ARTICLE_V1: &str = include_str!("entity_type/article.json");
 pub const BLOCK_V1: &str = include_str!("entity_type/block.json");
 pub const BOOK_V1: &str = include_str!("entity_type/book.json");
 pub const BUILDING_V1: &str = include_str!("entity_type/building.json");
@@ -12,3 +13,4 @@ pub const PAGE_V2: &str = include_str!("entity_type/page_v2.json");
 pub const PERSON_V1: &str = include_str!("entity_type/person.json");
 pub const PLAYLIST_V1: &str = include_str!("entity_type/playlist.json");
 pub const SONG_V1: &str = include_str!("entity_type/song.json");
+pub const USER_V1: &str = include_str!("entity_type/user.json");
@@ -11,6 +11,7 @@ pub const FAVORITE_SONG_V1: &str = include_str!("property_type/favorite_song.jso
 pub const HOBBY_V1: &str = include_str!("property_type/hobby.json");
 pub const INTERESTS_V1: &str = include_str!("property_type/interests.json");
 pub const NAME_V1: &str = include_str!("property_type/name.json");
+pub const NICKNAME_V1: &str = include_str!("property_type/nickname.json");
 pub const NUMBERS_V1: &str = include_str!("property_type/numbers.json");
 pub const PHONE_NUMBER_V1: &str = include_str!("property_type/phone_number.json");
 pub const POSTCODE_NUMBER_V1: &str = include_str!("property_type/postcode.json");
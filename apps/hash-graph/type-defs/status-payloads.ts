@@ -1,13 +1,19 @@
 import {
+  BadRequest,
   ErrorInfo,
   RequestInfo,
   ResourceInfo,
 } from "@local/status/type-defs/status-payloads";
 
 export {
+  BadRequest,
   ErrorInfo,
   RequestInfo,
   ResourceInfo,
 } from "@local/status/type-defs/status-payloads";
 
-export type StatusPayloads = ErrorInfo | RequestInfo | ResourceInfo;
+export type StatusPayloads =
+  | ErrorInfo
+  | RequestInfo
+  | ResourceInfo
+  | BadRequest;
@@ -0,0 +1,95 @@
+use std::fmt::Debug;
+
+use axum::{
+    http,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+
+use crate::Status;
+
+impl<D> IntoResponse for Status<D>
+where
+    D: Send + Sync + Debug + Serialize,
+{
+    /// Responds with the HTTP status code corresponding to the [`StatusCode`] of the status and
+    /// the status serialized as JSON as body.
+    ///
+    /// [`StatusCode`]: crate::StatusCode
+    fn into_response(self) -> Response {
+        let status_code = http::StatusCode::from_u16(self.code.to_http_code())
+            .unwrap_or(http::StatusCode::INTERNAL_SERVER_ERROR);
+
+        (status_code, Json(self)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::HttpBody;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::StatusCode;
+
+    async fn body(response: Response) -> Value {
+        let mut body = response.into_body();
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = body.data().await {
+            bytes.extend_from_slice(&chunk.expect("body should be readable"));
+        }
+
+        serde_json::from_slice(&bytes).expect("body should be valid JSON")
+    }
+
+    #[tokio::test]
+    async fn into_response() {
+        let status = Status::new(
+            StatusCode::NotFound,
+            Some("entity not found".to_owned()),
+            vec![json!({ "entityId": "1" })],
+        );
+
+        let response = status.into_response();
+
+        assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers()[http::header::CONTENT_TYPE],
+            "application/json"
+        );
+        assert_eq!(
+            body(response).await,
+            json!({
+                "code": "NOT_FOUND",
+                "message": "entity not found",
+                "contents": [{ "entityId": "1" }],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn http_status_code() {
+        let cases = [
+            (StatusCode::Ok, http::StatusCode::OK),
+            (StatusCode::InvalidArgument, http::StatusCode::BAD_REQUEST),
+            (StatusCode::AlreadyExists, http::StatusCode::CONFLICT),
+            (StatusCode::Unknown, http::StatusCode::INTERNAL_SERVER_ERROR),
+            (
+                StatusCode::Cancelled,
+                http::StatusCode::from_u16(499).expect("valid code"),
+            ),
+        ];
+
+        for (code, expected) in cases {
+            let response = Status::<Value>::new(code, None, Vec::new()).into_response();
+
+            assert_eq!(response.status(), expected, "{code}");
+            assert_eq!(
+                body(response).await,
+                json!({ "code": code, "contents": [] })
+            );
+        }
+    }
+}
@@ -0,0 +1,232 @@
+// Attribution: Adapted from the Google Cloud API Error Model
+//  https://github.com/googleapis/googleapis/blob/master/google/rpc/error_details.proto
+
+//! Standard payloads describing the details of an error.
+//!
+//! Services should use these types as contents of a [`Status`] where applicable, so that clients
+//! can handle errors consistently across services. [`ErrorDetail`] combines all standard payloads
+//! and can be used directly as the content type of a [`Status`].
+//!
+//! [`Status`]: crate::Status
+
+mod bad_request;
+mod error_info;
+mod help;
+mod precondition_failure;
+mod quota_failure;
+mod resource_info;
+mod retry_info;
+
+use serde::{Deserialize, Serialize};
+
+pub use self::{
+    bad_request::{BadRequest, FieldViolation},
+    error_info::ErrorInfo,
+    help::{Help, Link},
+    precondition_failure::{PreconditionFailure, PreconditionViolation},
+    quota_failure::{QuotaFailure, QuotaViolation},
+    resource_info::ResourceInfo,
+    retry_info::RetryInfo,
+};
+
+/// Any of the standard error detail payloads.
+///
+/// The payload is serialized with a `type` field naming the variant, e.g.
+/// `{"type": "RetryInfo", "retryDelay": 5000}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ErrorDetail {
+    ErrorInfo(ErrorInfo),
+    BadRequest(BadRequest),
+    RetryInfo(RetryInfo),
+    QuotaFailure(QuotaFailure),
+    PreconditionFailure(PreconditionFailure),
+    ResourceInfo(ResourceInfo),
+    Help(Help),
+}
+
+macro_rules! impl_from {
+    ($($variant:ident),* $(,)?) => {
+        $(
+            impl From<$variant> for ErrorDetail {
+                fn from(value: $variant) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    ErrorInfo,
+    BadRequest,
+    RetryInfo,
+    QuotaFailure,
+    PreconditionFailure,
+    ResourceInfo,
+    Help,
+);
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// Serializes `detail`, compares it to `expected` and deserializes it again.
+    fn round_trip(detail: impl Into<ErrorDetail>, expected: &Value) {
+        let detail = detail.into();
+
+        let value = serde_json::to_value(&detail).expect("detail should serialize");
+        assert_eq!(value, *expected);
+
+        let deserialized: ErrorDetail =
+            serde_json::from_value(value).expect("detail should deserialize");
+        assert_eq!(deserialized, detail);
+    }
+
+    #[test]
+    fn error_info() {
+        round_trip(
+            ErrorInfo::new("ENTITY_NOT_FOUND", "hash.ai").with_metadata("entityId", "1"),
+            &json!({
+                "type": "ErrorInfo",
+                "reason": "ENTITY_NOT_FOUND",
+                "domain": "hash.ai",
+                "metadata": { "entityId": "1" },
+            }),
+        );
+
+        // empty metadata is omitted
+        round_trip(
+            ErrorInfo::new("UNKNOWN", "hash.ai"),
+            &json!({
+                "type": "ErrorInfo",
+                "reason": "UNKNOWN",
+                "domain": "hash.ai",
+            }),
+        );
+    }
+
+    #[test]
+    fn bad_request() {
+        round_trip(
+            BadRequest::new()
+                .with_field_violation(FieldViolation::new("properties.name", "must be a string"))
+                .with_field_violation(FieldViolation::new("links[0].target", "is required")),
+            &json!({
+                "type": "BadRequest",
+                "fieldViolations": [
+                    { "field": "properties.name", "description": "must be a string" },
+                    { "field": "links[0].target", "description": "is required" },
+                ],
+            }),
+        );
+    }
+
+    #[test]
+    fn retry_info() {
+        round_trip(
+            RetryInfo::new(Duration::from_millis(1500)),
+            &json!({
+                "type": "RetryInfo",
+                "retryDelay": 1500,
+            }),
+        );
+    }
+
+    #[test]
+    fn retry_info_precision() {
+        // sub-millisecond precision is discarded
+        let value = serde_json::to_value(ErrorDetail::from(RetryInfo::new(Duration::new(5, 999))))
+            .expect("detail should serialize");
+        assert_eq!(value, json!({ "type": "RetryInfo", "retryDelay": 5000 }));
+
+        let value = serde_json::to_value(ErrorDetail::from(RetryInfo::new(Duration::MAX)))
+            .expect("detail should serialize");
+        assert_eq!(
+            value,
+            json!({ "type": "RetryInfo", "retryDelay": u64::MAX })
+        );
+
+        serde_json::from_value::<ErrorDetail>(json!({ "type": "RetryInfo", "retryDelay": -1 }))
+            .expect_err("negative delays are invalid");
+    }
+
+    #[test]
+    fn quota_failure() {
+        round_trip(
+            QuotaFailure::new().with_violation(QuotaViolation::new(
+                "clientip:127.0.0.1",
+                "daily limit exceeded",
+            )),
+            &json!({
+                "type": "QuotaFailure",
+                "violations": [
+                    { "subject": "clientip:127.0.0.1", "description": "daily limit exceeded" },
+                ],
+            }),
+        );
+    }
+
+    #[test]
+    fn precondition_failure() {
+        round_trip(
+            PreconditionFailure::new().with_violation(PreconditionViolation::new(
+                "TOS",
+                "hash.ai/tos",
+                "terms of service not accepted",
+            )),
+            &json!({
+                "type": "PreconditionFailure",
+                "violations": [
+                    {
+                        "type": "TOS",
+                        "subject": "hash.ai/tos",
+                        "description": "terms of service not accepted",
+                    },
+                ],
+            }),
+        );
+    }
+
+    #[test]
+    fn resource_info() {
+        round_trip(
+            ResourceInfo::new("Entity", "entity-1", "entity is archived").with_owner("alice"),
+            &json!({
+                "type": "ResourceInfo",
+                "resourceType": "Entity",
+                "resourceName": "entity-1",
+                "owner": "alice",
+                "description": "entity is archived",
+            }),
+        );
+
+        // a missing owner is omitted
+        round_trip(
+            ResourceInfo::new("Entity", "entity-1", "entity is archived"),
+            &json!({
+                "type": "ResourceInfo",
+                "resourceType": "Entity",
+                "resourceName": "entity-1",
+                "description": "entity is archived",
+            }),
+        );
+    }
+
+    #[test]
+    fn help() {
+        round_trip(
+            Help::new().with_link(Link::new("documentation", "https://hash.ai/docs")),
+            &json!({
+                "type": "Help",
+                "links": [
+                    { "description": "documentation", "url": "https://hash.ai/docs" },
+                ],
+            }),
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+/// A single bad request field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldViolation {
+    /// A path that leads to a field in the request body.
+    ///
+    /// The value is a sequence of dot-separated identifiers that identify a field, e.g.
+    /// `properties.name` or `links[0].target`.
+    field: String,
+    /// A description of why the request element is bad.
+    description: String,
+}
+
+impl FieldViolation {
+    #[must_use]
+    pub fn new(field: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            description: description.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+/// Describes violations in a client request.
+///
+/// This error type focuses on the syntactic aspects of the request and is typically returned
+/// together with [`StatusCode::InvalidArgument`].
+///
+/// [`StatusCode::InvalidArgument`]: crate::StatusCode::InvalidArgument
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BadRequest {
+    /// Describes all violations in a client request.
+    field_violations: Vec<FieldViolation>,
+}
+
+impl BadRequest {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            field_violations: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_field_violation(mut self, violation: FieldViolation) -> Self {
+        self.field_violations.push(violation);
+        self
+    }
+
+    #[must_use]
+    pub fn field_violations(&self) -> &[FieldViolation] {
+        &self.field_violations
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Generalized information about an error.
+///
+/// Includes the cause of the error, its origin, and a collection of weakly-typed additional
+/// metadata.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorInfo {
+    /// The reason of the error.
+    ///
+    /// This is a constant value that identifies the proximate cause of the error. Error reasons
+    /// are unique within a particular domain of errors. This should be at most 63 characters and
+    /// match a regular expression of `[A-Z][A-Z0-9_]+[A-Z0-9]`, which represents
+    /// `UPPER_SNAKE_CASE`.
+    reason: String,
+    /// The logical grouping to which the `reason` belongs.
+    ///
+    /// The error domain is typically the registered service name of the tool or product that
+    /// generates the error.
+    domain: String,
+    /// Additional structured details about this error.
+    ///
+    /// Keys should match `[a-zA-Z0-9-_]` and be limited to 64 characters in length. When
+    /// identifying the current value of an exceeded limit, the units should be contained in the
+    /// key, not the value. For example, rather than `{"instanceLimit": "100/request"}`, it should
+    /// be returned as `{"instanceLimitPerRequest": "100"}`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    metadata: HashMap<String, Value>,
+}
+
+impl ErrorInfo {
+    #[must_use]
+    pub fn new(reason: impl Into<String>, domain: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+            domain: domain.into(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Adds an entry to the metadata, replacing any previous entry with the same key.
+    #[must_use]
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    #[must_use]
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    #[must_use]
+    pub const fn metadata(&self) -> &HashMap<String, Value> {
+        &self.metadata
+    }
+}
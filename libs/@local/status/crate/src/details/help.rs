@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes a URL link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Link {
+    /// Describes what the link offers.
+    description: String,
+    /// The URL of the link.
+    url: String,
+}
+
+impl Link {
+    #[must_use]
+    pub fn new(description: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            url: url.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+/// Provides links to documentation or for performing an out of band action.
+///
+/// For example, if a quota check failed with an error indicating the calling project hasn't
+/// enabled the accessed service, this can contain a URL pointing directly to the right place in
+/// the developer console to flip the bit.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Help {
+    /// URL(s) pointing to additional information on handling the current error.
+    links: Vec<Link>,
+}
+
+impl Help {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { links: Vec::new() }
+    }
+
+    #[must_use]
+    pub fn with_link(mut self, link: Link) -> Self {
+        self.links.push(link);
+        self
+    }
+
+    #[must_use]
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+/// A single precondition failure.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreconditionViolation {
+    /// The type of precondition failure.
+    ///
+    /// It's recommended to use a service-specific enum type to define the supported precondition
+    /// violation subjects, e.g. `"TOS"` for "Terms of Service violation".
+    #[serde(rename = "type")]
+    kind: String,
+    /// The subject, relative to the type, that failed.
+    ///
+    /// For example, `"hash.ai/tos"` would indicate which terms of service is being referenced.
+    subject: String,
+    /// A description of how the precondition failed.
+    ///
+    /// Developers can use this description to understand how to fix the failure.
+    description: String,
+}
+
+impl PreconditionViolation {
+    #[must_use]
+    pub fn new(
+        kind: impl Into<String>,
+        subject: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind: kind.into(),
+            subject: subject.into(),
+            description: description.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+/// Describes what preconditions have failed.
+///
+/// For example, if an update failed because the entity was modified concurrently, the
+/// [`PreconditionFailure`] detail could describe which version of the entity was expected. This is
+/// typically returned together with [`StatusCode::FailedPrecondition`].
+///
+/// [`StatusCode::FailedPrecondition`]: crate::StatusCode::FailedPrecondition
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreconditionFailure {
+    /// Describes all precondition violations.
+    violations: Vec<PreconditionViolation>,
+}
+
+impl PreconditionFailure {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_violation(mut self, violation: PreconditionViolation) -> Self {
+        self.violations.push(violation);
+        self
+    }
+
+    #[must_use]
+    pub fn violations(&self) -> &[PreconditionViolation] {
+        &self.violations
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+/// A single quota violation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuotaViolation {
+    /// The subject on which the quota check failed.
+    ///
+    /// For example, `"clientip:<ip address of client>"` or `"project:<project id>"`.
+    subject: String,
+    /// A description of how the quota check failed.
+    ///
+    /// Clients can use this description to find more about the quota configuration in the
+    /// service's public documentation, or find the relevant quota limit to adjust through the
+    /// developer console.
+    description: String,
+}
+
+impl QuotaViolation {
+    #[must_use]
+    pub fn new(subject: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            description: description.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+/// Describes how a quota check failed.
+///
+/// For example if a daily limit was exceeded for the calling project, a service could respond
+/// with a [`QuotaFailure`] detail containing the project id and the description of the quota
+/// limit that was exceeded. This is typically returned together with
+/// [`StatusCode::ResourceExhausted`].
+///
+/// [`StatusCode::ResourceExhausted`]: crate::StatusCode::ResourceExhausted
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuotaFailure {
+    /// Describes all quota violations.
+    violations: Vec<QuotaViolation>,
+}
+
+impl QuotaFailure {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_violation(mut self, violation: QuotaViolation) -> Self {
+        self.violations.push(violation);
+        self
+    }
+
+    #[must_use]
+    pub fn violations(&self) -> &[QuotaViolation] {
+        &self.violations
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes the resource that is being accessed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceInfo {
+    /// A name for the type of resource being accessed.
+    ///
+    /// For example `"SQL table"`, `"Entity"`, `"Property Type"`, `"Redis"`; or the type URL of
+    /// the resource, e.g. `"https://blockprotocol.org/type-system/0.3/schema/meta/entity-type"`.
+    resource_type: String,
+    /// The name of the resource being accessed.
+    ///
+    /// For example, an ontology type ID: `https://hash.ai/@alice/types/entity-type/Person/`, if
+    /// the current error is [`StatusCode::PermissionDenied`].
+    ///
+    /// [`StatusCode::PermissionDenied`]: crate::StatusCode::PermissionDenied
+    resource_name: String,
+    /// The owner of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    /// Describes what error is encountered when accessing this resource.
+    ///
+    /// For example, updating a property on a user's entity may require write permission on that
+    /// property.
+    description: String,
+}
+
+impl ResourceInfo {
+    #[must_use]
+    pub fn new(
+        resource_type: impl Into<String>,
+        resource_name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            resource_type: resource_type.into(),
+            resource_name: resource_name.into(),
+            owner: None,
+            description: description.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    #[must_use]
+    pub fn resource_type(&self) -> &str {
+        &self.resource_type
+    }
+
+    #[must_use]
+    pub fn resource_name(&self) -> &str {
+        &self.resource_name
+    }
+
+    #[must_use]
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
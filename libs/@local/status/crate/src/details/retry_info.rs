@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Describes when the clients can retry a failed request.
+///
+/// Clients could ignore the recommendation here or retry when this information is missing from
+/// error responses. It's always recommended that clients should use exponential backoff when
+/// retrying.
+///
+/// Clients should wait until `retry_delay` amount of time has passed since receiving the error
+/// response before retrying. If retrying requests also fail, clients should use an exponential
+/// backoff scheme to gradually increase the delay between retries based on `retry_delay`, until
+/// either a maximum number of retries have been reached or a maximum retry delay cap has been
+/// reached.
+///
+/// The delay is serialized as an integer number of milliseconds, e.g. `{"retryDelay": 1500}`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryInfo {
+    /// Clients should wait at least this long between retrying the same request.
+    #[serde(with = "milliseconds")]
+    retry_delay: Duration,
+}
+
+impl RetryInfo {
+    /// Creates a new `RetryInfo`.
+    ///
+    /// The delay is only transmitted with millisecond precision, any remaining sub-millisecond
+    /// part is discarded when serializing.
+    #[must_use]
+    pub const fn new(retry_delay: Duration) -> Self {
+        Self { retry_delay }
+    }
+
+    #[must_use]
+    pub const fn retry_delay(&self) -> Duration {
+        self.retry_delay
+    }
+}
+
+mod milliseconds {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        u64::try_from(duration.as_millis())
+            .unwrap_or(u64::MAX)
+            .serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
@@ -4,10 +4,14 @@
 //! Defines a logical status and error model that is suitable for different programming
 //! environments, including REST APIs and RPC APIs.
 
+#[cfg(feature = "axum")]
+mod axum_compat;
+pub mod details;
 mod status_code;
 
 use std::fmt::Debug;
 
+use error_stack::{Context, Report};
 use serde::{Deserialize, Serialize};
 pub use status_code::StatusCode;
 
@@ -54,3 +58,156 @@ where
         &self.contents
     }
 }
+
+impl<D> Status<D>
+where
+    D: Clone + Send + Sync + Debug + Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    /// Creates a `Status` from the frames of a [`Report`].
+    ///
+    /// The [`StatusCode`] is taken from the outermost frame containing a [`StatusCode`], either
+    /// attached or as context. If no frame contains a [`StatusCode`], [`StatusCode::Unknown`] is
+    /// used. Every frame containing a `D` is added to the contents of the status, starting with
+    /// the outermost frame. The message is the [`Display`] output of the current context of the
+    /// report.
+    ///
+    /// [`Display`]: std::fmt::Display
+    #[must_use]
+    pub fn from_report<C: Context>(report: &Report<C>) -> Self {
+        let code = report
+            .frames()
+            .find_map(|frame| frame.downcast_ref::<StatusCode>())
+            .copied()
+            .unwrap_or(StatusCode::Unknown);
+
+        let contents = report
+            .frames()
+            .filter_map(|frame| frame.downcast_ref::<D>())
+            .cloned()
+            .collect();
+
+        Self::new(code, Some(report.current_context().to_string()), contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use error_stack::Report;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct InnerError;
+
+    impl fmt::Display for InnerError {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt.write_str("inner error")
+        }
+    }
+
+    impl Context for InnerError {}
+
+    #[derive(Debug)]
+    struct OuterError;
+
+    impl fmt::Display for OuterError {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt.write_str("outer error")
+        }
+    }
+
+    impl Context for OuterError {}
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Detail(String);
+
+    #[test]
+    fn from_report_without_status_code() {
+        let report = Report::new(InnerError);
+
+        let status = Status::<Detail>::from_report(&report);
+
+        assert_eq!(status.code(), StatusCode::Unknown);
+        assert_eq!(status.message().as_deref(), Some("inner error"));
+        assert!(status.contents().is_empty());
+    }
+
+    #[test]
+    fn from_report_uses_outermost_status_code() {
+        let report = Report::new(InnerError)
+            .attach(StatusCode::NotFound)
+            .change_context(OuterError)
+            .attach(StatusCode::InvalidArgument);
+
+        let status = Status::<Detail>::from_report(&report);
+
+        assert_eq!(status.code(), StatusCode::InvalidArgument);
+        assert_eq!(status.message().as_deref(), Some("outer error"));
+    }
+
+    #[test]
+    fn from_report_uses_inner_status_code() {
+        let report = Report::new(InnerError)
+            .attach(StatusCode::NotFound)
+            .change_context(OuterError);
+
+        let status = Status::<Detail>::from_report(&report);
+
+        assert_eq!(status.code(), StatusCode::NotFound);
+        assert_eq!(status.message().as_deref(), Some("outer error"));
+    }
+
+    #[test]
+    fn from_report_collects_contents() {
+        let mut report = Report::new(InnerError)
+            .attach(Detail("inner".to_owned()))
+            .attach_printable("not a detail")
+            .change_context(OuterError)
+            .attach(Detail("outer".to_owned()));
+        report.extend_one(Report::new(OuterError).attach(Detail("sibling".to_owned())));
+
+        let status = Status::<Detail>::from_report(&report);
+
+        assert_eq!(status.contents(), [
+            Detail("outer".to_owned()),
+            Detail("inner".to_owned()),
+            Detail("sibling".to_owned()),
+        ]);
+    }
+
+    #[test]
+    fn serialize() {
+        let status = Status::new(
+            StatusCode::NotFound,
+            Some("entity not found".to_owned()),
+            vec![Detail("detail".to_owned())],
+        );
+
+        let value = serde_json::to_value(&status).expect("status should serialize");
+        assert_eq!(
+            value,
+            json!({
+                "code": "NOT_FOUND",
+                "message": "entity not found",
+                "contents": ["detail"],
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Status<Detail>>(value).expect("status should deserialize"),
+            status
+        );
+
+        // the message is omitted if not present
+        assert_eq!(
+            serde_json::to_value(Status::<Detail>::new(StatusCode::Ok, None, Vec::new()))
+                .expect("status should serialize"),
+            json!({
+                "code": "OK",
+                "contents": [],
+            })
+        );
+    }
+}
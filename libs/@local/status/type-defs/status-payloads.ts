@@ -1,3 +1,4 @@
+export { BadRequest, FieldViolation } from "./status-payloads/bad-request";
 export { ErrorInfo } from "./status-payloads/error-info";
 export { RequestInfo } from "./status-payloads/request-info";
 export { ResourceInfo } from "./status-payloads/resource-info";
@@ -0,0 +1,29 @@
+/* Adapted from the Google Cloud Error Model
+    - https://github.com/googleapis/googleapis/blob/master/google/rpc/error_details.proto
+ */
+
+/**
+ * A single bad request field.
+ */
+export type FieldViolation = {
+  /**
+   * A path that leads to a field in the request body.
+   */
+  field: string;
+
+  /**
+   * A description of why the request element is bad.
+   */
+  description: string;
+};
+
+/**
+ * Describes violations in a client request. This error type focuses on the syntactic aspects of the
+ * request.
+ */
+export type BadRequest = {
+  /**
+   * Describes all violations in a client request.
+   */
+  fieldViolations: FieldViolation[];
+};
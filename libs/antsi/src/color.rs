@@ -1,3 +1,7 @@
+use core::fmt::{self, Write};
+
+use crate::sgr::Sequence;
+
 /// Basic colors variants
 ///
 /// ## Support
@@ -57,6 +61,21 @@ impl BasicColor {
     pub const fn bright(self) -> BrightColor {
         BrightColor(self)
     }
+
+    /// Returns the color corresponding to the offset from the first color parameter (`30` or `40`)
+    pub(crate) const fn from_offset(offset: u16) -> Option<Self> {
+        match offset {
+            0 => Some(Self::Black),
+            1 => Some(Self::Red),
+            2 => Some(Self::Green),
+            3 => Some(Self::Yellow),
+            4 => Some(Self::Blue),
+            5 => Some(Self::Magenta),
+            6 => Some(Self::Cyan),
+            7 => Some(Self::White),
+            _ => None,
+        }
+    }
 }
 
 /// Bright color variants
@@ -149,6 +168,12 @@ impl IndexedColor {
     pub const fn new(index: u8) -> Self {
         Self(index)
     }
+
+    /// The index into the color table
+    #[must_use]
+    pub const fn index(self) -> u8 {
+        self.0
+    }
 }
 
 impl From<BasicColor> for IndexedColor {
@@ -260,6 +285,24 @@ impl RgbColor {
     pub const fn new(red: u8, green: u8, blue: u8) -> Self {
         Self { red, green, blue }
     }
+
+    /// The red component of the color
+    #[must_use]
+    pub const fn red(self) -> u8 {
+        self.red
+    }
+
+    /// The green component of the color
+    #[must_use]
+    pub const fn green(self) -> u8 {
+        self.green
+    }
+
+    /// The blue component of the color
+    #[must_use]
+    pub const fn blue(self) -> u8 {
+        self.blue
+    }
 }
 
 /// Truecolor 32-bit RGBA support
@@ -423,6 +466,154 @@ pub enum Color {
     Cmyk(CmykColor),
 }
 
+/// The attribute a [`Color`] is applied to
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ColorTarget {
+    Foreground,
+    Background,
+    #[cfg(feature = "underline-color")]
+    Underline,
+}
+
+impl ColorTarget {
+    /// Parameter used for colors outside of the basic and bright color palette
+    const fn extended(self) -> u8 {
+        match self {
+            Self::Foreground => 38,
+            Self::Background => 48,
+            #[cfg(feature = "underline-color")]
+            Self::Underline => 58,
+        }
+    }
+
+    /// Parameter used to reset the color to the terminal default
+    const fn reset(self) -> u8 {
+        match self {
+            Self::Foreground => 39,
+            Self::Background => 49,
+            #[cfg(feature = "underline-color")]
+            Self::Underline => 59,
+        }
+    }
+
+    /// Writes the SGR parameters required to change the color from `current` to `next`
+    pub(crate) fn write_transition_parameters<W: Write + ?Sized>(
+        self,
+        current: Option<Color>,
+        next: Option<Color>,
+        sequence: &mut Sequence<W>,
+    ) -> fmt::Result {
+        if current == next {
+            return Ok(());
+        }
+
+        match next {
+            Some(color) => color.write_parameters(sequence, self),
+            None => sequence.push(self.reset()),
+        }
+    }
+}
+
+impl Color {
+    /// Writes the SGR parameters of the color
+    ///
+    /// Extended colors are written in the format specified by [ISO 8613-6], using `:` as a
+    /// delimiter between elements. The underline color has no dedicated parameters for the basic
+    /// and bright color palette, these colors are written as their [`IndexedColor`] counterpart
+    /// instead.
+    ///
+    /// [ISO 8613-6]: https://www.iso.org/standard/22943.html
+    pub(crate) fn write_parameters<W: Write + ?Sized>(
+        self,
+        sequence: &mut Sequence<W>,
+        target: ColorTarget,
+    ) -> fmt::Result {
+        let extended = target.extended();
+
+        match self {
+            Self::Basic(color) => {
+                let IndexedColor(index) = color.into();
+
+                match target {
+                    ColorTarget::Foreground => sequence.push(30 + index),
+                    ColorTarget::Background => sequence.push(40 + index),
+                    #[cfg(feature = "underline-color")]
+                    ColorTarget::Underline => sequence.push(format_args!("{extended}:5:{index}")),
+                }
+            }
+            Self::Bright(color) => {
+                let IndexedColor(index) = color.into();
+
+                match target {
+                    ColorTarget::Foreground => sequence.push(90 + index - 8),
+                    ColorTarget::Background => sequence.push(100 + index - 8),
+                    #[cfg(feature = "underline-color")]
+                    ColorTarget::Underline => sequence.push(format_args!("{extended}:5:{index}")),
+                }
+            }
+            Self::Indexed(IndexedColor(index)) => {
+                sequence.push(format_args!("{extended}:5:{index}"))
+            }
+            Self::Transparent(TransparentColor) => sequence.push(format_args!("{extended}:1")),
+            Self::Rgb(RgbColor { red, green, blue }) => {
+                sequence.push(format_args!("{extended}:2::{red}:{green}:{blue}"))
+            }
+            #[cfg(feature = "rgba")]
+            Self::Rgba(RgbaColor {
+                red,
+                green,
+                blue,
+                alpha,
+            }) => sequence.push(format_args!("{extended}:6::{red}:{green}:{blue}:{alpha}")),
+            Self::Cmy(CmyColor {
+                cyan,
+                magenta,
+                yellow,
+            }) => sequence.push(format_args!("{extended}:3::{cyan}:{magenta}:{yellow}")),
+            Self::Cmyk(CmykColor {
+                cyan,
+                magenta,
+                yellow,
+                black,
+            }) => sequence.push(format_args!(
+                "{extended}:4::{cyan}:{magenta}:{yellow}:{black}"
+            )),
+        }
+    }
+}
+
+impl Color {
+    /// Converts the color into its [`RgbColor`] counterpart
+    ///
+    /// [`TransparentColor`] has no [`RgbColor`] counterpart and returns [`None`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Color, RgbColor, TransparentColor};
+    ///
+    /// assert_eq!(
+    ///     Color::from(BasicColor::Red).to_rgb(),
+    ///     Some(RgbColor::new(205, 0, 0))
+    /// );
+    /// assert_eq!(Color::from(TransparentColor).to_rgb(), None);
+    /// ```
+    #[must_use]
+    pub fn to_rgb(self) -> Option<RgbColor> {
+        match self {
+            Self::Basic(color) => Some(color.into()),
+            Self::Bright(color) => Some(color.into()),
+            Self::Indexed(color) => Some(color.into()),
+            Self::Transparent(_) => None,
+            Self::Rgb(color) => Some(color),
+            #[cfg(feature = "rgba")]
+            Self::Rgba(color) => Some(color.into()),
+            Self::Cmy(color) => Some(color.into()),
+            Self::Cmyk(color) => Some(color.into()),
+        }
+    }
+}
+
 impl From<BasicColor> for Color {
     fn from(value: BasicColor) -> Self {
         Self::Basic(value)
@@ -471,3 +662,134 @@ impl From<TransparentColor> for Color {
         Self::Transparent(value)
     }
 }
+
+/// Approximation of the default palette of xterm for the [`BasicColor`] and [`BrightColor`]
+/// variants
+///
+/// Most terminals allow users to customize these colors, the actual colors displayed may differ.
+const PALETTE: [RgbColor; 16] = [
+    RgbColor::new(0, 0, 0),
+    RgbColor::new(205, 0, 0),
+    RgbColor::new(0, 205, 0),
+    RgbColor::new(205, 205, 0),
+    RgbColor::new(0, 0, 238),
+    RgbColor::new(205, 0, 205),
+    RgbColor::new(0, 205, 205),
+    RgbColor::new(229, 229, 229),
+    RgbColor::new(127, 127, 127),
+    RgbColor::new(255, 0, 0),
+    RgbColor::new(0, 255, 0),
+    RgbColor::new(255, 255, 0),
+    RgbColor::new(92, 92, 255),
+    RgbColor::new(255, 0, 255),
+    RgbColor::new(0, 255, 255),
+    RgbColor::new(255, 255, 255),
+];
+
+/// Converts the color using the default xterm palette
+///
+/// `0` - `15` use an approximation of the default palette, `16` - `231` map to the 6x6x6 color cube
+/// and `232` - `255` to the grayscale ramp.
+///
+/// # Example
+///
+/// ```
+/// use antsi::{IndexedColor, RgbColor};
+///
+/// assert_eq!(
+///     RgbColor::from(IndexedColor::new(208)),
+///     RgbColor::new(255, 135, 0)
+/// );
+/// assert_eq!(
+///     RgbColor::from(IndexedColor::new(244)),
+///     RgbColor::new(128, 128, 128)
+/// );
+/// ```
+impl From<IndexedColor> for RgbColor {
+    fn from(IndexedColor(index): IndexedColor) -> Self {
+        const fn level(value: u8) -> u8 {
+            if value == 0 { 0 } else { 55 + value * 40 }
+        }
+
+        match index {
+            16..=231 => {
+                let index = index - 16;
+
+                Self::new(level(index / 36), level(index / 6 % 6), level(index % 6))
+            }
+            232..=255 => {
+                let value = 8 + (index - 232) * 10;
+
+                Self::new(value, value, value)
+            }
+            _ => PALETTE[usize::from(index)],
+        }
+    }
+}
+
+impl From<BasicColor> for RgbColor {
+    fn from(value: BasicColor) -> Self {
+        IndexedColor::from(value).into()
+    }
+}
+
+impl From<BrightColor> for RgbColor {
+    fn from(value: BrightColor) -> Self {
+        IndexedColor::from(value).into()
+    }
+}
+
+/// Converts the color by discarding the alpha channel
+#[cfg(feature = "rgba")]
+impl From<RgbaColor> for RgbColor {
+    fn from(value: RgbaColor) -> Self {
+        Self::new(value.red, value.green, value.blue)
+    }
+}
+
+/// Converts the color using the naive conversion, without any color profile
+///
+/// # Example
+///
+/// ```
+/// use antsi::{CmyColor, RgbColor};
+///
+/// assert_eq!(
+///     RgbColor::from(CmyColor::new(0, 255, 255)),
+///     RgbColor::new(255, 0, 0)
+/// );
+/// ```
+impl From<CmyColor> for RgbColor {
+    fn from(value: CmyColor) -> Self {
+        Self::new(255 - value.cyan, 255 - value.magenta, 255 - value.yellow)
+    }
+}
+
+/// Converts the color using the naive conversion, without any color profile
+///
+/// # Example
+///
+/// ```
+/// use antsi::{CmykColor, RgbColor};
+///
+/// assert_eq!(
+///     RgbColor::from(CmykColor::new(0, 255, 255, 51)),
+///     RgbColor::new(204, 0, 0)
+/// );
+/// ```
+impl From<CmykColor> for RgbColor {
+    fn from(value: CmykColor) -> Self {
+        let component = |color: u8| {
+            let value = u16::from(255 - color) * u16::from(255 - value.black) / 255;
+
+            // `value` is at most `255 * 255 / 255`
+            u8::try_from(value).unwrap_or(u8::MAX)
+        };
+
+        Self::new(
+            component(value.cyan),
+            component(value.magenta),
+            component(value.yellow),
+        )
+    }
+}
@@ -1,3 +1,7 @@
+use core::fmt::{self, Display, Write};
+
+use crate::sgr::Sequence;
+
 /// Frame around text
 ///
 /// Not well supported by any major terminal, frames are either rectangular or an oval. [ISO 6429]
@@ -53,4 +57,64 @@ impl Decorations {
     pub const fn new() -> Self {
         Self { frame: None }
     }
+
+    #[must_use]
+    pub const fn with_frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+
+        self
+    }
+
+    /// Combines two decorations, decorations set in `overlay` take precedence
+    #[must_use]
+    pub fn merge(self, overlay: Self) -> Self {
+        Self {
+            frame: overlay.frame.or(self.frame),
+        }
+    }
+
+    /// Writes the SGR parameters enabling every decoration
+    pub(crate) fn write_parameters<W: Write + ?Sized>(
+        self,
+        sequence: &mut Sequence<W>,
+    ) -> fmt::Result {
+        Self::new().write_transition_parameters(self, sequence)
+    }
+
+    /// Writes the SGR parameters required to change the decorations from `self` to `next`
+    pub(crate) fn write_transition_parameters<W: Write + ?Sized>(
+        self,
+        next: Self,
+        sequence: &mut Sequence<W>,
+    ) -> fmt::Result {
+        if self.frame == next.frame {
+            return Ok(());
+        }
+
+        match next.frame {
+            Some(Frame::Framed) => sequence.push(51),
+            Some(Frame::Encircled) => sequence.push(52),
+            None => sequence.push(54),
+        }
+    }
+}
+
+/// Emits the SGR sequence enabling every decoration
+///
+/// # Example
+///
+/// ```
+/// use antsi::{Decorations, Frame};
+///
+/// assert_eq!(
+///     Decorations::new().with_frame(Frame::Encircled).to_string(),
+///     "\x1b[52m"
+/// );
+/// ```
+impl Display for Decorations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sequence = Sequence::new(f);
+        self.write_parameters(&mut sequence)?;
+        sequence.finish()
+    }
 }
@@ -1,3 +1,7 @@
+use core::fmt::{self, Display, Write};
+
+use crate::sgr::Sequence;
+
 /// Font Weight
 ///
 /// ## Support
@@ -323,4 +327,363 @@ impl Font {
     pub const fn is_overline(&self) -> bool {
         self.is_style(FontStyle::Overline)
     }
+
+    /// Combines two fonts, attributes set in `overlay` take precedence
+    ///
+    /// Attributes that can only be enabled (like [`Self::is_italic`]) are enabled if they are
+    /// enabled in either font.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{Font, FontWeight, Underline};
+    ///
+    /// let font = Font::new()
+    ///     .with_weight(FontWeight::Bold)
+    ///     .with_italic()
+    ///     .merge(
+    ///         Font::new()
+    ///             .with_weight(FontWeight::Faint)
+    ///             .with_underline(Underline::Single),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     font,
+    ///     Font::new()
+    ///         .with_weight(FontWeight::Faint)
+    ///         .with_underline(Underline::Single)
+    ///         .with_italic()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn merge(self, overlay: Self) -> Self {
+        Self {
+            weight: overlay.weight.or(self.weight),
+            family: overlay.family.or(self.family),
+            #[cfg(feature = "script")]
+            script: overlay.script.or(self.script),
+            style: self.style | overlay.style,
+            underline: overlay.underline.or(self.underline),
+            blinking: overlay.blinking.or(self.blinking),
+        }
+    }
+
+    /// Writes the SGR parameters enabling every attribute of the font
+    pub(crate) fn write_parameters<W: Write + ?Sized>(
+        self,
+        sequence: &mut Sequence<W>,
+    ) -> fmt::Result {
+        Self::new().write_transition_parameters(self, sequence)
+    }
+
+    /// Writes the SGR parameters required to change the font from `self` to `next`
+    ///
+    /// Attributes that are the same in both fonts are skipped. Some attributes share the same reset
+    /// parameter, if one of them is disabled, the others are enabled again if required.
+    pub(crate) fn write_transition_parameters<W: Write + ?Sized>(
+        self,
+        next: Self,
+        sequence: &mut Sequence<W>,
+    ) -> fmt::Result {
+        // some terminals allow bold and faint at the same time, therefore the weight is always
+        // reset before being changed
+        if self.weight != next.weight {
+            if self.weight.is_some() {
+                sequence.push(22)?;
+            }
+
+            match next.weight {
+                Some(FontWeight::Bold) => sequence.push(1)?,
+                Some(FontWeight::Faint) => sequence.push(2)?,
+                None => {}
+            }
+        }
+
+        // `23` resets both italic and Fraktur
+        let is_fraktur = |font: Self| font.family == Some(FontFamily::Fraktur);
+        let mut current = self;
+        if (self.is_italic() && !next.is_italic()) || (is_fraktur(self) && !is_fraktur(next)) {
+            sequence.push(23)?;
+
+            current.set_italic(false);
+            if is_fraktur(current) {
+                current.family = None;
+            }
+        }
+
+        if next.is_italic() && !current.is_italic() {
+            sequence.push(3)?;
+        }
+
+        if self.underline != next.underline {
+            match next.underline {
+                Some(Underline::Single) => sequence.push(4)?,
+                Some(Underline::Double) => sequence.push(21)?,
+                #[cfg(feature = "underline-variants")]
+                Some(Underline::Curly) => sequence.push("4:3")?,
+                #[cfg(feature = "underline-variants")]
+                Some(Underline::Dotted) => sequence.push("4:4")?,
+                #[cfg(feature = "underline-variants")]
+                Some(Underline::Dashed) => sequence.push("4:5")?,
+                None => sequence.push(24)?,
+            }
+        }
+
+        if self.blinking != next.blinking {
+            match next.blinking {
+                Some(Blinking::Slow) => sequence.push(5)?,
+                Some(Blinking::Fast) => sequence.push(6)?,
+                None => sequence.push(25)?,
+            }
+        }
+
+        if self.is_inverse() != next.is_inverse() {
+            sequence.push(if next.is_inverse() { 7 } else { 27 })?;
+        }
+
+        // `28` resets both hidden and over-strike
+        #[cfg(feature = "overstrike")]
+        let is_overstrike_removed = self.is_overstrike() && !next.is_overstrike();
+        #[cfg(not(feature = "overstrike"))]
+        let is_overstrike_removed = false;
+
+        if (self.is_hidden() && !next.is_hidden()) || is_overstrike_removed {
+            sequence.push(28)?;
+
+            current.set_hidden(false);
+            #[cfg(feature = "overstrike")]
+            current.set_overstrike(false);
+        }
+
+        if next.is_hidden() && !current.is_hidden() {
+            sequence.push(8)?;
+        }
+
+        // mintty extension, over-strike is a sub-parameter of hidden
+        #[cfg(feature = "overstrike")]
+        if next.is_overstrike() && !current.is_overstrike() {
+            sequence.push("8:7")?;
+        }
+
+        if self.is_strikethrough() != next.is_strikethrough() {
+            sequence.push(if next.is_strikethrough() { 9 } else { 29 })?;
+        }
+
+        if current.family != next.family {
+            match next.family {
+                Some(FontFamily::Fraktur) => sequence.push(20)?,
+                Some(FontFamily::Alternative(AlternativeFontFamily(variant))) => {
+                    sequence.push(11 + variant)?;
+                }
+                None => sequence.push(10)?,
+            }
+        }
+
+        if self.is_overline() != next.is_overline() {
+            sequence.push(if next.is_overline() { 53 } else { 55 })?;
+        }
+
+        #[cfg(feature = "script")]
+        if self.script != next.script {
+            match next.script {
+                Some(FontScript::Super) => sequence.push(73)?,
+                Some(FontScript::Sub) => sequence.push(74)?,
+                None => sequence.push(75)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Emits the SGR sequence enabling every attribute of the font
+///
+/// # Example
+///
+/// ```
+/// use antsi::{Font, FontWeight, Underline};
+///
+/// let font = Font::new()
+///     .with_weight(FontWeight::Bold)
+///     .with_underline(Underline::Double)
+///     .with_italic();
+///
+/// assert_eq!(font.to_string(), "\x1b[1;3;21m");
+/// assert_eq!(Font::new().to_string(), "");
+/// ```
+impl Display for Font {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sequence = Sequence::new(f);
+        self.write_parameters(&mut sequence)?;
+        sequence.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use super::*;
+
+    fn transition(current: Font, next: Font) -> String {
+        let mut buffer = String::new();
+
+        let mut sequence = Sequence::new(&mut buffer);
+        current
+            .write_transition_parameters(next, &mut sequence)
+            .expect("writing to a string is infallible");
+        sequence
+            .finish()
+            .expect("writing to a string is infallible");
+
+        buffer
+    }
+
+    #[test]
+    fn enable() {
+        let cases = [
+            (Font::new().with_weight(FontWeight::Bold), "\x1b[1m"),
+            (Font::new().with_weight(FontWeight::Faint), "\x1b[2m"),
+            (Font::new().with_italic(), "\x1b[3m"),
+            (Font::new().with_underline(Underline::Single), "\x1b[4m"),
+            (Font::new().with_underline(Underline::Double), "\x1b[21m"),
+            (Font::new().with_blinking(Blinking::Slow), "\x1b[5m"),
+            (Font::new().with_blinking(Blinking::Fast), "\x1b[6m"),
+            (Font::new().with_inverse(), "\x1b[7m"),
+            (Font::new().with_hidden(), "\x1b[8m"),
+            (Font::new().with_strikethrough(), "\x1b[9m"),
+            (Font::new().with_family(FontFamily::Fraktur), "\x1b[20m"),
+            (Font::new().with_overline(), "\x1b[53m"),
+        ];
+
+        for (font, expected) in cases {
+            assert_eq!(font.to_string(), expected, "{font:?}");
+        }
+    }
+
+    #[test]
+    fn disable() {
+        let cases = [
+            (Font::new().with_weight(FontWeight::Bold), "\x1b[22m"),
+            (Font::new().with_weight(FontWeight::Faint), "\x1b[22m"),
+            (Font::new().with_italic(), "\x1b[23m"),
+            (Font::new().with_underline(Underline::Double), "\x1b[24m"),
+            (Font::new().with_blinking(Blinking::Fast), "\x1b[25m"),
+            (Font::new().with_inverse(), "\x1b[27m"),
+            (Font::new().with_hidden(), "\x1b[28m"),
+            (Font::new().with_strikethrough(), "\x1b[29m"),
+            (Font::new().with_family(FontFamily::Fraktur), "\x1b[23m"),
+            (Font::new().with_overline(), "\x1b[55m"),
+        ];
+
+        for (font, expected) in cases {
+            assert_eq!(transition(font, Font::new()), expected, "{font:?}");
+        }
+    }
+
+    #[test]
+    fn alternative_family() {
+        let first = FontFamily::Alternative(AlternativeFontFamily::new(0).expect("valid variant"));
+        let last = FontFamily::Alternative(AlternativeFontFamily::new(8).expect("valid variant"));
+
+        assert_eq!(AlternativeFontFamily::new(9), None);
+
+        assert_eq!(Font::new().with_family(first).to_string(), "\x1b[11m");
+        assert_eq!(Font::new().with_family(last).to_string(), "\x1b[19m");
+        assert_eq!(
+            transition(Font::new().with_family(first), Font::new()),
+            "\x1b[10m"
+        );
+    }
+
+    #[test]
+    fn weight_is_reset_before_change() {
+        // some terminals allow bold and faint at the same time
+        assert_eq!(
+            transition(
+                Font::new().with_weight(FontWeight::Bold),
+                Font::new().with_weight(FontWeight::Faint)
+            ),
+            "\x1b[22;2m"
+        );
+    }
+
+    #[test]
+    fn italic_and_fraktur_share_reset() {
+        let italic = Font::new().with_italic();
+        let fraktur = Font::new().with_family(FontFamily::Fraktur);
+        let both = italic.with_family(FontFamily::Fraktur);
+
+        // disabling Fraktur also disables italic, which needs to be enabled again
+        assert_eq!(transition(both, italic), "\x1b[23;3m");
+        // disabling italic also disables Fraktur, which needs to be enabled again
+        assert_eq!(transition(both, fraktur), "\x1b[23;20m");
+        assert_eq!(transition(both, Font::new()), "\x1b[23m");
+        assert_eq!(transition(italic, both), "\x1b[20m");
+    }
+
+    #[test]
+    #[cfg(feature = "overstrike")]
+    fn hidden_and_overstrike_share_reset() {
+        let hidden = Font::new().with_hidden();
+        let overstrike = Font::new().with_overstrike();
+        let both = hidden.with_overstrike();
+
+        assert_eq!(overstrike.to_string(), "\x1b[8:7m");
+        assert_eq!(transition(both, hidden), "\x1b[28;8m");
+        assert_eq!(transition(both, overstrike), "\x1b[28;8:7m");
+        assert_eq!(transition(both, Font::new()), "\x1b[28m");
+    }
+
+    #[test]
+    #[cfg(feature = "underline-variants")]
+    fn underline_variants() {
+        assert_eq!(
+            Font::new().with_underline(Underline::Curly).to_string(),
+            "\x1b[4:3m"
+        );
+        assert_eq!(
+            Font::new().with_underline(Underline::Dotted).to_string(),
+            "\x1b[4:4m"
+        );
+        assert_eq!(
+            Font::new().with_underline(Underline::Dashed).to_string(),
+            "\x1b[4:5m"
+        );
+        assert_eq!(
+            transition(
+                Font::new().with_underline(Underline::Curly),
+                Font::new().with_underline(Underline::Single)
+            ),
+            "\x1b[4m"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "script")]
+    fn script() {
+        assert_eq!(
+            Font::new().with_script(FontScript::Super).to_string(),
+            "\x1b[73m"
+        );
+        assert_eq!(
+            Font::new().with_script(FontScript::Sub).to_string(),
+            "\x1b[74m"
+        );
+        assert_eq!(
+            transition(Font::new().with_script(FontScript::Sub), Font::new()),
+            "\x1b[75m"
+        );
+    }
+
+    #[test]
+    fn unchanged() {
+        let font = Font::new()
+            .with_weight(FontWeight::Bold)
+            .with_italic()
+            .with_underline(Underline::Single);
+
+        assert_eq!(transition(font, font), "");
+        assert_eq!(transition(font, font.with_inverse()), "\x1b[7m");
+    }
 }
@@ -23,6 +23,13 @@
 // future PR will add remaining documentation
 #![allow(missing_docs)]
 
+#[cfg(any(test, feature = "alloc"))]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt::{self, Display, Write};
+
 #[cfg(feature = "rgba")]
 pub use color::RgbaColor;
 pub use color::{
@@ -32,10 +39,23 @@ pub use decorations::{Decorations, Frame};
 #[cfg(feature = "script")]
 pub use font::FontScript;
 pub use font::{Blinking, Font, FontFamily, FontWeight, Underline};
+pub use parse::Parser;
+pub use profile::ColorProfile;
+#[cfg(feature = "alloc")]
+pub use string::StyledString;
+pub use styled::Styled;
+
+use crate::{color::ColorTarget, sgr::Sequence};
 
 mod color;
 mod decorations;
 mod font;
+mod parse;
+mod profile;
+mod sgr;
+#[cfg(feature = "alloc")]
+mod string;
+mod styled;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Foreground(Color);
@@ -61,6 +81,32 @@ where
     }
 }
 
+/// Emits the SGR sequence setting the foreground color
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, Foreground, IndexedColor, RgbColor};
+///
+/// assert_eq!(Foreground::from(BasicColor::Red).to_string(), "\x1b[31m");
+/// assert_eq!(
+///     Foreground::from(IndexedColor::new(208)).to_string(),
+///     "\x1b[38:5:208m"
+/// );
+/// assert_eq!(
+///     Foreground::from(RgbColor::new(255, 128, 0)).to_string(),
+///     "\x1b[38:2::255:128:0m"
+/// );
+/// ```
+impl Display for Foreground {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sequence = Sequence::new(f);
+        self.0
+            .write_parameters(&mut sequence, ColorTarget::Foreground)?;
+        sequence.finish()
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Background(Color);
 
@@ -85,6 +131,27 @@ where
     }
 }
 
+/// Emits the SGR sequence setting the background color
+///
+/// # Example
+///
+/// ```
+/// use antsi::{Background, BasicColor};
+///
+/// assert_eq!(
+///     Background::from(BasicColor::Blue.bright()).to_string(),
+///     "\x1b[104m"
+/// );
+/// ```
+impl Display for Background {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sequence = Sequence::new(f);
+        self.0
+            .write_parameters(&mut sequence, ColorTarget::Background)?;
+        sequence.finish()
+    }
+}
+
 // kitty + vte extension
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg(feature = "underline-color")]
@@ -113,6 +180,28 @@ where
     }
 }
 
+/// Emits the SGR sequence setting the underline color
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, UnderlineColor};
+///
+/// assert_eq!(
+///     UnderlineColor::from(BasicColor::Red).to_string(),
+///     "\x1b[58:5:1m"
+/// );
+/// ```
+#[cfg(feature = "underline-color")]
+impl Display for UnderlineColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sequence = Sequence::new(f);
+        self.0
+            .write_parameters(&mut sequence, ColorTarget::Underline)?;
+        sequence.finish()
+    }
+}
+
 /// Enables the use of styles on target text
 ///
 /// Enables the use of all **S**elect **G**raphic **M**ode escaped initially defined by [`ISO 6429`]
@@ -189,4 +278,324 @@ impl Style {
 
         self
     }
+
+    #[must_use]
+    pub const fn with_decorations(mut self, decorations: Decorations) -> Self {
+        self.decorations = decorations;
+
+        self
+    }
+
+    /// Applies the style to `value`, see [`Styled`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Font, Foreground, Style};
+    ///
+    /// let style = Style::new()
+    ///     .with_foreground(Foreground::from(BasicColor::Green))
+    ///     .with_font(Font::new().with_italic());
+    ///
+    /// assert_eq!(style.apply("ok").to_string(), "\x1b[3;32mok\x1b[23;39m");
+    /// ```
+    #[must_use]
+    pub const fn apply<T>(self, value: T) -> Styled<T> {
+        Styled::new(value, self)
+    }
+
+    /// Combines two styles, attributes set in `overlay` take precedence
+    ///
+    /// This is used to inherit the attributes of an enclosing style, see [`Font::merge`] for how
+    /// font attributes are combined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Font, FontWeight, Foreground, Style};
+    ///
+    /// let red = Style::new().with_foreground(Foreground::from(BasicColor::Red));
+    /// let bold = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+    ///
+    /// assert_eq!(
+    ///     red.merge(bold),
+    ///     bold.with_foreground(Foreground::from(BasicColor::Red))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn merge(self, overlay: Self) -> Self {
+        Self {
+            font: self.font.merge(overlay.font),
+            decorations: self.decorations.merge(overlay.decorations),
+            foreground: overlay.foreground.or(self.foreground),
+            background: overlay.background.or(self.background),
+            #[cfg(feature = "underline-color")]
+            underline_color: overlay.underline_color.or(self.underline_color),
+        }
+    }
+
+    /// Writes the SGR sequence enabling every attribute of the style
+    ///
+    /// Nothing is written if no attribute is set, as an empty SGR sequence would reset all
+    /// attributes. This is the same as the [`Display`] implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> fmt::Result {
+        Self::new().write_transition_to(self, writer)
+    }
+
+    /// Writes the SGR sequence resetting every attribute of the style to the terminal default
+    ///
+    /// Only the attributes that are set are reset, instead of using the catch-all reset `ESC[0m`,
+    /// which means that styles can be nested, as long as they do not set the same attribute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{Background, BasicColor, Font, FontWeight, Style};
+    ///
+    /// let style = Style::new()
+    ///     .with_font(Font::new().with_weight(FontWeight::Bold))
+    ///     .with_background(Background::from(BasicColor::Red));
+    ///
+    /// let mut reset = String::new();
+    /// style.write_reset_to(&mut reset)?;
+    ///
+    /// assert_eq!(reset, "\x1b[22;49m");
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_reset_to<W: Write + ?Sized>(&self, writer: &mut W) -> fmt::Result {
+        self.write_transition_to(&Self::new(), writer)
+    }
+
+    /// Writes the shortest SGR sequence changing the active style from `self` to `next`
+    ///
+    /// Attributes that are the same in both styles are skipped, nothing is written if both styles
+    /// are the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Font, FontWeight, Foreground, Style};
+    ///
+    /// let bold = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+    /// let red = bold.with_foreground(Foreground::from(BasicColor::Red));
+    ///
+    /// let mut transition = String::new();
+    /// bold.write_transition_to(&red, &mut transition)?;
+    /// red.write_transition_to(&Style::new(), &mut transition)?;
+    ///
+    /// assert_eq!(transition, "\x1b[31m\x1b[22;39m");
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_transition_to<W: Write + ?Sized>(
+        &self,
+        next: &Self,
+        writer: &mut W,
+    ) -> fmt::Result {
+        let mut sequence = Sequence::new(writer);
+
+        self.font
+            .write_transition_parameters(next.font, &mut sequence)?;
+        self.decorations
+            .write_transition_parameters(next.decorations, &mut sequence)?;
+
+        ColorTarget::Foreground.write_transition_parameters(
+            self.foreground.map(Foreground::color),
+            next.foreground.map(Foreground::color),
+            &mut sequence,
+        )?;
+        ColorTarget::Background.write_transition_parameters(
+            self.background.map(Background::color),
+            next.background.map(Background::color),
+            &mut sequence,
+        )?;
+
+        #[cfg(feature = "underline-color")]
+        ColorTarget::Underline.write_transition_parameters(
+            self.underline_color.map(UnderlineColor::color),
+            next.underline_color.map(UnderlineColor::color),
+            &mut sequence,
+        )?;
+
+        sequence.finish()
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use super::*;
+
+    fn transition(current: Style, next: Style) -> String {
+        let mut buffer = String::new();
+        current
+            .write_transition_to(&next, &mut buffer)
+            .expect("writing to a string is infallible");
+
+        buffer
+    }
+
+    #[test]
+    fn basic_color() {
+        assert_eq!(Foreground::from(BasicColor::Black).to_string(), "\x1b[30m");
+        assert_eq!(Foreground::from(BasicColor::White).to_string(), "\x1b[37m");
+        assert_eq!(Background::from(BasicColor::Black).to_string(), "\x1b[40m");
+        assert_eq!(Background::from(BasicColor::White).to_string(), "\x1b[47m");
+    }
+
+    #[test]
+    fn bright_color() {
+        assert_eq!(
+            Foreground::from(BasicColor::Black.bright()).to_string(),
+            "\x1b[90m"
+        );
+        assert_eq!(
+            Foreground::from(BasicColor::White.bright()).to_string(),
+            "\x1b[97m"
+        );
+        assert_eq!(
+            Background::from(BasicColor::Black.bright()).to_string(),
+            "\x1b[100m"
+        );
+        assert_eq!(
+            Background::from(BasicColor::White.bright()).to_string(),
+            "\x1b[107m"
+        );
+    }
+
+    #[test]
+    fn extended_color() {
+        assert_eq!(
+            Foreground::from(IndexedColor::new(208)).to_string(),
+            "\x1b[38:5:208m"
+        );
+        assert_eq!(
+            Background::from(IndexedColor::new(0)).to_string(),
+            "\x1b[48:5:0m"
+        );
+        assert_eq!(
+            Foreground::from(RgbColor::new(1, 2, 3)).to_string(),
+            "\x1b[38:2::1:2:3m"
+        );
+        assert_eq!(
+            Background::from(RgbColor::new(255, 255, 255)).to_string(),
+            "\x1b[48:2::255:255:255m"
+        );
+        assert_eq!(
+            Foreground::from(CmyColor::new(1, 2, 3)).to_string(),
+            "\x1b[38:3::1:2:3m"
+        );
+        assert_eq!(
+            Background::from(CmykColor::new(1, 2, 3, 4)).to_string(),
+            "\x1b[48:4::1:2:3:4m"
+        );
+        assert_eq!(Foreground::from(TransparentColor).to_string(), "\x1b[38:1m");
+    }
+
+    #[test]
+    #[cfg(feature = "rgba")]
+    fn rgba_color() {
+        assert_eq!(
+            Foreground::from(RgbaColor::new(1, 2, 3, 4)).to_string(),
+            "\x1b[38:6::1:2:3:4m"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "underline-color")]
+    fn underline_color() {
+        // the underline color has no dedicated basic and bright palette parameters
+        assert_eq!(
+            UnderlineColor::from(BasicColor::Red).to_string(),
+            "\x1b[58:5:1m"
+        );
+        assert_eq!(
+            UnderlineColor::from(BasicColor::Red.bright()).to_string(),
+            "\x1b[58:5:9m"
+        );
+        assert_eq!(
+            UnderlineColor::from(RgbColor::new(1, 2, 3)).to_string(),
+            "\x1b[58:2::1:2:3m"
+        );
+
+        let style = Style::new().with_underline_color(UnderlineColor::from(BasicColor::Red));
+        assert_eq!(transition(style, Style::new()), "\x1b[59m");
+    }
+
+    #[test]
+    fn empty_style() {
+        assert_eq!(Style::new().to_string(), "");
+        assert_eq!(transition(Style::new(), Style::new()), "");
+        assert_eq!(Style::new().apply("value").to_string(), "value");
+    }
+
+    #[test]
+    fn style() {
+        let style = Style::new()
+            .with_font(Font::new().with_weight(FontWeight::Bold))
+            .with_decorations(Decorations::new().with_frame(Frame::Framed))
+            .with_foreground(Foreground::from(BasicColor::Red))
+            .with_background(Background::from(IndexedColor::new(16)));
+
+        // font, decorations, foreground and background are always emitted in the same order
+        assert_eq!(style.to_string(), "\x1b[1;51;31;48:5:16m");
+        assert_eq!(
+            style.apply("value").to_string(),
+            "\x1b[1;51;31;48:5:16mvalue\x1b[22;54;39;49m"
+        );
+    }
+
+    #[test]
+    fn reset() {
+        let style = Style::new()
+            .with_foreground(Foreground::from(RgbColor::new(1, 2, 3)))
+            .with_background(Background::from(BasicColor::Blue))
+            .with_decorations(Decorations::new().with_frame(Frame::Encircled));
+
+        let mut buffer = String::new();
+        style.write_reset_to(&mut buffer).expect("infallible");
+
+        // only the attributes that are set are reset, instead of the catch-all `ESC[0m`
+        assert_eq!(buffer, "\x1b[54;39;49m");
+    }
+
+    #[test]
+    fn transition_skips_unchanged() {
+        let red = Style::new().with_foreground(Foreground::from(BasicColor::Red));
+        let red_on_blue = red.with_background(Background::from(BasicColor::Blue));
+        let green_on_blue = red_on_blue.with_foreground(Foreground::from(BasicColor::Green));
+
+        assert_eq!(transition(red, red), "");
+        assert_eq!(transition(red, red_on_blue), "\x1b[44m");
+        assert_eq!(transition(red_on_blue, green_on_blue), "\x1b[32m");
+        assert_eq!(transition(green_on_blue, red), "\x1b[31;49m");
+    }
+
+    #[test]
+    fn transition_decorations() {
+        let framed = Style::new().with_decorations(Decorations::new().with_frame(Frame::Framed));
+        let encircled =
+            Style::new().with_decorations(Decorations::new().with_frame(Frame::Encircled));
+
+        assert_eq!(transition(framed, encircled), "\x1b[52m");
+        assert_eq!(transition(encircled, Style::new()), "\x1b[54m");
+    }
 }
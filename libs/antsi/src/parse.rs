@@ -0,0 +1,718 @@
+use core::str::Split;
+
+#[cfg(feature = "script")]
+use crate::FontScript;
+#[cfg(feature = "rgba")]
+use crate::RgbaColor;
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{
+    font::AlternativeFontFamily, Background, BasicColor, Blinking, CmyColor, CmykColor, Color,
+    Decorations, Font, FontFamily, FontWeight, Foreground, Frame, IndexedColor, RgbColor, Style,
+    Styled, TransparentColor, Underline,
+};
+
+/// Parses text containing escape sequences into spans of text and the [`Style`] applied to them
+///
+/// Every **S**elect **G**raphic **R**endition sequence (`ESC[...m`) is interpreted and changes the
+/// style of all following text until it is changed again. The parser understands:
+///
+/// * every attribute that can be emitted by [`Style`], including attributes only available through
+///   feature flags
+/// * extended colors using `;` as a delimiter (`ESC[38;5;208m`, `ESC[38;2;255;128;0m`), as well as
+///   `:` with or without the color space identifier ([ISO 8613-6]), e.g. `ESC[38:2::255:128:0m` and
+///   `ESC[38:2:255:128:0m`
+/// * resets of single attributes (e.g. `ESC[22m`) and the full reset (`ESC[0m` or `ESC[m`)
+///
+/// Unknown or malformed parameters are ignored, all other escape sequences (like cursor movement or
+/// operating system commands) are removed from the output.
+///
+/// Spans are never empty, but consecutive spans may share the same style.
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, Font, FontWeight, Foreground, Parser, Style, Styled};
+///
+/// let spans: Vec<_> =
+///     Parser::new("\x1b[1;31merror\x1b[22;39m: \x1b[1mmismatched types\x1b[0m").collect();
+///
+/// let bold = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+///
+/// assert_eq!(spans, [
+///     Styled::new(
+///         "error",
+///         bold.with_foreground(Foreground::from(BasicColor::Red))
+///     ),
+///     Styled::new(": ", Style::new()),
+///     Styled::new("mismatched types", bold),
+/// ]);
+///
+/// // stripping all escape sequences
+/// let text: String = Parser::new("\x1b[38:2::255:128:0mwarning\x1b[m")
+///     .map(|span| *span.value())
+///     .collect();
+///
+/// assert_eq!(text, "warning");
+/// ```
+///
+/// [ISO 8613-6]: https://www.iso.org/standard/22943.html
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    input: &'a str,
+    style: Style,
+}
+
+impl<'a> Parser<'a> {
+    #[must_use]
+    pub const fn new(input: &'a str) -> Self {
+        Self::with_style(input, Style::new())
+    }
+
+    /// Creates a parser, which applies all parsed sequences on top of `style`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Foreground, IndexedColor, Parser, RgbColor, Style};
+    ///
+    /// let style = Style::new().with_foreground(Foreground::from(BasicColor::Red));
+    ///
+    /// let mut parser = Parser::with_style("\x1b[48;5;208;38:2::0:128:255m", style);
+    /// assert_eq!(parser.next(), None);
+    /// assert_eq!(
+    ///     parser.style(),
+    ///     Style::new()
+    ///         .with_foreground(Foreground::from(RgbColor::new(0, 128, 255)))
+    ///         .with_background(IndexedColor::new(208).into())
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_style(input: &'a str, style: Style) -> Self {
+        Self { input, style }
+    }
+
+    /// The style after all sequences parsed so far have been applied
+    #[must_use]
+    pub const fn style(&self) -> Style {
+        self.style
+    }
+
+    /// The input that has not been parsed yet
+    #[must_use]
+    pub const fn remaining(&self) -> &'a str {
+        self.input
+    }
+
+    /// Skips the escape sequence at the start of the input, applying it if it is an SGR sequence
+    fn escape_sequence(&mut self) {
+        let mut chars = self.input.chars();
+        // skip `ESC`
+        chars.next();
+
+        match chars.next() {
+            Some('[') => {
+                let sequence = chars.as_str();
+
+                // control sequences end with a final byte in the range `0x40..=0x7E`, parameters
+                // and intermediate bytes are in the range `0x20..=0x3F`
+                self.input = sequence
+                    .find(|char| !matches!(char, '\x20'..='\x3F'))
+                    .map_or("", |end| {
+                        let (parameters, tail) = sequence.split_at(end);
+                        let mut tail = tail.chars();
+
+                        if tail.next() == Some('m') {
+                            apply(&mut self.style, parameters);
+                        }
+
+                        tail.as_str()
+                    });
+            }
+            Some(']') => {
+                // operating system commands are terminated by `BEL` or `ST` (`ESC\`)
+                let command = chars.as_str();
+
+                self.input = command.find(['\x07', '\x1b']).map_or("", |end| {
+                    let (_, tail) = command.split_at(end);
+
+                    tail.strip_prefix('\x07')
+                        .or_else(|| tail.strip_prefix("\x1b\\"))
+                        .unwrap_or(tail)
+                });
+            }
+            // any other escape sequence consists of a single character
+            _ => self.input = chars.as_str(),
+        }
+    }
+}
+
+impl<'a> Iterator for Parser<'a> {
+    type Item = Styled<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.input.is_empty() {
+                return None;
+            }
+
+            let end = self.input.find('\x1b').unwrap_or(self.input.len());
+            let (text, tail) = self.input.split_at(end);
+            self.input = tail;
+
+            if !text.is_empty() {
+                return Some(Styled::new(text, self.style));
+            }
+
+            self.escape_sequence();
+        }
+    }
+}
+
+/// Parses a single (sub-)parameter, empty parameters default to `0`
+fn number(parameter: &str) -> Option<u16> {
+    if parameter.is_empty() {
+        Some(0)
+    } else {
+        parameter.parse().ok()
+    }
+}
+
+/// Parses the components of a color delimited by `:`
+///
+/// The color space identifier preceding the components is optional and ignored.
+fn components<const N: usize>(parameters: Split<'_, char>) -> Option<[u8; N]> {
+    let mut values = [0; 5];
+    let mut length = 0;
+
+    for parameter in parameters {
+        *values.get_mut(length)? = number(parameter)?;
+        length += 1;
+    }
+
+    let offset = match length.checked_sub(N)? {
+        0 => 0,
+        1 => 1,
+        _ => return None,
+    };
+
+    let mut components = [0; N];
+    for (component, value) in components.iter_mut().zip(values.into_iter().skip(offset)) {
+        *component = u8::try_from(value).ok()?;
+    }
+
+    Some(components)
+}
+
+/// Takes the components of a color delimited by `;`
+fn take<const N: usize>(parameters: &mut Split<'_, char>) -> Option<[u8; N]> {
+    let mut components = [0; N];
+
+    for component in &mut components {
+        *component = u8::try_from(number(parameters.next()?)?).ok()?;
+    }
+
+    Some(components)
+}
+
+/// Parses an extended color (`38`, `48` and `58`)
+///
+/// If the color is not delimited by `:`, the components are taken from the remaining parameters.
+fn color(mut sub_parameters: Split<'_, char>, parameters: &mut Split<'_, char>) -> Option<Color> {
+    if let Some(kind) = sub_parameters.next() {
+        return match number(kind)? {
+            1 => Some(TransparentColor.into()),
+            2 => components(sub_parameters)
+                .map(|[red, green, blue]| RgbColor::new(red, green, blue).into()),
+            3 => components(sub_parameters)
+                .map(|[cyan, magenta, yellow]| CmyColor::new(cyan, magenta, yellow).into()),
+            4 => components(sub_parameters).map(|[cyan, magenta, yellow, black]| {
+                CmykColor::new(cyan, magenta, yellow, black).into()
+            }),
+            5 => {
+                let [index] = take(&mut sub_parameters)?;
+                Some(IndexedColor::new(index).into())
+            }
+            #[cfg(feature = "rgba")]
+            6 => components(sub_parameters)
+                .map(|[red, green, blue, alpha]| RgbaColor::new(red, green, blue, alpha).into()),
+            _ => None,
+        };
+    }
+
+    match number(parameters.next()?)? {
+        1 => Some(TransparentColor.into()),
+        2 => take(parameters).map(|[red, green, blue]| RgbColor::new(red, green, blue).into()),
+        3 => take(parameters)
+            .map(|[cyan, magenta, yellow]| CmyColor::new(cyan, magenta, yellow).into()),
+        4 => take(parameters).map(|[cyan, magenta, yellow, black]| {
+            CmykColor::new(cyan, magenta, yellow, black).into()
+        }),
+        5 => take(parameters).map(|[index]| IndexedColor::new(index).into()),
+        #[cfg(feature = "rgba")]
+        6 => take(parameters)
+            .map(|[red, green, blue, alpha]| RgbaColor::new(red, green, blue, alpha).into()),
+        _ => None,
+    }
+}
+
+/// Parses the style of an underline (`4`), which may be followed by a sub-parameter
+fn underline(sub_parameter: Option<&str>) -> Option<Underline> {
+    match sub_parameter.and_then(number) {
+        Some(0) => None,
+        Some(2) => Some(Underline::Double),
+        #[cfg(feature = "underline-variants")]
+        Some(3) => Some(Underline::Curly),
+        #[cfg(feature = "underline-variants")]
+        Some(4) => Some(Underline::Dotted),
+        #[cfg(feature = "underline-variants")]
+        Some(5) => Some(Underline::Dashed),
+        // unsupported variants fall back to a single underline
+        _ => Some(Underline::Single),
+    }
+}
+
+/// Applies the parameters of an SGR sequence to `style`
+fn apply(style: &mut Style, parameters: &str) {
+    let mut parameters = parameters.split(';');
+
+    while let Some(parameter) = parameters.next() {
+        let mut sub_parameters = parameter.split(':');
+        let code = match sub_parameters.next().and_then(number) {
+            Some(code) => code,
+            None => continue,
+        };
+
+        match code {
+            0 => *style = Style::new(),
+            30..=37 => {
+                style.foreground = BasicColor::from_offset(code - 30).map(Foreground::from);
+            }
+            38 => {
+                if let Some(color) = color(sub_parameters, &mut parameters) {
+                    style.foreground = Some(Foreground::new(color));
+                }
+            }
+            39 => style.foreground = None,
+            40..=47 => {
+                style.background = BasicColor::from_offset(code - 40).map(Background::from);
+            }
+            48 => {
+                if let Some(color) = color(sub_parameters, &mut parameters) {
+                    style.background = Some(Background::new(color));
+                }
+            }
+            49 => style.background = None,
+            51 => style.decorations.frame = Some(Frame::Framed),
+            52 => style.decorations.frame = Some(Frame::Encircled),
+            54 => style.decorations = Decorations::new(),
+            // the color needs to be parsed regardless, to skip its parameters
+            58 => {
+                let color = color(sub_parameters, &mut parameters);
+
+                #[cfg(feature = "underline-color")]
+                if let Some(color) = color {
+                    style.underline_color = Some(UnderlineColor::new(color));
+                }
+                #[cfg(not(feature = "underline-color"))]
+                let _ = color;
+            }
+            #[cfg(feature = "underline-color")]
+            59 => style.underline_color = None,
+            90..=97 => {
+                style.foreground = BasicColor::from_offset(code - 90)
+                    .map(BasicColor::bright)
+                    .map(Foreground::from);
+            }
+            100..=107 => {
+                style.background = BasicColor::from_offset(code - 100)
+                    .map(BasicColor::bright)
+                    .map(Background::from);
+            }
+            _ => apply_font(&mut style.font, code, sub_parameters.next()),
+        }
+    }
+}
+
+/// Applies a single parameter of an SGR sequence, which changes the font, to `font`
+fn apply_font(font: &mut Font, code: u16, sub_parameter: Option<&str>) {
+    match code {
+        1 => font.weight = Some(FontWeight::Bold),
+        2 => font.weight = Some(FontWeight::Faint),
+        3 => {
+            font.set_italic(true);
+        }
+        4 => font.underline = underline(sub_parameter),
+        5 => font.blinking = Some(Blinking::Slow),
+        6 => font.blinking = Some(Blinking::Fast),
+        7 => {
+            font.set_inverse(true);
+        }
+        8 => match sub_parameter.and_then(number) {
+            None | Some(0) => {
+                font.set_hidden(true);
+            }
+            // mintty extension
+            #[cfg(feature = "overstrike")]
+            Some(7) => {
+                font.set_overstrike(true);
+            }
+            Some(_) => {}
+        },
+        9 => {
+            font.set_strikethrough(true);
+        }
+        10 => font.family = None,
+        11..=19 => {
+            font.family = u8::try_from(code - 11)
+                .ok()
+                .and_then(AlternativeFontFamily::new)
+                .map(FontFamily::Alternative);
+        }
+        20 => font.family = Some(FontFamily::Fraktur),
+        21 => font.underline = Some(Underline::Double),
+        22 => font.weight = None,
+        23 => {
+            font.set_italic(false);
+
+            if font.family == Some(FontFamily::Fraktur) {
+                font.family = None;
+            }
+        }
+        24 => font.underline = None,
+        25 => font.blinking = None,
+        27 => {
+            font.set_inverse(false);
+        }
+        28 => {
+            font.set_hidden(false);
+            #[cfg(feature = "overstrike")]
+            font.set_overstrike(false);
+        }
+        29 => {
+            font.set_strikethrough(false);
+        }
+        53 => {
+            font.set_overline(true);
+        }
+        55 => {
+            font.set_overline(false);
+        }
+        #[cfg(feature = "script")]
+        73 => font.script = Some(FontScript::Super),
+        #[cfg(feature = "script")]
+        74 => font.script = Some(FontScript::Sub),
+        #[cfg(feature = "script")]
+        75 => font.script = None,
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    /// Parses a sequence without any text and returns the resulting style
+    fn parse(input: &str) -> Style {
+        let mut parser = Parser::new(input);
+        assert_eq!(parser.next(), None, "{input:?} should not contain any text");
+
+        parser.style()
+    }
+
+    fn foreground(color: impl Into<Color>) -> Style {
+        Style::new().with_foreground(Foreground::new(color.into()))
+    }
+
+    fn background(color: impl Into<Color>) -> Style {
+        Style::new().with_background(Background::new(color.into()))
+    }
+
+    const fn with_font(font: Font) -> Style {
+        Style::new().with_font(font)
+    }
+
+    #[test]
+    fn spans() {
+        let red = foreground(BasicColor::Red);
+
+        let spans: Vec<_> = Parser::new("plain \x1b[31mred\x1b[39m plain").collect();
+
+        assert_eq!(spans, [
+            Styled::new("plain ", Style::new()),
+            Styled::new("red", red),
+            Styled::new(" plain", Style::new()),
+        ]);
+    }
+
+    #[test]
+    fn with_style() {
+        let red = foreground(BasicColor::Red);
+
+        let mut parser = Parser::with_style("text\x1b[1m", red);
+        assert_eq!(parser.next(), Some(Styled::new("text", red)));
+        assert_eq!(parser.remaining(), "\x1b[1m");
+        assert_eq!(parser.next(), None);
+        assert_eq!(
+            parser.style(),
+            red.with_font(Font::new().with_weight(FontWeight::Bold))
+        );
+    }
+
+    #[test]
+    fn basic_and_bright_colors() {
+        assert_eq!(parse("\x1b[30m"), foreground(BasicColor::Black));
+        assert_eq!(parse("\x1b[37m"), foreground(BasicColor::White));
+        assert_eq!(parse("\x1b[41m"), background(BasicColor::Red));
+        assert_eq!(parse("\x1b[92m"), foreground(BasicColor::Green.bright()));
+        assert_eq!(parse("\x1b[107m"), background(BasicColor::White.bright()));
+    }
+
+    #[test]
+    fn indexed_color() {
+        let expected = foreground(IndexedColor::new(208));
+
+        assert_eq!(parse("\x1b[38;5;208m"), expected);
+        assert_eq!(parse("\x1b[38:5:208m"), expected);
+        assert_eq!(parse("\x1b[48;5;0m"), background(IndexedColor::new(0)));
+        assert_eq!(parse("\x1b[48:5:0m"), background(IndexedColor::new(0)));
+    }
+
+    #[test]
+    fn truecolor() {
+        let expected = foreground(RgbColor::new(255, 128, 0));
+
+        assert_eq!(parse("\x1b[38;2;255;128;0m"), expected);
+        // with the color space identifier ISO 8613-6
+        assert_eq!(parse("\x1b[38:2::255:128:0m"), expected);
+        assert_eq!(parse("\x1b[38:2:0:255:128:0m"), expected);
+        // without the color space identifier
+        assert_eq!(parse("\x1b[38:2:255:128:0m"), expected);
+
+        assert_eq!(
+            parse("\x1b[48:2::1:2:3m"),
+            background(RgbColor::new(1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn other_color_spaces() {
+        assert_eq!(parse("\x1b[38;1m"), foreground(TransparentColor));
+        assert_eq!(parse("\x1b[38:1m"), foreground(TransparentColor));
+        assert_eq!(
+            parse("\x1b[38;3;1;2;3m"),
+            foreground(CmyColor::new(1, 2, 3))
+        );
+        assert_eq!(
+            parse("\x1b[38:3::1:2:3m"),
+            foreground(CmyColor::new(1, 2, 3))
+        );
+        assert_eq!(
+            parse("\x1b[48;4;1;2;3;4m"),
+            background(CmykColor::new(1, 2, 3, 4))
+        );
+        assert_eq!(
+            parse("\x1b[48:4::1:2:3:4m"),
+            background(CmykColor::new(1, 2, 3, 4))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rgba")]
+    fn rgba_color() {
+        assert_eq!(
+            parse("\x1b[38:6::1:2:3:4m"),
+            foreground(RgbaColor::new(1, 2, 3, 4))
+        );
+    }
+
+    #[test]
+    fn semicolon_color_consumes_parameters() {
+        // `1` and `3` are components of the color and must not be interpreted as bold and italic
+        assert_eq!(
+            parse("\x1b[38;2;1;3;4;9m"),
+            foreground(RgbColor::new(1, 3, 4)).with_font(Font::new().with_strikethrough())
+        );
+    }
+
+    #[test]
+    fn colon_color_does_not_consume_parameters() {
+        assert_eq!(
+            parse("\x1b[38:5:1;3m"),
+            foreground(IndexedColor::new(1)).with_font(Font::new().with_italic())
+        );
+    }
+
+    #[test]
+    fn underline_color() {
+        #[cfg(feature = "underline-color")]
+        let expected =
+            Style::new().with_underline_color(UnderlineColor::from(RgbColor::new(1, 2, 3)));
+        #[cfg(not(feature = "underline-color"))]
+        let expected = Style::new();
+
+        // if unsupported, the color is still parsed to skip over its parameters
+        assert_eq!(parse("\x1b[58;2;1;2;3m"), expected);
+        assert_eq!(parse("\x1b[58:2::1:2:3m"), expected);
+        assert_eq!(parse("\x1b[58;2;1;2;3;59m"), Style::new());
+    }
+
+    #[test]
+    fn font_attributes() {
+        assert_eq!(
+            parse("\x1b[1;3;5;7;8;9;53m"),
+            with_font(
+                Font::new()
+                    .with_weight(FontWeight::Bold)
+                    .with_italic()
+                    .with_blinking(Blinking::Slow)
+                    .with_inverse()
+                    .with_hidden()
+                    .with_strikethrough()
+                    .with_overline()
+            )
+        );
+        assert_eq!(
+            parse("\x1b[20m"),
+            with_font(Font::new().with_family(FontFamily::Fraktur))
+        );
+        assert_eq!(
+            parse("\x1b[11m"),
+            with_font(Font::new().with_family(FontFamily::Alternative(
+                AlternativeFontFamily::new(0).expect("valid variant")
+            )))
+        );
+    }
+
+    #[test]
+    fn underline_sub_parameters() {
+        let single = with_font(Font::new().with_underline(Underline::Single));
+        let double = with_font(Font::new().with_underline(Underline::Double));
+
+        assert_eq!(parse("\x1b[4m"), single);
+        assert_eq!(parse("\x1b[4:1m"), single);
+        assert_eq!(parse("\x1b[4:2m"), double);
+        assert_eq!(parse("\x1b[21m"), double);
+        assert_eq!(parse("\x1b[4m\x1b[4:0m"), Style::new());
+        // unknown variants fall back to a single underline
+        assert_eq!(parse("\x1b[4:9m"), single);
+        // sub-parameters are delimited by `:`, `2` is faint
+        assert_eq!(
+            parse("\x1b[4;2m"),
+            with_font(
+                Font::new()
+                    .with_underline(Underline::Single)
+                    .with_weight(FontWeight::Faint)
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "underline-variants")]
+    fn underline_variants() {
+        assert_eq!(
+            parse("\x1b[4:3m"),
+            with_font(Font::new().with_underline(Underline::Curly))
+        );
+        assert_eq!(
+            parse("\x1b[4:4m"),
+            with_font(Font::new().with_underline(Underline::Dotted))
+        );
+        assert_eq!(
+            parse("\x1b[4:5m"),
+            with_font(Font::new().with_underline(Underline::Dashed))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "overstrike")]
+    fn overstrike() {
+        assert_eq!(parse("\x1b[8:7m"), with_font(Font::new().with_overstrike()));
+        assert_eq!(parse("\x1b[8;8:7;28m"), Style::new());
+    }
+
+    #[test]
+    fn full_reset() {
+        let set = "\x1b[1;3;4;31;44;51m";
+
+        assert_eq!(parse(&alloc::format!("{set}\x1b[0m")), Style::new());
+        // an empty sequence or empty parameter is the same as `0`
+        assert_eq!(parse(&alloc::format!("{set}\x1b[m")), Style::new());
+        assert_eq!(parse("\x1b[1;;3m"), with_font(Font::new().with_italic()));
+
+        // the style of `with_style` is reset as well
+        let mut parser = Parser::with_style("\x1b[0m", foreground(BasicColor::Red));
+        assert_eq!(parser.next(), None);
+        assert_eq!(parser.style(), Style::new());
+    }
+
+    #[test]
+    fn attribute_reset() {
+        let style = parse("\x1b[1;3;4;5;7;8;9;53;31;44;51m\x1b[22;24;25;27;28;29;55;39;49;54m");
+
+        // italic is the only attribute that has not been reset
+        assert_eq!(style, with_font(Font::new().with_italic()));
+
+        // `23` resets both italic and Fraktur
+        assert_eq!(parse("\x1b[3;20;23m"), Style::new());
+        // `10` resets the font family, but not italic
+        assert_eq!(parse("\x1b[3;20;10m"), with_font(Font::new().with_italic()));
+    }
+
+    #[test]
+    fn malformed_colors_are_ignored() {
+        let red = foreground(BasicColor::Red);
+
+        // missing components
+        assert_eq!(parse("\x1b[31;38;5m"), red);
+        assert_eq!(parse("\x1b[31;38:2:1:2m"), red);
+        // too many components
+        assert_eq!(parse("\x1b[31;38:2::1:2:3:4:5m"), red);
+        // out of range components
+        assert_eq!(parse("\x1b[31;38;5;256m"), red);
+        assert_eq!(parse("\x1b[31;38:2::1:2:256m"), red);
+        // unknown color space
+        assert_eq!(parse("\x1b[31;38:9:1m"), red);
+        // the parameter after an unknown color space is interpreted on its own
+        assert_eq!(
+            parse("\x1b[31;38;9;1m"),
+            red.with_font(Font::new().with_weight(FontWeight::Bold))
+        );
+    }
+
+    #[test]
+    fn malformed_parameters_are_ignored() {
+        let bold = with_font(Font::new().with_weight(FontWeight::Bold));
+
+        assert_eq!(parse("\x1b[>2;1m"), bold);
+        assert_eq!(parse("\x1b[1;99999m"), bold);
+        assert_eq!(parse("\x1b[1;-1m"), bold);
+        // unknown parameters
+        assert_eq!(parse("\x1b[1;26;50;200m"), bold);
+    }
+
+    #[test]
+    fn other_sequences_are_removed() {
+        let text: Vec<_> = Parser::new(
+            "\x1b[2J\x1b[1;1Hcursor \x1b]8;;https://hash.ai\x07link\x1b]8;;\x1b\\ \x1b7single",
+        )
+        .map(|span| *span.value())
+        .collect();
+
+        assert_eq!(text, ["cursor ", "link", " ", "single"]);
+    }
+
+    #[test]
+    fn unterminated_sequences() {
+        let spans: Vec<_> = Parser::new("text\x1b[31").collect();
+        assert_eq!(spans, [Styled::new("text", Style::new())]);
+
+        let spans: Vec<_> = Parser::new("text\x1b]0;title").collect();
+        assert_eq!(spans, [Styled::new("text", Style::new())]);
+
+        let spans: Vec<_> = Parser::new("text\x1b").collect();
+        assert_eq!(spans, [Styled::new("text", Style::new())]);
+    }
+}
@@ -0,0 +1,449 @@
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{Background, BasicColor, Color, Foreground, IndexedColor, RgbColor, Style};
+
+/// Color capabilities of a terminal
+///
+/// Profiles are ordered by the amount of colors they are able to represent, a profile is able to
+/// represent every color of all lower profiles.
+///
+/// ## Support
+///
+/// There is no reliable way to query the capabilities of a terminal, [`Self::from_variables`] (and
+/// [`Self::detect`] if the `std` feature is enabled) use the environment variables set by most
+/// terminal emulators as a heuristic.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ColorProfile {
+    /// Colors are not supported
+    None,
+    /// The 16 [`BasicColor`] and [`BrightColor`] variants are supported
+    ///
+    /// [`BrightColor`]: crate::BrightColor
+    Basic,
+    /// The 256 [`IndexedColor`] variants are supported
+    Indexed,
+    /// Any 24-bit [`RgbColor`] is supported
+    TrueColor,
+}
+
+impl ColorProfile {
+    /// Determines the profile from the values of the `NO_COLOR`, `COLORTERM` and `TERM` environment
+    /// variables
+    ///
+    /// * colors are disabled if `NO_COLOR` is set and not empty, as specified by [no-color.org]
+    /// * `COLORTERM` is set to either `truecolor` or `24bit` by terminals supporting [`RgbColor`]
+    /// * `TERM` is either unset or `dumb` if the terminal does not support colors, terminals
+    ///   supporting [`IndexedColor`] usually use a name ending with `256color` (e.g.
+    ///   `xterm-256color`), while names ending with `-direct` support [`RgbColor`] (e.g.
+    ///   `xterm-direct`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::ColorProfile;
+    ///
+    /// assert_eq!(
+    ///     ColorProfile::from_variables(None, Some("truecolor"), Some("xterm-256color")),
+    ///     ColorProfile::TrueColor
+    /// );
+    /// assert_eq!(
+    ///     ColorProfile::from_variables(None, None, Some("xterm-256color")),
+    ///     ColorProfile::Indexed
+    /// );
+    /// assert_eq!(
+    ///     ColorProfile::from_variables(None, None, Some("xterm")),
+    ///     ColorProfile::Basic
+    /// );
+    /// assert_eq!(
+    ///     ColorProfile::from_variables(Some("1"), Some("truecolor"), Some("xterm")),
+    ///     ColorProfile::None
+    /// );
+    /// ```
+    ///
+    /// [no-color.org]: https://no-color.org/
+    #[must_use]
+    pub fn from_variables(
+        no_color: Option<&str>,
+        color_term: Option<&str>,
+        term: Option<&str>,
+    ) -> Self {
+        if no_color.map_or(false, |value| !value.is_empty()) {
+            return Self::None;
+        }
+
+        if matches!(color_term, Some("truecolor" | "24bit")) {
+            return Self::TrueColor;
+        }
+
+        match term {
+            None | Some("" | "dumb") => Self::None,
+            Some(term) if term.ends_with("-direct") => Self::TrueColor,
+            Some(term) if term.ends_with("256color") => Self::Indexed,
+            Some(_) => Self::Basic,
+        }
+    }
+
+    /// Determines the profile from the environment variables of the current process
+    ///
+    /// See [`Self::from_variables`] for the variables that are taken into account.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn detect() -> Self {
+        let variable = |name| std::env::var(name).ok();
+
+        Self::from_variables(
+            variable("NO_COLOR").as_deref(),
+            variable("COLORTERM").as_deref(),
+            variable("TERM").as_deref(),
+        )
+    }
+}
+
+/// Approximation of the perceived difference between two colors
+///
+/// Uses the "redmean" weighting of the euclidean distance, which is cheap to compute, while still
+/// being closer to human perception than the plain euclidean distance.
+fn distance(lhs: RgbColor, rhs: RgbColor) -> u32 {
+    let red_mean = (u32::from(lhs.red()) + u32::from(rhs.red())) / 2;
+    let difference = |lhs: u8, rhs: u8| u32::from(lhs.abs_diff(rhs)).pow(2);
+
+    (((512 + red_mean) * difference(lhs.red(), rhs.red())) >> 8)
+        + 4 * difference(lhs.green(), rhs.green())
+        + (((767 - red_mean) * difference(lhs.blue(), rhs.blue())) >> 8)
+}
+
+/// Finds the [`IndexedColor`] in `range` that is perceptually closest to `color`
+fn nearest(color: RgbColor, range: core::ops::RangeInclusive<u8>) -> IndexedColor {
+    range
+        .map(IndexedColor::new)
+        .min_by_key(|&candidate| distance(color, candidate.into()))
+        .unwrap_or(IndexedColor::new(0))
+}
+
+/// Converts the first 16 [`IndexedColor`] variants into their [`BasicColor`] and [`BrightColor`]
+/// counterparts
+///
+/// [`BrightColor`]: crate::BrightColor
+fn basic(color: IndexedColor) -> Option<Color> {
+    match color.index() {
+        index @ 0..=7 => BasicColor::from_offset(u16::from(index)).map(Color::Basic),
+        index @ 8..=15 => BasicColor::from_offset(u16::from(index - 8))
+            .map(BasicColor::bright)
+            .map(Color::Bright),
+        _ => None,
+    }
+}
+
+impl Color {
+    /// Converts the color into the perceptually closest color representable in `profile`
+    ///
+    /// Returns [`None`] if the terminal default color should be used instead, which is the case for
+    /// every color in [`ColorProfile::None`] and [`TransparentColor`] in every profile.
+    ///
+    /// * [`ColorProfile::TrueColor`]: colors without widespread support ([`CmyColor`],
+    ///   [`CmykColor`] and `RgbaColor`) are converted into [`RgbColor`]
+    /// * [`ColorProfile::Indexed`]: colors are converted to the closest color of the color cube or
+    ///   grayscale ramp (`16` - `255`), which (unlike the first 16 colors) are not customized by
+    ///   most terminal themes
+    /// * [`ColorProfile::Basic`]: colors are converted to the closest [`BasicColor`] or
+    ///   [`BrightColor`] using the default xterm palette
+    ///
+    /// Colors already representable in a profile are never changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Color, ColorProfile, IndexedColor, RgbColor};
+    ///
+    /// let orange = Color::from(RgbColor::new(255, 135, 0));
+    ///
+    /// assert_eq!(orange.downgrade(ColorProfile::TrueColor), Some(orange));
+    /// assert_eq!(
+    ///     orange.downgrade(ColorProfile::Indexed),
+    ///     Some(IndexedColor::new(208).into())
+    /// );
+    /// assert_eq!(
+    ///     orange.downgrade(ColorProfile::Basic),
+    ///     Some(BasicColor::Yellow.into())
+    /// );
+    /// assert_eq!(orange.downgrade(ColorProfile::None), None);
+    /// ```
+    ///
+    /// [`BrightColor`]: crate::BrightColor
+    /// [`TransparentColor`]: crate::TransparentColor
+    /// [`CmyColor`]: crate::CmyColor
+    /// [`CmykColor`]: crate::CmykColor
+    #[must_use]
+    pub fn downgrade(self, profile: ColorProfile) -> Option<Self> {
+        match (profile, self) {
+            (ColorProfile::None, _) | (_, Self::Transparent(_)) => None,
+            (_, Self::Basic(_) | Self::Bright(_))
+            | (ColorProfile::Indexed | ColorProfile::TrueColor, Self::Indexed(_))
+            | (ColorProfile::TrueColor, Self::Rgb(_)) => Some(self),
+            (ColorProfile::Basic, Self::Indexed(color)) => {
+                basic(color).or_else(|| basic(nearest(color.into(), 0..=15)))
+            }
+            (ColorProfile::TrueColor, _) => self.to_rgb().map(Self::Rgb),
+            (ColorProfile::Indexed, _) => self
+                .to_rgb()
+                .map(|color| Self::Indexed(nearest(color, 16..=255))),
+            (ColorProfile::Basic, _) => self
+                .to_rgb()
+                .and_then(|color| basic(nearest(color, 0..=15))),
+        }
+    }
+}
+
+impl Style {
+    /// Converts all colors of the style into colors representable in `profile`
+    ///
+    /// See [`Color::downgrade`] for details on how colors are converted, all other attributes of
+    /// the style are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{ColorProfile, Foreground, RgbColor, Style};
+    ///
+    /// let style = Style::new().with_foreground(Foreground::from(RgbColor::new(255, 135, 0)));
+    ///
+    /// assert_eq!(style.downgrade(ColorProfile::None), Style::new());
+    /// ```
+    #[must_use]
+    pub fn downgrade(mut self, profile: ColorProfile) -> Self {
+        self.foreground = self
+            .foreground
+            .and_then(|foreground| foreground.color().downgrade(profile))
+            .map(Foreground::new);
+        self.background = self
+            .background
+            .and_then(|background| background.color().downgrade(profile))
+            .map(Background::new);
+
+        #[cfg(feature = "underline-color")]
+        {
+            self.underline_color = self
+                .underline_color
+                .and_then(|underline_color| underline_color.color().downgrade(profile))
+                .map(UnderlineColor::new);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CmyColor, CmykColor, Font, FontWeight, TransparentColor};
+
+    const PROFILES: [ColorProfile; 4] = [
+        ColorProfile::None,
+        ColorProfile::Basic,
+        ColorProfile::Indexed,
+        ColorProfile::TrueColor,
+    ];
+
+    #[test]
+    fn from_variables() {
+        let cases = [
+            ((None, None, None), ColorProfile::None),
+            ((None, None, Some("")), ColorProfile::None),
+            ((None, None, Some("dumb")), ColorProfile::None),
+            ((None, None, Some("xterm")), ColorProfile::Basic),
+            ((None, None, Some("screen-256color")), ColorProfile::Indexed),
+            ((None, None, Some("xterm-direct")), ColorProfile::TrueColor),
+            (
+                (None, Some("24bit"), Some("xterm")),
+                ColorProfile::TrueColor,
+            ),
+            ((None, Some("truecolor"), None), ColorProfile::TrueColor),
+            // unknown values of `COLORTERM` fall back to `TERM`
+            ((None, Some("yes"), Some("xterm")), ColorProfile::Basic),
+            // `NO_COLOR` is ignored if empty
+            ((Some(""), None, Some("xterm")), ColorProfile::Basic),
+            ((Some("1"), None, Some("xterm-direct")), ColorProfile::None),
+        ];
+
+        for ((no_color, color_term, term), expected) in cases {
+            assert_eq!(
+                ColorProfile::from_variables(no_color, color_term, term),
+                expected,
+                "NO_COLOR={no_color:?} COLORTERM={color_term:?} TERM={term:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(PROFILES.windows(2).all(|window| window[0] < window[1]));
+    }
+
+    #[test]
+    fn none_and_transparent() {
+        for profile in PROFILES {
+            assert_eq!(Color::from(TransparentColor).downgrade(profile), None);
+        }
+
+        for color in [
+            Color::from(BasicColor::Red),
+            Color::from(IndexedColor::new(208)),
+            Color::from(RgbColor::new(1, 2, 3)),
+        ] {
+            assert_eq!(color.downgrade(ColorProfile::None), None);
+        }
+    }
+
+    #[test]
+    fn representable_colors_are_unchanged() {
+        let red = Color::from(BasicColor::Red);
+        let bright_red = Color::from(BasicColor::Red.bright());
+        let indexed = Color::from(IndexedColor::new(208));
+        let rgb = Color::from(RgbColor::new(1, 2, 3));
+
+        for profile in [
+            ColorProfile::Basic,
+            ColorProfile::Indexed,
+            ColorProfile::TrueColor,
+        ] {
+            assert_eq!(red.downgrade(profile), Some(red));
+            assert_eq!(bright_red.downgrade(profile), Some(bright_red));
+        }
+
+        assert_eq!(indexed.downgrade(ColorProfile::Indexed), Some(indexed));
+        assert_eq!(indexed.downgrade(ColorProfile::TrueColor), Some(indexed));
+        assert_eq!(rgb.downgrade(ColorProfile::TrueColor), Some(rgb));
+    }
+
+    #[test]
+    fn true_color() {
+        assert_eq!(
+            Color::from(CmyColor::new(0, 255, 255)).downgrade(ColorProfile::TrueColor),
+            Some(RgbColor::new(255, 0, 0).into())
+        );
+        assert_eq!(
+            Color::from(CmykColor::new(0, 255, 255, 51)).downgrade(ColorProfile::TrueColor),
+            Some(RgbColor::new(204, 0, 0).into())
+        );
+    }
+
+    #[test]
+    fn indexed() {
+        let cases = [
+            // exact matches in the color cube and grayscale ramp
+            (RgbColor::new(255, 0, 0), 196),
+            (RgbColor::new(0, 0, 0), 16),
+            (RgbColor::new(255, 255, 255), 231),
+            (RgbColor::new(128, 128, 128), 244),
+            // nearest colors
+            (RgbColor::new(250, 130, 10), 208),
+            (RgbColor::new(16, 17, 19), 233),
+        ];
+
+        for (color, expected) in cases {
+            assert_eq!(
+                Color::from(color).downgrade(ColorProfile::Indexed),
+                Some(IndexedColor::new(expected).into()),
+                "{color:?}"
+            );
+        }
+
+        // the first 16 colors are never chosen, as they are customized by most terminal themes
+        assert_eq!(
+            Color::from(RgbColor::new(205, 0, 0)).downgrade(ColorProfile::Indexed),
+            Some(IndexedColor::new(160).into())
+        );
+    }
+
+    #[test]
+    fn basic() {
+        let cases = [
+            (Color::from(IndexedColor::new(1)), BasicColor::Red.into()),
+            (
+                Color::from(IndexedColor::new(9)),
+                BasicColor::Red.bright().into(),
+            ),
+            (Color::from(IndexedColor::new(16)), BasicColor::Black.into()),
+            (
+                Color::from(IndexedColor::new(231)),
+                BasicColor::White.bright().into(),
+            ),
+            (
+                Color::from(IndexedColor::new(208)),
+                BasicColor::Yellow.into(),
+            ),
+            (
+                Color::from(RgbColor::new(0, 0, 240)),
+                BasicColor::Blue.into(),
+            ),
+            (
+                Color::from(RgbColor::new(0, 250, 250)),
+                BasicColor::Cyan.bright().into(),
+            ),
+            (
+                Color::from(CmyColor::new(0, 255, 255)),
+                BasicColor::Red.bright().into(),
+            ),
+        ];
+
+        for (color, expected) in cases {
+            assert_eq!(
+                color.downgrade(ColorProfile::Basic),
+                Some(expected),
+                "{color:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn idempotent() {
+        let colors = [
+            Color::from(RgbColor::new(12, 34, 56)),
+            Color::from(CmykColor::new(10, 20, 30, 40)),
+            Color::from(IndexedColor::new(100)),
+        ];
+
+        for color in colors {
+            for profile in PROFILES {
+                let downgraded = color.downgrade(profile);
+
+                assert_eq!(
+                    downgraded.and_then(|color| color.downgrade(profile)),
+                    downgraded,
+                    "{color:?} in {profile:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn style() {
+        let font = Font::new().with_weight(FontWeight::Bold);
+        let style = Style::new()
+            .with_font(font)
+            .with_foreground(Foreground::from(RgbColor::new(255, 0, 0)))
+            .with_background(Background::from(TransparentColor));
+
+        assert_eq!(
+            style.downgrade(ColorProfile::Indexed),
+            Style::new()
+                .with_font(font)
+                .with_foreground(Foreground::from(IndexedColor::new(196)))
+        );
+        assert_eq!(
+            style.downgrade(ColorProfile::None),
+            Style::new().with_font(font)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "underline-color")]
+    fn underline_color() {
+        let style = Style::new().with_underline_color(UnderlineColor::from(RgbColor::new(0, 0, 0)));
+
+        assert_eq!(
+            style.downgrade(ColorProfile::Basic),
+            Style::new().with_underline_color(UnderlineColor::from(BasicColor::Black))
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use core::fmt::{self, Display, Write};
+
+/// Writes the parameters of a single **S**elect **G**raphic **R**endition sequence.
+///
+/// The control sequence introducer (`ESC[`) is only written once the first parameter is pushed,
+/// parameters are separated by `;`. If no parameter has been pushed, [`Self::finish`] does not
+/// write anything, so that an empty style never results in an (empty) escape sequence, which
+/// would be interpreted as a full reset by terminals.
+pub(crate) struct Sequence<'a, W: ?Sized> {
+    writer: &'a mut W,
+    empty: bool,
+}
+
+impl<'a, W: Write + ?Sized> Sequence<'a, W> {
+    pub(crate) fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer,
+            empty: true,
+        }
+    }
+
+    pub(crate) fn push(&mut self, parameter: impl Display) -> fmt::Result {
+        if self.empty {
+            self.writer.write_str("\x1b[")?;
+            self.empty = false;
+        } else {
+            self.writer.write_char(';')?;
+        }
+
+        write!(self.writer, "{parameter}")
+    }
+
+    pub(crate) fn finish(self) -> fmt::Result {
+        if self.empty {
+            Ok(())
+        } else {
+            self.writer.write_char('m')
+        }
+    }
+}
@@ -0,0 +1,490 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Alignment, Display, Write};
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{Style, Styled};
+
+/// A contiguous part of a [`StyledString`], which ends at byte `end` of the text
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Span {
+    end: usize,
+    style: Style,
+}
+
+/// Text consisting of multiple spans, each with their own [`Style`]
+///
+/// Styles can be nested using [`Self::push_style`] and [`Self::pop_style`], nested styles inherit
+/// all attributes of the enclosing styles (see [`Style::merge`]), e.g. a bold span inside a red
+/// span is rendered bold and red.
+///
+/// When displayed, only the attributes that change between two adjacent spans are emitted and every
+/// attribute is reset at the end.
+///
+/// Formatting options are interpreted in terms of the display width of the text, ignoring escape
+/// sequences: the precision truncates the text to the given width and the width pads the text using
+/// the fill character and alignment (the padding is never styled).
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, Font, FontWeight, Foreground, Style, StyledString};
+///
+/// let red = Style::new().with_foreground(Foreground::from(BasicColor::Red));
+/// let bold = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+///
+/// let mut string = StyledString::new();
+/// string.push_str("error: ");
+/// string.push_style(red);
+/// string.push_str("file ");
+/// string.push_styled("main.rs", bold);
+/// string.push_str(" not found");
+/// string.pop_style();
+///
+/// assert_eq!(string.as_str(), "error: file main.rs not found");
+/// assert_eq!(
+///     string.to_string(),
+///     "error: \x1b[31mfile \x1b[1mmain.rs\x1b[22m not found\x1b[39m"
+/// );
+///
+/// // width and precision ignore escape sequences
+/// assert_eq!(format!("{string:.11}|"), "error: \x1b[31mfile\x1b[39m|");
+/// assert_eq!(format!("{:>8}|", StyledString::from("ok")), "      ok|");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StyledString {
+    text: String,
+    spans: Vec<Span>,
+    stack: Vec<Style>,
+}
+
+impl StyledString {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            text: String::new(),
+            spans: Vec::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// The style applied to text pushed using [`Self::push_str`]
+    #[must_use]
+    pub fn current_style(&self) -> Style {
+        self.stack.last().copied().unwrap_or_default()
+    }
+
+    /// Begins a nested style, which inherits all attributes of the current style
+    ///
+    /// All text pushed until the style is removed using [`Self::pop_style`] uses the nested style.
+    pub fn push_style(&mut self, style: Style) {
+        let style = self.current_style().merge(style);
+
+        self.stack.push(style);
+    }
+
+    /// Ends the innermost nested style and returns it (including inherited attributes)
+    pub fn pop_style(&mut self) -> Option<Style> {
+        self.stack.pop()
+    }
+
+    /// Appends text using the current style
+    pub fn push_str(&mut self, text: &str) {
+        let style = self.current_style();
+        self.push_span(text, style);
+    }
+
+    /// Appends text using a nested style, which inherits all attributes of the current style
+    pub fn push_styled(&mut self, text: &str, style: Style) {
+        let style = self.current_style().merge(style);
+        self.push_span(text, style);
+    }
+
+    fn push_span(&mut self, text: &str, style: Style) {
+        if text.is_empty() {
+            return;
+        }
+
+        self.text.push_str(text);
+        let end = self.text.len();
+
+        match self.spans.last_mut() {
+            Some(span) if span.style == style => span.end = end,
+            _ => self.spans.push(Span { end, style }),
+        }
+    }
+
+    /// The text without any styles applied
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns an iterator over all spans and the styles applied to them
+    ///
+    /// Adjacent spans always have different styles.
+    pub fn spans(&self) -> impl Iterator<Item = Styled<&str>> + '_ {
+        let mut start = 0;
+
+        self.spans.iter().map(move |span| {
+            let text = self.text.get(start..span.end).unwrap_or_default();
+            start = span.end;
+
+            Styled::new(text, span.style)
+        })
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// The display width of the text, as it would be displayed in a terminal
+    ///
+    /// Control characters have a width of `0`, while wide characters (like most CJK characters)
+    /// have a width of `2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Foreground, Style, StyledString};
+    ///
+    /// let mut string = StyledString::new();
+    /// string.push_styled(
+    ///     "日本",
+    ///     Style::new().with_foreground(Foreground::from(BasicColor::Red)),
+    /// );
+    ///
+    /// assert_eq!(string.width(), 4);
+    /// ```
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.text.chars().filter_map(UnicodeWidthChar::width).sum()
+    }
+
+    /// Returns the length in bytes and the display width of the longest prefix of the text that
+    /// is at most `width` wide
+    fn prefix(&self, width: usize) -> (usize, usize) {
+        let mut total = 0;
+
+        for (index, char) in self.text.char_indices() {
+            let char_width = char.width().unwrap_or(0);
+
+            if total + char_width > width {
+                return (index, total);
+            }
+
+            total += char_width;
+        }
+
+        (self.text.len(), total)
+    }
+
+    /// Shortens the text to at most `width` columns, keeping the styles of the remaining text
+    ///
+    /// Wide characters that would exceed `width` are removed entirely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::StyledString;
+    ///
+    /// let mut string = StyledString::from("日本語");
+    /// string.truncate(5);
+    ///
+    /// assert_eq!(string.as_str(), "日本");
+    /// ```
+    pub fn truncate(&mut self, width: usize) {
+        let (length, _) = self.prefix(width);
+
+        self.text.truncate(length);
+
+        let index = self.spans.partition_point(|span| span.end < length);
+        self.spans.truncate(index + 1);
+        if let Some(span) = self.spans.last_mut() {
+            span.end = length;
+        }
+        if length == 0 {
+            self.spans.clear();
+        }
+    }
+}
+
+impl Display for StyledString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (length, width) = f.precision().map_or_else(
+            || (self.text.len(), self.width()),
+            |precision| self.prefix(precision),
+        );
+
+        let padding = f.width().unwrap_or(0).saturating_sub(width);
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Left) | None => (0, padding),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+
+        let mut current = Style::new();
+        let mut start = 0;
+        for span in &self.spans {
+            let end = span.end.min(length);
+            if start >= end {
+                break;
+            }
+
+            current.write_transition_to(&span.style, f)?;
+            f.write_str(self.text.get(start..end).unwrap_or_default())?;
+
+            current = span.style;
+            start = end;
+        }
+        current.write_reset_to(f)?;
+
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&str> for StyledString {
+    fn from(value: &str) -> Self {
+        let mut string = Self::new();
+        string.push_str(value);
+        string
+    }
+}
+
+impl<T: Display> From<Styled<T>> for StyledString {
+    fn from(value: Styled<T>) -> Self {
+        let mut string = Self::new();
+        string.extend([value]);
+        string
+    }
+}
+
+/// Appends every span, the style of each span inherits the current style
+///
+/// This can be used together with [`Parser`] to convert text containing escape sequences into a
+/// [`StyledString`].
+///
+/// # Example
+///
+/// ```
+/// use antsi::{Parser, StyledString};
+///
+/// let string: StyledString = Parser::new("\x1b[1mbold\x1b[22m text").collect();
+///
+/// assert_eq!(string.as_str(), "bold text");
+/// assert_eq!(string.spans().count(), 2);
+/// ```
+///
+/// [`Parser`]: crate::Parser
+impl<T: Display> Extend<Styled<T>> for StyledString {
+    fn extend<I: IntoIterator<Item = Styled<T>>>(&mut self, iter: I) {
+        let mut buffer = String::new();
+
+        for span in iter {
+            buffer.clear();
+            // writing to a `String` only fails if the `Display` implementation fails
+            if write!(buffer, "{}", span.value()).is_ok() {
+                self.push_styled(&buffer, *span.style());
+            }
+        }
+    }
+}
+
+impl<T: Display> FromIterator<Styled<T>> for StyledString {
+    fn from_iter<I: IntoIterator<Item = Styled<T>>>(iter: I) -> Self {
+        let mut string = Self::new();
+        string.extend(iter);
+        string
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use super::*;
+    use crate::{Background, BasicColor, Font, FontWeight, Foreground, Parser};
+
+    fn red() -> Style {
+        Style::new().with_foreground(Foreground::from(BasicColor::Red))
+    }
+
+    const fn bold() -> Style {
+        Style::new().with_font(Font::new().with_weight(FontWeight::Bold))
+    }
+
+    #[test]
+    fn empty() {
+        let string = StyledString::new();
+
+        assert!(string.is_empty());
+        assert_eq!(string.spans().count(), 0);
+        assert_eq!(string.to_string(), "");
+        assert_eq!(format!("{string:3}|"), "   |");
+    }
+
+    #[test]
+    fn unstyled() {
+        let string = StyledString::from("plain");
+
+        // no escape sequences are emitted for unstyled text, not even a reset
+        assert_eq!(string.to_string(), "plain");
+    }
+
+    #[test]
+    fn adjacent_spans_are_merged() {
+        let mut string = StyledString::new();
+        string.push_styled("a", red());
+        string.push_styled("", bold());
+        string.push_styled("b", red());
+        string.push_style(red());
+        string.push_str("c");
+
+        assert_eq!(string.spans().collect::<Vec<_>>(), [Styled::new(
+            "abc",
+            red()
+        )]);
+        assert_eq!(string.to_string(), "\x1b[31mabc\x1b[39m");
+    }
+
+    #[test]
+    fn minimal_transitions() {
+        let blue = Style::new().with_background(Background::from(BasicColor::Blue));
+
+        let mut string = StyledString::new();
+        string.push_style(red());
+        string.push_str("a");
+        string.push_styled("b", bold());
+        string.push_style(blue);
+        string.push_str("c");
+        string.pop_style();
+        string.push_str("d");
+        string.pop_style();
+        string.push_str("e");
+
+        // only attributes that change between spans are emitted, there is no full reset in between
+        assert_eq!(
+            string.to_string(),
+            "\x1b[31ma\x1b[1mb\x1b[22;44mc\x1b[49md\x1b[39me"
+        );
+    }
+
+    #[test]
+    fn final_reset() {
+        let mut string = StyledString::new();
+        string.push_str("a");
+        string.push_styled("b", red().merge(bold()));
+
+        // every attribute still active at the end is reset
+        assert_eq!(string.to_string(), "a\x1b[1;31mb\x1b[22;39m");
+    }
+
+    #[test]
+    fn nested_styles() {
+        let mut string = StyledString::new();
+
+        string.push_style(red());
+        string.push_style(bold());
+        assert_eq!(string.current_style(), red().merge(bold()));
+        assert_eq!(string.pop_style(), Some(red().merge(bold())));
+        assert_eq!(string.current_style(), red());
+        assert_eq!(string.pop_style(), Some(red()));
+        assert_eq!(string.pop_style(), None);
+        assert_eq!(string.current_style(), Style::new());
+
+        // the innermost style takes precedence
+        let green = Style::new().with_foreground(Foreground::from(BasicColor::Green));
+        string.push_style(red());
+        string.push_styled("green", green);
+        assert_eq!(string.spans().collect::<Vec<_>>(), [Styled::new(
+            "green", green
+        )]);
+    }
+
+    #[test]
+    fn precision() {
+        let mut string = StyledString::new();
+        string.push_styled("ab", red());
+        string.push_styled("cd", bold());
+
+        assert_eq!(format!("{string:.0}"), "");
+        assert_eq!(format!("{string:.1}"), "\x1b[31ma\x1b[39m");
+        // the span that is cut off is never started
+        assert_eq!(format!("{string:.2}"), "\x1b[31mab\x1b[39m");
+        assert_eq!(format!("{string:.3}"), "\x1b[31mab\x1b[1;39mc\x1b[22m");
+        assert_eq!(format!("{string:.10}"), string.to_string());
+    }
+
+    #[test]
+    fn precision_wide_characters() {
+        let string = StyledString::from(Styled::new("日本", red()));
+
+        // wide characters are never split
+        assert_eq!(format!("{string:.3}|"), "\x1b[31m日\x1b[39m|");
+        assert_eq!(format!("{string:.1}|"), "|");
+    }
+
+    #[test]
+    fn padding() {
+        let string = StyledString::from(Styled::new("日本", red()));
+
+        assert_eq!(format!("{string:7}|"), "\x1b[31m日本\x1b[39m   |");
+        assert_eq!(format!("{string:>7}|"), "   \x1b[31m日本\x1b[39m|");
+        assert_eq!(format!("{string:-^7}|"), "-\x1b[31m日本\x1b[39m--|");
+        assert_eq!(format!("{string:*>5.2}|"), "***\x1b[31m日\x1b[39m|");
+        // the width is a minimum
+        assert_eq!(format!("{string:2}|"), "\x1b[31m日本\x1b[39m|");
+    }
+
+    #[test]
+    fn truncate() {
+        let mut string = StyledString::new();
+        string.push_styled("ab", red());
+        string.push_styled("cd", bold());
+        string.push_str("ef");
+
+        string.truncate(3);
+        assert_eq!(string.as_str(), "abc");
+        assert_eq!(string.spans().collect::<Vec<_>>(), [
+            Styled::new("ab", red()),
+            Styled::new("c", bold()),
+        ]);
+
+        string.truncate(2);
+        assert_eq!(string.spans().collect::<Vec<_>>(), [Styled::new(
+            "ab",
+            red()
+        )]);
+
+        string.truncate(0);
+        assert!(string.is_empty());
+        assert_eq!(string.spans().count(), 0);
+    }
+
+    #[test]
+    fn parser_round_trip() {
+        let mut string = StyledString::new();
+        string.push_str("error: ");
+        string.push_style(red());
+        string.push_str("file ");
+        string.push_styled("main.rs", bold());
+        string.pop_style();
+        string.push_str(" not found");
+
+        let rendered = string.to_string();
+        let parsed: StyledString = Parser::new(&rendered).collect();
+
+        assert_eq!(parsed, string);
+        assert_eq!(parsed.to_string(), rendered);
+    }
+}
@@ -0,0 +1,68 @@
+use core::fmt::{self, Display};
+
+use crate::Style;
+
+/// A value with a [`Style`] applied to it
+///
+/// When displayed, the SGR sequence of the style is written before the value, afterwards only the
+/// attributes that have been set by the style are reset. Formatting options (like width or
+/// precision) are passed through to the value.
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, Font, FontWeight, Foreground, Style, Styled};
+///
+/// let style = Style::new()
+///     .with_foreground(Foreground::from(BasicColor::Red))
+///     .with_font(Font::new().with_weight(FontWeight::Bold));
+///
+/// assert_eq!(
+///     Styled::new("error", style).to_string(),
+///     "\x1b[1;31merror\x1b[22;39m"
+/// );
+/// assert_eq!(Styled::new(42, Style::new()).to_string(), "42");
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct Styled<T> {
+    value: T,
+    style: Style,
+}
+
+impl<T> Styled<T> {
+    #[must_use]
+    pub const fn new(value: T, style: Style) -> Self {
+        Self { value, style }
+    }
+
+    #[must_use]
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    #[must_use]
+    pub const fn style(&self) -> &Style {
+        &self.style
+    }
+
+    pub fn style_mut(&mut self) -> &mut Style {
+        &mut self.style
+    }
+
+    #[must_use]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Display> Display for Styled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.style.write_to(f)?;
+        Display::fmt(&self.value, f)?;
+        self.style.write_reset_to(f)
+    }
+}
@@ -14,7 +14,11 @@ use error_stack::{Report, Result, ResultExt};
 use num_traits::ToPrimitive;
 
 use crate::{
-    array::ArrayAccess, object::ObjectAccess, skip::skip_tokens, tape::Tape, token::Token,
+    array::ArrayAccess,
+    object::ObjectAccess,
+    skip::{ensure_consumed, skip_tokens},
+    tape::Tape,
+    token::Token,
 };
 
 macro_rules! forward {
@@ -74,8 +78,12 @@ impl<'a, 'de> deer::Deserializer<'de> for &mut Deserializer<'a, 'de> {
             Token::Bytes(value) => visitor.visit_bytes(value),
             Token::BorrowedBytes(value) => visitor.visit_borrowed_bytes(value),
             Token::BytesBuf(value) => visitor.visit_bytes_buffer(value.to_vec()),
-            Token::Array { length } => visitor.visit_array(ArrayAccess::new(self, length)),
-            Token::Object { length } => visitor.visit_object(ObjectAccess::new(self, length)),
+            Token::Array { length } => ensure_consumed(self, &token, |de| {
+                visitor.visit_array(ArrayAccess::new(de, length))
+            }),
+            Token::Object { length } => ensure_consumed(self, &token, |de| {
+                visitor.visit_object(ObjectAccess::new(de, length))
+            }),
             Token::Null => visitor.visit_null(),
             _ => {
                 panic!("Deserializer did not expect {token}");
@@ -127,12 +135,14 @@ impl<'a, 'de> deer::Deserializer<'de> for &mut Deserializer<'a, 'de> {
         let token = self.next();
 
         match token {
-            Token::Array { length } => visitor
-                .visit_array(ArrayAccess::new(self, length))
-                .change_context(DeserializerError),
-            Token::Object { length } => visitor
-                .visit_object(ObjectAccess::new(self, length))
-                .change_context(DeserializerError),
+            Token::Array { length } => ensure_consumed(self, &token, |de| {
+                visitor.visit_array(ArrayAccess::new(de, length))
+            })
+            .change_context(DeserializerError),
+            Token::Object { length } => ensure_consumed(self, &token, |de| {
+                visitor.visit_object(ObjectAccess::new(de, length))
+            })
+            .change_context(DeserializerError),
             other => Err(Report::new(TypeError.into_error())
                 .attach(ExpectedType::new(visitor.expecting()))
                 .attach(ReceivedType::new(other.schema()))
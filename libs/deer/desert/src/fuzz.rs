@@ -0,0 +1,156 @@
+//! Property checks for [`Deserialize`] implementations, meant to be used together with the
+//! strategies in [`strategy`].
+//!
+//! [`strategy`]: crate::strategy
+use alloc::{format, vec::Vec};
+
+use deer::{error::ReportExt, Context, Deserialize, Value};
+use proptest::{prop_assert, prop_assert_eq, test_runner::TestCaseError};
+use serde_json::{to_value, Value as Json};
+
+use crate::{deserializer::Deserializer, error::ErrorVec, token::Token};
+
+/// Follows a single location segment, returns `None` if the segment does not exist in `value`.
+fn step<'a>(value: &'a Value, location: &Json) -> Option<&'a Value> {
+    let kind = location.get("type")?.as_str()?;
+    let segment = location.get("value")?;
+
+    match (kind, value) {
+        ("tuple" | "array", Value::Array(values)) => {
+            values.get(usize::try_from(segment.as_u64()?).ok()?)
+        }
+        ("field" | "entry", Value::Object(entries)) => {
+            let key = segment.as_str()?;
+
+            entries
+                .iter()
+                .find(|(name, _)| matches!(name, Value::String(name) if name == key))
+                .map(|(_, value)| value)
+        }
+        // variants of externally tagged enums are nested in an object, while other enums, like
+        // untagged ones, do not add a level of nesting
+        ("variant", Value::Object(entries)) => {
+            let key = segment.as_str()?;
+
+            Some(
+                entries
+                    .iter()
+                    .find(|(name, _)| matches!(name, Value::String(name) if name == key))
+                    .map_or(value, |(_, value)| value),
+            )
+        }
+        ("variant", value) => Some(value),
+        _ => None,
+    }
+}
+
+/// Checks that every location of the error points into the input.
+///
+/// The last segment is allowed to be absent, as errors like [`MissingError`] point to values that
+/// should have been present.
+///
+/// [`MissingError`]: deer::error::MissingError
+fn check_location(input: &Value, error: &Json) -> Result<(), TestCaseError> {
+    let Some(location) = error
+        .pointer("/properties/location")
+        .and_then(Json::as_array)
+    else {
+        return Ok(());
+    };
+
+    let mut value = input;
+
+    if let Some((_, path)) = location.split_last() {
+        for segment in path {
+            let Some(next) = step(value, segment) else {
+                return Err(TestCaseError::fail(format!(
+                    "location {location:?} does not point into the input, {segment} could not be \
+                     resolved"
+                )));
+            };
+
+            value = next;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the tokens can be deserialized into `T` and that every token has been consumed.
+///
+/// This is meant to be used with tokens generated from the [`Reflection`] of `T`, like
+/// [`tokens`].
+///
+/// # Errors
+///
+/// if the value could not be deserialized, or there are remaining tokens
+///
+/// [`Reflection`]: deer::Reflection
+/// [`tokens`]: crate::strategy::tokens
+pub fn prop_assert_tokens<'de, T>(tokens: &'de [Token]) -> Result<(), TestCaseError>
+where
+    T: Deserialize<'de>,
+{
+    let context = Context::new();
+    let mut de = Deserializer::new(tokens, &context);
+
+    if let Err(error) = T::deserialize(&mut de) {
+        return Err(TestCaseError::fail(format!(
+            "tokens {tokens:?} should deserialize: {error:?}"
+        )));
+    }
+
+    prop_assert_eq!(de.remaining(), 0, "{} remaining tokens", de.remaining());
+
+    Ok(())
+}
+
+/// Checks that deserializing arbitrary tokens into `T` is well-behaved.
+///
+/// Deserialization is allowed to fail, but:
+///
+/// * it must not panic
+/// * on success, every token must have been consumed
+/// * on failure, at least one error must be reported, every error must be exportable and every
+///   [`Location`] must point into the input
+///
+/// This is meant to be used with [`any_tokens`].
+///
+/// # Errors
+///
+/// if any of the above conditions is violated
+///
+/// [`Location`]: deer::error::Location
+/// [`any_tokens`]: crate::strategy::any_tokens
+pub fn prop_assert_tokens_total<'de, T>(tokens: &'de [Token]) -> Result<(), TestCaseError>
+where
+    T: Deserialize<'de>,
+{
+    let context = Context::new();
+
+    let input = Value::deserialize(&mut Deserializer::new(tokens, &context))
+        .map_err(|error| TestCaseError::reject(format!("tokens are not well-formed: {error:?}")))?;
+
+    let mut de = Deserializer::new(tokens, &context);
+
+    let Err(error) = T::deserialize(&mut de) else {
+        prop_assert_eq!(de.remaining(), 0, "{} remaining tokens", de.remaining());
+        return Ok(());
+    };
+
+    let exported = to_value(error.export())
+        .map_err(|error| TestCaseError::fail(format!("error should serialize: {error}")))?;
+
+    let errors = ErrorVec::from_value(&exported);
+    prop_assert!(errors.is_some(), "malformed error: {exported}");
+
+    let errors = exported.as_array().map_or(&[][..], Vec::as_slice);
+    prop_assert!(!errors.is_empty(), "deserialization failed without error");
+
+    for error in errors {
+        check_location(&input, error)
+            .map_err(|failure| TestCaseError::fail(format!("{failure}, in error {error}")))?;
+    }
+
+    Ok(())
+}
@@ -6,8 +6,12 @@ pub(crate) mod array;
 mod assert;
 mod deserializer;
 pub mod error;
+#[cfg(feature = "proptest")]
+pub mod fuzz;
 pub(crate) mod object;
 mod skip;
+#[cfg(feature = "proptest")]
+pub mod strategy;
 pub(crate) mod tape;
 mod token;
 
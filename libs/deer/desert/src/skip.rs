@@ -43,3 +43,30 @@ pub(crate) fn skip_tokens(deserializer: &mut Deserializer, start: &Token) {
         deserializer.tape_mut().bump_n(n);
     }
 }
+
+/// Calls `visit`, which should consume the array or object started by `start`, and ensures that
+/// all of its tokens have been consumed afterwards.
+///
+/// Visitors are allowed to return early (e.g. if they do not support arrays or objects), without
+/// calling `end()`, which would otherwise leave the remaining tokens on the tape.
+pub(crate) fn ensure_consumed<'a, 'de, T>(
+    deserializer: &mut Deserializer<'a, 'de>,
+    start: &Token,
+    visit: impl FnOnce(&mut Deserializer<'a, 'de>) -> T,
+) -> T {
+    let n = match start {
+        Token::Array { .. } => scan_object(&*deserializer, &Token::ArrayEnd),
+        Token::Object { .. } => scan_object(&*deserializer, &Token::ObjectEnd),
+        _ => 0,
+    };
+
+    let end = deserializer.remaining().saturating_sub(n);
+    let value = visit(deserializer);
+
+    let remaining = deserializer.remaining();
+    if remaining > end {
+        deserializer.tape_mut().bump_n(remaining - end);
+    }
+
+    value
+}
@@ -0,0 +1,426 @@
+//! [`proptest`] strategies, which generate values and token streams from the [`Reflection`] of a
+//! type.
+//!
+//! The [`Document`] of a type is interpreted as a JSON-Schema, only the subset of keywords used by
+//! the implementations in `deer` is understood (`type`, `enum`, `oneOf`, `not`, `minimum`,
+//! `maximum`, `minLength`, `maxLength`, `items`, `prefixItems`, `minItems`, `maxItems` and
+//! `properties`), unknown types generate arbitrary values.
+//!
+//! Integers are restricted to the range that is representable by [`Number`], values nested deeper
+//! than [`MAX_DEPTH`] are truncated to `null`, which ensures that recursive types terminate.
+//!
+//! [`Reflection`]: deer::Reflection
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+use deer::{Deserialize, Document, Number, Value};
+use proptest::{
+    collection::vec as vec_of,
+    prelude::*,
+    sample::select,
+    strategy::{BoxedStrategy, Just, Strategy, Union},
+};
+use serde_json::{Map, Value as Schema};
+
+use crate::token::Token;
+
+/// Maximum nesting of generated values.
+pub const MAX_DEPTH: u32 = 6;
+
+const MAX_LENGTH: usize = 16;
+const MAX_ITEMS: u32 = 8;
+
+/// Leaks the value, [`Token`] only supports `'static` references, this is fine in the context of
+/// tests, as the amount of leaked memory is bounded by the amount of test cases.
+fn leak<T: ?Sized>(value: Box<T>) -> &'static T {
+    Box::leak(value)
+}
+
+fn push_tokens(value: &Value, tokens: &mut Vec<Token>) {
+    match value {
+        Value::Null => tokens.push(Token::Null),
+        Value::Bool(value) => tokens.push(Token::Bool(*value)),
+        Value::Number(value) => tokens.push(Token::Number(value.clone())),
+        Value::String(value) => tokens.push(Token::String(leak(value.clone().into_boxed_str()))),
+        Value::Bytes(value) => tokens.push(Token::Bytes(leak(value.clone().into_boxed_slice()))),
+        Value::Array(values) => {
+            tokens.push(Token::Array {
+                length: Some(values.len()),
+            });
+
+            for value in values {
+                push_tokens(value, tokens);
+            }
+
+            tokens.push(Token::ArrayEnd);
+        }
+        Value::Object(entries) => {
+            tokens.push(Token::Object {
+                length: Some(entries.len()),
+            });
+
+            for (key, value) in entries {
+                push_tokens(key, tokens);
+                push_tokens(value, tokens);
+            }
+
+            tokens.push(Token::ObjectEnd);
+        }
+    }
+}
+
+/// Converts a [`Value`] into the equivalent token stream.
+///
+/// Strings and bytes are leaked, as [`Token`] only supports `'static` references.
+#[must_use]
+pub fn to_tokens(value: &Value) -> Vec<Token> {
+    let mut tokens = vec![];
+    push_tokens(value, &mut tokens);
+    tokens
+}
+
+fn json_to_value(value: &Schema) -> Value {
+    match value {
+        Schema::Null => Value::Null,
+        Schema::Bool(value) => Value::Bool(*value),
+        Schema::Number(number) => number
+            .as_i64()
+            .map(Number::from)
+            .or_else(|| number.as_u64().map(Number::from))
+            .or_else(|| number.as_f64().map(Number::from))
+            .map_or(Value::Null, Value::Number),
+        Schema::String(value) => Value::String(value.clone()),
+        Schema::Array(values) => Value::Array(values.iter().map(json_to_value).collect()),
+        Schema::Object(entries) => Value::Object(
+            entries
+                .iter()
+                .map(|(key, value)| (Value::String(key.clone()), json_to_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Clamps the bound of an integer schema to the range representable by [`Number`].
+fn integer_bound(value: Option<&Schema>, default: i128) -> i128 {
+    let Some(Schema::Number(number)) = value else {
+        return default;
+    };
+
+    // 128 bit integers are not representable by `i64` or `u64`, but are out of range anyway
+    let value = number
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| number.as_u64().map(i128::from))
+        .or_else(|| {
+            number
+                .as_f64()
+                .map(|value| if value < 0.0 { i128::MIN } else { i128::MAX })
+        });
+
+    value.map_or(default, |value| {
+        value.clamp(i128::from(i64::MIN), i128::from(u64::MAX))
+    })
+}
+
+fn length(schema: &Map<String, Schema>, min: &str, max: &str, default: usize) -> (usize, usize) {
+    let get = |key: &str| {
+        schema
+            .get(key)
+            .and_then(Schema::as_u64)
+            .and_then(|value| usize::try_from(value).ok())
+    };
+
+    let min = get(min).unwrap_or(0);
+    let max = get(max).unwrap_or_else(|| default.max(min));
+
+    (min, max.max(min))
+}
+
+fn any_number() -> BoxedStrategy<Value> {
+    prop_oneof![
+        any::<i64>().prop_map(Number::from),
+        any::<u64>().prop_map(Number::from),
+        (prop::num::f32::NORMAL | prop::num::f32::ZERO).prop_map(Number::from),
+    ]
+    .prop_map(Value::Number)
+    .boxed()
+}
+
+fn any_string(min: usize, max: usize) -> BoxedStrategy<String> {
+    vec_of(any::<char>(), min..=max)
+        .prop_map(|chars| chars.into_iter().collect())
+        .boxed()
+}
+
+/// Strategy for arbitrary values, which are not constrained by any schema.
+pub fn any_value() -> BoxedStrategy<Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        any_number(),
+        any_string(0, MAX_LENGTH).prop_map(Value::String),
+        vec_of(any::<u8>(), 0..=MAX_LENGTH).prop_map(Value::Bytes),
+    ];
+
+    leaf.prop_recursive(MAX_DEPTH, 64, MAX_ITEMS, |inner| {
+        prop_oneof![
+            vec_of(inner.clone(), 0..=MAX_ITEMS as usize).prop_map(Value::Array),
+            vec_of(
+                (any_string(0, MAX_LENGTH).prop_map(Value::String), inner),
+                0..=MAX_ITEMS as usize
+            )
+            .prop_map(Value::Object),
+        ]
+    })
+    .boxed()
+}
+
+/// Strategy for arbitrary token streams, which are always well-formed.
+///
+/// This is useful to check that a [`Deserialize`] implementation does not panic on unexpected
+/// input, see [`prop_assert_tokens_total`].
+///
+/// [`prop_assert_tokens_total`]: crate::fuzz::prop_assert_tokens_total
+pub fn any_tokens() -> BoxedStrategy<Vec<Token>> {
+    any_value().prop_map(|value| to_tokens(&value)).boxed()
+}
+
+struct Generator<'a> {
+    definitions: &'a Map<String, Schema>,
+}
+
+impl<'a> Generator<'a> {
+    fn resolve(&self, mut schema: &'a Schema) -> Option<&'a Map<String, Schema>> {
+        // the amount of indirections is bounded by the amount of definitions
+        for _ in 0..=self.definitions.len() {
+            let object = schema.as_object()?;
+
+            let Some(reference) = object.get("$ref").and_then(Schema::as_str) else {
+                return Some(object);
+            };
+
+            schema = self
+                .definitions
+                .get(reference.trim_start_matches("#/$defs/"))?;
+        }
+
+        None
+    }
+
+    fn integer(schema: &Map<String, Schema>) -> BoxedStrategy<Value> {
+        let min = integer_bound(schema.get("minimum"), i128::from(i64::MIN));
+        let max = integer_bound(schema.get("maximum"), i128::from(u64::MAX));
+
+        let excluded: Vec<Value> = schema
+            .get("not")
+            .and_then(|not| not.get("enum"))
+            .and_then(Schema::as_array)
+            .map(|values| values.iter().map(json_to_value).collect())
+            .unwrap_or_default();
+
+        (min..=max)
+            .prop_map(|value| {
+                let number = i64::try_from(value).map_or_else(
+                    |_| Number::from(u64::try_from(value).expect("should be clamped to u64")),
+                    Number::from,
+                );
+
+                Value::Number(number)
+            })
+            .prop_filter("value is excluded by the schema", move |value| {
+                !excluded.contains(value)
+            })
+            .boxed()
+    }
+
+    fn array(&self, schema: &Map<String, Schema>, depth: u32) -> BoxedStrategy<Value> {
+        if let Some(prefix) = schema.get("prefixItems").and_then(Schema::as_array) {
+            let items: Vec<_> = prefix
+                .iter()
+                .map(|item| self.strategy(item, depth + 1))
+                .collect();
+
+            return items.prop_map(Value::Array).boxed();
+        }
+
+        let (min, max) = length(schema, "minItems", "maxItems", MAX_ITEMS as usize);
+        let item = match schema.get("items") {
+            Some(item @ Schema::Object(_)) => self.strategy(item, depth + 1),
+            _ => any_value(),
+        };
+
+        vec_of(item, min..=max).prop_map(Value::Array).boxed()
+    }
+
+    fn one_of(&self, variants: &'a [Schema], depth: u32) -> BoxedStrategy<Value> {
+        let variants: Vec<_> = variants
+            .iter()
+            .flat_map(|variant| match variant {
+                Schema::Object(object)
+                    if !object.contains_key("$ref") && !object.contains_key("type") =>
+                {
+                    // externally tagged variant, in the form of `{ "Variant": <schema> }`
+                    object
+                        .iter()
+                        .map(|(name, schema)| {
+                            let name = Value::String(name.clone());
+
+                            self.strategy(schema, depth + 1)
+                                .prop_map(move |value| Value::Object(vec![(name.clone(), value)]))
+                                .boxed()
+                        })
+                        .collect()
+                }
+                Schema::Object(_) => vec![self.strategy(variant, depth)],
+                value => vec![Just(json_to_value(value)).boxed()],
+            })
+            .collect();
+
+        if variants.is_empty() {
+            return any_value();
+        }
+
+        Union::new(variants).boxed()
+    }
+
+    fn object(&self, schema: &Map<String, Schema>, depth: u32) -> BoxedStrategy<Value> {
+        let Some(properties) = schema.get("properties").and_then(Schema::as_object) else {
+            return vec_of(
+                (
+                    any_string(0, MAX_LENGTH).prop_map(Value::String),
+                    any_value(),
+                ),
+                0..=MAX_ITEMS as usize,
+            )
+            .prop_map(Value::Object)
+            .boxed();
+        };
+
+        let entries: Vec<_> = properties
+            .iter()
+            .map(|(key, value)| {
+                let key = Value::String(key.clone());
+
+                self.strategy(value, depth + 1)
+                    .prop_map(move |value| (key.clone(), value))
+            })
+            .collect();
+
+        // the order of fields should not matter
+        entries.prop_shuffle().prop_map(Value::Object).boxed()
+    }
+
+    fn strategy(&self, schema: &'a Schema, depth: u32) -> BoxedStrategy<Value> {
+        if depth > MAX_DEPTH {
+            return Just(Value::Null).boxed();
+        }
+
+        let Some(schema) = self.resolve(schema) else {
+            return any_value();
+        };
+
+        if let Some(values) = schema.get("enum").and_then(Schema::as_array) {
+            if !values.is_empty() {
+                return select(values.iter().map(json_to_value).collect::<Vec<_>>()).boxed();
+            }
+        }
+
+        if let Some(variants) = schema.get("oneOf").and_then(Schema::as_array) {
+            return self.one_of(variants, depth);
+        }
+
+        match schema.get("type").and_then(Schema::as_str) {
+            Some("null") => Just(Value::Null).boxed(),
+            Some("boolean") => any::<bool>().prop_map(Value::Bool).boxed(),
+            Some("integer") => Self::integer(schema),
+            Some("number") => (prop::num::f32::NORMAL | prop::num::f32::ZERO)
+                .prop_map(|value| Value::Number(Number::from(value)))
+                .boxed(),
+            Some("string") => {
+                let (min, max) = length(schema, "minLength", "maxLength", MAX_LENGTH);
+
+                any_string(min, max).prop_map(Value::String).boxed()
+            }
+            Some("bytes") => vec_of(any::<u8>(), 0..=MAX_LENGTH)
+                .prop_map(Value::Bytes)
+                .boxed(),
+            Some("array") => self.array(schema, depth),
+            Some("object") => self.object(schema, depth),
+            _ => any_value(),
+        }
+    }
+}
+
+/// Strategy for values, which conform to the schema of the [`Document`].
+///
+/// # Panics
+///
+/// if the document could not be serialized
+pub fn value_from_document(document: &Document) -> BoxedStrategy<Value> {
+    let document = serde_json::to_value(document).expect("document should be serializable");
+
+    let definitions = document
+        .get("$defs")
+        .and_then(Schema::as_object)
+        .cloned()
+        .unwrap_or_default();
+
+    let root = Schema::Object(
+        document
+            .as_object()
+            .map(|object| {
+                object
+                    .iter()
+                    .filter(|(key, _)| *key == "$ref")
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    );
+
+    Generator {
+        definitions: &definitions,
+    }
+    .strategy(&root, 0)
+}
+
+/// Strategy for values, which conform to the [`Reflection`] of `T`.
+///
+/// ```
+/// use deer::{value::IntoDeserializer, Context, Deserialize};
+/// use deer_desert::strategy::value;
+/// use proptest::prelude::*;
+///
+/// proptest!(|(value in value::<u8>())| {
+///     let context = Context::new();
+///
+///     prop_assert!(u8::deserialize(value.into_deserializer(&context)).is_ok());
+/// });
+/// ```
+///
+/// [`Reflection`]: deer::Reflection
+pub fn value<'de, T: Deserialize<'de>>() -> BoxedStrategy<Value> {
+    value_from_document(&T::reflection())
+}
+
+/// Strategy for token streams, which conform to the schema of the [`Document`].
+pub fn tokens_from_document(document: &Document) -> BoxedStrategy<Vec<Token>> {
+    value_from_document(document)
+        .prop_map(|value| to_tokens(&value))
+        .boxed()
+}
+
+/// Strategy for token streams, which conform to the [`Reflection`] of `T`.
+///
+/// ```
+/// use deer_desert::{fuzz::prop_assert_tokens, strategy::tokens};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(tokens in tokens::<(u8, bool)>())| {
+///     prop_assert_tokens::<(u8, bool)>(&tokens)?;
+/// });
+/// ```
+///
+/// [`Reflection`]: deer::Reflection
+pub fn tokens<'de, T: Deserialize<'de>>() -> BoxedStrategy<Vec<Token>> {
+    tokens_from_document(&T::reflection())
+}
@@ -1,6 +1,6 @@
 use deer::{
     error::{ArrayAccessError, ArrayLengthError, DeserializerError, Error, Variant},
-    Context, Deserialize, Deserializer as _,
+    Context, Deserialize, Deserializer as _, MaxCollectionLength,
 };
 use error_stack::{Report, Result, ResultExt};
 use justjson::parser::{PeekableTokenKind, Token};
@@ -16,6 +16,9 @@ pub(crate) struct ArrayAccess<'a, 'b, 'de: 'a> {
 
     dirty: bool,
     expected: usize,
+
+    limit: Option<MaxCollectionLength>,
+    exceeded: bool,
 }
 
 impl<'a, 'b, 'de: 'a> ArrayAccess<'a, 'b, 'de> {
@@ -24,10 +27,15 @@ impl<'a, 'b, 'de: 'a> ArrayAccess<'a, 'b, 'de> {
     ) -> Result<Self, DeserializerError> {
         deserializer.try_stack_push(&Token::Array)?;
 
+        let limit = deserializer.context().request_ref().copied();
+
         Ok(Self {
             deserializer,
             dirty: false,
             expected: 0,
+
+            limit,
+            exceeded: false,
         })
     }
 
@@ -52,6 +60,16 @@ impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, '_, 'de> {
     {
         let mut errors = ErrorAccumulator::new();
 
+        if self.exceeded {
+            // the remaining items are skipped in `.end()`
+            return None;
+        }
+
+        if self.dirty && self.deserializer.is_trailing_comma(b']') {
+            // a trailing comma is handled (and potentially reported) in `.end()`
+            return None;
+        }
+
         if self.dirty {
             // we parse in a way where every subsequent invocation (except the first one)
             // needs to parse the `,` that is the token, if that token is not present we will error
@@ -76,6 +94,17 @@ impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, '_, 'de> {
 
         self.expected += 1;
 
+        if let Some(limit) = self.limit {
+            if let Err(error) = limit.ensure(self.expected, None) {
+                self.exceeded = true;
+
+                let error = error.attach(Position::new(self.deserializer.offset()));
+                return Some(Err(errors
+                    .extend_existing(error)
+                    .change_context(ArrayAccessError)));
+            }
+        }
+
         let value = T::deserialize(&mut *self.deserializer);
 
         Some(value.change_context(ArrayAccessError))
@@ -88,14 +117,30 @@ impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, '_, 'de> {
     fn end(self) -> Result<(), ArrayAccessError> {
         self.deserializer.stack.pop();
 
+        let mut errors = ErrorAccumulator::new();
+
+        // a bound array access might have stopped before the trailing comma was consumed
+        if self.dirty {
+            if let Err(error) = self.deserializer.try_skip_trailing_comma(b']') {
+                errors.extend_one(error);
+            }
+        }
+
         let result = match self.deserializer.peek() {
             None => Err(Report::new(SyntaxError::UnexpectedEof.into_error())
                 .attach(Position::new(self.deserializer.offset()))),
             Some(PeekableTokenKind::ArrayEnd) => Ok(()),
+            // the limit has already been reported in `.next()`
+            Some(_) if self.exceeded => Ok(()),
             Some(_) => Err(ArrayLengthError::new(&self, self.expected)),
         };
 
-        skip_tokens(&mut self.deserializer.tokenizer, &Token::Array);
+        skip_tokens(&mut self.deserializer.lexer, &Token::Array);
+
+        let result = match result {
+            Ok(()) => errors.into_result(),
+            Err(error) => Err(errors.extend_existing(error)),
+        };
 
         result.change_context(ArrayAccessError)
     }
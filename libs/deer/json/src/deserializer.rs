@@ -2,26 +2,28 @@ use core::ops::Range;
 
 use deer::{
     error::{
-        DeserializerError, Error, ExpectedLength, ExpectedType, ObjectLengthError, ReceivedType,
-        TypeError, Variant,
+        DepthLimitError, DeserializerError, Error, ExpectedLength, ExpectedType, Limit,
+        ObjectLengthError, ReceivedType, TypeError, Variant,
     },
     schema::Document,
     value::NoneDeserializer,
-    Context, Deserialize, EnumVisitor, IdentifierVisitor, Number, OptionalVisitor, Reflection,
-    StructVisitor, Visitor,
+    CoerceNumbers, Context, Deserialize, EnumVisitor, IdentifierVisitor, MaxDepth, MaxStringLength,
+    Number, OptionalVisitor, Reflection, StructVisitor, Visitor,
 };
 use error_stack::{Report, Result, ResultExt};
 use justjson::{
-    parser::{PeekableTokenKind, Token, Tokenizer},
+    parser::{PeekableTokenKind, Token},
     AnyStr,
 };
 
 use crate::{
     array::ArrayAccess,
     error::{
-        convert_tokenizer_error, BytesUnsupportedError, ErrorAccumulator, Position,
-        RecursionLimitError, SyntaxError,
+        BytesEncodingError, BytesUnsupportedError, ErrorAccumulator, Position, RecursionLimitError,
+        Span, SyntaxError,
     },
+    lexer::Lexer,
+    mode::{BytesEncoding, Syntax},
     number::try_convert_number,
     object::ObjectAccess,
     skip::skip_tokens,
@@ -45,21 +47,35 @@ impl StackLimit {
 
 pub(crate) struct Stack {
     limit: usize,
+    max_depth: Option<MaxDepth>,
     depth: usize,
 }
 
 impl Stack {
-    const fn new(limit: usize) -> Self {
-        Self { limit, depth: 0 }
+    const fn new(limit: usize, max_depth: Option<MaxDepth>) -> Self {
+        Self {
+            limit,
+            max_depth,
+            depth: 0,
+        }
     }
 
     pub(crate) fn push(&mut self) -> Result<(), DeserializerError> {
         self.depth += 1;
 
         if self.depth >= self.limit {
-            Err(Report::new(RecursionLimitError.into_error()).change_context(DeserializerError))
-        } else {
-            Ok(())
+            return Err(
+                Report::new(RecursionLimitError.into_error()).change_context(DeserializerError)
+            );
+        }
+
+        match self.max_depth {
+            Some(max_depth) if self.depth > max_depth.limit() => {
+                Err(Report::new(DepthLimitError.into_error())
+                    .attach(Limit::new(max_depth.limit()))
+                    .change_context(DeserializerError))
+            }
+            _ => Ok(()),
         }
     }
 
@@ -69,7 +85,7 @@ impl Stack {
 }
 
 pub struct Deserializer<'a, 'de> {
-    pub(crate) tokenizer: Tokenizer<'de, false>,
+    pub(crate) lexer: Lexer<'de>,
 
     context: &'a Context,
     pub(crate) stack: Stack,
@@ -81,36 +97,41 @@ impl<'a, 'de> Deserializer<'a, 'de> {
             .request_ref::<StackLimit>()
             .map_or(usize::MAX, StackLimit::limit);
 
+        let max_depth = context.request_ref::<MaxDepth>().copied();
+
+        let syntax = context.request_ref::<Syntax>().copied().unwrap_or_default();
+
         Self {
-            tokenizer: Tokenizer::for_json_bytes(slice),
+            lexer: Lexer::new(slice, syntax),
             context,
-            stack: Stack::new(limit),
+            stack: Stack::new(limit, max_depth),
         }
     }
 
     fn next(&mut self) -> Result<Token<'de>, DeserializerError> {
-        let offset = self.tokenizer.offset();
-        let Some(token) = self.tokenizer.next() else {
+        let offset = self.lexer.offset();
+        let Some(token) = self.lexer.next() else {
             return Err(Report::new(SyntaxError::UnexpectedEof.into_error())
                 .attach(Position::new(offset))
                 .change_context(DeserializerError));
         };
 
-        token
-            .map_err(|error| convert_tokenizer_error(&error))
-            .change_context(DeserializerError)
+        token.change_context(DeserializerError)
     }
 
     fn next_value(&mut self) -> Result<ValueToken<'de>, DeserializerError> {
+        let offset = self.lexer.token_offset();
         let token = self.next()?;
 
-        ValueToken::try_from(token).change_context(DeserializerError)
+        ValueToken::try_from(token)
+            .map_err(|error| error.attach(Position::new(offset)))
+            .change_context(DeserializerError)
     }
 
     pub(crate) fn recover(&mut self, token: &ValueToken<'de>) {
         match token {
-            ValueToken::Object => skip_tokens(&mut self.tokenizer, &Token::Object),
-            ValueToken::Array => skip_tokens(&mut self.tokenizer, &Token::Array),
+            ValueToken::Object => skip_tokens(&mut self.lexer, &Token::Object),
+            ValueToken::Array => skip_tokens(&mut self.lexer, &Token::Array),
             _ => {}
         }
     }
@@ -118,14 +139,14 @@ impl<'a, 'de> Deserializer<'a, 'de> {
     pub(crate) fn skip(&mut self) -> Range<usize> {
         // `.next()` will only error out if a string or number is malformed
         // we can safely skip those as they do not affect how we skip
-        let start = self.tokenizer.offset();
-        let next = self.tokenizer.next();
+        let start = self.lexer.token_offset();
+        let next = self.lexer.next();
 
         if let Some(Ok(token)) = next {
-            skip_tokens(&mut self.tokenizer, &token);
+            skip_tokens(&mut self.lexer, &token);
         }
 
-        start..self.tokenizer.offset()
+        start..self.lexer.offset()
     }
 
     pub(crate) fn skip_if(&mut self, token: PeekableTokenKind) -> Option<Range<usize>> {
@@ -146,12 +167,37 @@ impl<'a, 'de> Deserializer<'a, 'de> {
         }
     }
 
+    /// Skips a comma, which is directly followed by `end` (either `]` or `}`).
+    ///
+    /// Will error out if a trailing comma has been skipped, but trailing commas are not enabled.
+    pub(crate) fn try_skip_trailing_comma(&mut self, end: u8) -> Result<(), Error> {
+        let Some(span) = self.lexer.trailing_comma(end) else {
+            return Ok(());
+        };
+
+        self.skip_if(PeekableTokenKind::Comma);
+
+        if self.lexer.syntax().trailing_commas() {
+            Ok(())
+        } else {
+            Err(Report::new(SyntaxError::TrailingComma.into_error()).attach(Span::from(span)))
+        }
+    }
+
+    pub(crate) fn is_trailing_comma(&self, end: u8) -> bool {
+        self.lexer.trailing_comma(end).is_some()
+    }
+
+    pub(crate) fn next_identifier(&mut self) -> Option<&'de str> {
+        self.lexer.next_identifier()
+    }
+
     pub(crate) fn peek(&mut self) -> Option<PeekableTokenKind> {
-        self.tokenizer.peek()
+        self.lexer.peek()
     }
 
     pub(crate) const fn offset(&self) -> usize {
-        self.tokenizer.offset()
+        self.lexer.offset()
     }
 
     pub(crate) fn try_stack_push(&mut self, token: &Token) -> Result<(), DeserializerError> {
@@ -159,7 +205,7 @@ impl<'a, 'de> Deserializer<'a, 'de> {
             // we can still recover, we pop us again from the stack as we stopped before and do not
             // commit. We still show the error, but we could continue, so we skip all tokens.
             self.stack.pop();
-            skip_tokens(&mut self.tokenizer, token);
+            skip_tokens(&mut self.lexer, token);
 
             return Err(error);
         }
@@ -167,6 +213,19 @@ impl<'a, 'de> Deserializer<'a, 'de> {
         Ok(())
     }
 
+    /// Checks the length of a string or byte sequence against [`MaxStringLength`], which starts at
+    /// `offset`.
+    fn ensure_string_length(&self, offset: usize, length: usize) -> Result<(), DeserializerError> {
+        let Some(limit) = self.context.request_ref::<MaxStringLength>() else {
+            return Ok(());
+        };
+
+        limit
+            .ensure(length)
+            .attach(Position::new(offset))
+            .change_context(DeserializerError)
+    }
+
     fn error_invalid_type(
         &mut self,
         received: &ValueToken<'de>,
@@ -190,15 +249,21 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
     where
         V: Visitor<'de>,
     {
+        let offset = self.offset();
         let token = self.next_value()?;
 
         match token {
             ValueToken::Null => visitor.visit_null(),
             ValueToken::Bool(value) => visitor.visit_bool(value),
-            ValueToken::String(value) => match value.decode_if_needed() {
-                AnyStr::Owned(value) => visitor.visit_string(value),
-                AnyStr::Borrowed(value) => visitor.visit_borrowed_str(value),
-            },
+            ValueToken::String(value) => {
+                let value = value.decode_if_needed();
+                self.ensure_string_length(offset, value.len())?;
+
+                match value {
+                    AnyStr::Owned(value) => visitor.visit_string(value),
+                    AnyStr::Borrowed(value) => visitor.visit_borrowed_str(value),
+                }
+            }
             ValueToken::Number(value) => {
                 let value = try_convert_number(&value).change_context(DeserializerError)?;
 
@@ -238,12 +303,26 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
     where
         V: Visitor<'de>,
     {
+        let offset = self.offset();
         let token = self.next_value()?;
 
         match token {
             ValueToken::Number(value) => visitor
                 .visit_number(try_convert_number(&value).change_context(DeserializerError)?)
                 .change_context(DeserializerError),
+            // the visitor is responsible for parsing the string, as only it knows which numbers
+            // are acceptable
+            ValueToken::String(value)
+                if self
+                    .context
+                    .request_ref::<CoerceNumbers>()
+                    .map_or(false, CoerceNumbers::coerce) =>
+            {
+                let value = value.decode_if_needed();
+                self.ensure_string_length(offset, value.len())?;
+
+                visitor.visit_str(&value).change_context(DeserializerError)
+            }
             token => Err(self.error_invalid_type(&token, Number::reflection())),
         }
     }
@@ -266,23 +345,56 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
     where
         V: Visitor<'de>,
     {
+        let offset = self.offset();
         let token = self.next_value()?;
 
         match token {
-            ValueToken::String(value) => match value.decode_if_needed() {
-                AnyStr::Owned(value) => visitor.visit_string(value),
-                AnyStr::Borrowed(value) => visitor.visit_borrowed_str(value),
+            ValueToken::String(value) => {
+                let value = value.decode_if_needed();
+                self.ensure_string_length(offset, value.len())?;
+
+                match value {
+                    AnyStr::Owned(value) => visitor.visit_string(value),
+                    AnyStr::Borrowed(value) => visitor.visit_borrowed_str(value),
+                }
+                .change_context(DeserializerError)
             }
-            .change_context(DeserializerError),
             token => Err(self.error_invalid_type(&token, str::document())),
         }
     }
 
-    fn deserialize_bytes<V>(self, _: V) -> Result<V::Value, DeserializerError>
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
     where
         V: Visitor<'de>,
     {
-        Err(Report::new(BytesUnsupportedError.into_error()).change_context(DeserializerError))
+        let Some(encoding) = self.context.request_ref::<BytesEncoding>().copied() else {
+            // skip the value, so that we're able to continue deserialization
+            let span = self.skip();
+
+            return Err(Report::new(BytesUnsupportedError.into_error())
+                .attach(Span::new(span))
+                .change_context(DeserializerError));
+        };
+
+        let offset = self.lexer.token_offset();
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::String(value) => {
+                let bytes = encoding.decode(&value.decode_if_needed()).ok_or_else(|| {
+                    Report::new(BytesEncodingError(encoding).into_error())
+                        .attach(Position::new(offset))
+                        .change_context(DeserializerError)
+                })?;
+
+                self.ensure_string_length(offset, bytes.len())?;
+
+                visitor
+                    .visit_bytes_buffer(bytes)
+                    .change_context(DeserializerError)
+            }
+            token => Err(self.error_invalid_type(&token, <[u8]>::document())),
+        }
     }
 
     fn deserialize_bytes_buffer<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
@@ -324,14 +436,14 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
     where
         V: OptionalVisitor<'de>,
     {
-        let offset = self.tokenizer.offset();
-        match self.tokenizer.peek() {
+        let offset = self.lexer.offset();
+        match self.lexer.peek() {
             None => Err(Report::new(SyntaxError::UnexpectedEof.into_error())
                 .attach(Position::new(offset))
                 .change_context(DeserializerError)),
             Some(PeekableTokenKind::Null) => {
                 // we know the value will be `null`, therefore we can just discard the next token
-                let _ = self.tokenizer.next();
+                let _ = self.lexer.next();
 
                 visitor.visit_null().change_context(DeserializerError)
             }
@@ -400,6 +512,15 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
         };
 
         if is_map {
+            if let Err(error) = self.try_skip_trailing_comma(b'}') {
+                let error = error.change_context(DeserializerError);
+
+                match &mut value {
+                    Err(value) => value.extend_one(error),
+                    value => *value = Err(error),
+                }
+            }
+
             if self.peek() == Some(PeekableTokenKind::ObjectEnd) {
                 // we can safely continue
                 // we know this won't error because parsing of `ObjectEnd` will never fail
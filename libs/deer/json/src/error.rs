@@ -1,6 +1,6 @@
 use core::{
     fmt::{Display, Formatter},
-    ops::Range,
+    ops::{Range, RangeInclusive},
 };
 
 #[cfg(not(feature = "arbitrary-precision"))]
@@ -12,6 +12,8 @@ use deer::{
 use error_stack::Report;
 use justjson::ErrorKind;
 
+use crate::mode::BytesEncoding;
+
 const NAMESPACE: Namespace = Namespace::new("deer-json");
 
 #[derive(Debug)]
@@ -49,7 +51,7 @@ impl Display for BytesUnsupportedError {
 }
 
 impl Variant for BytesUnsupportedError {
-    type Properties = (Location,);
+    type Properties = (Location, Span);
 
     const ID: Id = id!["bytes"];
     const NAMESPACE: Namespace = NAMESPACE;
@@ -57,9 +59,49 @@ impl Variant for BytesUnsupportedError {
     fn message(
         &self,
         fmt: &mut Formatter,
-        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
     ) -> core::fmt::Result {
-        fmt.write_str("deer-json does not support deserialization of bytes")
+        fmt.write_str(
+            "deer-json does not support deserialization of bytes, unless a `BytesEncoding` is \
+             specified",
+        )?;
+
+        if let Some(Range { start, end }) = properties.1 {
+            fmt.write_fmt(format_args!(" at {start}..{end}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct BytesEncodingError(pub(crate) BytesEncoding);
+
+impl Display for BytesEncodingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            BytesEncoding::Base64 => f.write_str("invalid base64 encoded bytes"),
+            BytesEncoding::Hex => f.write_str("invalid hex encoded bytes"),
+        }
+    }
+}
+
+impl Variant for BytesEncodingError {
+    type Properties = (Location, Position);
+
+    const ID: Id = id!["bytes", "encoding"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        if let Some(position) = properties.1 {
+            fmt.write_fmt(format_args!("{self} at {position}"))
+        } else {
+            Display::fmt(self, fmt)
+        }
     }
 }
 
@@ -137,6 +179,14 @@ impl Span {
     }
 }
 
+impl From<RangeInclusive<usize>> for Span {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        let (start, end) = range.into_inner();
+
+        Self::new(start..end.saturating_add(1))
+    }
+}
+
 impl ErrorProperty for Span {
     type Value<'a> = Option<&'a Range<usize>> where Self: 'a ;
 
@@ -164,6 +214,7 @@ pub(crate) enum SyntaxError {
     ObjectKeyMustBeString,
     InvalidHexadecimal,
     InvalidEscape,
+    TrailingComma,
     // TODO: trailing non whitespace?
     UnclosedString,
 }
@@ -190,6 +241,7 @@ impl Display for SyntaxError {
                 f.write_str("invalid hexadecimal in unicode escape sequence")
             }
             Self::InvalidEscape => f.write_str("invalid escape character"),
+            Self::TrailingComma => f.write_str("trailing comma (`,`) is not allowed"),
             Self::UnclosedString => f.write_str(r#"expected end of string (`"`)"#),
         }
     }
@@ -245,8 +297,12 @@ impl Variant for NativeError {
     }
 }
 
-pub(crate) fn convert_tokenizer_error(error: &justjson::Error) -> Report<deer::error::Error> {
-    let offset = error.offset();
+/// Converts an error of the tokenizer, `base` is the offset at which the tokenizer started.
+pub(crate) fn convert_tokenizer_error(
+    error: &justjson::Error,
+    base: usize,
+) -> Report<deer::error::Error> {
+    let offset = base + error.offset();
 
     let error = match error.kind() {
         ErrorKind::Utf8 => SyntaxError::InvalidUtf8Sequence.into_error(),
@@ -0,0 +1,165 @@
+use core::ops::RangeInclusive;
+
+use deer::error::Error;
+use error_stack::Report;
+use justjson::parser::{PeekableTokenKind, Token, Tokenizer};
+
+use crate::{error::convert_tokenizer_error, mode::Syntax};
+
+const fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+const fn is_identifier_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || byte == b'_' || byte == b'$'
+}
+
+const fn is_identifier_continue(byte: u8) -> bool {
+    is_identifier_start(byte) || byte.is_ascii_digit()
+}
+
+/// Wrapper around the [`Tokenizer`] of `justjson`, which is strict JSON only.
+///
+/// Syntax extensions that are not value tokens (comments, trailing commas and unquoted keys) are
+/// handled here, by scanning the source directly and restarting the tokenizer after the extension.
+/// As the tokenizer only knows about the slice it has been created with, all offsets are relative
+/// to `base`.
+pub(crate) struct Lexer<'de> {
+    source: &'de [u8],
+    base: usize,
+
+    tokenizer: Tokenizer<'de, false>,
+    syntax: Syntax,
+}
+
+impl<'de> Lexer<'de> {
+    pub(crate) fn new(source: &'de [u8], syntax: Syntax) -> Self {
+        Self {
+            source,
+            base: 0,
+            tokenizer: Tokenizer::for_json_bytes(source),
+            syntax,
+        }
+    }
+
+    pub(crate) const fn syntax(&self) -> Syntax {
+        self.syntax
+    }
+
+    pub(crate) const fn offset(&self) -> usize {
+        self.base + self.tokenizer.offset()
+    }
+
+    /// Returns the offset of the next token, skipping any whitespace or comments in front of it.
+    pub(crate) fn token_offset(&self) -> usize {
+        self.scan_trivia(self.offset())
+    }
+
+    fn seek(&mut self, offset: usize) {
+        self.base = offset;
+        self.tokenizer = Tokenizer::for_json_bytes(&self.source[offset..]);
+    }
+
+    /// Returns the offset of the next byte, which isn't whitespace or (if enabled) a comment.
+    fn scan_trivia(&self, mut offset: usize) -> usize {
+        loop {
+            while offset < self.source.len() && is_whitespace(self.source[offset]) {
+                offset += 1;
+            }
+
+            if !self.syntax.comments() {
+                return offset;
+            }
+
+            match self.source.get(offset..offset + 2) {
+                Some(b"//") => {
+                    offset = memchr::memchr(b'\n', &self.source[offset..])
+                        .map_or(self.source.len(), |index| offset + index + 1);
+                }
+                Some(b"/*") => {
+                    // an unclosed block comment swallows the rest of the input, which will lead to
+                    // an unexpected EOF error on the next token
+                    offset = memchr::memmem::find(&self.source[offset + 2..], b"*/")
+                        .map_or(self.source.len(), |index| offset + 2 + index + 2);
+                }
+                _ => return offset,
+            }
+        }
+    }
+
+    fn skip_comments(&mut self) {
+        if !self.syntax.comments() {
+            return;
+        }
+
+        let offset = self.offset();
+        let next = self.scan_trivia(offset);
+
+        // only restart the tokenizer if we actually skipped a comment, skipping whitespace is
+        // already done by the tokenizer itself
+        if self.source[offset..next]
+            .iter()
+            .any(|byte| !is_whitespace(*byte))
+        {
+            self.seek(next);
+        }
+    }
+
+    pub(crate) fn next(&mut self) -> Option<core::result::Result<Token<'de>, Report<Error>>> {
+        self.skip_comments();
+
+        let base = self.base;
+
+        self.tokenizer
+            .next()
+            .map(|token| token.map_err(|error| convert_tokenizer_error(&error, base)))
+    }
+
+    pub(crate) fn peek(&mut self) -> Option<PeekableTokenKind> {
+        self.skip_comments();
+
+        self.tokenizer.peek()
+    }
+
+    /// Returns the span of the next comma, if it is directly followed by `end`.
+    ///
+    /// This does not consume any tokens.
+    pub(crate) fn trailing_comma(&self, end: u8) -> Option<RangeInclusive<usize>> {
+        let comma = self.scan_trivia(self.offset());
+
+        if self.source.get(comma) != Some(&b',') {
+            return None;
+        }
+
+        let next = self.scan_trivia(comma + 1);
+
+        (self.source.get(next) == Some(&end)).then_some(comma..=comma)
+    }
+
+    /// Consumes an unquoted object key, if unquoted keys are enabled and one is present.
+    ///
+    /// Unquoted keys are restricted to ASCII identifiers (`[A-Za-z_$][A-Za-z0-9_$]*`).
+    pub(crate) fn next_identifier(&mut self) -> Option<&'de str> {
+        if !self.syntax.unquoted_keys() {
+            return None;
+        }
+
+        let start = self.scan_trivia(self.offset());
+
+        if !matches!(self.source.get(start), Some(byte) if is_identifier_start(*byte)) {
+            return None;
+        }
+
+        let end = self.source[start..]
+            .iter()
+            .position(|byte| !is_identifier_continue(*byte))
+            .map_or(self.source.len(), |length| start + length);
+
+        let identifier = core::str::from_utf8(&self.source[start..end])
+            .expect("identifiers should only consist of ASCII characters");
+
+        self.seek(end);
+
+        Some(identifier)
+    }
+}
@@ -5,6 +5,8 @@
 mod array;
 mod deserializer;
 mod error;
+mod lexer;
+mod mode;
 mod number;
 mod object;
 mod skip;
@@ -15,7 +17,10 @@ extern crate alloc;
 use deer::{error::DeserializeError, Context, Deserialize};
 use error_stack::Result;
 
-pub use crate::deserializer::{Deserializer, StackLimit};
+pub use crate::{
+    deserializer::{Deserializer, StackLimit},
+    mode::{BytesEncoding, Syntax},
+};
 
 pub fn from_slice<'de, T>(slice: &'de [u8], context: &Context) -> Result<T, DeserializeError>
 where
@@ -0,0 +1,112 @@
+use alloc::vec::Vec;
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+/// Syntax extensions the [`Deserializer`] accepts on top of strict JSON.
+///
+/// The configuration is read from the [`Context`], in the same way [`StackLimit`] is, if no
+/// configuration is present [`Syntax::strict`] is used.
+///
+/// ```
+/// use deer::Context;
+/// use deer_json::Syntax;
+///
+/// let mut context = Context::new();
+/// context.insert(Syntax::json5_lite());
+/// ```
+///
+/// [`Deserializer`]: crate::Deserializer
+/// [`Context`]: deer::Context
+/// [`StackLimit`]: crate::StackLimit
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Syntax {
+    comments: bool,
+    trailing_commas: bool,
+    unquoted_keys: bool,
+}
+
+impl Syntax {
+    /// Strict JSON as specified in [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259)
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self {
+            comments: false,
+            trailing_commas: false,
+            unquoted_keys: false,
+        }
+    }
+
+    /// A subset of [JSON5](https://json5.org/), which is commonly used for user-authored
+    /// configuration files.
+    ///
+    /// This enables line (`//`) and block (`/* */`) comments, trailing commas in arrays and objects
+    /// and unquoted object keys (e.g. `{ key: 1 }`). Unlike JSON5, unquoted keys are restricted to
+    /// ASCII identifiers (`[A-Za-z_$][A-Za-z0-9_$]*`).
+    #[must_use]
+    pub const fn json5_lite() -> Self {
+        Self {
+            comments: true,
+            trailing_commas: true,
+            unquoted_keys: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_comments(mut self, enabled: bool) -> Self {
+        self.comments = enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_trailing_commas(mut self, enabled: bool) -> Self {
+        self.trailing_commas = enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_unquoted_keys(mut self, enabled: bool) -> Self {
+        self.unquoted_keys = enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn comments(&self) -> bool {
+        self.comments
+    }
+
+    #[must_use]
+    pub const fn trailing_commas(&self) -> bool {
+        self.trailing_commas
+    }
+
+    #[must_use]
+    pub const fn unquoted_keys(&self) -> bool {
+        self.unquoted_keys
+    }
+}
+
+/// Encoding used to represent bytes in JSON strings.
+///
+/// JSON has no native representation of bytes, if this is present in the [`Context`], calls to
+/// `deserialize_bytes` and `deserialize_bytes_buffer` will expect a string, which is decoded
+/// using the specified encoding, otherwise they will error out.
+///
+/// [`Context`]: deer::Context
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BytesEncoding {
+    /// Base64 using the standard alphabet with padding
+    /// ([RFC 4648 Section 4](https://www.rfc-editor.org/rfc/rfc4648#section-4))
+    Base64,
+    /// Case-insensitive hexadecimal (base16) encoding
+    Hex,
+}
+
+impl BytesEncoding {
+    pub(crate) fn decode(self, value: &str) -> Option<Vec<u8>> {
+        match self {
+            Self::Base64 => STANDARD.decode(value).ok(),
+            Self::Hex => hex::decode(value).ok(),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use deer::{
     error::{DeserializerError, Error, ObjectAccessError, ObjectLengthError, Variant},
-    Context, Deserializer as _, FieldVisitor,
+    value::BorrowedStrDeserializer,
+    Context, Deserializer as _, FieldVisitor, MaxCollectionLength,
 };
 use error_stack::{Report, Result, ResultExt};
 use justjson::parser::{PeekableTokenKind, Token};
@@ -16,6 +17,9 @@ pub(crate) struct ObjectAccess<'a, 'b, 'de: 'a> {
 
     dirty: bool,
     expected: usize,
+
+    limit: Option<MaxCollectionLength>,
+    exceeded: bool,
 }
 
 impl<'a, 'b, 'de: 'a> ObjectAccess<'a, 'b, 'de> {
@@ -24,11 +28,16 @@ impl<'a, 'b, 'de: 'a> ObjectAccess<'a, 'b, 'de> {
     ) -> Result<Self, DeserializerError> {
         deserializer.try_stack_push(&Token::Object)?;
 
+        let limit = deserializer.context().request_ref().copied();
+
         Ok(Self {
             deserializer,
 
             dirty: false,
             expected: 0,
+
+            limit,
+            exceeded: false,
         })
     }
 
@@ -62,6 +71,16 @@ impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
     {
         let mut errors = ErrorAccumulator::new();
 
+        if self.exceeded {
+            // the remaining entries are skipped in `.end()`
+            return Err(visitor);
+        }
+
+        if self.dirty && self.deserializer.is_trailing_comma(b'}') {
+            // a trailing comma is handled (and potentially reported) in `.end()`
+            return Err(visitor);
+        }
+
         if self.dirty {
             // we parse in a way where every subsequent invocation (except the first one)
             // needs to parse the `,` that is the token, if that token is not present we will error
@@ -86,8 +105,26 @@ impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
 
         self.expected += 1;
 
+        if let Some(limit) = self.limit {
+            if let Err(error) = limit.ensure(self.expected, None) {
+                self.exceeded = true;
+
+                let error = error.attach(Position::new(self.deserializer.offset()));
+                return Ok(Err(errors
+                    .extend_existing(error)
+                    .change_context(ObjectAccessError)));
+            }
+        }
+
+        // unquoted keys are only valid if enabled, in that case the key is not a string token
+        let identifier = if peek_key == Some(PeekableTokenKind::String) {
+            None
+        } else {
+            self.deserializer.next_identifier()
+        };
+
         // only strings are valid, therefore we skip both tokens and error out!
-        if peek_key != Some(PeekableTokenKind::String) {
+        if peek_key != Some(PeekableTokenKind::String) && identifier.is_none() {
             let span = self.deserializer.skip(); // skip key
 
             if let Err(skip) = self.try_skip_colon() {
@@ -104,12 +141,26 @@ impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
             return Ok(Err(error.change_context(ObjectAccessError)));
         }
 
-        let key = visitor.visit_key(&mut *self.deserializer);
+        let key = match identifier {
+            Some(identifier) => visitor.visit_key(BorrowedStrDeserializer::new(
+                identifier,
+                self.deserializer.context(),
+            )),
+            None => visitor.visit_key(&mut *self.deserializer),
+        };
 
         let result = match key {
-            Ok(key) => visitor
-                .visit_value(key, &mut *self.deserializer)
-                .change_context(ObjectAccessError),
+            Ok(key) => {
+                // key value are separated by `:`, if one forgets we will still error out but _try_
+                // to deserialize
+                if let Err(skip) = self.try_skip_colon() {
+                    errors.extend_one(skip);
+                }
+
+                visitor
+                    .visit_value(key, &mut *self.deserializer)
+                    .change_context(ObjectAccessError)
+            }
             Err(error) => {
                 let mut error = error.change_context(ObjectAccessError);
 
@@ -123,12 +174,6 @@ impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
             }
         };
 
-        // key value are separated by `:`, if one forgets we will still error out but _try_ to
-        // deserialize
-        if let Err(skip) = self.try_skip_colon() {
-            errors.extend_one(skip);
-        }
-
         // same as `(result, errors).into_result()`
         let result = match (
             result,
@@ -152,14 +197,30 @@ impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
     fn end(self) -> Result<(), ObjectAccessError> {
         self.deserializer.stack.pop();
 
+        let mut errors = ErrorAccumulator::new();
+
+        // a bound object access might have stopped before the trailing comma was consumed
+        if self.dirty {
+            if let Err(error) = self.deserializer.try_skip_trailing_comma(b'}') {
+                errors.extend_one(error);
+            }
+        }
+
         let result = match self.deserializer.peek() {
             None => Err(Report::new(SyntaxError::UnexpectedEof.into_error())
                 .attach(Position::new(self.deserializer.offset()))),
             Some(PeekableTokenKind::ObjectEnd) => Ok(()),
+            // the limit has already been reported in `.try_field()`
+            Some(_) if self.exceeded => Ok(()),
             Some(_) => Err(ObjectLengthError::new(&self, self.expected)),
         };
 
-        skip_tokens(&mut self.deserializer.tokenizer, &Token::Object);
+        skip_tokens(&mut self.deserializer.lexer, &Token::Object);
+
+        let result = match result {
+            Ok(()) => errors.into_result(),
+            Err(error) => Err(errors.extend_existing(error)),
+        };
 
         result.change_context(ObjectAccessError)
     }
@@ -1,11 +1,20 @@
-use justjson::parser::{Token, Tokenizer};
+use justjson::parser::Token;
 
-fn skip_nested(tokenizer: &mut Tokenizer<false>, stop: &Token) {
+use crate::lexer::Lexer;
+
+fn skip_nested(lexer: &mut Lexer, stop: &Token) {
     let mut objects: usize = 0;
     let mut arrays: usize = 0;
 
     loop {
-        let Some(token) = tokenizer.next() else {
+        // unquoted keys are not understood by the tokenizer, they never modify the nested status
+        // and are therefore consumed without any further checks. (`null`, `true` and `false` might
+        // also be consumed this way, but those do not modify the nested status either)
+        if lexer.next_identifier().is_some() {
+            continue;
+        }
+
+        let Some(token) = lexer.next() else {
             // we're at the end
             return;
         };
@@ -36,10 +45,10 @@ fn skip_nested(tokenizer: &mut Tokenizer<false>, stop: &Token) {
 
 /// Skips all tokens required for the start token, be aware that the token should already be
 /// consumed.
-pub(crate) fn skip_tokens(tokenizer: &mut Tokenizer<false>, start: &Token) {
+pub(crate) fn skip_tokens(lexer: &mut Lexer, start: &Token) {
     match start {
-        Token::Array => skip_nested(tokenizer, &Token::ArrayEnd),
-        Token::Object => skip_nested(tokenizer, &Token::ObjectEnd),
+        Token::Array => skip_nested(lexer, &Token::ArrayEnd),
+        Token::Object => skip_nested(lexer, &Token::ObjectEnd),
         _ => {}
     };
 }
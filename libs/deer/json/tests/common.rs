@@ -0,0 +1,51 @@
+// every test binary compiles the helpers, but not every test binary uses all of them
+#![allow(dead_code)]
+
+use deer::{
+    error::{DeserializeError, ReportExt},
+    value::Value,
+    Context, Number,
+};
+use deer_json::Syntax;
+use error_stack::Report;
+use serde_json::Value as JsonValue;
+
+pub(crate) fn context(syntax: Syntax) -> Context {
+    let mut context = Context::new();
+    context.insert(syntax);
+
+    context
+}
+
+pub(crate) fn number(value: u8) -> Value {
+    Value::Number(Number::from(value))
+}
+
+pub(crate) fn string(value: &str) -> Value {
+    Value::String(value.to_owned())
+}
+
+/// Exports the errors of a report and returns the `id` and `properties` of every error.
+///
+/// The `location` property and properties which are not present are omitted, to only compare the
+/// position in the input.
+pub(crate) fn errors(report: Report<DeserializeError>) -> Vec<(JsonValue, JsonValue)> {
+    let exported = serde_json::to_value(report.export()).expect("error should serialize");
+
+    exported
+        .as_array()
+        .expect("errors should be exported as array")
+        .iter()
+        .map(|error| {
+            let mut properties = error["properties"]
+                .as_object()
+                .expect("properties should be an object")
+                .clone();
+
+            properties.remove("location");
+            properties.retain(|_, value| !value.is_null());
+
+            (error["id"].clone(), JsonValue::Object(properties))
+        })
+        .collect()
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use deer::{
+    error::{DeserializeError, VisitorError},
+    Context, Deserialize, Deserializer, Document, Reflection, Visitor,
+};
+use deer_json::{from_str, BytesEncoding, Syntax};
+use error_stack::{Result, ResultExt};
+use serde_json::json;
+
+use crate::common::errors;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Bytes(Vec<u8>);
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Bytes;
+
+    fn expecting(&self) -> Document {
+        <[u8]>::document()
+    }
+
+    fn visit_bytes_buffer(self, value: Vec<u8>) -> Result<Self::Value, VisitorError> {
+        Ok(Bytes(value))
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    type Reflection = [u8];
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_bytes_buffer(BytesVisitor)
+            .change_context(DeserializeError)
+    }
+}
+
+fn context(encoding: BytesEncoding) -> Context {
+    let mut context = Context::new();
+    context.insert(encoding);
+
+    context
+}
+
+#[test]
+fn base64() {
+    let bytes = from_str::<Bytes>(r#""3q2+7w==""#, &context(BytesEncoding::Base64))
+        .expect("should deserialize");
+
+    assert_eq!(bytes, Bytes(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+}
+
+#[test]
+fn base64_escaped() {
+    // `\/` is a valid escape in JSON and needs to be decoded before the bytes are decoded
+    let bytes = from_str::<Bytes>(r#""\/w==""#, &context(BytesEncoding::Base64))
+        .expect("should deserialize");
+
+    assert_eq!(bytes, Bytes(vec![0xFF]));
+}
+
+#[test]
+fn base64_invalid() {
+    let error = from_str::<Bytes>(r#"  "3q2+7w""#, &context(BytesEncoding::Base64))
+        .expect_err("padding is missing");
+
+    assert_eq!(errors(error), [(
+        json!(["bytes", "encoding"]),
+        json!({"position": 2})
+    )]);
+}
+
+#[test]
+fn hex() {
+    let bytes = from_str::<Bytes>(r#""DEADbeef""#, &context(BytesEncoding::Hex))
+        .expect("should deserialize");
+
+    assert_eq!(bytes, Bytes(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+}
+
+#[test]
+fn hex_invalid() {
+    let error = from_str::<Bytes>(r#"  "DEADbee""#, &context(BytesEncoding::Hex))
+        .expect_err("odd number of digits");
+
+    assert_eq!(errors(error), [(
+        json!(["bytes", "encoding"]),
+        json!({"position": 2})
+    )]);
+}
+
+#[test]
+fn unsupported() {
+    let error = from_str::<Bytes>(r#" "3q2+7w==" "#, &Context::new())
+        .expect_err("no encoding is configured");
+
+    assert_eq!(errors(error), [(
+        json!(["bytes"]),
+        json!({"span": {"start": 1, "end": 11}})
+    )]);
+}
+
+#[test]
+fn unsupported_skips_value() {
+    // the value needs to be skipped, even if it is not a string, so that deserialization is able
+    // to continue
+    let error = from_str::<(Bytes, u8)>(r#"[{"a": [1]}, 2]"#, &Context::new())
+        .expect_err("no encoding is configured");
+
+    assert_eq!(errors(error), [(
+        json!(["bytes"]),
+        json!({"span": {"start": 1, "end": 11}})
+    )]);
+}
+
+#[test]
+fn invalid_type() {
+    let error =
+        from_str::<Bytes>("12", &context(BytesEncoding::Hex)).expect_err("bytes are strings");
+
+    assert_eq!(errors(error)[0].0, json!(["type"]));
+}
+
+#[test]
+fn with_syntax() {
+    let mut context = context(BytesEncoding::Hex);
+    context.insert(Syntax::json5_lite());
+
+    let bytes = from_str::<(Bytes, Bytes)>(r#"[/* first */ "00", "ff", ]"#, &context)
+        .expect("should deserialize");
+
+    assert_eq!(bytes, (Bytes(vec![0x00]), Bytes(vec![0xFF])));
+}
@@ -0,0 +1,299 @@
+mod common;
+
+use deer::value::Value;
+use deer_json::{from_str, Syntax};
+use serde_json::json;
+
+use crate::common::{context, errors, number, string};
+
+#[test]
+fn strict_is_default() {
+    assert_eq!(Syntax::default(), Syntax::strict());
+
+    let syntax = Syntax::strict();
+    assert!(!syntax.comments());
+    assert!(!syntax.trailing_commas());
+    assert!(!syntax.unquoted_keys());
+}
+
+#[test]
+fn json5_lite_enables_all_extensions() {
+    let syntax = Syntax::json5_lite();
+    assert!(syntax.comments());
+    assert!(syntax.trailing_commas());
+    assert!(syntax.unquoted_keys());
+
+    assert_eq!(
+        syntax,
+        Syntax::strict()
+            .with_comments(true)
+            .with_trailing_commas(true)
+            .with_unquoted_keys(true)
+    );
+}
+
+#[test]
+fn strict_without_context() {
+    let value = from_str::<Value>(r#" { "a": [1, 2], "b": null } "#, &deer::Context::new())
+        .expect("should deserialize");
+
+    assert_eq!(
+        value,
+        Value::Object(vec![
+            (string("a"), Value::Array(vec![number(1), number(2)])),
+            (string("b"), Value::Null),
+        ])
+    );
+}
+
+#[test]
+fn line_comment() {
+    let context = context(Syntax::strict().with_comments(true));
+
+    let value = from_str::<Value>(
+        "// leading\n[1, // inline\n 2 // trailing\n]// end",
+        &context,
+    )
+    .expect("should deserialize");
+
+    assert_eq!(value, Value::Array(vec![number(1), number(2)]));
+}
+
+#[test]
+fn block_comment() {
+    let context = context(Syntax::strict().with_comments(true));
+
+    let value = from_str::<Value>(
+        r#"/* leading */ { /* key */ "a" /* colon */ : /* value */ 1 /* multi
+        line */ } /* end */"#,
+        &context,
+    )
+    .expect("should deserialize");
+
+    assert_eq!(value, Value::Object(vec![(string("a"), number(1))]));
+}
+
+#[test]
+fn comment_in_string_is_not_a_comment() {
+    let context = context(Syntax::strict().with_comments(true));
+
+    let value =
+        from_str::<Value>(r#""// /* not a comment */""#, &context).expect("should deserialize");
+
+    assert_eq!(value, string("// /* not a comment */"));
+}
+
+#[test]
+fn comment_strict() {
+    let error = from_str::<Value>("[1, // comment\n 2]", &context(Syntax::strict()))
+        .expect_err("comments should be rejected");
+
+    // deserialization continues after the comment, which leads to subsequent errors
+    assert_eq!(
+        errors(error)[0],
+        (json!(["syntax"]), json!({"position": 4}))
+    );
+}
+
+#[test]
+fn block_comment_unclosed() {
+    let context = context(Syntax::strict().with_comments(true));
+
+    let error =
+        from_str::<Value>("[1, /* unclosed", &context).expect_err("comment should be closed");
+
+    // the comment swallows the rest of the input
+    assert_eq!(errors(error), [(
+        json!(["syntax"]),
+        json!({"position": 15})
+    )]);
+}
+
+#[test]
+fn trailing_comma_array() {
+    let context = context(Syntax::strict().with_trailing_commas(true));
+
+    let value = from_str::<Value>("[1, 2, ]", &context).expect("should deserialize");
+
+    assert_eq!(value, Value::Array(vec![number(1), number(2)]));
+}
+
+#[test]
+fn trailing_comma_object() {
+    let context = context(Syntax::strict().with_trailing_commas(true));
+
+    let value = from_str::<Value>(r#"{"a": 1, "b": 2 ,}"#, &context).expect("should deserialize");
+
+    assert_eq!(
+        value,
+        Value::Object(vec![(string("a"), number(1)), (string("b"), number(2))])
+    );
+}
+
+#[test]
+fn trailing_comma_array_strict() {
+    let error = from_str::<Value>("[1, 2, ]", &context(Syntax::strict()))
+        .expect_err("trailing comma should be rejected");
+
+    assert_eq!(errors(error), [(
+        json!(["syntax"]),
+        json!({"span": {"start": 5, "end": 6}})
+    )]);
+}
+
+#[test]
+fn trailing_comma_object_strict() {
+    let error = from_str::<Value>(r#"{"a": 1, "b": 2 ,}"#, &context(Syntax::strict()))
+        .expect_err("trailing comma should be rejected");
+
+    assert_eq!(errors(error), [(
+        json!(["syntax"]),
+        json!({"span": {"start": 16, "end": 17}})
+    )]);
+}
+
+#[test]
+fn trailing_comma_empty_array() {
+    let context = context(Syntax::strict().with_trailing_commas(true));
+
+    // a trailing comma needs to follow an element
+    let error = from_str::<Value>("[,]", &context).expect_err("comma without element");
+
+    assert_eq!(errors(error), [(json!(["syntax"]), json!({"position": 1}))]);
+}
+
+#[test]
+fn trailing_comma_with_comments() {
+    let context = context(Syntax::json5_lite());
+
+    let value =
+        from_str::<Value>("[1, /* two */ 2, // end\n]", &context).expect("should deserialize");
+
+    assert_eq!(value, Value::Array(vec![number(1), number(2)]));
+}
+
+#[test]
+fn unquoted_keys() {
+    let context = context(Syntax::strict().with_unquoted_keys(true));
+
+    let value = from_str::<Value>(r#"{ a: 1, _b$2: 2, "c": 3, $: 4 }"#, &context)
+        .expect("should deserialize");
+
+    assert_eq!(
+        value,
+        Value::Object(vec![
+            (string("a"), number(1)),
+            (string("_b$2"), number(2)),
+            (string("c"), number(3)),
+            (string("$"), number(4)),
+        ])
+    );
+}
+
+#[test]
+fn unquoted_keys_strict() {
+    let error = from_str::<Value>("{ a: 1 }", &context(Syntax::strict()))
+        .expect_err("unquoted keys should be rejected");
+
+    assert_eq!(errors(error), [(
+        json!(["syntax"]),
+        json!({"span": {"start": 2, "end": 3}})
+    )]);
+}
+
+#[test]
+fn unquoted_keys_must_be_identifiers() {
+    let context = context(Syntax::strict().with_unquoted_keys(true));
+
+    // identifiers cannot start with a digit
+    let error = from_str::<Value>("{ 1a: 1 }", &context).expect_err("key is not an identifier");
+
+    // `1` is read as number key, which is followed by the unexpected `a`
+    assert_eq!(
+        errors(error)[0],
+        (json!(["syntax"]), json!({"span": {"start": 2, "end": 3}}))
+    );
+}
+
+#[test]
+fn unquoted_keys_are_ascii() {
+    let context = context(Syntax::strict().with_unquoted_keys(true));
+
+    let error = from_str::<Value>("{ ä: 1 }", &context).expect_err("key is not ASCII");
+
+    let (id, properties) = &errors(error)[0];
+    assert_eq!(*id, json!(["syntax"]));
+    assert_eq!(properties["span"]["start"], 2);
+}
+
+#[test]
+fn unquoted_values_are_rejected() {
+    let context = context(Syntax::json5_lite());
+
+    let error = from_str::<Value>("{ a: b }", &context).expect_err("values must be quoted");
+
+    assert_eq!(
+        errors(error)[0],
+        (json!(["syntax"]), json!({"position": 5}))
+    );
+}
+
+#[test]
+fn json5_lite() {
+    let context = context(Syntax::json5_lite());
+
+    let value = from_str::<Value>(
+        r#"
+        // configuration
+        {
+            name: "deer", /* the name */
+            tags: ["json", "json5",],
+            nested: { enabled: true, },
+        }
+        "#,
+        &context,
+    )
+    .expect("should deserialize");
+
+    assert_eq!(
+        value,
+        Value::Object(vec![
+            (string("name"), string("deer")),
+            (
+                string("tags"),
+                Value::Array(vec![string("json"), string("json5")])
+            ),
+            (
+                string("nested"),
+                Value::Object(vec![(string("enabled"), Value::Bool(true))])
+            ),
+        ])
+    );
+}
+
+#[test]
+fn unexpected_eof() {
+    let error = from_str::<Value>(r#"{"a": [1, 2"#, &context(Syntax::strict()))
+        .expect_err("input is incomplete");
+
+    // both the array and the object are unclosed
+    assert_eq!(errors(error), [
+        (json!(["syntax"]), json!({"position": 11})),
+        (json!(["syntax"]), json!({"position": 11}))
+    ]);
+}
+
+#[test]
+fn expected_colon() {
+    let error =
+        from_str::<Value>(r#"{"a" 1}"#, &context(Syntax::strict())).expect_err("colon is missing");
+
+    assert_eq!(errors(error), [(json!(["syntax"]), json!({"position": 5}))]);
+}
+
+#[test]
+fn unexpected_byte() {
+    let error = from_str::<Value>("[1, ?]", &context(Syntax::strict())).expect_err("invalid value");
+
+    assert_eq!(errors(error), [(json!(["syntax"]), json!({"position": 4}))]);
+}
@@ -1,9 +1,19 @@
-use alloc::{boxed::Box, collections::BTreeMap};
+use alloc::{collections::BTreeMap, rc::Rc};
 use core::any::{Any, TypeId};
 
-#[derive(Debug)]
+use error_stack::{Report, Result};
+
+use crate::error::{
+    CollectionLengthLimitError, Error, Limit, ReceivedLength, StringLengthLimitError, Variant,
+};
+
+/// Type map, which is used to configure deserialization.
+///
+/// Values are reference counted, cloning a [`Context`] is therefore cheap, which is needed when
+/// values need to be buffered and deserialized again at a later stage (e.g. untagged enums).
+#[derive(Debug, Clone)]
 pub struct Context {
-    inner: BTreeMap<TypeId, Box<dyn Any>>,
+    inner: BTreeMap<TypeId, Rc<dyn Any>>,
 }
 
 impl Context {
@@ -16,18 +26,22 @@ impl Context {
 
     pub fn insert<T: 'static>(&mut self, value: T) -> &mut Self {
         let type_id = TypeId::of::<T>();
-        self.inner.insert(type_id, Box::new(value));
+        self.inner.insert(type_id, Rc::new(value));
 
         self
     }
 
-    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+    /// Removes the value of type `T` from the context.
+    ///
+    /// If a clone of this context still shares the value, the value is cloned, the clone keeps its
+    /// value.
+    pub fn remove<T: Clone + 'static>(&mut self) -> Option<T> {
         let type_id = TypeId::of::<T>();
 
         self.inner
             .remove(&type_id)
             .and_then(|value| value.downcast().ok())
-            .map(|value| *value)
+            .map(|value| Rc::try_unwrap(value).unwrap_or_else(|value| T::clone(&value)))
     }
 
     pub fn clear(&mut self) {
@@ -57,6 +71,135 @@ impl Default for Context {
     }
 }
 
+/// Maximum nesting depth of arrays and objects a deserializer accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaxDepth(usize);
+
+impl MaxDepth {
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self(limit)
+    }
+
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.0
+    }
+}
+
+/// Maximum length of strings and byte sequences, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaxStringLength(usize);
+
+impl MaxStringLength {
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self(limit)
+    }
+
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.0
+    }
+
+    /// Checks if a string or byte sequence of `length` bytes is within the limit.
+    ///
+    /// # Errors
+    ///
+    /// [`StringLengthLimitError`] if `length` exceeds the limit
+    pub fn ensure(self, length: usize) -> Result<(), Error> {
+        if length <= self.0 {
+            return Ok(());
+        }
+
+        Err(Report::new(StringLengthLimitError.into_error())
+            .attach(Limit::new(self.0))
+            .attach(ReceivedLength::new(length)))
+    }
+}
+
+/// Maximum number of items in an array or entries in an object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaxCollectionLength(usize);
+
+impl MaxCollectionLength {
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self(limit)
+    }
+
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.0
+    }
+
+    /// Checks if a collection of `length` items is still within the limit.
+    ///
+    /// The received length is only attached if it is known, e.g. through a size hint.
+    ///
+    /// # Errors
+    ///
+    /// [`CollectionLengthLimitError`] if `length` exceeds the limit
+    pub fn ensure(self, length: usize, received: Option<usize>) -> Result<(), Error> {
+        if length <= self.0 {
+            return Ok(());
+        }
+
+        let mut error =
+            Report::new(CollectionLengthLimitError.into_error()).attach(Limit::new(self.0));
+
+        if let Some(received) = received {
+            error = error.attach(ReceivedLength::new(received));
+        }
+
+        Err(error)
+    }
+}
+
+/// Whether fields that are not known by the type should be rejected.
+///
+/// If absent, unknown fields are rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DenyUnknownFields(bool);
+
+impl DenyUnknownFields {
+    #[must_use]
+    pub const fn new(deny: bool) -> Self {
+        Self(deny)
+    }
+
+    #[must_use]
+    pub const fn deny(&self) -> bool {
+        self.0
+    }
+
+    pub(crate) fn enabled(context: &Context) -> bool {
+        context.request_ref::<Self>().map_or(true, Self::deny)
+    }
+}
+
+/// Whether numbers should also be accepted when they are encoded as strings, e.g. `"12"`.
+///
+/// If absent, strings are not coerced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoerceNumbers(bool);
+
+impl CoerceNumbers {
+    #[must_use]
+    pub const fn new(coerce: bool) -> Self {
+        Self(coerce)
+    }
+
+    #[must_use]
+    pub const fn coerce(&self) -> bool {
+        self.0
+    }
+
+    pub(crate) fn enabled(context: &Context) -> bool {
+        context.request_ref::<Self>().map_or(false, Self::coerce)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,6 +250,18 @@ mod tests {
         assert!(!context.contains::<u32>());
     }
 
+    #[test]
+    fn remove_shared() {
+        let mut context = Context::new();
+        context.insert::<u8>(0u8);
+
+        let clone = context.clone();
+
+        assert_eq!(context.remove::<u8>(), Some(0u8));
+        assert!(!context.contains::<u8>());
+        assert_eq!(clone.request_ref::<u8>(), Some(&0u8));
+    }
+
     #[test]
     fn request_ref() {
         let mut context = Context::new();
@@ -70,12 +70,14 @@ pub use extra::{
     ReceivedLength,
 };
 pub use internal::BoundedContractViolationError;
+pub use limit::{CollectionLengthLimitError, DepthLimitError, Limit, StringLengthLimitError};
 pub use location::Location;
 use serde::ser::SerializeMap;
 pub use r#type::{ExpectedType, ReceivedType, TypeError};
 pub use unknown::{
     ExpectedField, ExpectedIdentifier, ExpectedVariant, ReceivedField, ReceivedIdentifier,
     ReceivedVariant, UnknownFieldError, UnknownIdentifierError, UnknownVariantError,
+    UntaggedVariantError,
 };
 pub use value::{MissingError, ReceivedValue, ValueError};
 
@@ -84,6 +86,7 @@ use crate::error::serialize::{impl_serialize, Export};
 mod duplicate;
 mod extra;
 mod internal;
+mod limit;
 mod location;
 mod macros;
 mod serialize;
@@ -162,7 +162,7 @@ impl ErrorProperty for ExpectedLength {
 }
 
 #[derive(serde::Serialize)]
-pub struct ReceivedLength(usize);
+pub struct ReceivedLength(pub(super) usize);
 
 impl ReceivedLength {
     #[must_use]
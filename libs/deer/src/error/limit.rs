@@ -0,0 +1,277 @@
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+use crate::{
+    error::{
+        ErrorProperties, ErrorProperty, Id, Location, Namespace, ReceivedLength, Variant, NAMESPACE,
+    },
+    id,
+};
+
+#[derive(serde::Serialize)]
+pub struct Limit(usize);
+
+impl Limit {
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self(limit)
+    }
+}
+
+impl ErrorProperty for Limit {
+    type Value<'a> = Option<&'a Self>;
+
+    fn key() -> &'static str {
+        "limit"
+    }
+
+    fn value<'a>(mut stack: impl Iterator<Item = &'a Self>) -> Self::Value<'a> {
+        stack.next()
+    }
+}
+
+#[derive(Debug)]
+pub struct DepthLimitError;
+
+impl Variant for DepthLimitError {
+    type Properties = (Location, Limit);
+
+    const ID: Id = id!["limit", "depth"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> fmt::Result {
+        let (_, limit) = properties;
+
+        if let Some(Limit(limit)) = limit {
+            write!(fmt, "exceeded maximum nesting depth of {limit}")
+        } else {
+            Display::fmt(self, fmt)
+        }
+    }
+}
+
+impl Display for DepthLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("exceeded maximum nesting depth")
+    }
+}
+
+#[derive(Debug)]
+pub struct StringLengthLimitError;
+
+impl Variant for StringLengthLimitError {
+    type Properties = (Location, Limit, ReceivedLength);
+
+    const ID: Id = id!["limit", "string"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> fmt::Result {
+        // received string of length {received}, which exceeds the maximum length of {limit}
+        let (_, limit, received) = properties;
+
+        match (limit, received) {
+            (Some(Limit(limit)), Some(ReceivedLength(received))) => write!(
+                fmt,
+                "received string of length {received}, which exceeds the maximum length of {limit}"
+            ),
+            (Some(Limit(limit)), None) => {
+                write!(fmt, "string exceeds the maximum length of {limit}")
+            }
+            _ => Display::fmt(self, fmt),
+        }
+    }
+}
+
+impl Display for StringLengthLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("string exceeds the maximum length")
+    }
+}
+
+#[derive(Debug)]
+pub struct CollectionLengthLimitError;
+
+impl Variant for CollectionLengthLimitError {
+    type Properties = (Location, Limit, ReceivedLength);
+
+    const ID: Id = id!["limit", "collection"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> fmt::Result {
+        // received collection of length {received}, which exceeds the maximum length of {limit}
+        let (_, limit, received) = properties;
+
+        match (limit, received) {
+            (Some(Limit(limit)), Some(ReceivedLength(received))) => write!(
+                fmt,
+                "received collection of length {received}, which exceeds the maximum length of \
+                 {limit}"
+            ),
+            (Some(Limit(limit)), None) => {
+                write!(fmt, "collection exceeds the maximum length of {limit}")
+            }
+            _ => Display::fmt(self, fmt),
+        }
+    }
+}
+
+impl Display for CollectionLengthLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("collection exceeds the maximum length")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::Report;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        error::Error,
+        test::{to_json, to_message},
+    };
+
+    #[test]
+    fn depth() {
+        // we simulate that the error happens in:
+        // [[[...] <- here]]
+        let error = Report::new(Error::new(DepthLimitError))
+            .attach(Location::Array(0))
+            .attach(Location::Array(0))
+            .attach(Limit::new(2));
+
+        let value = to_json::<DepthLimitError>(&error);
+
+        assert_eq!(
+            value,
+            json!({
+                "location": [
+                    {"type": "array", "value": 0},
+                    {"type": "array", "value": 0}
+                ],
+                "limit": 2
+            })
+        );
+    }
+
+    #[test]
+    fn depth_message() {
+        assert_eq!(
+            to_message::<DepthLimitError>(&Report::new(DepthLimitError.into_error())),
+            "exceeded maximum nesting depth"
+        );
+
+        assert_eq!(
+            to_message::<DepthLimitError>(
+                &Report::new(DepthLimitError.into_error()) //
+                    .attach(Limit::new(2))
+            ),
+            "exceeded maximum nesting depth of 2"
+        );
+    }
+
+    #[test]
+    fn string() {
+        // we simulate that the error happens in:
+        // {field1: "..." <- here}
+        let error = Report::new(Error::new(StringLengthLimitError))
+            .attach(Location::Field("field1"))
+            .attach(Limit::new(4))
+            .attach(ReceivedLength::new(12));
+
+        let value = to_json::<StringLengthLimitError>(&error);
+
+        assert_eq!(
+            value,
+            json!({
+                "location": [
+                    {"type": "field", "value": "field1"}
+                ],
+                "limit": 4,
+                "received": 12
+            })
+        );
+    }
+
+    #[test]
+    fn string_message() {
+        assert_eq!(
+            to_message::<StringLengthLimitError>(&Report::new(StringLengthLimitError.into_error())),
+            "string exceeds the maximum length"
+        );
+
+        assert_eq!(
+            to_message::<StringLengthLimitError>(
+                &Report::new(StringLengthLimitError.into_error()) //
+                    .attach(Limit::new(4))
+            ),
+            "string exceeds the maximum length of 4"
+        );
+
+        assert_eq!(
+            to_message::<StringLengthLimitError>(
+                &Report::new(StringLengthLimitError.into_error())
+                    .attach(Limit::new(4))
+                    .attach(ReceivedLength::new(12))
+            ),
+            "received string of length 12, which exceeds the maximum length of 4"
+        );
+    }
+
+    #[test]
+    fn collection() {
+        // we simulate that the error happens in:
+        // [..., [...] <- here]
+        let error = Report::new(Error::new(CollectionLengthLimitError))
+            .attach(Location::Array(1))
+            .attach(Limit::new(8))
+            .attach(ReceivedLength::new(9));
+
+        let value = to_json::<CollectionLengthLimitError>(&error);
+
+        assert_eq!(
+            value,
+            json!({
+                "location": [
+                    {"type": "array", "value": 1}
+                ],
+                "limit": 8,
+                "received": 9
+            })
+        );
+    }
+
+    #[test]
+    fn collection_message() {
+        assert_eq!(
+            to_message::<CollectionLengthLimitError>(&Report::new(
+                CollectionLengthLimitError.into_error()
+            )),
+            "collection exceeds the maximum length"
+        );
+
+        assert_eq!(
+            to_message::<CollectionLengthLimitError>(
+                &Report::new(CollectionLengthLimitError.into_error())
+                    .attach(Limit::new(8))
+                    .attach(ReceivedLength::new(9))
+            ),
+            "received collection of length 9, which exceeds the maximum length of 8"
+        );
+    }
+}
@@ -5,7 +5,8 @@ use core::{
 };
 
 use super::{
-    fmt_fold_fields, ErrorProperties, ErrorProperty, Id, Location, Namespace, Variant, NAMESPACE,
+    fmt_fold_fields, ErrorProperties, ErrorProperty, ExpectedType, Id, Location, Namespace,
+    Variant, NAMESPACE,
 };
 use crate::id;
 
@@ -203,6 +204,43 @@ impl Display for UnknownVariantError {
     }
 }
 
+/// None of the variants of an untagged enum could be deserialized.
+///
+/// The errors of every variant that has been tried are attached as related errors.
+#[derive(Debug)]
+pub struct UntaggedVariantError;
+
+impl Variant for UntaggedVariantError {
+    type Properties = (Location, ExpectedType);
+
+    const ID: Id = id!["unknown", "untagged"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> fmt::Result {
+        let (_, expected) = properties;
+
+        Display::fmt(self, fmt)?;
+
+        if let Some(expected) = expected {
+            let ty = expected.document().schema().ty();
+
+            fmt.write_fmt(format_args!(", expected value of type {ty}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for UntaggedVariantError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("received value did not match any variant of the untagged enum")
+    }
+}
+
 fn fmt_byte_slice(slice: &[u8], fmt: &mut Formatter<'_>) -> fmt::Result {
     fmt.write_str("[")?;
 
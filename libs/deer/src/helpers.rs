@@ -1,12 +1,20 @@
-use error_stack::{Result, ResultExt};
+use alloc::{string::String, vec::Vec};
+
+use error_stack::{Report, Result, ResultExt};
 use serde::{ser::SerializeMap, Serialize, Serializer};
 
 use crate::{
-    error::{DeserializeError, VisitorError},
+    error::{
+        DeserializeError, DeserializerError, DuplicateField, DuplicateFieldError, ExpectedField,
+        ExpectedType, Location, MissingError, ObjectAccessError, ReceivedField, UnknownFieldError,
+        UntaggedVariantError, Variant, VisitorError,
+    },
     ext::TupleExt,
     schema::Reference,
-    Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor, ObjectAccess, Reflection,
-    Schema, Visitor,
+    value::{IntoDeserializer, NoneDeserializer, ValueDeserializer},
+    Context, DenyUnknownFields, Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor,
+    IdentifierVisitor, ObjectAccess, OptionalVisitor, Reflection, Schema, StructVisitor, Value,
+    Visitor,
 };
 
 struct EnumObjectFieldVisitor<T> {
@@ -81,6 +89,401 @@ where
     }
 }
 
+fn is_key(key: &Value, expected: &str) -> bool {
+    match key {
+        Value::String(key) => key == expected,
+        Value::Bytes(key) => key == expected.as_bytes(),
+        _ => false,
+    }
+}
+
+fn key_name(key: &Value) -> Option<String> {
+    match key {
+        Value::String(key) => Some(key.clone()),
+        Value::Bytes(key) => core::str::from_utf8(key).ok().map(String::from),
+        _ => None,
+    }
+}
+
+/// Buffers all entries of an object, so that they can be looked at out of order.
+fn buffer_object<'de, A>(object: &mut A) -> Result<Vec<(Value, Value)>, ObjectAccessError>
+where
+    A: ObjectAccess<'de>,
+{
+    let mut entries = Vec::with_capacity(object.size_hint().unwrap_or(0));
+    let mut result: Result<(), ObjectAccessError> = Ok(());
+
+    while let Some(entry) = object.next() {
+        match entry {
+            Ok(entry) => entries.push(entry),
+            Err(error) => match &mut result {
+                Err(result) => result.extend_one(error),
+                result => *result = Err(error),
+            },
+        }
+    }
+
+    result.map(|()| entries)
+}
+
+/// Deserializer used for the remaining fields of an internally tagged enum.
+///
+/// Unit variants are represented by an object that only contains the tag, which means that the
+/// remaining object is empty, in that case `deserialize_any` will call [`Visitor::visit_none`],
+/// which is what unit variants (through [`ExpectNone`]) expect.
+struct InternallyTaggedContentDeserializer<'a> {
+    context: &'a Context,
+    entries: Vec<(Value, Value)>,
+}
+
+impl<'a> InternallyTaggedContentDeserializer<'a> {
+    fn into_inner(self) -> ValueDeserializer<'a> {
+        Value::Object(self.entries).into_deserializer(self.context)
+    }
+}
+
+impl<'de> Deserializer<'de> for InternallyTaggedContentDeserializer<'_> {
+    forward_to_deserialize_any!(
+        null
+        bool
+        number
+        i8 i16 i32 i64 i128
+        u8 u16 u32 u64 u128
+        f32 f64
+        char str string
+        bytes bytes_buffer
+        array
+    );
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        if self.entries.is_empty() {
+            visitor.visit_none().change_context(DeserializerError)
+        } else {
+            self.into_inner().deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_object<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.into_inner().deserialize_object(visitor)
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        self.into_inner().deserialize_optional(visitor)
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: EnumVisitor<'de>,
+    {
+        self.into_inner().deserialize_enum(visitor)
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: StructVisitor<'de>,
+    {
+        self.into_inner().deserialize_struct(visitor)
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        self.into_inner().deserialize_identifier(visitor)
+    }
+}
+
+/// Visitor for internally tagged enums, where the discriminant is stored in a field of the
+/// object, alongside the fields of the variant.
+///
+/// ```json
+/// { "type": "Variant", "field": 1 }
+/// ```
+///
+/// The object is buffered, as the tag might not be the first field, the remaining fields are then
+/// given to [`EnumVisitor::visit_value`] as an object. Unit variants receive
+/// [`Visitor::visit_none`] if no other fields are present.
+///
+/// This visitor should be used together with [`Deserializer::deserialize_object`].
+pub struct InternallyTaggedVisitor<T> {
+    tag: &'static str,
+    visitor: T,
+}
+
+impl<T> InternallyTaggedVisitor<T> {
+    #[must_use]
+    pub const fn new(tag: &'static str, visitor: T) -> Self {
+        Self { tag, visitor }
+    }
+}
+
+impl<'de, T> Visitor<'de> for InternallyTaggedVisitor<T>
+where
+    T: EnumVisitor<'de>,
+{
+    type Value = T::Value;
+
+    fn expecting(&self) -> Document {
+        self.visitor.expecting()
+    }
+
+    fn visit_object<A>(self, mut object: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        let entries = buffer_object(&mut object).change_context(VisitorError);
+
+        let value = entries.and_then(|entries| {
+            let context = object.context();
+
+            let mut tag = None;
+            let mut rest = Vec::with_capacity(entries.len());
+
+            for (key, value) in entries {
+                if !is_key(&key, self.tag) {
+                    rest.push((key, value));
+                } else if tag.is_none() {
+                    tag = Some(value);
+                } else {
+                    return Err(Report::new(DuplicateFieldError.into_error())
+                        .attach(DuplicateField::new(self.tag))
+                        .change_context(VisitorError));
+                }
+            }
+
+            let Some(tag) = tag else {
+                return Err(Report::new(MissingError.into_error())
+                    .attach(Location::Field(self.tag))
+                    .attach(ExpectedType::new(T::Discriminant::reflection()))
+                    .change_context(VisitorError));
+            };
+
+            let discriminant = self
+                .visitor
+                .visit_discriminant(tag.into_deserializer(context))
+                .attach(Location::Field(self.tag))?;
+
+            self.visitor
+                .visit_value(discriminant, InternallyTaggedContentDeserializer {
+                    context,
+                    entries: rest,
+                })
+        });
+
+        let end = object.end().change_context(VisitorError);
+
+        (value, end).fold_reports().map(|(value, ())| value)
+    }
+}
+
+/// Visitor for adjacently tagged enums, where the discriminant and the value of the variant are
+/// stored in two separate fields of the object.
+///
+/// ```json
+/// { "tag": "Variant", "content": 1 }
+/// ```
+///
+/// The `content` field may be omitted, in that case [`EnumVisitor::visit_value`] receives a
+/// deserializer that will call [`Visitor::visit_none`], which is used for unit variants.
+///
+/// This visitor should be used together with [`Deserializer::deserialize_object`].
+pub struct AdjacentlyTaggedVisitor<T> {
+    tag: &'static str,
+    content: &'static str,
+    visitor: T,
+}
+
+impl<T> AdjacentlyTaggedVisitor<T> {
+    #[must_use]
+    pub const fn new(tag: &'static str, content: &'static str, visitor: T) -> Self {
+        Self {
+            tag,
+            content,
+            visitor,
+        }
+    }
+}
+
+impl<'de, T> Visitor<'de> for AdjacentlyTaggedVisitor<T>
+where
+    T: EnumVisitor<'de>,
+{
+    type Value = T::Value;
+
+    fn expecting(&self) -> Document {
+        self.visitor.expecting()
+    }
+
+    fn visit_object<A>(self, mut object: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        let entries = buffer_object(&mut object).change_context(VisitorError);
+
+        let value = entries.and_then(|entries| {
+            let context = object.context();
+
+            let deny_unknown_fields = DenyUnknownFields::enabled(context);
+
+            let mut tag = None;
+            let mut content_value = None;
+            let mut errors: Result<(), VisitorError> = Ok(());
+
+            for (key, value) in entries {
+                let (field, slot) = if is_key(&key, self.tag) {
+                    (self.tag, &mut tag)
+                } else if is_key(&key, self.content) {
+                    (self.content, &mut content_value)
+                } else if !deny_unknown_fields {
+                    continue;
+                } else {
+                    let mut error = Report::new(UnknownFieldError.into_error())
+                        .attach(ExpectedField::new(self.tag))
+                        .attach(ExpectedField::new(self.content));
+
+                    if let Some(key) = key_name(&key) {
+                        error = error.attach(ReceivedField::new(key));
+                    }
+
+                    let error = error.change_context(VisitorError);
+                    match &mut errors {
+                        Err(errors) => errors.extend_one(error),
+                        errors => *errors = Err(error),
+                    }
+
+                    continue;
+                };
+
+                if slot.is_some() {
+                    let error = Report::new(DuplicateFieldError.into_error())
+                        .attach(DuplicateField::new(field))
+                        .change_context(VisitorError);
+
+                    match &mut errors {
+                        Err(errors) => errors.extend_one(error),
+                        errors => *errors = Err(error),
+                    }
+                } else {
+                    *slot = Some(value);
+                }
+            }
+
+            let Some(tag) = tag else {
+                let error = Report::new(MissingError.into_error())
+                    .attach(Location::Field(self.tag))
+                    .attach(ExpectedType::new(T::Discriminant::reflection()))
+                    .change_context(VisitorError);
+
+                return Err(match errors {
+                    Err(mut errors) => {
+                        errors.extend_one(error);
+                        errors
+                    }
+                    Ok(()) => error,
+                });
+            };
+
+            let value = self
+                .visitor
+                .visit_discriminant(tag.into_deserializer(context))
+                .attach(Location::Field(self.tag))
+                .and_then(|discriminant| match content_value {
+                    Some(content_value) => self
+                        .visitor
+                        .visit_value(discriminant, content_value.into_deserializer(context))
+                        .attach(Location::Field(self.content)),
+                    None => self
+                        .visitor
+                        .visit_value(discriminant, NoneDeserializer::new(context)),
+                });
+
+            (value, errors).fold_reports().map(|(value, ())| value)
+        });
+
+        let end = object.end().change_context(VisitorError);
+
+        (value, end).fold_reports().map(|(value, ())| value)
+    }
+}
+
+/// A single variant of an untagged enum, used in [`deserialize_untagged`].
+pub type UntaggedVariant<T> = (
+    &'static str,
+    for<'a> fn(ValueDeserializer<'a>) -> Result<T, DeserializeError>,
+);
+
+/// Deserialize an untagged enum, by trying every variant in order.
+///
+/// The value is buffered as a [`Value`] and then given to every variant, until one succeeds.
+/// If no variant succeeds an [`UntaggedVariantError`] is returned, which contains the errors of
+/// every variant that has been tried, so that one is able to see why each candidate failed.
+///
+/// ```
+/// use deer::{error::DeserializeError, helpers::deserialize_untagged, Deserialize, Deserializer};
+/// use error_stack::Result;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Untagged {
+///     Number(u8),
+///     Bool(bool),
+/// }
+///
+/// fn deserialize<'de, D: Deserializer<'de>>(
+///     deserializer: D,
+/// ) -> Result<Untagged, DeserializeError> {
+///     deserialize_untagged(deserializer, u8::reflection(), &[
+///         ("Number", |deserializer| {
+///             u8::deserialize(deserializer).map(Untagged::Number)
+///         }),
+///         ("Bool", |deserializer| {
+///             bool::deserialize(deserializer).map(Untagged::Bool)
+///         }),
+///     ])
+/// }
+/// ```
+///
+/// # Errors
+///
+/// The value could not be buffered, or none of the variants succeeded.
+pub fn deserialize_untagged<'de, D, T>(
+    deserializer: D,
+    expected: Document,
+    variants: &[UntaggedVariant<T>],
+) -> Result<T, DeserializeError>
+where
+    D: Deserializer<'de>,
+{
+    // the context is borrowed from the deserializer, which is consumed while buffering
+    let context = deserializer.context().clone();
+    let value = Value::deserialize(deserializer)?;
+
+    let mut error = Report::new(UntaggedVariantError.into_error())
+        .attach(ExpectedType::new(expected))
+        .change_context(DeserializeError);
+
+    for (name, variant) in variants {
+        match variant(value.clone().into_deserializer(&context)) {
+            Ok(value) => return Ok(value),
+            Err(variant) => error.extend_one(variant.attach(Location::Variant(name))),
+        }
+    }
+
+    Err(error)
+}
+
 struct ExpectNoneVisitor;
 
 impl<'de> Visitor<'de> for ExpectNoneVisitor {
@@ -4,10 +4,13 @@ use error_stack::ResultExt;
 
 use crate::{
     error::{DeserializeError, VisitorError},
-    Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+    Deserialize, Deserializer, Document, MaxStringLength, Reflection, Schema, Visitor,
 };
 
-struct BytesVisitor<'de>(PhantomData<fn() -> &'de ()>);
+struct BytesVisitor<'de> {
+    limit: Option<MaxStringLength>,
+    _marker: PhantomData<fn() -> &'de ()>,
+}
 
 impl<'de> Visitor<'de> for BytesVisitor<'de> {
     type Value = &'de [u8];
@@ -20,6 +23,10 @@ impl<'de> Visitor<'de> for BytesVisitor<'de> {
         self,
         value: &'de [u8],
     ) -> error_stack::Result<Self::Value, VisitorError> {
+        if let Some(limit) = self.limit {
+            limit.ensure(value.len()).change_context(VisitorError)?;
+        }
+
         Ok(value)
     }
 }
@@ -38,8 +45,13 @@ impl<'de> Deserialize<'de> for &'de [u8] {
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
     ) -> error_stack::Result<Self, DeserializeError> {
+        let limit = deserializer.context().request_ref().copied();
+
         deserializer
-            .deserialize_bytes(BytesVisitor(PhantomData))
+            .deserialize_bytes(BytesVisitor {
+                limit,
+                _marker: PhantomData,
+            })
             .change_context(DeserializeError)
     }
 }
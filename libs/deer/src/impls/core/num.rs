@@ -108,7 +108,9 @@ macro_rules! impl_num {
             where
                 D: Deserializer<'de>,
             {
-                struct PrimitiveVisitor;
+                struct PrimitiveVisitor {
+                    coerce: bool,
+                }
 
                 impl<'de> Visitor<'de> for PrimitiveVisitor {
                     type Value = $primitive;
@@ -118,9 +120,15 @@ macro_rules! impl_num {
                     }
 
                     $($($method!($val :: $visit);)*)*
+
+                    num_coerce!();
                 }
 
-                deserializer.$deserialize(PrimitiveVisitor).change_context(DeserializeError)
+                let coerce = CoerceNumbers::enabled(deserializer.context());
+
+                deserializer
+                    .$deserialize(PrimitiveVisitor { coerce })
+                    .change_context(DeserializeError)
             }
         }
     };
@@ -193,6 +201,28 @@ macro_rules! num_number {
     };
 }
 
+macro_rules! num_coerce {
+    () => {
+        fn visit_str(self, value: &str) -> Result<Self::Value, VisitorError> {
+            if !self.coerce {
+                return Err(Report::new(TypeError.into_error())
+                    .attach(ReceivedType::new(<&str>::reflection()))
+                    .attach(ExpectedType::new(self.expecting()))
+                    .change_context(VisitorError));
+            }
+
+            let number = Number::parse(value).ok_or_else(|| {
+                Report::new(ValueError.into_error())
+                    .attach(ExpectedType::new(self.expecting()))
+                    .attach(ReceivedValue::new(String::from(value)))
+                    .change_context(VisitorError)
+            })?;
+
+            self.visit_number(number)
+        }
+    };
+}
+
 // these imports are down here as they make use of the macros declared above
 mod floating;
 mod integral;
@@ -1,9 +1,14 @@
+use alloc::string::String;
+
 use error_stack::{Report, Result, ResultExt};
 use num_traits::ToPrimitive;
 
 use crate::{
-    error::{DeserializeError, ExpectedType, ReceivedValue, ValueError, Variant, VisitorError},
-    Deserialize, Deserializer, Document, Number, Reflection, Schema, Visitor,
+    error::{
+        DeserializeError, ExpectedType, ReceivedType, ReceivedValue, TypeError, ValueError,
+        Variant, VisitorError,
+    },
+    CoerceNumbers, Deserialize, Deserializer, Document, Number, Reflection, Schema, Visitor,
 };
 
 macro_rules! impl_reflection {
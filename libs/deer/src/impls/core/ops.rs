@@ -16,8 +16,8 @@ use crate::{
     impls::UnitVariantVisitor,
     schema::Reference,
     value::NoneDeserializer,
-    ArrayAccess, Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor, ObjectAccess,
-    Reflection, Schema, StructVisitor,
+    ArrayAccess, DenyUnknownFields, Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor,
+    ObjectAccess, Reflection, Schema, StructVisitor, Value,
 };
 
 identifier! {
@@ -120,13 +120,33 @@ where
     T: Deserialize<'de>,
     U: Deserialize<'de>,
 {
-    type Key = RangeIdent;
+    // `None` is used for unknown fields, which are ignored if `DenyUnknownFields` is disabled
+    type Key = Option<RangeIdent>;
     type Value = ();
 
+    fn visit_key<D>(&self, deserializer: D) -> Result<Self::Key, VisitorError>
+    where
+        D: Deserializer<'de>,
+    {
+        if DenyUnknownFields::enabled(deserializer.context()) {
+            RangeIdent::deserialize(deserializer)
+                .map(Some)
+                .change_context(VisitorError)
+        } else {
+            Ok(RangeIdent::deserialize(deserializer).ok())
+        }
+    }
+
     fn visit_value<D>(self, key: Self::Key, deserializer: D) -> Result<Self::Value, VisitorError>
     where
         D: Deserializer<'de>,
     {
+        let Some(key) = key else {
+            return Value::deserialize(deserializer)
+                .map(|_| ())
+                .change_context(VisitorError);
+        };
+
         match key {
             RangeIdent::Start => {
                 let value = T::deserialize(deserializer)
@@ -4,10 +4,13 @@ use error_stack::{Report, Result, ResultExt};
 
 use crate::{
     error::{DeserializeError, ExpectedType, ReceivedType, TypeError, Variant, VisitorError},
-    Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+    Deserialize, Deserializer, Document, MaxStringLength, Reflection, Schema, Visitor,
 };
 
-struct StrVisitor<'a>(PhantomData<fn() -> &'a ()>);
+struct StrVisitor<'a> {
+    limit: Option<MaxStringLength>,
+    _marker: PhantomData<fn() -> &'a ()>,
+}
 
 impl<'de: 'a, 'a> Visitor<'de> for StrVisitor<'a> {
     type Value = &'a str;
@@ -17,6 +20,10 @@ impl<'de: 'a, 'a> Visitor<'de> for StrVisitor<'a> {
     }
 
     fn visit_borrowed_str(self, value: &'de str) -> Result<Self::Value, VisitorError> {
+        if let Some(limit) = self.limit {
+            limit.ensure(value.len()).change_context(VisitorError)?;
+        }
+
         Ok(value)
     }
 }
@@ -31,8 +38,13 @@ impl<'de: 'a, 'a> Deserialize<'de> for &'a str {
     type Reflection = str;
 
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        let limit = deserializer.context().request_ref().copied();
+
         deserializer
-            .deserialize_str(StrVisitor(PhantomData))
+            .deserialize_str(StrVisitor {
+                limit,
+                _marker: PhantomData,
+            })
             .change_context(DeserializeError)
     }
 }
@@ -31,7 +31,13 @@ use crate::{
     },
     schema::visitor,
 };
-pub use crate::{context::Context, number::Number};
+pub use crate::{
+    context::{
+        CoerceNumbers, Context, DenyUnknownFields, MaxCollectionLength, MaxDepth, MaxStringLength,
+    },
+    number::Number,
+    value::Value,
+};
 
 mod context;
 pub mod error;
@@ -496,9 +502,19 @@ pub trait StructVisitor<'de>: Sized {
 
 // internal visitor, which is used during the default implementation of the `deserialize_i*` and
 // `deserialize_u*` methods.
-struct NumberVisitor<T: Reflection>(PhantomData<fn() -> *const T>);
+struct NumberVisitor<T: Reflection> {
+    coerce: bool,
+    _marker: PhantomData<fn() -> *const T>,
+}
 
 impl<T: Reflection> NumberVisitor<T> {
+    fn new(context: &Context) -> Self {
+        Self {
+            coerce: CoerceNumbers::enabled(context),
+            _marker: PhantomData,
+        }
+    }
+
     fn value_error(
         &self,
         value: impl erased_serde::Serialize + Send + Sync + 'static,
@@ -532,6 +548,17 @@ impl<T: Reflection> Visitor<'_> for NumberVisitor<T> {
             .ok_or_else(|| self.value_error(value))
             .and_then(|number| self.visit_number(number))
     }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, VisitorError> {
+        if !self.coerce {
+            return Err(Report::new(TypeError.into_error())
+                .attach(ReceivedType::new(<&str>::reflection()))
+                .attach(ExpectedType::new(self.expecting()))
+                .change_context(VisitorError));
+        }
+
+        Number::parse(value).ok_or_else(|| self.value_error(String::from(value)))
+    }
 }
 
 macro_rules! derive_from_number {
@@ -550,7 +577,8 @@ macro_rules! derive_from_number {
         where
             V: Visitor<'de>,
         {
-            let n = self.deserialize_number(NumberVisitor::<<$schema as Deserialize>::Reflection>(PhantomData))?;
+            let number = NumberVisitor::<<$schema as Deserialize>::Reflection>::new(self.context());
+            let n = self.deserialize_number(number)?;
             let v = n
                 .$to()
                 .ok_or_else(||
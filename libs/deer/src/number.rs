@@ -74,6 +74,82 @@ impl Number {
     pub unsafe fn from_string_unchecked(value: impl Into<String>) -> Self {
         Self(value.into())
     }
+
+    /// Parses a number from its textual representation, as used by [`CoerceNumbers`].
+    ///
+    /// Only the JSON number grammar is accepted, e.g. `"12"`, `"-1.5"` or `"1e3"`.
+    ///
+    /// [`CoerceNumbers`]: crate::CoerceNumbers
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        if !is_number(value.as_bytes()) {
+            return None;
+        }
+
+        #[cfg(not(feature = "arbitrary-precision"))]
+        {
+            if let Ok(value) = value.parse::<u64>() {
+                return Some(Self::pos(value));
+            }
+
+            if let Ok(value) = value.parse::<i64>() {
+                return Self::from_i64(value);
+            }
+
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(Self::float)
+        }
+
+        #[cfg(feature = "arbitrary-precision")]
+        {
+            Some(Self(value.to_owned()))
+        }
+    }
+}
+
+/// Checks if `value` adheres to the JSON number grammar.
+fn is_number(value: &[u8]) -> bool {
+    fn digits(value: &[u8]) -> usize {
+        value
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    }
+
+    let mut value = value.strip_prefix(b"-").unwrap_or(value);
+
+    // integer part, leading zeros are not allowed
+    match digits(value) {
+        0 => return false,
+        length if length > 1 && value[0] == b'0' => return false,
+        length => value = &value[length..],
+    }
+
+    if let Some(rest) = value.strip_prefix(b".") {
+        match digits(rest) {
+            0 => return false,
+            length => value = &rest[length..],
+        }
+    }
+
+    if let Some(rest) = value
+        .strip_prefix(b"e")
+        .or_else(|| value.strip_prefix(b"E"))
+    {
+        let rest = rest
+            .strip_prefix(b"+")
+            .or_else(|| rest.strip_prefix(b"-"))
+            .unwrap_or(rest);
+
+        match digits(rest) {
+            0 => return false,
+            length => value = &rest[length..],
+        }
+    }
+
+    value.is_empty()
 }
 
 impl FromPrimitive for Number {
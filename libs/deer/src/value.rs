@@ -704,11 +704,13 @@ impl<'de> Deserializer<'de> for NullDeserializer<'_> {
 mod array;
 mod bytes;
 mod object;
+mod owned;
 mod string;
 
 pub use array::ArrayAccessDeserializer;
 pub use bytes::{BorrowedBytesDeserializer, BytesBufferDeserializer, BytesDeserializer};
 pub use object::ObjectAccessDeserializer;
+pub use owned::{Value, ValueDeserializer};
 pub use string::{BorrowedStrDeserializer, StrDeserializer, StringDeserializer};
 
 use crate::error::MissingError;
@@ -0,0 +1,524 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use error_stack::{Report, Result, ResultExt};
+use num_traits::{FromPrimitive, ToPrimitive};
+
+use crate::{
+    error::{
+        ArrayAccessError, ArrayLengthError, DeserializeError, DeserializerError, ExpectedType,
+        Location, ObjectAccessError, ObjectLengthError, ReceivedType, ReceivedValue, TypeError,
+        ValueError, Variant, VisitorError,
+    },
+    schema::visitor::{ArraySchema, BinarySchema, ObjectSchema},
+    value::{EnumUnitDeserializer, IntoDeserializer, ObjectAccessDeserializer},
+    ArrayAccess, Context, Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor,
+    IdentifierVisitor, MaxCollectionLength, MaxStringLength, Number, ObjectAccess, OptionalVisitor,
+    Reflection, Schema, StructVisitor, Visitor,
+};
+
+/// Owned representation of any value that can be expressed in the `deer` data model.
+///
+/// This is the equivalent of `serde`s private `Content` type, it can be used to buffer a value,
+/// which is needed if the same input needs to be deserialized multiple times, like for untagged or
+/// internally tagged enums.
+///
+/// Objects are stored as a list of entries instead of a map, which means that the order of keys is
+/// preserved, keys can be of any type and duplicate keys are retained, this ensures that
+/// deserializing the buffered value behaves the same as deserializing the original input.
+///
+/// [`Value`] implements [`IntoDeserializer`], to deserialize the same value twice, clone it
+/// before calling [`IntoDeserializer::into_deserializer`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Bytes(Vec<u8>),
+    Array(Vec<Value>),
+    Object(Vec<(Value, Value)>),
+}
+
+impl Value {
+    fn received_type(&self) -> Document {
+        match self {
+            Self::Null => <()>::reflection(),
+            Self::Bool(_) => bool::reflection(),
+            Self::Number(_) => Number::reflection(),
+            Self::String(_) => str::document(),
+            Self::Bytes(_) => BinarySchema::document(),
+            Self::Array(_) => ArraySchema::document(),
+            Self::Object(_) => ObjectSchema::document(),
+        }
+    }
+}
+
+impl Reflection for Value {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("any")
+    }
+}
+
+struct ValueVisitor {
+    strings: Option<MaxStringLength>,
+    collections: Option<MaxCollectionLength>,
+}
+
+impl ValueVisitor {
+    fn new(context: &Context) -> Self {
+        Self {
+            strings: context.request_ref().copied(),
+            collections: context.request_ref().copied(),
+        }
+    }
+
+    fn ensure_string(&self, length: usize) -> Result<(), VisitorError> {
+        self.strings
+            .map_or(Ok(()), |limit| limit.ensure(length))
+            .change_context(VisitorError)
+    }
+}
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self) -> Document {
+        Value::reflection()
+    }
+
+    fn visit_null(self) -> Result<Self::Value, VisitorError> {
+        Ok(Value::Null)
+    }
+
+    fn visit_bool(self, value: bool) -> Result<Self::Value, VisitorError> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_number(self, value: Number) -> Result<Self::Value, VisitorError> {
+        Ok(Value::Number(value))
+    }
+
+    fn visit_char(self, value: char) -> Result<Self::Value, VisitorError> {
+        self.ensure_string(value.len_utf8())?;
+
+        Ok(Value::String(value.into()))
+    }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, VisitorError> {
+        self.ensure_string(value.len())?;
+
+        Ok(Value::String(value.into()))
+    }
+
+    fn visit_string(self, value: String) -> Result<Self::Value, VisitorError> {
+        self.ensure_string(value.len())?;
+
+        Ok(Value::String(value))
+    }
+
+    fn visit_bytes(self, value: &[u8]) -> Result<Self::Value, VisitorError> {
+        self.ensure_string(value.len())?;
+
+        Ok(Value::Bytes(value.to_vec()))
+    }
+
+    fn visit_bytes_buffer(self, value: Vec<u8>) -> Result<Self::Value, VisitorError> {
+        self.ensure_string(value.len())?;
+
+        Ok(Value::Bytes(value))
+    }
+
+    fn visit_array<A>(self, mut array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let size_hint = array.size_hint();
+        let limit = self.collections.map_or(usize::MAX, |limit| limit.limit());
+
+        let mut items = Vec::with_capacity(size_hint.unwrap_or(0).min(limit));
+        let mut result: Result<(), ArrayAccessError> = Ok(());
+
+        let mut index = 0;
+        while let Some(item) = array.next() {
+            // once the limit has been exceeded the remaining items are still consumed, but
+            // discarded, to leave the deserializer in a consistent state
+            if index == limit {
+                if let Some(collections) = self.collections {
+                    let error = collections
+                        .ensure(index + 1, size_hint)
+                        .change_context(ArrayAccessError);
+
+                    if let Err(error) = error {
+                        match &mut result {
+                            Err(result) => result.extend_one(error),
+                            result => *result = Err(error),
+                        }
+                    }
+                }
+            }
+
+            match item {
+                Ok(item) if index < limit => items.push(item),
+                Ok(_) => {}
+                Err(error) => {
+                    let error = error.attach(Location::Array(index));
+
+                    match &mut result {
+                        Err(result) => result.extend_one(error),
+                        result => *result = Err(error),
+                    }
+                }
+            }
+
+            index += 1;
+        }
+
+        if let Err(error) = array.end() {
+            match &mut result {
+                Err(result) => result.extend_one(error),
+                result => *result = Err(error),
+            }
+        }
+
+        result
+            .map(|()| Value::Array(items))
+            .change_context(VisitorError)
+    }
+
+    fn visit_object<A>(self, mut object: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        let size_hint = object.size_hint();
+        let limit = self.collections.map_or(usize::MAX, |limit| limit.limit());
+
+        let mut entries = Vec::with_capacity(size_hint.unwrap_or(0).min(limit));
+        let mut result: Result<(), ObjectAccessError> = Ok(());
+
+        let mut index = 0;
+        while let Some(entry) = object.next() {
+            // see `visit_array`
+            if index == limit {
+                if let Some(collections) = self.collections {
+                    let error = collections
+                        .ensure(index + 1, size_hint)
+                        .change_context(ObjectAccessError);
+
+                    if let Err(error) = error {
+                        match &mut result {
+                            Err(result) => result.extend_one(error),
+                            result => *result = Err(error),
+                        }
+                    }
+                }
+            }
+
+            match entry {
+                Ok(entry) if index < limit => entries.push(entry),
+                Ok(_) => {}
+                Err(error) => match &mut result {
+                    Err(result) => result.extend_one(error),
+                    result => *result = Err(error),
+                },
+            }
+
+            index += 1;
+        }
+
+        if let Err(error) = object.end() {
+            match &mut result {
+                Err(result) => result.extend_one(error),
+                result => *result = Err(error),
+            }
+        }
+
+        result
+            .map(|()| Value::Object(entries))
+            .change_context(VisitorError)
+    }
+
+    fn visit_i128(self, value: i128) -> Result<Self::Value, VisitorError> {
+        Number::from_i128(value).map(Value::Number).ok_or_else(|| {
+            Report::new(ValueError.into_error())
+                .attach(ReceivedValue::new(value))
+                .attach(ExpectedType::new(self.expecting()))
+                .change_context(VisitorError)
+        })
+    }
+
+    fn visit_u128(self, value: u128) -> Result<Self::Value, VisitorError> {
+        Number::from_u128(value).map(Value::Number).ok_or_else(|| {
+            Report::new(ValueError.into_error())
+                .attach(ReceivedValue::new(value))
+                .attach(ExpectedType::new(self.expecting()))
+                .change_context(VisitorError)
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    type Reflection = Self;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        let visitor = ValueVisitor::new(deserializer.context());
+
+        deserializer
+            .deserialize_any(visitor)
+            .change_context(DeserializeError)
+    }
+}
+
+#[derive(Debug)]
+struct ValueArrayAccess<'a> {
+    context: &'a Context,
+    items: vec::IntoIter<Value>,
+
+    dirty: bool,
+    length: usize,
+    consumed: usize,
+}
+
+impl<'a> ValueArrayAccess<'a> {
+    fn new(context: &'a Context, items: Vec<Value>) -> Self {
+        Self {
+            context,
+            length: items.len(),
+            items: items.into_iter(),
+            dirty: false,
+            consumed: 0,
+        }
+    }
+}
+
+impl<'de> ArrayAccess<'de> for ValueArrayAccess<'_> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn next<T>(&mut self) -> Option<Result<T, ArrayAccessError>>
+    where
+        T: Deserialize<'de>,
+    {
+        self.dirty = true;
+
+        let item = self.items.next()?;
+        self.consumed += 1;
+
+        Some(T::deserialize(item.into_deserializer(self.context)).change_context(ArrayAccessError))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length)
+    }
+
+    fn end(self) -> Result<(), ArrayAccessError> {
+        if self.items.len() == 0 {
+            Ok(())
+        } else {
+            Err(ArrayLengthError::new(&self, self.consumed).change_context(ArrayAccessError))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ValueObjectAccess<'a> {
+    context: &'a Context,
+    entries: vec::IntoIter<(Value, Value)>,
+
+    dirty: bool,
+    length: usize,
+    consumed: usize,
+}
+
+impl<'a> ValueObjectAccess<'a> {
+    fn new(context: &'a Context, entries: Vec<(Value, Value)>) -> Self {
+        Self {
+            context,
+            length: entries.len(),
+            entries: entries.into_iter(),
+            dirty: false,
+            consumed: 0,
+        }
+    }
+}
+
+impl<'de> ObjectAccess<'de> for ValueObjectAccess<'_> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn try_field<F>(
+        &mut self,
+        visitor: F,
+    ) -> core::result::Result<Result<F::Value, ObjectAccessError>, F>
+    where
+        F: FieldVisitor<'de>,
+    {
+        self.dirty = true;
+
+        let Some((key, value)) = self.entries.next() else {
+            return Err(visitor);
+        };
+        self.consumed += 1;
+
+        let value = visitor
+            .visit_key(key.into_deserializer(self.context))
+            .and_then(|key| visitor.visit_value(key, value.into_deserializer(self.context)));
+
+        Ok(value.change_context(ObjectAccessError))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length)
+    }
+
+    fn end(self) -> Result<(), ObjectAccessError> {
+        if self.entries.len() == 0 {
+            Ok(())
+        } else {
+            Err(ObjectLengthError::new(&self, self.consumed).change_context(ObjectAccessError))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ValueDeserializer<'a> {
+    context: &'a Context,
+    value: Value,
+}
+
+impl<'a> ValueDeserializer<'a> {
+    #[must_use]
+    pub const fn new(value: Value, context: &'a Context) -> Self {
+        Self { context, value }
+    }
+
+    fn error_invalid_type(&self, expected: Document) -> Report<DeserializerError> {
+        Report::new(TypeError.into_error())
+            .attach(ExpectedType::new(expected))
+            .attach(ReceivedType::new(self.value.received_type()))
+            .change_context(DeserializerError)
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'_> {
+    forward_to_deserialize_any!(
+        null
+        bool
+        number
+        i8 i16 i32 i64 i128
+        u8 u16 u32 u64 u128
+        f32 f64
+        char str string
+        bytes bytes_buffer
+        array object
+    );
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_null(),
+            Value::Bool(value) => visitor.visit_bool(value),
+            Value::Number(value) => visitor.visit_number(value),
+            Value::String(value) => visitor.visit_string(value),
+            Value::Bytes(value) => visitor.visit_bytes_buffer(value),
+            Value::Array(items) => visitor.visit_array(ValueArrayAccess::new(self.context, items)),
+            Value::Object(entries) => {
+                visitor.visit_object(ValueObjectAccess::new(self.context, entries))
+            }
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_null(),
+            _ => visitor.visit_some(self),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: EnumVisitor<'de>,
+    {
+        let context = self.context;
+
+        match self.value {
+            Value::Object(entries) => {
+                ObjectAccessDeserializer::new(context, ValueObjectAccess::new(context, entries))
+                    .deserialize_enum(visitor)
+            }
+            value => EnumUnitDeserializer::new(context, Self::new(value, context))
+                .deserialize_enum(visitor),
+        }
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: StructVisitor<'de>,
+    {
+        match self.value {
+            Value::Array(items) => visitor
+                .visit_array(ValueArrayAccess::new(self.context, items))
+                .change_context(DeserializerError),
+            Value::Object(entries) => visitor
+                .visit_object(ValueObjectAccess::new(self.context, entries))
+                .change_context(DeserializerError),
+            _ => {
+                let expected = visitor.expecting();
+                Err(self.error_invalid_type(expected))
+            }
+        }
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        match &self.value {
+            Value::String(value) => visitor.visit_str(value).change_context(DeserializerError),
+            Value::Bytes(value) => visitor.visit_bytes(value).change_context(DeserializerError),
+            Value::Number(value) => {
+                if let Some(value) = value.to_u64() {
+                    visitor.visit_u64(value).change_context(DeserializerError)
+                } else {
+                    let expected = visitor.expecting();
+                    Err(self.error_invalid_type(expected))
+                }
+            }
+            _ => {
+                let expected = visitor.expecting();
+                Err(self.error_invalid_type(expected))
+            }
+        }
+    }
+}
+
+impl<'de> IntoDeserializer<'de> for Value {
+    type Deserializer<'a> = ValueDeserializer<'a> where Self: 'a;
+
+    fn into_deserializer<'a>(self, context: &'a Context) -> Self::Deserializer<'a>
+    where
+        Self: 'a,
+    {
+        ValueDeserializer::new(self, context)
+    }
+}
@@ -0,0 +1,263 @@
+use core::ops::Range;
+
+use deer::{
+    CoerceNumbers, Context, DenyUnknownFields, Deserialize, MaxCollectionLength, MaxStringLength,
+    Number, Value,
+};
+use deer_desert::{assert_tokens_with_context, assert_tokens_with_context_error, error, Token};
+use serde_json::json;
+
+fn context_with<T: 'static>(value: T) -> Context {
+    let mut context = Context::new();
+    context.insert(value);
+    context
+}
+
+#[test]
+fn coerce_numbers_ok() {
+    let context = context_with(CoerceNumbers::new(true));
+
+    assert_tokens_with_context(&12u8, &[Token::Str("12")], &context);
+    assert_tokens_with_context(&-12i64, &[Token::Str("-12")], &context);
+    assert_tokens_with_context(&1.5f64, &[Token::Str("1.5")], &context);
+    assert_tokens_with_context(&1000u16, &[Token::String("1000")], &context);
+    assert_tokens_with_context(&1000f32, &[Token::Str("1e3")], &context);
+}
+
+#[test]
+fn coerce_numbers_disabled_err() {
+    let context = context_with(CoerceNumbers::new(false));
+
+    assert_tokens_with_context_error::<u8>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": <&str>::reflection(),
+                "location": []
+            }
+        }]),
+        &[Token::Str("12")],
+        &context,
+    );
+}
+
+#[test]
+fn coerce_numbers_invalid_err() {
+    let context = context_with(CoerceNumbers::new(true));
+
+    assert_tokens_with_context_error::<u8>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": "twelve",
+                "location": []
+            }
+        }]),
+        &[Token::Str("twelve")],
+        &context,
+    );
+
+    assert_tokens_with_context_error::<u8>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": []
+            }
+        }]),
+        &[Token::Str("256")],
+        &context,
+    );
+}
+
+#[test]
+fn max_string_length_ok() {
+    let context = context_with(MaxStringLength::new(4));
+
+    assert_tokens_with_context(&"abcd", &[Token::BorrowedStr("abcd")], &context);
+    assert_tokens_with_context(
+        &b"abcd".as_slice(),
+        &[Token::BorrowedBytes(b"abcd")],
+        &context,
+    );
+}
+
+#[test]
+fn max_string_length_err() {
+    let context = context_with(MaxStringLength::new(4));
+
+    assert_tokens_with_context_error::<&str>(
+        &error!([{
+            ns: "deer",
+            id: ["limit", "string"],
+            properties: {
+                "limit": 4,
+                "received": 5,
+                "location": []
+            }
+        }]),
+        &[Token::BorrowedStr("abcde")],
+        &context,
+    );
+
+    assert_tokens_with_context_error::<&[u8]>(
+        &error!([{
+            ns: "deer",
+            id: ["limit", "string"],
+            properties: {
+                "limit": 4,
+                "received": 5,
+                "location": []
+            }
+        }]),
+        &[Token::BorrowedBytes(b"abcde")],
+        &context,
+    );
+}
+
+#[test]
+fn max_string_length_value_err() {
+    let context = context_with(MaxStringLength::new(2));
+
+    assert_tokens_with_context_error::<Value>(
+        &error!([{
+            ns: "deer",
+            id: ["limit", "string"],
+            properties: {
+                "limit": 2,
+                "received": 3,
+                "location": [{"type": "array", "value": 1}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(2) },
+            Token::Str("ab"),
+            Token::Str("abc"),
+            Token::ArrayEnd,
+        ],
+        &context,
+    );
+}
+
+#[test]
+fn max_collection_length_ok() {
+    let context = context_with(MaxCollectionLength::new(2));
+
+    assert_tokens_with_context(
+        &Value::Array(vec![Value::Null, Value::Bool(true)]),
+        &[
+            Token::Array { length: Some(2) },
+            Token::Null,
+            Token::Bool(true),
+            Token::ArrayEnd,
+        ],
+        &context,
+    );
+}
+
+#[test]
+fn max_collection_length_array_err() {
+    let context = context_with(MaxCollectionLength::new(2));
+
+    assert_tokens_with_context_error::<Value>(
+        &error!([{
+            ns: "deer",
+            id: ["limit", "collection"],
+            properties: {
+                "limit": 2,
+                "received": 3,
+                "location": []
+            }
+        }]),
+        &[
+            Token::Array { length: Some(3) },
+            Token::Null,
+            Token::Null,
+            Token::Null,
+            Token::ArrayEnd,
+        ],
+        &context,
+    );
+}
+
+#[test]
+fn max_collection_length_object_err() {
+    let context = context_with(MaxCollectionLength::new(1));
+
+    assert_tokens_with_context_error::<Value>(
+        &error!([{
+            ns: "deer",
+            id: ["limit", "collection"],
+            properties: {
+                "limit": 1,
+                "received": null,
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: None },
+            Token::Str("a"),
+            Token::Null,
+            Token::Str("b"),
+            Token::Null,
+            Token::ObjectEnd,
+        ],
+        &context,
+    );
+}
+
+#[test]
+fn unknown_fields_ignored_ok() {
+    let context = context_with(DenyUnknownFields::new(false));
+
+    assert_tokens_with_context(
+        &(1u8..2),
+        &[
+            Token::Object { length: Some(3) },
+            Token::Str("start"),
+            Token::Number(Number::from(1)),
+            Token::Str("step"),
+            Token::Array { length: Some(1) },
+            Token::Number(Number::from(1)),
+            Token::ArrayEnd,
+            Token::Str("end"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+        ],
+        &context,
+    );
+}
+
+#[test]
+fn unknown_fields_denied_err() {
+    let context = context_with(DenyUnknownFields::new(true));
+
+    assert_tokens_with_context_error::<Range<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["unknown", "identifier"],
+            properties: {
+                "expected": ["start", "end"],
+                "received": ["step"],
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: Some(3) },
+            Token::Str("start"),
+            Token::Number(Number::from(1)),
+            Token::Str("step"),
+            Token::Number(Number::from(1)),
+            Token::Str("end"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+        ],
+        &context,
+    );
+}
@@ -4,10 +4,10 @@ use deer::{
         ObjectLengthError, ReceivedLength, ReceivedVariant, UnknownVariantError, Variant,
         VisitorError,
     },
-    helpers::ExpectNone,
+    helpers::{deserialize_untagged, AdjacentlyTaggedVisitor, ExpectNone, InternallyTaggedVisitor},
     schema::Reference,
     Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor, ObjectAccess, Reflection,
-    Schema, Visitor,
+    Schema, Value, Visitor,
 };
 use deer_desert::{assert_tokens, assert_tokens_error, error, Token};
 use error_stack::{Report, Result, ResultExt};
@@ -461,59 +461,260 @@ fn struct_variant() {
     );
 }
 
-#[allow(unused)]
-enum InternallyTaggedMessage {
-    Request {
-        id: String,
-        method: String,
-        params: u8,
-    },
-    Response {
-        id: String,
-        result: u64,
-    },
+#[derive(Debug, PartialEq)]
+enum Message {
+    Ping,
+    Pong(Value),
+}
+
+enum MessageDiscriminant {
+    Ping,
+    Pong,
+}
+
+impl Reflection for MessageDiscriminant {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("string").with("enum", ["Ping", "Pong"])
+    }
+}
+
+struct MessageDiscriminantVisitor;
+
+impl<'de> Visitor<'de> for MessageDiscriminantVisitor {
+    type Value = MessageDiscriminant;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, VisitorError> {
+        match value {
+            "Ping" => Ok(MessageDiscriminant::Ping),
+            "Pong" => Ok(MessageDiscriminant::Pong),
+            _ => Err(Report::new(UnknownVariantError.into_error())
+                .attach(ExpectedVariant::new("Ping"))
+                .attach(ExpectedVariant::new("Pong"))
+                .attach(ReceivedVariant::new(value))
+                .change_context(VisitorError)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageDiscriminant {
+    type Reflection = Self;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_str(MessageDiscriminantVisitor)
+            .change_context(DeserializeError)
+    }
+}
+
+struct MessageVisitor;
+
+impl<'de> EnumVisitor<'de> for MessageVisitor {
+    type Discriminant = MessageDiscriminant;
+    type Value = Message;
+
+    fn expecting(&self) -> Document {
+        MessageDiscriminant::reflection()
+    }
+
+    fn visit_value<D>(
+        self,
+        discriminant: Self::Discriminant,
+        deserializer: D,
+    ) -> Result<Self::Value, VisitorError>
+    where
+        D: Deserializer<'de>,
+    {
+        match discriminant {
+            MessageDiscriminant::Ping => ExpectNone::deserialize(deserializer)
+                .map(|_| Message::Ping)
+                .change_context(VisitorError),
+            MessageDiscriminant::Pong => Value::deserialize(deserializer)
+                .map(Message::Pong)
+                .change_context(VisitorError),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct InternallyTaggedMessage(Message);
+
+impl<'de> Deserialize<'de> for InternallyTaggedMessage {
+    type Reflection = MessageDiscriminant;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_object(InternallyTaggedVisitor::new("type", MessageVisitor))
+            .map(Self)
+            .change_context(DeserializeError)
+    }
 }
 
 #[test]
-#[ignore = "requires `Value<'de>` / `Content<'de>` variant"]
 fn internally_tagged() {
-    unimplemented!()
+    assert_tokens(&InternallyTaggedMessage(Message::Ping), &[
+        Token::Object { length: Some(1) },
+        Token::String("type"),
+        Token::String("Ping"),
+        Token::ObjectEnd,
+    ]);
+
+    // the tag does not need to be the first field
+    assert_tokens(
+        &InternallyTaggedMessage(Message::Pong(Value::Object(vec![(
+            Value::String("id".to_owned()),
+            Value::Number(12u8.into()),
+        )]))),
+        &[
+            Token::Object { length: Some(2) },
+            Token::String("id"),
+            Token::Number(12u8.into()),
+            Token::String("type"),
+            Token::String("Pong"),
+            Token::ObjectEnd,
+        ],
+    );
+
+    assert_tokens_error::<InternallyTaggedMessage>(
+        &error!([
+            {
+                ns: "deer",
+                id: ["value", "missing"],
+                properties: {
+                    "expected": MessageDiscriminant::reflection(),
+                    "location": [{"type": "field", "value": "type"}]
+                }
+            }
+        ]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::String("id"),
+            Token::Number(12u8.into()),
+            Token::ObjectEnd,
+        ],
+    );
 }
 
-#[allow(unused)]
-enum AdjacentlyTaggedMessage {
-    Request {
-        id: String,
-        method: String,
-        params: u8,
-    },
-    Response {
-        id: String,
-        result: u64,
-    },
+#[derive(Debug, PartialEq)]
+struct AdjacentlyTaggedMessage(Message);
+
+impl<'de> Deserialize<'de> for AdjacentlyTaggedMessage {
+    type Reflection = MessageDiscriminant;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_object(AdjacentlyTaggedVisitor::new("t", "c", MessageVisitor))
+            .map(Self)
+            .change_context(DeserializeError)
+    }
 }
 
 #[test]
-#[ignore = "requires `Value<'de>` / `Content<'de>` if the tag is not first"]
 fn adjacently_tagged() {
-    unimplemented!()
+    assert_tokens(&AdjacentlyTaggedMessage(Message::Ping), &[
+        Token::Object { length: Some(1) },
+        Token::String("t"),
+        Token::String("Ping"),
+        Token::ObjectEnd,
+    ]);
+
+    // the content may come before the tag
+    assert_tokens(
+        &AdjacentlyTaggedMessage(Message::Pong(Value::Bool(true))),
+        &[
+            Token::Object { length: Some(2) },
+            Token::String("c"),
+            Token::Bool(true),
+            Token::String("t"),
+            Token::String("Pong"),
+            Token::ObjectEnd,
+        ],
+    );
+
+    assert_tokens_error::<AdjacentlyTaggedMessage>(
+        &error!([
+            {
+                ns: "deer",
+                id: ["value", "missing"],
+                properties: {
+                    "expected": MessageDiscriminant::reflection(),
+                    "location": [{"type": "field", "value": "t"}]
+                }
+            }
+        ]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::String("c"),
+            Token::Bool(true),
+            Token::ObjectEnd,
+        ],
+    );
 }
 
-#[allow(unused)]
+#[derive(Debug, PartialEq)]
 enum UntaggedMessage {
-    Request {
-        id: String,
-        method: String,
-        params: u8,
-    },
-    Response {
-        id: String,
-        result: u64,
-    },
+    Id(u8),
+    Flag(bool),
+}
+
+impl Reflection for UntaggedMessage {
+    fn schema(doc: &mut Document) -> Schema {
+        Schema::new("object").with("oneOf", [doc.add::<u8>(), doc.add::<bool>()])
+    }
+}
+
+impl<'de> Deserialize<'de> for UntaggedMessage {
+    type Reflection = Self;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserialize_untagged(deserializer, Self::reflection(), &[
+            ("Id", |deserializer| {
+                u8::deserialize(deserializer).map(UntaggedMessage::Id)
+            }),
+            ("Flag", |deserializer| {
+                bool::deserialize(deserializer).map(UntaggedMessage::Flag)
+            }),
+        ])
+    }
 }
 
 #[test]
-#[ignore = "required `Value<'de>` / `Content<'de>`"]
 fn untagged() {
-    unimplemented!()
+    assert_tokens(&UntaggedMessage::Id(12), &[Token::Number(12u8.into())]);
+    assert_tokens(&UntaggedMessage::Flag(true), &[Token::Bool(true)]);
+
+    assert_tokens_error::<UntaggedMessage>(
+        &error!([
+            {
+                ns: "deer",
+                id: ["unknown", "untagged"],
+                properties: {
+                    "expected": UntaggedMessage::reflection(),
+                    "location": []
+                }
+            },
+            {
+                ns: "deer",
+                id: ["type"],
+                properties: {
+                    "expected": u8::reflection(),
+                    "received": <()>::reflection(),
+                    "location": [{"type": "variant", "value": "Id"}]
+                }
+            },
+            {
+                ns: "deer",
+                id: ["type"],
+                properties: {
+                    "expected": bool::reflection(),
+                    "received": <()>::reflection(),
+                    "location": [{"type": "variant", "value": "Flag"}]
+                }
+            }
+        ]),
+        &[Token::Null],
+    );
 }
@@ -0,0 +1,65 @@
+use core::{
+    cmp::Ordering,
+    num::NonZeroU8,
+    ops::{Bound, Range},
+};
+
+use deer::Value;
+use deer_desert::{
+    fuzz::{prop_assert_tokens, prop_assert_tokens_total},
+    strategy::{any_tokens, tokens},
+};
+use proptest::prelude::*;
+
+macro_rules! fuzz {
+    ($($name:ident: $ty:ty),* $(,)?) => {
+        #[cfg(not(miri))]
+        mod valid {
+            use super::*;
+
+            proptest! {
+                $(
+                #[test]
+                fn $name(tokens in tokens::<$ty>()) {
+                    prop_assert_tokens::<$ty>(&tokens)?;
+                }
+                )*
+            }
+        }
+
+        #[cfg(not(miri))]
+        mod total {
+            use super::*;
+
+            proptest! {
+                $(
+                #[test]
+                fn $name(tokens in any_tokens()) {
+                    prop_assert_tokens_total::<$ty>(&tokens)?;
+                }
+                )*
+            }
+        }
+    };
+}
+
+fuzz! {
+    bool: bool,
+    char: char,
+    u8: u8,
+    i64: i64,
+    u128: u128,
+    i128: i128,
+    f32: f32,
+    non_zero_u8: NonZeroU8,
+    unit: (),
+    option: Option<u8>,
+    result: Result<u8, bool>,
+    tuple: (u8, bool, char),
+    nested_tuple: ((u8,), Option<bool>),
+    array: [u16; 3],
+    ordering: Ordering,
+    range: Range<u8>,
+    bound: Bound<i8>,
+    value: Value,
+}
@@ -0,0 +1,127 @@
+use deer::{value::IntoDeserializer, Context, Deserialize, Number, Value};
+use deer_desert::{assert_tokens, Token};
+use proptest::prelude::*;
+
+#[test]
+fn primitives_ok() {
+    assert_tokens(&Value::Null, &[Token::Null]);
+    assert_tokens(&Value::Bool(true), &[Token::Bool(true)]);
+    assert_tokens(&Value::Number(Number::from(12)), &[Token::Number(
+        Number::from(12),
+    )]);
+    assert_tokens(&Value::String("example".to_owned()), &[Token::Str(
+        "example",
+    )]);
+    assert_tokens(&Value::String("a".to_owned()), &[Token::Char('a')]);
+    assert_tokens(&Value::Bytes(vec![0, 1]), &[Token::Bytes(&[0, 1])]);
+}
+
+#[test]
+fn nested_ok() {
+    let expected = Value::Object(vec![
+        (
+            Value::String("a".to_owned()),
+            Value::Array(vec![Value::Number(Number::from(1)), Value::Null]),
+        ),
+        (Value::String("b".to_owned()), Value::Bool(false)),
+    ]);
+
+    assert_tokens(&expected, &[
+        Token::Object { length: Some(2) },
+        Token::String("a"),
+        Token::Array { length: Some(2) },
+        Token::Number(Number::from(1)),
+        Token::Null,
+        Token::ArrayEnd,
+        Token::String("b"),
+        Token::Bool(false),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn object_retains_order_and_duplicates() {
+    let expected = Value::Object(vec![
+        (Value::String("b".to_owned()), Value::Null),
+        (Value::String("a".to_owned()), Value::Null),
+        (Value::String("b".to_owned()), Value::Bool(true)),
+    ]);
+
+    assert_tokens(&expected, &[
+        Token::Object { length: Some(3) },
+        Token::String("b"),
+        Token::Null,
+        Token::String("a"),
+        Token::Null,
+        Token::String("b"),
+        Token::Bool(true),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn deserialize_twice() {
+    let context = Context::new();
+
+    let value = Value::Object(vec![(
+        Value::String("Ok".to_owned()),
+        Value::Number(Number::from(8)),
+    )]);
+
+    let result = Result::<u8, bool>::deserialize(value.clone().into_deserializer(&context))
+        .expect("should be able to deserialize `Result`");
+    assert_eq!(result, Ok(8));
+
+    let result = Result::<u16, bool>::deserialize(value.into_deserializer(&context))
+        .expect("should be able to deserialize `Result`");
+    assert_eq!(result, Ok(8));
+}
+
+#[test]
+fn array_into_tuple() {
+    let context = Context::new();
+
+    let value = Value::Array(vec![Value::Bool(true), Value::Number(Number::from(2))]);
+
+    let tuple = <(bool, u8)>::deserialize(value.into_deserializer(&context))
+        .expect("should be able to deserialize tuple");
+    assert_eq!(tuple, (true, 2));
+}
+
+#[test]
+fn array_into_tuple_too_many_err() {
+    let context = Context::new();
+
+    let value = Value::Array(vec![Value::Bool(true), Value::Bool(false)]);
+
+    let result = <(bool,)>::deserialize(value.into_deserializer(&context));
+    assert!(result.is_err());
+}
+
+#[test]
+fn optional() {
+    let context = Context::new();
+
+    let value = Option::<bool>::deserialize(Value::Null.into_deserializer(&context))
+        .expect("should be able to deserialize `Option`");
+    assert_eq!(value, None);
+
+    let value = Option::<bool>::deserialize(Value::Bool(true).into_deserializer(&context))
+        .expect("should be able to deserialize `Option`");
+    assert_eq!(value, Some(true));
+}
+
+#[cfg(not(miri))]
+proptest! {
+    #[test]
+    fn number_round_trip(expected in any::<u64>()) {
+        let context = Context::new();
+
+        let value = Value::deserialize(Number::from(expected).into_deserializer(&context))
+            .expect("should be able to deserialize `Value`");
+        let received = u64::deserialize(value.into_deserializer(&context))
+            .expect("should be able to deserialize `u64`");
+
+        prop_assert_eq!(expected, received);
+    }
+}
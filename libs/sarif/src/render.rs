@@ -0,0 +1,356 @@
+//! Human-readable rendering of SARIF logs.
+//!
+//! [`TerminalRenderer`] renders results as compiler-style diagnostics styled with [`antsi`], while
+//! [`MarkdownRenderer`] renders a summary of each run, suitable for example for pull request
+//! comments. Both renderers write to any [`fmt::Write`].
+//!
+//! Messages are resolved as described in [SARIF specification §3.11.7]: if a message does not
+//! contain a string itself, it is looked up in the [`message_strings`] of the rule the result is
+//! relevant to. Placeholders in the resolved string are substituted by the message
+//! [`arguments`] (see [`format_message`]).
+//!
+//! [SARIF specification §3.11.7]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317468
+//! [`message_strings`]: ReportingDescriptor::message_strings
+//! [`arguments`]: Message::arguments
+
+mod markdown;
+mod terminal;
+
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Write};
+
+pub use self::{
+    markdown::MarkdownRenderer,
+    terminal::{TerminalRenderer, Theme},
+};
+use crate::schema::{ArtifactLocation, Location, Message, ReportingDescriptor, Run};
+
+/// The format in which a message string is requested.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MessageFormat {
+    /// A plain text message string.
+    Text,
+    /// A message string formatted in GitHub-Flavored Markdown.
+    ///
+    /// If no Markdown string is available, the plain text string is used instead.
+    Markdown,
+}
+
+/// Looks up the message string of `message` without substituting any placeholders.
+///
+/// The string of the message itself is preferred, otherwise the string is looked up by the
+/// [`id`] of the message in the [`message_strings`] of `rule`. Returns `None` if no string could
+/// be found, which means the message is invalid.
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     render::{message_string, MessageFormat},
+///     schema::{Message, MultiformatMessageString, ReportingDescriptor},
+/// };
+///
+/// let rule = ReportingDescriptor::new("unused_variables").with_message_string(
+///     "default",
+///     MultiformatMessageString::new("unused variable: {0}")
+///         .with_markdown("unused variable: `{0}`"),
+/// );
+/// let message = Message::from_id("default").with_arguments(["x"]);
+///
+/// assert_eq!(
+///     message_string(&message, Some(&rule), MessageFormat::Markdown),
+///     Some("unused variable: `{0}`")
+/// );
+/// assert_eq!(message_string(&message, None, MessageFormat::Text), None);
+/// ```
+///
+/// [`id`]: Message::id
+/// [`message_strings`]: ReportingDescriptor::message_strings
+#[must_use]
+pub fn message_string<'a>(
+    message: &'a Message<'_>,
+    rule: Option<&'a ReportingDescriptor<'_>>,
+    format: MessageFormat,
+) -> Option<&'a str> {
+    if let Some(text) = &message.text {
+        return Some(select_format(text, message.markdown.as_deref(), format));
+    }
+
+    let string = rule?.message_strings.get(message.id.as_deref()?)?;
+    Some(select_format(
+        &string.text,
+        string.markdown.as_deref(),
+        format,
+    ))
+}
+
+/// Looks up the message string of `message` and substitutes its placeholders.
+///
+/// Every placeholder `{n}` is replaced by the `n`-th element of [`arguments`], and the escape
+/// sequences `{{` and `}}` are replaced by `{` and `}`. Placeholders without a corresponding
+/// argument are kept as they are.
+///
+/// See [`message_string`] for how the message string is looked up.
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     render::{format_message, MessageFormat},
+///     schema::Message,
+/// };
+///
+/// let message = Message::new("The variable \"{0}\" defined on line {1} is never used.")
+///     .with_arguments(["pBuffer", "42"]);
+///
+/// assert_eq!(
+///     format_message(&message, None, MessageFormat::Text).unwrap(),
+///     "The variable \"pBuffer\" defined on line 42 is never used."
+/// );
+/// ```
+///
+/// [`arguments`]: Message::arguments
+#[must_use]
+pub fn format_message(
+    message: &Message<'_>,
+    rule: Option<&ReportingDescriptor<'_>>,
+    format: MessageFormat,
+) -> Option<String> {
+    let template = message_string(message, rule, format)?;
+
+    let mut output = String::with_capacity(template.len());
+    write_message(&mut output, template, &message.arguments).ok()?;
+    Some(output)
+}
+
+/// Returns the help text of `rule`.
+///
+/// The [`help`] of the rule is preferred, otherwise its [`full_description`] is used.
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     render::{rule_help, MessageFormat},
+///     schema::{MultiformatMessageString, ReportingDescriptor},
+/// };
+///
+/// let rule = ReportingDescriptor::new("unused_variables").with_full_description(
+///     MultiformatMessageString::new("detects variables which are not used in any way"),
+/// );
+///
+/// assert_eq!(
+///     rule_help(&rule, MessageFormat::Markdown),
+///     Some("detects variables which are not used in any way")
+/// );
+/// ```
+///
+/// [`help`]: ReportingDescriptor::help
+/// [`full_description`]: ReportingDescriptor::full_description
+#[must_use]
+pub fn rule_help<'a>(rule: &'a ReportingDescriptor<'_>, format: MessageFormat) -> Option<&'a str> {
+    rule.help
+        .as_ref()
+        .or(rule.full_description.as_ref())
+        .map(|help| select_format(&help.text, help.markdown.as_deref(), format))
+}
+
+const fn select_format<'a>(
+    text: &'a str,
+    markdown: Option<&'a str>,
+    format: MessageFormat,
+) -> &'a str {
+    match (format, markdown) {
+        (MessageFormat::Markdown, Some(markdown)) => markdown,
+        _ => text,
+    }
+}
+
+fn write_message(
+    output: &mut impl Write,
+    template: &str,
+    arguments: &[Cow<'_, str>],
+) -> fmt::Result {
+    let mut remaining = template;
+
+    while let Some(position) = remaining.find(['{', '}']) {
+        let (text, tail) = remaining.split_at(position);
+        output.write_str(text)?;
+
+        if let Some(tail) = tail.strip_prefix("{{") {
+            output.write_char('{')?;
+            remaining = tail;
+        } else if let Some(tail) = tail.strip_prefix("}}") {
+            output.write_char('}')?;
+            remaining = tail;
+        } else if let Some((argument, tail)) = placeholder(tail, arguments) {
+            output.write_str(argument)?;
+            remaining = tail;
+        } else {
+            // A lone brace or a placeholder without argument is written as is
+            let (brace, tail) = tail.split_at(1);
+            output.write_str(brace)?;
+            remaining = tail;
+        }
+    }
+
+    output.write_str(remaining)
+}
+
+/// Parses a placeholder at the start of `input` and returns its argument and the remaining input.
+fn placeholder<'a>(input: &'a str, arguments: &'a [Cow<'_, str>]) -> Option<(&'a str, &'a str)> {
+    let (index, tail) = input.strip_prefix('{')?.split_once('}')?;
+    if index.is_empty() || !index.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let argument = arguments.get(index.parse::<usize>().ok()?)?;
+    Some((argument, tail))
+}
+
+/// Returns the URI of an artifact location, falling back to the artifact it refers to.
+fn artifact_uri<'a>(run: &'a Run<'_>, location: &'a ArtifactLocation<'_>) -> Option<&'a str> {
+    location.uri.as_deref().or_else(|| {
+        run.artifacts
+            .get(location.index?)?
+            .location
+            .as_ref()?
+            .uri
+            .as_deref()
+    })
+}
+
+/// Writes a location as `uri:line:column`, omitting unknown parts.
+///
+/// If the location has no physical location, the fully qualified name of its first logical
+/// location is written instead. Returns `false` if nothing was written.
+fn write_location(
+    output: &mut impl Write,
+    run: &Run<'_>,
+    location: &Location<'_>,
+) -> Result<bool, fmt::Error> {
+    let physical_location = location.physical_location.as_ref();
+
+    if let Some(uri) = physical_location
+        .and_then(|location| location.artifact_location.as_ref())
+        .and_then(|location| artifact_uri(run, location))
+    {
+        output.write_str(uri)?;
+
+        let region = physical_location.and_then(|location| location.region.as_ref());
+        if let Some(line) = region.and_then(|region| region.start_line) {
+            write!(output, ":{line}")?;
+            if let Some(column) = region.and_then(|region| region.start_column) {
+                write!(output, ":{column}")?;
+            }
+        }
+
+        return Ok(true);
+    }
+
+    let name = location.logical_locations.first().and_then(|location| {
+        location
+            .fully_qualified_name
+            .as_deref()
+            .or(location.name.as_deref())
+    });
+    if let Some(name) = name {
+        output.write_str(name)?;
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloc::{borrow::Cow, string::String};
+
+    use coverage_helper::test;
+
+    use super::{format_message, message_string, rule_help, write_message, MessageFormat};
+    use crate::schema::{Message, MultiformatMessageString, ReportingDescriptor};
+
+    fn substitute(template: &str, arguments: &[&str]) -> String {
+        let arguments: alloc::vec::Vec<_> = arguments.iter().copied().map(Cow::Borrowed).collect();
+
+        let mut output = String::new();
+        write_message(&mut output, template, &arguments).expect("writing to a string never fails");
+        output
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(substitute("{0} and {1}", &["a", "b"]), "a and b");
+        assert_eq!(substitute("{1}{0}{1}", &["a", "b"]), "bab");
+        assert_eq!(substitute("no placeholders", &["a"]), "no placeholders");
+        assert_eq!(substitute("", &[]), "");
+    }
+
+    #[test]
+    fn escaped_braces() {
+        assert_eq!(substitute("{{0}}", &["a"]), "{0}");
+        assert_eq!(substitute("{{{0}}}", &["a"]), "{a}");
+        assert_eq!(substitute("fn main() {{}}", &[]), "fn main() {}");
+    }
+
+    #[test]
+    fn invalid_placeholders() {
+        assert_eq!(substitute("{2}", &["a"]), "{2}");
+        assert_eq!(substitute("{}", &["a"]), "{}");
+        assert_eq!(substitute("{+0}", &["a"]), "{+0}");
+        assert_eq!(substitute("{x}", &["a"]), "{x}");
+        assert_eq!(substitute("{0", &["a"]), "{0");
+        assert_eq!(substitute("}", &[]), "}");
+        assert_eq!(substitute("{ü}", &[]), "{ü}");
+    }
+
+    #[test]
+    fn lookup() {
+        let rule = ReportingDescriptor::new("unused_variables").with_message_string(
+            "default",
+            MultiformatMessageString::new("unused variable: {0}")
+                .with_markdown("unused variable: `{0}`"),
+        );
+
+        let message = Message::from_id("default").with_arguments(["x"]);
+        assert_eq!(
+            format_message(&message, Some(&rule), MessageFormat::Text).as_deref(),
+            Some("unused variable: x")
+        );
+        assert_eq!(
+            format_message(&message, Some(&rule), MessageFormat::Markdown).as_deref(),
+            Some("unused variable: `x`")
+        );
+        assert_eq!(format_message(&message, None, MessageFormat::Text), None);
+
+        let message = Message::from_id("unknown");
+        assert_eq!(
+            message_string(&message, Some(&rule), MessageFormat::Text),
+            None
+        );
+
+        let message = Message::new("unused variable: x").with_markdown("unused variable: `x`");
+        assert_eq!(
+            message_string(&message, Some(&rule), MessageFormat::Markdown),
+            Some("unused variable: `x`")
+        );
+        assert_eq!(
+            message_string(&Message::new("text"), None, MessageFormat::Markdown),
+            Some("text")
+        );
+    }
+
+    #[test]
+    fn help() {
+        let rule = ReportingDescriptor::new("unused_variables");
+        assert_eq!(rule_help(&rule, MessageFormat::Text), None);
+
+        let rule = rule.with_full_description(MultiformatMessageString::new("description"));
+        assert_eq!(rule_help(&rule, MessageFormat::Text), Some("description"));
+
+        let rule = rule.with_help(MultiformatMessageString::new("help").with_markdown("**help**"));
+        assert_eq!(rule_help(&rule, MessageFormat::Text), Some("help"));
+        assert_eq!(rule_help(&rule, MessageFormat::Markdown), Some("**help**"));
+    }
+}
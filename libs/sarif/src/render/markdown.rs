@@ -0,0 +1,314 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use crate::{
+    render::{format_message, rule_help, write_location, MessageFormat},
+    schema::{Level, ReportingDescriptor, Run, SarifLog},
+};
+
+/// Renders a summary of the results as GitHub-Flavored Markdown.
+///
+/// Every run is rendered as a section with the number of results per level and a table of all
+/// results. The help of every rule referenced by a result is listed below the table.
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     render::MarkdownRenderer,
+///     schema::{Level, Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent},
+/// };
+///
+/// let log = SarifLog::new(SchemaVersion::V2_1_0).with_run(
+///     Run::new(Tool::new(ToolComponent::new("rustc"))).with_result(
+///         Result::new(Message::new("mismatched types"))
+///             .with_rule_id("E0308")
+///             .with_level(Level::Error),
+///     ),
+/// );
+///
+/// let mut output = String::new();
+/// MarkdownRenderer::new().render_log(&mut output, &log)?;
+///
+/// assert_eq!(
+///     output,
+///     "## rustc\n\n**1** error\n\n| Level | Rule | Message | Location |\n| --- | --- | --- | \
+///      --- |\n| error | `E0308` | mismatched types | |\n"
+/// );
+/// # Ok::<(), core::fmt::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownRenderer {
+    help: bool,
+}
+
+impl MarkdownRenderer {
+    /// Creates a new `MarkdownRenderer` rendering rule help.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { help: true }
+    }
+
+    /// Sets whether the help of the rules referenced by the results is rendered.
+    #[must_use]
+    pub const fn with_help(mut self, help: bool) -> Self {
+        self.help = help;
+        self
+    }
+
+    /// Renders every run in `log`, separated by an empty line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `output` fails.
+    pub fn render_log(&self, output: &mut impl Write, log: &SarifLog<'_>) -> fmt::Result {
+        for (index, run) in log.runs.iter().flatten().enumerate() {
+            if index > 0 {
+                writeln!(output)?;
+            }
+            self.render_run(output, run)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders a summary of `run`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `output` fails.
+    pub fn render_run(&self, output: &mut impl Write, run: &Run<'_>) -> fmt::Result {
+        let driver = &run.tool.driver;
+        write!(output, "## {}", driver.name)?;
+        if let Some(version) = &driver.version {
+            write!(output, " {version}")?;
+        }
+        writeln!(output)?;
+        writeln!(output)?;
+
+        if run.results.is_empty() {
+            return writeln!(output, "No results.");
+        }
+
+        let mut counts = [0_usize; 4];
+        for result in &run.results {
+            counts[result.effective_level() as usize] += 1;
+        }
+        let summary = [
+            (counts[Level::Error as usize], "error", "errors"),
+            (counts[Level::Warning as usize], "warning", "warnings"),
+            (counts[Level::Note as usize], "note", "notes"),
+            (
+                counts[Level::None as usize],
+                "other result",
+                "other results",
+            ),
+        ];
+        for (index, (count, singular, plural)) in summary
+            .into_iter()
+            .filter(|(count, ..)| *count > 0)
+            .enumerate()
+        {
+            if index > 0 {
+                output.write_str(", ")?;
+            }
+            let noun = if count == 1 { singular } else { plural };
+            write!(output, "**{count}** {noun}")?;
+        }
+        writeln!(output)?;
+        writeln!(output)?;
+
+        writeln!(output, "| Level | Rule | Message | Location |")?;
+        writeln!(output, "| --- | --- | --- | --- |")?;
+
+        let mut rules = Vec::<&ReportingDescriptor<'_>>::new();
+        for result in &run.results {
+            let rule = run.rule(result);
+            if let Some(rule) = rule {
+                if !rules.iter().any(|existing| existing.id == rule.id) {
+                    rules.push(rule);
+                }
+            }
+
+            let level = match result.effective_level() {
+                Level::Error => "error",
+                Level::Warning => "warning",
+                Level::Note => "note",
+                Level::None => "none",
+            };
+            write!(output, "| {level} | ")?;
+
+            if let Some(rule_id) = run.rule_id(result) {
+                match rule.and_then(|rule| rule.help_uri.as_ref()) {
+                    Some(help_uri) => write!(output, "[`{rule_id}`]({help_uri})")?,
+                    None => write!(output, "`{rule_id}`")?,
+                }
+                output.write_char(' ')?;
+            }
+            output.write_str("| ")?;
+
+            let message = format_message(&result.message, rule, MessageFormat::Markdown);
+            write_cell(output, message.as_deref().unwrap_or("*missing message*"))?;
+            output.write_str(" | ")?;
+
+            let mut location = String::new();
+            if let Some(primary) = result.locations.first() {
+                write_location(&mut location, run, primary)?;
+            }
+            if !location.is_empty() {
+                output.write_char('`')?;
+                write_cell(output, &location)?;
+                output.write_str("` ")?;
+            }
+            writeln!(output, "|")?;
+        }
+
+        if !self.help {
+            return Ok(());
+        }
+
+        let rules_with_help = rules
+            .into_iter()
+            .filter_map(|rule| Some((rule, rule_help(rule, MessageFormat::Markdown)?)));
+        for (index, (rule, help)) in rules_with_help.enumerate() {
+            if index == 0 {
+                writeln!(output)?;
+                writeln!(output, "### Rules")?;
+            }
+
+            writeln!(output)?;
+            writeln!(output, "#### `{}`", rule.id)?;
+            writeln!(output)?;
+            writeln!(output, "{}", help.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for MarkdownRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes `text` so that it can be used as content of a table cell.
+fn write_cell(output: &mut impl Write, text: &str) -> fmt::Result {
+    for char in text.trim().chars() {
+        match char {
+            '|' => output.write_str("\\|")?,
+            '\r' => {}
+            '\n' => output.write_char(' ')?,
+            _ => output.write_char(char)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloc::string::String;
+
+    use coverage_helper::test;
+    use url::Url;
+
+    use super::MarkdownRenderer;
+    use crate::schema::{
+        ArtifactLocation, Level, Location, Message, MultiformatMessageString, PhysicalLocation,
+        Region, ReportingDescriptor, Result, ResultKind, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent,
+    };
+
+    fn render(renderer: &MarkdownRenderer, log: &SarifLog<'_>) -> String {
+        let mut output = String::new();
+        renderer
+            .render_log(&mut output, log)
+            .expect("writing to a string never fails");
+        output
+    }
+
+    fn log() -> SarifLog<'static> {
+        let tool = Tool::new(
+            ToolComponent::new("clippy")
+                .with_version("0.1.70")
+                .with_rule(
+                    ReportingDescriptor::new("unused_variables")
+                        .with_message_string(
+                            "default",
+                            MultiformatMessageString::new("unused variable: {0}")
+                                .with_markdown("unused variable: `{0}`"),
+                        )
+                        .with_help(
+                            MultiformatMessageString::new("prefix it with an underscore")
+                                .with_markdown("prefix it with an **underscore**\n"),
+                        )
+                        .with_help_uri(
+                            Url::parse("https://doc.rust-lang.org/rustc/lints/index.html")
+                                .expect("valid URL"),
+                        ),
+                )
+                .with_rule(ReportingDescriptor::new("bitwise_or")),
+        );
+
+        let location = |line| {
+            Location::new().with_physical_location(
+                PhysicalLocation::new(ArtifactLocation::new("src/main.rs"))
+                    .with_region(Region::new().with_start_line(line)),
+            )
+        };
+
+        SarifLog::new(SchemaVersion::V2_1_0)
+            .with_run(
+                Run::new(tool).with_results([
+                    Result::new(Message::from_id("default").with_arguments(["x"]))
+                        .with_rule_index(0)
+                        .with_location(location(2)),
+                    Result::new(Message::from_id("default").with_arguments(["y"]))
+                        .with_rule_id("unused_variables")
+                        .with_location(location(3)),
+                    Result::new(Message::new("use `a | b`\ninstead of `a || b`"))
+                        .with_rule_index(1)
+                        .with_level(Level::Error),
+                    Result::new(Message::new("no unsafe code")).with_kind(ResultKind::Pass),
+                ]),
+            )
+            .with_run(Run::new(Tool::new(ToolComponent::new("rustfmt"))))
+    }
+
+    #[test]
+    fn full() {
+        assert_eq!(
+            render(&MarkdownRenderer::new(), &log()),
+            r"## clippy 0.1.70
+
+**1** error, **2** warnings, **1** other result
+
+| Level | Rule | Message | Location |
+| --- | --- | --- | --- |
+| warning | [`unused_variables`](https://doc.rust-lang.org/rustc/lints/index.html) | unused variable: `x` | `src/main.rs:2` |
+| warning | [`unused_variables`](https://doc.rust-lang.org/rustc/lints/index.html) | unused variable: `y` | `src/main.rs:3` |
+| error | `bitwise_or` | use `a \| b` instead of `a \|\| b` | |
+| none | | no unsafe code | |
+
+### Rules
+
+#### `unused_variables`
+
+prefix it with an **underscore**
+
+## rustfmt
+
+No results.
+"
+        );
+    }
+
+    #[test]
+    fn without_help() {
+        let output = render(&MarkdownRenderer::new().with_help(false), &log());
+
+        assert!(!output.contains("### Rules"));
+        assert!(output.ends_with("| none | | no unsafe code | |\n\n## rustfmt\n\nNo results.\n"));
+    }
+}
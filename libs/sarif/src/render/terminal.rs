@@ -0,0 +1,631 @@
+use alloc::string::String;
+use core::fmt::{self, Display, Write};
+
+use antsi::{BasicColor, Color, Font, FontWeight, Foreground, Style};
+
+use crate::{
+    render::{format_message, rule_help, write_location, MessageFormat},
+    schema::{Level, Location, Result, ResultKind, Run, SarifLog},
+};
+
+/// The styles used by the [`TerminalRenderer`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Theme {
+    /// The style of the label of results with level [`Level::Error`].
+    pub error: Style,
+    /// The style of the label of results with level [`Level::Warning`].
+    pub warning: Style,
+    /// The style of the label of results with level [`Level::Note`].
+    pub note: Style,
+    /// The style of the label of results with level [`Level::None`].
+    pub none: Style,
+    /// The style of the message of a result.
+    pub message: Style,
+    /// The style of the gutter, the location arrows and the markers below a snippet.
+    pub gutter: Style,
+    /// The style of the `help` and `note` labels.
+    pub label: Style,
+}
+
+impl Theme {
+    /// A theme without any styling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::Style;
+    /// use sarif::render::Theme;
+    ///
+    /// assert_eq!(Theme::plain().error, Style::new());
+    /// ```
+    #[must_use]
+    pub const fn plain() -> Self {
+        Self {
+            error: Style::new(),
+            warning: Style::new(),
+            note: Style::new(),
+            none: Style::new(),
+            message: Style::new(),
+            gutter: Style::new(),
+            label: Style::new(),
+        }
+    }
+
+    /// A theme using bold text and the basic colors, modeled after the diagnostics of `rustc`.
+    #[must_use]
+    pub const fn colored() -> Self {
+        const fn bold(color: Option<BasicColor>) -> Style {
+            let style = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+
+            match color {
+                Some(color) => style.with_foreground(Foreground::new(Color::Basic(color))),
+                None => style,
+            }
+        }
+
+        Self {
+            error: bold(Some(BasicColor::Red)),
+            warning: bold(Some(BasicColor::Yellow)),
+            note: bold(Some(BasicColor::Green)),
+            none: bold(Some(BasicColor::Cyan)),
+            message: bold(None),
+            gutter: bold(Some(BasicColor::Blue)),
+            label: bold(None),
+        }
+    }
+
+    const fn level(&self, level: Level) -> &Style {
+        match level {
+            Level::Error => &self.error,
+            Level::Warning => &self.warning,
+            Level::Note => &self.note,
+            Level::None => &self.none,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::colored()
+    }
+}
+
+/// Renders results as compiler-style diagnostics.
+///
+/// Every result is rendered as a header with its level, rule and message, followed by its
+/// location, the snippet of its region if present and the help of its rule:
+///
+/// ```text
+/// warning[unused_variables]: unused variable: `x`
+///  --> src/main.rs:2:9
+///   |
+/// 2 |     let x = 5;
+///   |         ^
+///   |
+///   = help: if this is intentional, prefix it with an underscore: `_x`
+///   = note: for further information visit https://doc.rust-lang.org/rustc/lints/listing/warn-by-default.html#unused-variables
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     render::{TerminalRenderer, Theme},
+///     schema::{Level, Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent},
+/// };
+///
+/// let log = SarifLog::new(SchemaVersion::V2_1_0).with_run(
+///     Run::new(Tool::new(ToolComponent::new("rustc"))).with_result(
+///         Result::new(Message::new("mismatched types"))
+///             .with_rule_id("E0308")
+///             .with_level(Level::Error),
+///     ),
+/// );
+///
+/// let mut output = String::new();
+/// TerminalRenderer::new()
+///     .with_theme(Theme::plain())
+///     .render_log(&mut output, &log)?;
+///
+/// assert_eq!(output, "error[E0308]: mismatched types\n\n");
+/// # Ok::<(), core::fmt::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalRenderer {
+    theme: Theme,
+    help: bool,
+}
+
+impl TerminalRenderer {
+    /// Creates a new `TerminalRenderer` using the [colored theme] and rendering rule help.
+    ///
+    /// [colored theme]: Theme::colored
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            theme: Theme::colored(),
+            help: true,
+        }
+    }
+
+    /// Sets the theme used to style the output.
+    #[must_use]
+    pub const fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets whether the help of the rule of a result is rendered.
+    #[must_use]
+    pub const fn with_help(mut self, help: bool) -> Self {
+        self.help = help;
+        self
+    }
+
+    /// Renders the results of every run in `log`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `output` fails.
+    pub fn render_log(&self, output: &mut impl Write, log: &SarifLog<'_>) -> fmt::Result {
+        for run in log.runs.iter().flatten() {
+            self.render_run(output, run)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the results of `run`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `output` fails.
+    pub fn render_run(&self, output: &mut impl Write, run: &Run<'_>) -> fmt::Result {
+        for result in &run.results {
+            self.render_result(output, run, result)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders a single `result` of `run`, followed by an empty line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `output` fails.
+    pub fn render_result(
+        &self,
+        output: &mut impl Write,
+        run: &Run<'_>,
+        result: &Result<'_>,
+    ) -> fmt::Result {
+        let theme = &self.theme;
+        let rule = run.rule(result);
+
+        let level = result.effective_level();
+        let label = match (level, result.kind) {
+            (Level::Error, _) => "error",
+            (Level::Warning, _) => "warning",
+            (Level::Note, _) => "note",
+            (Level::None, Some(ResultKind::Pass)) => "pass",
+            (Level::None, Some(ResultKind::Review)) => "review",
+            (Level::None, Some(ResultKind::Open)) => "open",
+            (Level::None, Some(ResultKind::NotApplicable)) => "not applicable",
+            (Level::None, _) => "info",
+        };
+
+        match run.rule_id(result) {
+            Some(rule_id) => write!(
+                output,
+                "{}",
+                theme.level(level).apply(format_args!("{label}[{rule_id}]"))
+            )?,
+            None => write!(output, "{}", theme.level(level).apply(label))?,
+        }
+
+        let message = format_message(&result.message, rule, MessageFormat::Text);
+        writeln!(
+            output,
+            "{}",
+            theme.message.apply(format_args!(
+                ": {}",
+                message.as_deref().unwrap_or("<missing message>")
+            ))
+        )?;
+
+        // The gutter is as wide as the largest line number of any snippet
+        let line_number_width = result
+            .locations
+            .iter()
+            .chain(&result.related_locations)
+            .filter_map(|location| location.physical_location.as_ref()?.region.as_ref())
+            .filter_map(|region| {
+                let snippet = region.snippet.as_ref()?.text.as_ref()?;
+                Some(region.start_line? + snippet.lines().count().saturating_sub(1))
+            })
+            .max()
+            .map_or(1, count_digits);
+
+        for location in &result.locations {
+            self.render_location(output, run, location, theme.level(level), line_number_width)?;
+        }
+
+        for location in &result.related_locations {
+            let message = location
+                .message
+                .as_ref()
+                .and_then(|message| format_message(message, rule, MessageFormat::Text));
+
+            write!(output, "{}", theme.note.apply("note"))?;
+            writeln!(
+                output,
+                "{}",
+                theme.message.apply(format_args!(
+                    ": {}",
+                    message.as_deref().unwrap_or("related location")
+                ))
+            )?;
+            self.render_location(output, run, location, &theme.gutter, line_number_width)?;
+        }
+
+        if let Some(rule) = rule.filter(|_| self.help) {
+            if let Some(help) = rule_help(rule, MessageFormat::Text) {
+                self.render_footer(output, "help", help, line_number_width)?;
+            }
+            if let Some(help_uri) = &rule.help_uri {
+                self.render_footer(
+                    output,
+                    "note",
+                    format_args!("for further information visit {help_uri}"),
+                    line_number_width,
+                )?;
+            }
+        }
+
+        writeln!(output)
+    }
+
+    fn render_location(
+        &self,
+        output: &mut impl Write,
+        run: &Run<'_>,
+        location: &Location<'_>,
+        marker_style: &Style,
+        line_number_width: usize,
+    ) -> fmt::Result {
+        let theme = &self.theme;
+
+        let mut rendered = String::new();
+        if !write_location(&mut rendered, run, location)? {
+            return Ok(());
+        }
+        writeln!(
+            output,
+            "{:line_number_width$}{} {rendered}",
+            "",
+            theme.gutter.apply("-->")
+        )?;
+
+        let Some(region) = location
+            .physical_location
+            .as_ref()
+            .and_then(|location| location.region.as_ref())
+        else {
+            return Ok(());
+        };
+        let (Some(start_line), Some(snippet)) = (
+            region.start_line,
+            region
+                .snippet
+                .as_ref()
+                .and_then(|snippet| snippet.text.as_deref()),
+        ) else {
+            return Ok(());
+        };
+
+        let gutter = theme.gutter.apply("|");
+        writeln!(output, "{:line_number_width$} {gutter}", "")?;
+
+        let mut line_count = 0;
+        for (line_number, line) in (start_line..).zip(snippet.lines()) {
+            writeln!(
+                output,
+                "{} {line}",
+                theme
+                    .gutter
+                    .apply(format_args!("{line_number:>line_number_width$} |"))
+            )?;
+            line_count += 1;
+        }
+
+        // Markers are only rendered for regions spanning a single line, columns are 1-based and
+        // the end column is exclusive
+        if line_count == 1
+            && region
+                .end_line
+                .map_or(true, |end_line| end_line == start_line)
+        {
+            if let Some(start_column) = region.start_column {
+                let length = region.end_column.map_or(1, |end_column| {
+                    end_column.saturating_sub(start_column).max(1)
+                });
+
+                writeln!(
+                    output,
+                    "{:line_number_width$} {gutter} {:offset$}{}",
+                    "",
+                    "",
+                    marker_style.apply(Markers(length)),
+                    offset = start_column.saturating_sub(1),
+                )?;
+            }
+        }
+
+        writeln!(output, "{:line_number_width$} {gutter}", "")
+    }
+
+    fn render_footer(
+        &self,
+        output: &mut impl Write,
+        label: &str,
+        text: impl Display,
+        line_number_width: usize,
+    ) -> fmt::Result {
+        let theme = &self.theme;
+
+        let mut rendered = String::new();
+        write!(rendered, "{text}")?;
+
+        let mut lines = rendered.lines();
+        writeln!(
+            output,
+            "{:line_number_width$} {} {}: {}",
+            "",
+            theme.gutter.apply("="),
+            theme.label.apply(label),
+            lines.next().unwrap_or_default()
+        )?;
+
+        // Continuation lines are aligned with the first line of the text
+        let indent = line_number_width + label.len() + 5;
+        for line in lines {
+            if line.is_empty() {
+                writeln!(output)?;
+            } else {
+                writeln!(output, "{:indent$}{line}", "")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for TerminalRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn count_digits(number: usize) -> usize {
+    number
+        .checked_ilog10()
+        .map_or(1, |digits| digits as usize + 1)
+}
+
+struct Markers(usize);
+
+impl Display for Markers {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for _ in 0..self.0 {
+            fmt.write_char('^')?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloc::string::String;
+
+    use coverage_helper::test;
+    use url::Url;
+
+    use super::{TerminalRenderer, Theme};
+    use crate::schema::{
+        Artifact, ArtifactContent, ArtifactLocation, Level, Location, LogicalLocation, Message,
+        MultiformatMessageString, PhysicalLocation, Region, ReportingDescriptor, Result,
+        ResultKind, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    fn run() -> Run<'static> {
+        Run::new(Tool::new(
+            ToolComponent::new("rustc").with_rule(
+                ReportingDescriptor::new("unused_variables")
+                    .with_message_string(
+                        "default",
+                        MultiformatMessageString::new("unused variable: `{0}`"),
+                    )
+                    .with_help(MultiformatMessageString::new(
+                        "if this is intentional, prefix it with an underscore: `_x`\n\nor remove \
+                         it",
+                    ))
+                    .with_help_uri(
+                        Url::parse("https://doc.rust-lang.org/rustc/lints/index.html")
+                            .expect("valid URL"),
+                    ),
+            ),
+        ))
+        .with_artifact(Artifact::new(ArtifactLocation::new("src/main.rs")))
+    }
+
+    fn unused_variable() -> Result<'static> {
+        Result::new(Message::from_id("default").with_arguments(["x"]))
+            .with_rule_index(0)
+            .with_location(
+                Location::new().with_physical_location(
+                    PhysicalLocation::new(ArtifactLocation::new("src/main.rs")).with_region(
+                        Region::new()
+                            .with_start_line(9)
+                            .with_start_column(9)
+                            .with_end_column(10)
+                            .with_snippet(ArtifactContent::from_text("    let x = 5;")),
+                    ),
+                ),
+            )
+    }
+
+    fn render(renderer: &TerminalRenderer, run: Run<'_>) -> String {
+        let mut output = String::new();
+        renderer
+            .render_log(
+                &mut output,
+                &SarifLog::new(SchemaVersion::V2_1_0).with_run(run),
+            )
+            .expect("writing to a string never fails");
+        output
+    }
+
+    #[test]
+    fn plain() {
+        let renderer = TerminalRenderer::new().with_theme(Theme::plain());
+        let output = render(&renderer, run().with_result(unused_variable()));
+
+        assert_eq!(
+            output,
+            concat!(
+                "warning[unused_variables]: unused variable: `x`\n",
+                " --> src/main.rs:9:9\n",
+                "  |\n",
+                "9 |     let x = 5;\n",
+                "  |         ^\n",
+                "  |\n",
+                "  = help: if this is intentional, prefix it with an underscore: `_x`\n",
+                "\n",
+                "          or remove it\n",
+                "  = note: for further information visit https://doc.rust-lang.org/rustc/lints/index.html\n",
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn without_help() {
+        let renderer = TerminalRenderer::new()
+            .with_theme(Theme::plain())
+            .with_help(false);
+        let output = render(
+            &renderer,
+            run().with_result(
+                Result::new(Message::new("mismatched types"))
+                    .with_level(Level::Error)
+                    .with_rule_index(0),
+            ),
+        );
+
+        assert_eq!(output, "error[unused_variables]: mismatched types\n\n");
+    }
+
+    #[test]
+    fn multiline_snippet() {
+        let renderer = TerminalRenderer::new()
+            .with_theme(Theme::plain())
+            .with_help(false);
+
+        // The URI is looked up in the artifacts of the run
+        let mut artifact_location = ArtifactLocation::new("src/main.rs").with_index(0);
+        artifact_location.uri = None;
+
+        let result =
+            Result::new(Message::new("this function has too many lines"))
+                .with_level(Level::Note)
+                .with_location(
+                    Location::new().with_physical_location(
+                        PhysicalLocation::new(artifact_location).with_region(
+                            Region::new()
+                                .with_start_line(9)
+                                .with_start_column(1)
+                                .with_end_line(11)
+                                .with_snippet(ArtifactContent::from_text(
+                                    "fn main() {\n    let x = 5;\n}\n",
+                                )),
+                        ),
+                    ),
+                )
+                .with_related_location(
+                    Location::new()
+                        .with_message(Message::new("`main` is defined here"))
+                        .with_logical_location(
+                            LogicalLocation::new()
+                                .with_name("main")
+                                .with_fully_qualified_name("crate::main"),
+                        ),
+                )
+                .with_related_location(Location::new().with_physical_location(
+                    PhysicalLocation::new(ArtifactLocation::new("src/lib.rs")),
+                ));
+        let output = render(&renderer, run().with_result(result));
+
+        assert_eq!(
+            output,
+            concat!(
+                "note: this function has too many lines\n",
+                "  --> src/main.rs:9:1\n",
+                "   |\n",
+                " 9 | fn main() {\n",
+                "10 |     let x = 5;\n",
+                "11 | }\n",
+                "   |\n",
+                "note: `main` is defined here\n",
+                "  --> crate::main\n",
+                "note: related location\n",
+                "  --> src/lib.rs\n",
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn labels() {
+        let renderer = TerminalRenderer::new().with_theme(Theme::plain());
+        let output = render(
+            &renderer,
+            run().with_results([
+                Result::new(Message::new("all variables are used")).with_kind(ResultKind::Pass),
+                Result::new(Message::new("check manually")).with_kind(ResultKind::Review),
+                Result::new(Message::new("not yet checked")).with_kind(ResultKind::Open),
+                Result::new(Message::new("no variables")).with_kind(ResultKind::NotApplicable),
+                Result::new(Message::new("42 variables")).with_kind(ResultKind::Informational),
+                Result::new(Message::from_id("unknown")),
+            ]),
+        );
+
+        assert_eq!(
+            output,
+            "pass: all variables are used\n\nreview: check manually\n\nopen: not yet \
+             checked\n\nnot applicable: no variables\n\ninfo: 42 variables\n\nwarning: <missing \
+             message>\n\n"
+        );
+    }
+
+    #[test]
+    fn colored() {
+        let renderer = TerminalRenderer::new().with_help(false);
+        let output = render(&renderer, run().with_result(unused_variable()));
+
+        assert_eq!(
+            output,
+            concat!(
+                "\x1b[1;33mwarning[unused_variables]\x1b[22;39m\x1b[1m: unused variable: \
+                 `x`\x1b[22m\n",
+                " \x1b[1;34m-->\x1b[22;39m src/main.rs:9:9\n",
+                "  \x1b[1;34m|\x1b[22;39m\n",
+                "\x1b[1;34m9 |\x1b[22;39m     let x = 5;\n",
+                "  \x1b[1;34m|\x1b[22;39m         \x1b[1;33m^\x1b[22;39m\n",
+                "  \x1b[1;34m|\x1b[22;39m\n",
+                "\n",
+            )
+        );
+    }
+}
@@ -1,10 +1,15 @@
 //! The JSON schema of the SARIF log file format as a Rust module.
 
+mod artifact;
+mod invocation;
+mod location;
 mod log;
+mod logical_location;
 mod message;
 mod multiformat_message_string;
 mod properties;
 mod reporting_descriptor;
+mod result;
 mod run;
 mod tool;
 
@@ -12,11 +17,16 @@ mod tool;
 use serde::{Deserialize, Serialize};
 
 pub use self::{
+    artifact::{Artifact, ArtifactContent, ArtifactLocation},
+    invocation::Invocation,
+    location::{Location, PhysicalLocation, Region},
     log::SarifLog,
+    logical_location::LogicalLocation,
     message::Message,
     multiformat_message_string::MultiformatMessageString,
     properties::PropertyBag,
     reporting_descriptor::ReportingDescriptor,
+    result::{BaselineState, Level, Result, ResultKind},
     run::Run,
     tool::{Tool, ToolComponent},
 };
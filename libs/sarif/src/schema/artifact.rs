@@ -0,0 +1,596 @@
+use alloc::{borrow::Cow, collections::BTreeMap};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Message, PropertyBag};
+
+/// Specifies the location of an artifact.
+///
+/// The artifact is either identified by an absolute URI, or by a relative reference together
+/// with [`uri_base_id`], which resolves the reference against a base URI provided by the
+/// consumer.
+///
+/// [`uri_base_id`]: Self::uri_base_id
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactLocation<'s> {
+    /// A string containing a valid relative or absolute URI.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uri: Option<Cow<'s, str>>,
+
+    /// A string which indirectly specifies the absolute URI with respect to which a relative URI
+    /// in the [`uri`] property is interpreted.
+    ///
+    /// [`uri`]: Self::uri
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uri_base_id: Option<Cow<'s, str>>,
+
+    /// The index within the [`Run::artifacts`] array of the [`Artifact`] object associated with
+    /// the artifact location.
+    ///
+    /// [`Run::artifacts`]: crate::schema::Run::artifacts
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<usize>,
+
+    /// A short description of the artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// Key/value pairs that provide additional information about the artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactLocation<'s> {
+    /// Creates a new `ArtifactLocation` pointing to the given URI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs");
+    ///
+    /// assert_eq!(location.uri.unwrap(), "src/lib.rs");
+    /// ```
+    #[must_use]
+    pub fn new(uri: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            uri: Some(uri.into()),
+            uri_base_id: None,
+            index: None,
+            description: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the identifier of the base URI the [`uri`] is relative to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs").with_uri_base_id("SRCROOT");
+    ///
+    /// assert_eq!(location.uri_base_id.unwrap(), "SRCROOT");
+    /// ```
+    ///
+    /// [`uri`]: Self::uri
+    #[must_use]
+    pub fn with_uri_base_id(mut self, uri_base_id: impl Into<Cow<'s, str>>) -> Self {
+        self.uri_base_id = Some(uri_base_id.into());
+        self
+    }
+
+    /// Sets the index of the associated [`Artifact`] in [`Run::artifacts`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs").with_index(0);
+    ///
+    /// assert_eq!(location.index, Some(0));
+    /// ```
+    ///
+    /// [`Run::artifacts`]: crate::schema::Run::artifacts
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Sets the description of the artifact location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Message};
+    ///
+    /// let location =
+    ///     ArtifactLocation::new("src/lib.rs").with_description(Message::new("The crate root"));
+    ///
+    /// assert_eq!(
+    ///     location.description.unwrap().text.unwrap(),
+    ///     "The crate root"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the properties of the artifact location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PropertyBag};
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs")
+    ///     .with_properties(PropertyBag::new().with_tag("generated"));
+    ///
+    /// assert!(location.properties.tags.contains("generated"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
+
+/// Represents the contents of an artifact, or a portion of it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactContent<'s> {
+    /// UTF-8-encoded content from a text artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub text: Option<Cow<'s, str>>,
+
+    /// MIME Base64-encoded content from a binary artifact, or from a text artifact in its
+    /// original encoding.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub binary: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the artifact content.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactContent<'s> {
+    /// Creates a new `ArtifactContent` from the given text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::from_text("fn main() {}");
+    ///
+    /// assert_eq!(content.text.unwrap(), "fn main() {}");
+    /// ```
+    #[must_use]
+    pub fn from_text(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: Some(text.into()),
+            binary: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Creates a new `ArtifactContent` from the given Base64-encoded binary content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::from_binary("AGFzbQ==");
+    ///
+    /// assert_eq!(content.binary.unwrap(), "AGFzbQ==");
+    /// ```
+    #[must_use]
+    pub fn from_binary(binary: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: None,
+            binary: Some(binary.into()),
+            properties: PropertyBag::new(),
+        }
+    }
+}
+
+/// A single artifact.
+///
+/// In some cases, this artifact might be nested within another artifact, e.g. a file inside of
+/// an archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Artifact<'s> {
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<ArtifactLocation<'s>>,
+
+    /// Identifies the index of the immediate parent of the artifact, if this artifact is nested.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<usize>,
+
+    /// The offset in bytes of the artifact within its containing artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub offset: Option<usize>,
+
+    /// The length of the artifact in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub length: Option<usize>,
+
+    /// The MIME type (RFC 2045) of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub mime_type: Option<Cow<'s, str>>,
+
+    /// The contents of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub contents: Option<ArtifactContent<'s>>,
+
+    /// Specifies the encoding for an artifact object that refers to a text file.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub encoding: Option<Cow<'s, str>>,
+
+    /// Specifies the source language for any artifact object that refers to a text file that
+    /// contains source code.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// A dictionary, each of whose keys is the name of a hash function and each of whose values
+    /// is the hashed value of the artifact produced by the specified hash function.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub hashes: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// A short description of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// Key/value pairs that provide additional information about the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Artifact<'s> {
+    /// Creates a new `Artifact` at the given location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs"));
+    ///
+    /// assert_eq!(artifact.location.unwrap().uri.unwrap(), "src/lib.rs");
+    /// ```
+    #[must_use]
+    pub const fn new(location: ArtifactLocation<'s>) -> Self {
+        Self {
+            location: Some(location),
+            parent_index: None,
+            offset: None,
+            length: None,
+            mime_type: None,
+            contents: None,
+            encoding: None,
+            source_language: None,
+            hashes: BTreeMap::new(),
+            description: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the index of the artifact this artifact is nested in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("lib.rs")).with_parent_index(0);
+    ///
+    /// assert_eq!(artifact.parent_index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_parent_index(mut self, parent_index: usize) -> Self {
+        self.parent_index = Some(parent_index);
+        self
+    }
+
+    /// Sets the offset in bytes of the artifact within its containing artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("lib.rs")).with_offset(512);
+    ///
+    /// assert_eq!(artifact.offset, Some(512));
+    /// ```
+    #[must_use]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the length of the artifact in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs")).with_length(1024);
+    ///
+    /// assert_eq!(artifact.length, Some(1024));
+    /// ```
+    #[must_use]
+    pub const fn with_length(mut self, length: usize) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the MIME type of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs")).with_mime_type("text/x-rust");
+    ///
+    /// assert_eq!(artifact.mime_type.unwrap(), "text/x-rust");
+    /// ```
+    #[must_use]
+    pub fn with_mime_type(mut self, mime_type: impl Into<Cow<'s, str>>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Sets the contents of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactContent, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/main.rs"))
+    ///     .with_contents(ArtifactContent::from_text("fn main() {}"));
+    ///
+    /// assert_eq!(artifact.contents.unwrap().text.unwrap(), "fn main() {}");
+    /// ```
+    #[must_use]
+    pub fn with_contents(mut self, contents: ArtifactContent<'s>) -> Self {
+        self.contents = Some(contents);
+        self
+    }
+
+    /// Sets the encoding of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs")).with_encoding("utf-8");
+    ///
+    /// assert_eq!(artifact.encoding.unwrap(), "utf-8");
+    /// ```
+    #[must_use]
+    pub fn with_encoding(mut self, encoding: impl Into<Cow<'s, str>>) -> Self {
+        self.encoding = Some(encoding.into());
+        self
+    }
+
+    /// Sets the source language of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs")).with_source_language("rust");
+    ///
+    /// assert_eq!(artifact.source_language.unwrap(), "rust");
+    /// ```
+    #[must_use]
+    pub fn with_source_language(mut self, source_language: impl Into<Cow<'s, str>>) -> Self {
+        self.source_language = Some(source_language.into());
+        self
+    }
+
+    /// Adds the hash of the artifact produced by the hash function `algorithm`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_hash("sha-256", "b13ce2678a8807ba0765ab94a0ecd394f869bc81");
+    ///
+    /// assert_eq!(
+    ///     artifact.hashes["sha-256"],
+    ///     "b13ce2678a8807ba0765ab94a0ecd394f869bc81"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_hash(
+        mut self,
+        algorithm: impl Into<Cow<'s, str>>,
+        hash: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.hashes.insert(algorithm.into(), hash.into());
+        self
+    }
+
+    /// Sets the description of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation, Message};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_description(Message::new("The crate root"));
+    ///
+    /// assert_eq!(
+    ///     artifact.description.unwrap().text.unwrap(),
+    ///     "The crate root"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the properties of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation, PropertyBag};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_properties(PropertyBag::new().with_tag("generated"));
+    ///
+    /// assert!(artifact.properties.tags.contains("generated"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, Artifact, ArtifactContent, ArtifactLocation, Message, Run,
+        SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    fn create_log(artifact: Artifact) -> SarifLog {
+        SarifLog::new(SchemaVersion::V2_1_0)
+            .with_run(Run::new(Tool::new(ToolComponent::new("clippy"))).with_artifact(artifact))
+    }
+
+    #[test]
+    fn minimal() {
+        validate_schema(&create_log(Artifact::new(ArtifactLocation::new(
+            "src/lib.rs",
+        ))));
+    }
+
+    #[test]
+    fn full() {
+        let artifact = Artifact::new(
+            ArtifactLocation::new("src/lib.rs")
+                .with_uri_base_id("SRCROOT")
+                .with_index(0)
+                .with_description(Message::new("The crate root")),
+        )
+        .with_parent_index(1)
+        .with_offset(0)
+        .with_length(12)
+        .with_mime_type("text/x-rust")
+        .with_contents(ArtifactContent::from_text("fn main() {}"))
+        .with_encoding("utf-8")
+        .with_source_language("rust")
+        .with_hash("sha-256", "b13ce2678a8807ba0765ab94a0ecd394f869bc81")
+        .with_description(Message::new("The crate root"));
+
+        validate_schema(&create_log(artifact));
+    }
+
+    #[test]
+    fn binary_contents() {
+        let artifact = Artifact::new(ArtifactLocation::new("app.wasm"))
+            .with_contents(ArtifactContent::from_binary("AGFzbQ=="));
+
+        validate_schema(&create_log(artifact));
+    }
+}
@@ -0,0 +1,395 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactLocation, PropertyBag};
+
+/// The runtime environment of the analysis tool run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Invocation<'s> {
+    /// The command line used to invoke the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub command_line: Option<Cow<'s, str>>,
+
+    /// The command line arguments, in the order they were passed to the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub arguments: Vec<Cow<'s, str>>,
+
+    /// The locations of any response files specified on the tool's command line.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub response_files: Vec<ArtifactLocation<'s>>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the invocation started.
+    ///
+    /// The value must be formatted as described in RFC 3339, e.g. `2016-07-16T14:18:25Z`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_time_utc: Option<Cow<'s, str>>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the invocation ended.
+    ///
+    /// The value must be formatted as described in RFC 3339, e.g. `2016-07-16T14:19:01Z`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_time_utc: Option<Cow<'s, str>>,
+
+    /// The process exit code.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_code: Option<i32>,
+
+    /// Specifies whether the tool's execution completed successfully.
+    pub execution_successful: bool,
+
+    /// The machine on which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub machine: Option<Cow<'s, str>>,
+
+    /// The account under which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub account: Option<Cow<'s, str>>,
+
+    /// The id of the process in which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub process_id: Option<u32>,
+
+    /// The working directory for the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub working_directory: Option<ArtifactLocation<'s>>,
+
+    /// The environment variables associated with the analysis tool process, expressed as
+    /// key/value pairs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub environment_variables: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Invocation<'s> {
+    /// Creates a new `Invocation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true);
+    ///
+    /// assert!(invocation.execution_successful);
+    /// ```
+    #[must_use]
+    pub const fn new(execution_successful: bool) -> Self {
+        Self {
+            command_line: None,
+            arguments: Vec::new(),
+            response_files: Vec::new(),
+            start_time_utc: None,
+            end_time_utc: None,
+            exit_code: None,
+            execution_successful,
+            machine: None,
+            account: None,
+            process_id: None,
+            working_directory: None,
+            environment_variables: BTreeMap::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the command line used to invoke the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_command_line("cargo clippy --workspace");
+    ///
+    /// assert_eq!(invocation.command_line.unwrap(), "cargo clippy --workspace");
+    /// ```
+    #[must_use]
+    pub fn with_command_line(mut self, command_line: impl Into<Cow<'s, str>>) -> Self {
+        self.command_line = Some(command_line.into());
+        self
+    }
+
+    /// Adds command line arguments passed to the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_arguments(["clippy", "--workspace"]);
+    ///
+    /// assert_eq!(invocation.arguments, ["clippy", "--workspace"]);
+    /// ```
+    #[must_use]
+    pub fn with_arguments(
+        mut self,
+        arguments: impl IntoIterator<Item = impl Into<Cow<'s, str>>>,
+    ) -> Self {
+        self.arguments.extend(arguments.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds the location of a response file specified on the command line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation =
+    ///     Invocation::new(true).with_response_file(ArtifactLocation::new("build/clippy.rsp"));
+    ///
+    /// assert_eq!(invocation.response_files.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_response_file(mut self, response_file: ArtifactLocation<'s>) -> Self {
+        self.response_files.push(response_file);
+        self
+    }
+
+    /// Sets the UTC date and time at which the invocation started.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_start_time_utc("2016-07-16T14:18:25Z");
+    ///
+    /// assert_eq!(invocation.start_time_utc.unwrap(), "2016-07-16T14:18:25Z");
+    /// ```
+    #[must_use]
+    pub fn with_start_time_utc(mut self, start_time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.start_time_utc = Some(start_time_utc.into());
+        self
+    }
+
+    /// Sets the UTC date and time at which the invocation ended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_end_time_utc("2016-07-16T14:19:01Z");
+    ///
+    /// assert_eq!(invocation.end_time_utc.unwrap(), "2016-07-16T14:19:01Z");
+    /// ```
+    #[must_use]
+    pub fn with_end_time_utc(mut self, end_time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.end_time_utc = Some(end_time_utc.into());
+        self
+    }
+
+    /// Sets the process exit code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(false).with_exit_code(101);
+    ///
+    /// assert_eq!(invocation.exit_code, Some(101));
+    /// ```
+    #[must_use]
+    pub const fn with_exit_code(mut self, exit_code: i32) -> Self {
+        self.exit_code = Some(exit_code);
+        self
+    }
+
+    /// Sets the machine on which the invocation occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_machine("BLD01");
+    ///
+    /// assert_eq!(invocation.machine.unwrap(), "BLD01");
+    /// ```
+    #[must_use]
+    pub fn with_machine(mut self, machine: impl Into<Cow<'s, str>>) -> Self {
+        self.machine = Some(machine.into());
+        self
+    }
+
+    /// Sets the account under which the invocation occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_account("buildAgent");
+    ///
+    /// assert_eq!(invocation.account.unwrap(), "buildAgent");
+    /// ```
+    #[must_use]
+    pub fn with_account(mut self, account: impl Into<Cow<'s, str>>) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// Sets the id of the process in which the invocation occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_process_id(1218);
+    ///
+    /// assert_eq!(invocation.process_id, Some(1218));
+    /// ```
+    #[must_use]
+    pub const fn with_process_id(mut self, process_id: u32) -> Self {
+        self.process_id = Some(process_id);
+        self
+    }
+
+    /// Sets the working directory of the invocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_working_directory(ArtifactLocation::new("file:///home/buildAgent/src"));
+    ///
+    /// assert_eq!(
+    ///     invocation.working_directory.unwrap().uri.unwrap(),
+    ///     "file:///home/buildAgent/src"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_working_directory(mut self, working_directory: ArtifactLocation<'s>) -> Self {
+        self.working_directory = Some(working_directory);
+        self
+    }
+
+    /// Adds an environment variable of the analysis tool process.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_environment_variable("TZ", "EST");
+    ///
+    /// assert_eq!(invocation.environment_variables["TZ"], "EST");
+    /// ```
+    #[must_use]
+    pub fn with_environment_variable(
+        mut self,
+        name: impl Into<Cow<'s, str>>,
+        value: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.environment_variables.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the properties of the invocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, PropertyBag};
+    ///
+    /// let invocation = Invocation::new(true).with_properties(PropertyBag::new().with_tag("ci"));
+    ///
+    /// assert!(invocation.properties.tags.contains("ci"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, Invocation, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent,
+    };
+
+    fn create_log(invocation: Invocation) -> SarifLog {
+        SarifLog::new(SchemaVersion::V2_1_0)
+            .with_run(Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(invocation))
+    }
+
+    #[test]
+    fn minimal() {
+        validate_schema(&create_log(Invocation::new(true)));
+    }
+
+    #[test]
+    fn full() {
+        let invocation = Invocation::new(false)
+            .with_command_line("cargo clippy @build/clippy.rsp")
+            .with_arguments(["clippy", "@build/clippy.rsp"])
+            .with_response_file(ArtifactLocation::new("build/clippy.rsp"))
+            .with_start_time_utc("2016-07-16T14:18:25Z")
+            .with_end_time_utc("2016-07-16T14:19:01Z")
+            .with_exit_code(101)
+            .with_machine("BLD01")
+            .with_account("buildAgent")
+            .with_process_id(1218)
+            .with_working_directory(ArtifactLocation::new("file:///home/buildAgent/src"))
+            .with_environment_variable("TZ", "EST");
+
+        validate_schema(&create_log(invocation));
+    }
+}
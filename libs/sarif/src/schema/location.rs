@@ -0,0 +1,708 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactContent, ArtifactLocation, LogicalLocation, Message, PropertyBag};
+
+/// A location within a programming artifact.
+///
+/// See [SARIF specification §3.28](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317670)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Location<'s> {
+    /// Value that distinguishes this location from all other locations within a single result
+    /// object.
+    ///
+    /// Embedded links in a [`Message`] refer to a location by this identifier.
+    ///
+    /// See [SARIF specification §3.28.2](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317672)
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<usize>,
+
+    /// Identifies the artifact and region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub physical_location: Option<PhysicalLocation<'s>>,
+
+    /// The logical locations associated with the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub logical_locations: Vec<LogicalLocation<'s>>,
+
+    /// A message relevant to the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// A set of regions relevant to the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub annotations: Vec<Region<'s>>,
+
+    /// Key/value pairs that provide additional information about the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Location<'s> {
+    /// Creates a new, empty `Location`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location = Location::new();
+    ///
+    /// assert!(location.physical_location.is_none());
+    /// assert!(location.logical_locations.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            id: None,
+            physical_location: None,
+            logical_locations: Vec::new(),
+            message: None,
+            annotations: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the identifier of the location within its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location = Location::new().with_id(0);
+    ///
+    /// assert_eq!(location.id, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_id(mut self, id: usize) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the physical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, PhysicalLocation};
+    ///
+    /// let location = Location::new()
+    ///     .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("src/lib.rs")));
+    ///
+    /// assert!(location.physical_location.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_physical_location(mut self, physical_location: PhysicalLocation<'s>) -> Self {
+        self.physical_location = Some(physical_location);
+        self
+    }
+
+    /// Adds a logical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, LogicalLocation};
+    ///
+    /// let location = Location::new()
+    ///     .with_logical_location(LogicalLocation::new().with_fully_qualified_name("sarif::schema"));
+    ///
+    /// assert_eq!(location.logical_locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_logical_location(mut self, logical_location: LogicalLocation<'s>) -> Self {
+        self.logical_locations.push(logical_location);
+        self
+    }
+
+    /// Sets the message relevant to the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message};
+    ///
+    /// let location = Location::new().with_message(Message::new("Variable declared here"));
+    ///
+    /// assert_eq!(
+    ///     location.message.unwrap().text.unwrap(),
+    ///     "Variable declared here"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Adds a region relevant to the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Region};
+    ///
+    /// let location = Location::new().with_annotation(Region::new().with_start_line(3));
+    ///
+    /// assert_eq!(location.annotations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_annotation(mut self, annotation: Region<'s>) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Sets the properties of the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, PropertyBag};
+    ///
+    /// let location = Location::new().with_properties(PropertyBag::new().with_tag("macro"));
+    ///
+    /// assert!(location.properties.tags.contains("macro"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
+
+/// A physical location relevant to a result.
+///
+/// Specifies a reference to a programming artifact together with a range of bytes or characters
+/// within that artifact.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct PhysicalLocation<'s> {
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub artifact_location: Option<ArtifactLocation<'s>>,
+
+    /// Specifies a portion of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub region: Option<Region<'s>>,
+
+    /// Specifies a portion of the artifact that encloses the region.
+    ///
+    /// Allows a viewer to display additional context around the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub context_region: Option<Region<'s>>,
+
+    /// Key/value pairs that provide additional information about the physical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> PhysicalLocation<'s> {
+    /// Creates a new `PhysicalLocation` in the given artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"));
+    ///
+    /// assert_eq!(
+    ///     location.artifact_location.unwrap().uri.unwrap(),
+    ///     "src/lib.rs"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn new(artifact_location: ArtifactLocation<'s>) -> Self {
+        Self {
+            artifact_location: Some(artifact_location),
+            region: None,
+            context_region: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the region within the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation, Region};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_region(Region::new().with_start_line(15));
+    ///
+    /// assert_eq!(location.region.unwrap().start_line, Some(15));
+    /// ```
+    #[must_use]
+    pub fn with_region(mut self, region: Region<'s>) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Sets the region enclosing [`region`] to provide additional context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation, Region};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_region(Region::new().with_start_line(15))
+    ///     .with_context_region(Region::new().with_start_line(14).with_end_line(16));
+    ///
+    /// assert_eq!(location.context_region.unwrap().end_line, Some(16));
+    /// ```
+    ///
+    /// [`region`]: Self::region
+    #[must_use]
+    pub fn with_context_region(mut self, context_region: Region<'s>) -> Self {
+        self.context_region = Some(context_region);
+        self
+    }
+
+    /// Sets the properties of the physical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation, PropertyBag};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_properties(PropertyBag::new().with_tag("generated"));
+    ///
+    /// assert!(location.properties.tags.contains("generated"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
+
+/// A region within an artifact where a result was detected.
+///
+/// Lines and columns are 1-based, offsets are 0-based. A text region is specified by its
+/// line/column or character properties, a binary region by its byte properties.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Region<'s> {
+    /// The line number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_line: Option<usize>,
+
+    /// The column number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_column: Option<usize>,
+
+    /// The line number of the last character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_line: Option<usize>,
+
+    /// The column number of the character following the end of the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_column: Option<usize>,
+
+    /// The zero-based offset from the beginning of the artifact of the first character in the
+    /// region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_offset: Option<usize>,
+
+    /// The length of the region in characters.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_length: Option<usize>,
+
+    /// The zero-based offset from the beginning of the artifact of the first byte in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_offset: Option<usize>,
+
+    /// The length of the region in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_length: Option<usize>,
+
+    /// The portion of the artifact contents within the specified region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub snippet: Option<ArtifactContent<'s>>,
+
+    /// A message relevant to the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// Specifies the source language, if any, of the portion of the artifact specified by the
+    /// region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Region<'s> {
+    /// Creates a new, empty `Region`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new();
+    ///
+    /// assert!(region.start_line.is_none());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            start_line: None,
+            start_column: None,
+            end_line: None,
+            end_column: None,
+            char_offset: None,
+            char_length: None,
+            byte_offset: None,
+            byte_length: None,
+            snippet: None,
+            message: None,
+            source_language: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the line number of the first character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start_line(15);
+    ///
+    /// assert_eq!(region.start_line, Some(15));
+    /// ```
+    #[must_use]
+    pub const fn with_start_line(mut self, start_line: usize) -> Self {
+        self.start_line = Some(start_line);
+        self
+    }
+
+    /// Sets the column number of the first character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start_line(15).with_start_column(9);
+    ///
+    /// assert_eq!(region.start_column, Some(9));
+    /// ```
+    #[must_use]
+    pub const fn with_start_column(mut self, start_column: usize) -> Self {
+        self.start_column = Some(start_column);
+        self
+    }
+
+    /// Sets the line number of the last character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start_line(15).with_end_line(17);
+    ///
+    /// assert_eq!(region.end_line, Some(17));
+    /// ```
+    #[must_use]
+    pub const fn with_end_line(mut self, end_line: usize) -> Self {
+        self.end_line = Some(end_line);
+        self
+    }
+
+    /// Sets the column number of the character following the end of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start_line(15).with_end_column(10);
+    ///
+    /// assert_eq!(region.end_column, Some(10));
+    /// ```
+    #[must_use]
+    pub const fn with_end_column(mut self, end_column: usize) -> Self {
+        self.end_column = Some(end_column);
+        self
+    }
+
+    /// Sets the character offset and length of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_char_range(254, 1);
+    ///
+    /// assert_eq!(region.char_offset, Some(254));
+    /// assert_eq!(region.char_length, Some(1));
+    /// ```
+    #[must_use]
+    pub const fn with_char_range(mut self, offset: usize, length: usize) -> Self {
+        self.char_offset = Some(offset);
+        self.char_length = Some(length);
+        self
+    }
+
+    /// Sets the byte offset and length of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_byte_range(512, 8);
+    ///
+    /// assert_eq!(region.byte_offset, Some(512));
+    /// assert_eq!(region.byte_length, Some(8));
+    /// ```
+    #[must_use]
+    pub const fn with_byte_range(mut self, offset: usize, length: usize) -> Self {
+        self.byte_offset = Some(offset);
+        self.byte_length = Some(length);
+        self
+    }
+
+    /// Sets the portion of the artifact contents within the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactContent, Region};
+    ///
+    /// let region = Region::new()
+    ///     .with_start_line(1)
+    ///     .with_snippet(ArtifactContent::from_text("fn main() {}"));
+    ///
+    /// assert_eq!(region.snippet.unwrap().text.unwrap(), "fn main() {}");
+    /// ```
+    #[must_use]
+    pub fn with_snippet(mut self, snippet: ArtifactContent<'s>) -> Self {
+        self.snippet = Some(snippet);
+        self
+    }
+
+    /// Sets the message relevant to the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Region};
+    ///
+    /// let region = Region::new()
+    ///     .with_start_line(1)
+    ///     .with_message(Message::new("expected `u32`"));
+    ///
+    /// assert_eq!(region.message.unwrap().text.unwrap(), "expected `u32`");
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the source language of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new()
+    ///     .with_start_line(1)
+    ///     .with_source_language("rust");
+    ///
+    /// assert_eq!(region.source_language.unwrap(), "rust");
+    /// ```
+    #[must_use]
+    pub fn with_source_language(mut self, source_language: impl Into<Cow<'s, str>>) -> Self {
+        self.source_language = Some(source_language.into());
+        self
+    }
+
+    /// Sets the properties of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{PropertyBag, Region};
+    ///
+    /// let region = Region::new().with_properties(PropertyBag::new().with_tag("primary"));
+    ///
+    /// assert!(region.properties.tags.contains("primary"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactContent, ArtifactLocation, Location, LogicalLocation,
+        Message, PhysicalLocation, Region, Result, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent,
+    };
+
+    fn create_log(location: Location) -> SarifLog {
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(
+            Run::new(Tool::new(ToolComponent::new("clippy")))
+                .with_result(Result::new(Message::new("unused variable")).with_location(location)),
+        )
+    }
+
+    #[test]
+    fn empty() {
+        validate_schema(&create_log(Location::new()));
+    }
+
+    #[test]
+    fn text_region() {
+        let location = Location::new().with_physical_location(
+            PhysicalLocation::new(ArtifactLocation::new("src/lib.rs").with_uri_base_id("SRCROOT"))
+                .with_region(
+                    Region::new()
+                        .with_start_line(15)
+                        .with_start_column(9)
+                        .with_end_line(15)
+                        .with_end_column(10)
+                        .with_char_range(254, 1)
+                        .with_snippet(ArtifactContent::from_text("x"))
+                        .with_source_language("rust"),
+                )
+                .with_context_region(Region::new().with_start_line(14).with_end_line(16)),
+        );
+
+        validate_schema(&create_log(location));
+    }
+
+    #[test]
+    fn binary_region() {
+        let location = Location::new().with_physical_location(
+            PhysicalLocation::new(ArtifactLocation::new("app.wasm"))
+                .with_region(Region::new().with_byte_range(512, 8)),
+        );
+
+        validate_schema(&create_log(location));
+    }
+
+    #[test]
+    fn full() {
+        let location = Location::new()
+            .with_id(0)
+            .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("src/lib.rs")))
+            .with_logical_location(
+                LogicalLocation::new()
+                    .with_fully_qualified_name("sarif::schema::Location::new")
+                    .with_kind("function"),
+            )
+            .with_message(Message::new("Variable declared here"))
+            .with_annotation(
+                Region::new()
+                    .with_start_line(3)
+                    .with_message(Message::new("first use")),
+            );
+
+        validate_schema(&create_log(location));
+    }
+}
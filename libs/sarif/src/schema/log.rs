@@ -3,7 +3,7 @@ use alloc::{borrow::Cow, vec, vec::Vec};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::schema::{Run, SchemaVersion};
+use crate::schema::{BaselineState, Level, Result, Run, SchemaVersion};
 
 /// Specifies the version of the file format and contains the output from one or more runs.
 ///
@@ -95,7 +95,9 @@ pub struct SarifLog<'s> {
 
 #[cfg(feature = "serde")]
 impl<'s, 'de: 's> Deserialize<'de> for SarifLog<'s> {
-    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
         #[derive(Deserialize)]
         #[serde(rename_all = "camelCase")]
         struct OptionalSarifLog<'s> {
@@ -204,6 +206,140 @@ impl<'s> SarifLog<'s> {
         }
         self
     }
+
+    /// Merges the runs of another log into this log.
+    ///
+    /// A run of `other` produced by the same [`Tool`] as a run of this log is merged into that run
+    /// using [`Run::merge`], all other runs are appended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent};
+    ///
+    /// let log = |tool: &'static str| {
+    ///     SarifLog::new(SchemaVersion::V2_1_0).with_run(
+    ///         Run::new(Tool::new(ToolComponent::new(tool)))
+    ///             .with_result(Result::new(Message::new("unused variable"))),
+    ///     )
+    /// };
+    ///
+    /// let mut merged = log("clippy");
+    /// merged.merge(log("clippy"));
+    /// merged.merge(log("rustc"));
+    ///
+    /// let runs = merged.runs.unwrap();
+    /// assert_eq!(runs.len(), 2);
+    /// assert_eq!(runs[0].results.len(), 2);
+    /// assert_eq!(runs[1].results.len(), 1);
+    /// ```
+    ///
+    /// [`Tool`]: crate::schema::Tool
+    pub fn merge(&mut self, other: Self) {
+        for run in other.runs.into_iter().flatten() {
+            let runs = self.runs.get_or_insert_with(Vec::new);
+            match runs.iter_mut().find(|existing| existing.tool == run.tool) {
+                Some(existing) => existing.merge(run),
+                None => runs.push(run),
+            }
+        }
+    }
+
+    /// Retains only the results for which `predicate` returns `true`.
+    ///
+    /// See [`Run::retain_results`] for details.
+    pub fn retain_results(&mut self, mut predicate: impl FnMut(&Result<'s>) -> bool) {
+        for run in self.runs.iter_mut().flatten() {
+            run.retain_results(&mut predicate);
+        }
+    }
+
+    /// Retains only the results which are at least as severe as `minimum`.
+    ///
+    /// See [`Run::retain_level`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{
+    ///     Level, Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    /// };
+    ///
+    /// let mut log = SarifLog::new(SchemaVersion::V2_1_0).with_run(
+    ///     Run::new(Tool::new(ToolComponent::new("rustc"))).with_results([
+    ///         Result::new(Message::new("mismatched types")).with_level(Level::Error),
+    ///         Result::new(Message::new("unused variable: `x`")).with_level(Level::Warning),
+    ///     ]),
+    /// );
+    ///
+    /// log.retain_level(Level::Error);
+    ///
+    /// assert_eq!(log.runs.unwrap()[0].results.len(), 1);
+    /// ```
+    pub fn retain_level(&mut self, minimum: Level) {
+        for run in self.runs.iter_mut().flatten() {
+            run.retain_level(minimum);
+        }
+    }
+
+    /// Retains only the results which are relevant to one of the rules in `rule_ids`.
+    ///
+    /// See [`Run::retain_rule_ids`] for details.
+    pub fn retain_rule_ids(&mut self, rule_ids: &[&str]) {
+        for run in self.runs.iter_mut().flatten() {
+            run.retain_rule_ids(rule_ids);
+        }
+    }
+
+    /// Sets the baseline state of every result by comparing it to the results of `baseline`.
+    ///
+    /// Runs are associated by the name of their tool's driver and compared using
+    /// [`Run::compare_with_baseline`]. Results of runs without a counterpart in `baseline` are
+    /// [`BaselineState::New`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{
+    ///     BaselineState, Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    /// };
+    ///
+    /// let log = || {
+    ///     SarifLog::new(SchemaVersion::V2_1_0).with_run(
+    ///         Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(
+    ///             Result::new(Message::new("unused variable"))
+    ///                 .with_partial_fingerprint("primaryLocationLineHash", "39fa2ee980eb94b0:1"),
+    ///         ),
+    ///     )
+    /// };
+    ///
+    /// let baseline = log();
+    /// let mut current = log();
+    /// current.compare_with_baseline(&baseline);
+    ///
+    /// assert_eq!(
+    ///     current.runs.unwrap()[0].results[0].baseline_state,
+    ///     Some(BaselineState::Unchanged)
+    /// );
+    /// ```
+    pub fn compare_with_baseline(&mut self, baseline: &Self) {
+        let baseline_runs = baseline.runs.as_deref().unwrap_or_default();
+
+        for run in self.runs.iter_mut().flatten() {
+            let baseline_run = baseline_runs
+                .iter()
+                .find(|baseline_run| baseline_run.tool.driver.name == run.tool.driver.name);
+
+            match baseline_run {
+                Some(baseline_run) => run.compare_with_baseline(baseline_run),
+                None => {
+                    for result in &mut run.results {
+                        result.baseline_state = Some(BaselineState::New);
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl<'s> Extend<Run<'s>> for SarifLog<'s> {
@@ -218,7 +354,7 @@ impl<'s> Extend<Run<'s>> for SarifLog<'s> {
 #[cfg(test)]
 #[cfg(feature = "serde")]
 pub(crate) mod tests {
-    use alloc::{borrow::Cow, vec};
+    use alloc::{borrow::Cow, vec, vec::Vec};
 
     use coverage_helper::test;
     use semver::Version;
@@ -226,8 +362,8 @@ pub(crate) mod tests {
     use serde_json::json;
 
     use crate::schema::{
-        tests::validate_schema, ReportingDescriptor, Run, SarifLog, SchemaVersion, Tool,
-        ToolComponent,
+        tests::validate_schema, BaselineState, Level, Message, ReportingDescriptor, Result, Run,
+        SarifLog, SchemaVersion, Tool, ToolComponent,
     };
 
     #[test]
@@ -379,4 +515,104 @@ pub(crate) mod tests {
             Some(Cow::Borrowed("0.1.0"))
         );
     }
+
+    fn create_log(
+        tool: &'static str,
+        results: impl IntoIterator<Item = Result<'static>>,
+    ) -> SarifLog<'static> {
+        SarifLog::new(SchemaVersion::V2_1_0)
+            .with_run(Run::new(Tool::new(ToolComponent::new(tool))).with_results(results))
+    }
+
+    fn result(level: Level, hash: &'static str) -> Result<'static> {
+        Result::new(Message::new("unused variable"))
+            .with_rule_id("unused_variables")
+            .with_level(level)
+            .with_partial_fingerprint("primaryLocationLineHash", hash)
+    }
+
+    #[test]
+    fn merge() {
+        let mut log = create_log("clippy", [result(Level::Warning, "1")]);
+        log.merge(create_log("rustc", [result(Level::Error, "2")]));
+        log.merge(create_log("clippy", [result(Level::Note, "3")]));
+        log.merge(SarifLog::new(SchemaVersion::V2_1_0));
+        validate_schema(&log);
+
+        let runs = log.runs.expect("no runs found");
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].tool.driver.name, "clippy");
+        assert_eq!(runs[0].results.len(), 2);
+        assert_eq!(runs[1].tool.driver.name, "rustc");
+        assert_eq!(runs[1].results.len(), 1);
+    }
+
+    #[test]
+    fn merge_into_empty() {
+        let mut log = SarifLog {
+            schema: SchemaVersion::V2_1_0.schema_id().into(),
+            version: SchemaVersion::V2_1_0,
+            runs: None,
+        };
+        log.merge(create_log("clippy", [result(Level::Warning, "1")]));
+        validate_schema(&log);
+
+        assert_eq!(log.runs.expect("no runs found").len(), 1);
+    }
+
+    #[test]
+    fn retain() {
+        let mut log = create_log("clippy", [
+            result(Level::Error, "1"),
+            result(Level::Warning, "2"),
+            result(Level::Note, "3"),
+        ])
+        .with_run(
+            Run::new(Tool::new(ToolComponent::new("rustc")))
+                .with_result(Result::new(Message::new("mismatched types")).with_rule_id("E0308")),
+        );
+
+        log.retain_level(Level::Warning);
+        log.retain_rule_ids(&["unused_variables"]);
+        log.retain_results(|result| result.effective_level() != Level::Warning);
+        validate_schema(&log);
+
+        let runs = log.runs.expect("no runs found");
+        assert_eq!(runs[0].results, [result(Level::Error, "1")]);
+        assert!(runs[1].results.is_empty());
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let baseline = create_log("clippy", [
+            result(Level::Warning, "1"),
+            result(Level::Warning, "2"),
+        ]);
+        let mut log = create_log("clippy", [
+            result(Level::Warning, "1"),
+            result(Level::Error, "2"),
+        ])
+        .with_run(
+            Run::new(Tool::new(ToolComponent::new("rustc"))).with_result(result(Level::Error, "3")),
+        );
+
+        log.compare_with_baseline(&baseline);
+        validate_schema(&log);
+
+        let states = log
+            .runs
+            .expect("no runs found")
+            .into_iter()
+            .map(|run| {
+                run.results
+                    .into_iter()
+                    .map(|result| result.baseline_state)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(states, [
+            vec![Some(BaselineState::Unchanged), Some(BaselineState::Updated)],
+            vec![Some(BaselineState::New)],
+        ]);
+    }
 }
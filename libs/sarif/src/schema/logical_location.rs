@@ -0,0 +1,270 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::PropertyBag;
+
+/// A logical location such as a function, a class or a namespace, that is relevant to a result.
+///
+/// Logical locations are shared across a run through [`Run::logical_locations`] and can be
+/// referenced by their index.
+///
+/// [`Run::logical_locations`]: crate::schema::Run::logical_locations
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct LogicalLocation<'s> {
+    /// Identifies the construct in which the result occurred.
+    ///
+    /// For example, this property might contain the name of a class or a method.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub name: Option<Cow<'s, str>>,
+
+    /// The index within [`Run::logical_locations`] of the logical location object this object
+    /// refers to.
+    ///
+    /// [`Run::logical_locations`]: crate::schema::Run::logical_locations
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<usize>,
+
+    /// The human-readable fully qualified name of the logical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub fully_qualified_name: Option<Cow<'s, str>>,
+
+    /// The machine-readable name for the logical location, such as a mangled function name.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub decorated_name: Option<Cow<'s, str>>,
+
+    /// Identifies the index of the immediate parent of the construct in which the result was
+    /// detected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<usize>,
+
+    /// The type of construct this logical location component refers to.
+    ///
+    /// Should be one of `function`, `member`, `module`, `namespace`, `parameter`, `resource`,
+    /// `returnType`, `type`, `variable`, `object`, `array`, `property`, `value`, `element`,
+    /// `text`, `attribute`, `comment`, `declaration`, `dtd` or `processingInstruction`, if any of
+    /// those accurately describe the construct.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub kind: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the logical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> LogicalLocation<'s> {
+    /// Creates a new, empty `LogicalLocation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new();
+    ///
+    /// assert!(location.name.is_none());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            name: None,
+            index: None,
+            fully_qualified_name: None,
+            decorated_name: None,
+            parent_index: None,
+            kind: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the name of the construct.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_name("new");
+    ///
+    /// assert_eq!(location.name.unwrap(), "new");
+    /// ```
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<Cow<'s, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the index of the logical location in [`Run::logical_locations`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_index(0);
+    ///
+    /// assert_eq!(location.index, Some(0));
+    /// ```
+    ///
+    /// [`Run::logical_locations`]: crate::schema::Run::logical_locations
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Sets the fully qualified name of the logical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_fully_qualified_name("sarif::schema::Run::new");
+    ///
+    /// assert_eq!(
+    ///     location.fully_qualified_name.unwrap(),
+    ///     "sarif::schema::Run::new"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_fully_qualified_name(
+        mut self,
+        fully_qualified_name: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.fully_qualified_name = Some(fully_qualified_name.into());
+        self
+    }
+
+    /// Sets the machine-readable name of the logical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_decorated_name("_ZN5sarif6schema3Run3new17h");
+    ///
+    /// assert_eq!(
+    ///     location.decorated_name.unwrap(),
+    ///     "_ZN5sarif6schema3Run3new17h"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_decorated_name(mut self, decorated_name: impl Into<Cow<'s, str>>) -> Self {
+        self.decorated_name = Some(decorated_name.into());
+        self
+    }
+
+    /// Sets the index of the parent logical location in [`Run::logical_locations`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_parent_index(1);
+    ///
+    /// assert_eq!(location.parent_index, Some(1));
+    /// ```
+    ///
+    /// [`Run::logical_locations`]: crate::schema::Run::logical_locations
+    #[must_use]
+    pub const fn with_parent_index(mut self, parent_index: usize) -> Self {
+        self.parent_index = Some(parent_index);
+        self
+    }
+
+    /// Sets the kind of construct the logical location refers to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new().with_kind("function");
+    ///
+    /// assert_eq!(location.kind.unwrap(), "function");
+    /// ```
+    #[must_use]
+    pub fn with_kind(mut self, kind: impl Into<Cow<'s, str>>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    /// Sets the properties of the logical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{LogicalLocation, PropertyBag};
+    ///
+    /// let location = LogicalLocation::new().with_properties(PropertyBag::new().with_tag("async"));
+    ///
+    /// assert!(location.properties.tags.contains("async"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, LogicalLocation, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn hierarchy() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_logical_location(
+                LogicalLocation::new()
+                    .with_name("new")
+                    .with_fully_qualified_name("sarif::schema::Run::new")
+                    .with_decorated_name("_ZN5sarif6schema3Run3new17h")
+                    .with_kind("function")
+                    .with_parent_index(1),
+            )
+            .with_logical_location(
+                LogicalLocation::new()
+                    .with_name("Run")
+                    .with_fully_qualified_name("sarif::schema::Run")
+                    .with_kind("type"),
+            );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -480,3 +480,105 @@ pub struct Message<'s> {
     )]
     pub properties: PropertyBag<'s>,
 }
+
+impl<'s> Message<'s> {
+    /// Creates a new `Message` from a plain text message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("unused variable: `x`");
+    ///
+    /// assert_eq!(message.text.unwrap(), "unused variable: `x`");
+    /// ```
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: Some(text.into()),
+            markdown: None,
+            id: None,
+            arguments: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Creates a new `Message` referring to a message string by its identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::from_id("default").with_arguments(["x"]);
+    ///
+    /// assert_eq!(message.id.unwrap(), "default");
+    /// assert_eq!(message.arguments, ["x"]);
+    /// ```
+    #[must_use]
+    pub fn from_id(id: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: None,
+            markdown: None,
+            id: Some(id.into()),
+            arguments: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the Markdown formatted message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("unused variable: x").with_markdown("unused variable: `x`");
+    ///
+    /// assert_eq!(message.markdown.unwrap(), "unused variable: `x`");
+    /// ```
+    #[must_use]
+    pub fn with_markdown(mut self, markdown: impl Into<Cow<'s, str>>) -> Self {
+        self.markdown = Some(markdown.into());
+        self
+    }
+
+    /// Adds arguments to substitute into the placeholders of the message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("unused variable: `{0}`").with_arguments(["x"]);
+    ///
+    /// assert_eq!(message.arguments, ["x"]);
+    /// ```
+    #[must_use]
+    pub fn with_arguments(
+        mut self,
+        arguments: impl IntoIterator<Item = impl Into<Cow<'s, str>>>,
+    ) -> Self {
+        self.arguments.extend(arguments.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the properties of the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, PropertyBag};
+    ///
+    /// let message =
+    ///     Message::new("unused variable").with_properties(PropertyBag::new().with_tag("lint"));
+    ///
+    /// assert!(message.properties.tags.contains("lint"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
@@ -87,3 +87,69 @@ pub struct MultiformatMessageString<'s> {
     )]
     pub properties: PropertyBag<'s>,
 }
+
+impl<'s> MultiformatMessageString<'s> {
+    /// Creates a new `MultiformatMessageString` from a plain text message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::MultiformatMessageString;
+    ///
+    /// let message =
+    ///     MultiformatMessageString::new("Variable \"{0}\" was used without being initialized.");
+    ///
+    /// assert_eq!(
+    ///     message.text,
+    ///     "Variable \"{0}\" was used without being initialized."
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: text.into(),
+            markdown: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the Markdown formatted message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::MultiformatMessageString;
+    ///
+    /// let message =
+    ///     MultiformatMessageString::new("Variable \"{0}\" was used without being initialized.")
+    ///         .with_markdown("Variable `{0}` was used without being initialized.");
+    ///
+    /// assert_eq!(
+    ///     message.markdown.unwrap(),
+    ///     "Variable `{0}` was used without being initialized."
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_markdown(mut self, markdown: impl Into<Cow<'s, str>>) -> Self {
+        self.markdown = Some(markdown.into());
+        self
+    }
+
+    /// Sets the properties of the message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{MultiformatMessageString, PropertyBag};
+    ///
+    /// let message = MultiformatMessageString::new("mismatched types")
+    ///     .with_properties(PropertyBag::new().with_tag("rustc"));
+    ///
+    /// assert!(message.properties.tags.contains("rustc"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
@@ -178,6 +178,45 @@ impl<'s> ReportingDescriptor<'s> {
         self
     }
 
+    /// Sets the unique identifier for the report in the form of a GUID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ReportingDescriptor;
+    /// use uuid::Uuid;
+    ///
+    /// let guid = Uuid::from_u128(0x9D4C_A2E0_8F3A_4C6B_9A1D_5E7F_0B2C_4D6E);
+    /// let descriptor = ReportingDescriptor::new("E0308").with_guid(guid);
+    ///
+    /// assert_eq!(descriptor.guid, Some(guid));
+    /// ```
+    #[must_use]
+    pub const fn with_guid(mut self, guid: Uuid) -> Self {
+        self.guid = Some(guid);
+        self
+    }
+
+    /// Adds a unique identifier in the form of a GUID by which this report was known in some
+    /// previous version of the analysis tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ReportingDescriptor;
+    /// use uuid::Uuid;
+    ///
+    /// let guid = Uuid::from_u128(0x9D4C_A2E0_8F3A_4C6B_9A1D_5E7F_0B2C_4D6E);
+    /// let descriptor = ReportingDescriptor::new("E0308").with_deprecated_guid(guid);
+    ///
+    /// assert!(descriptor.deprecated_guids.contains(&guid));
+    /// ```
+    #[must_use]
+    pub fn with_deprecated_guid(mut self, guid: Uuid) -> Self {
+        self.deprecated_guids.insert(guid);
+        self
+    }
+
     /// Sets the name of the `ReportingDescriptor`.
     ///
     /// # Example
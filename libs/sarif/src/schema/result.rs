@@ -0,0 +1,685 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+
+use crate::schema::{ArtifactLocation, Location, LogicalLocation, Message, PropertyBag};
+
+/// The kind of a [`Result`], i.e. the outcome of evaluating a rule.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ResultKind {
+    /// The rule was evaluated, and the tool concluded that there was no problem.
+    Pass,
+    /// The rule was evaluated, and the tool concluded that there was a problem.
+    Fail,
+    /// The rule was evaluated, and the tool did not find a problem, but also could not be sure.
+    Review,
+    /// The rule was evaluated, and the tool could not determine whether a problem exists.
+    Open,
+    /// The rule was not evaluated, because it does not apply to the analysis target.
+    NotApplicable,
+    /// The result is purely informational and does not indicate a problem.
+    Informational,
+}
+
+/// The severity of a [`Result`].
+///
+/// Levels are ordered by their severity, [`Level::None`] being the least severe.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Level {
+    /// The concept of "severity" does not apply to this result because the kind is not
+    /// [`ResultKind::Fail`].
+    None,
+    /// A minor problem or an opportunity to improve the code was found.
+    Note,
+    /// A problem was found.
+    Warning,
+    /// A serious problem was found.
+    Error,
+}
+
+/// The state of a [`Result`] relative to a baseline of a previous run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum BaselineState {
+    /// The result was detected in the current run but not in the baseline.
+    New,
+    /// The result was detected in both runs, and is considered identical.
+    Unchanged,
+    /// The result was detected in both runs, but has changed in a way the tool considers
+    /// significant.
+    Updated,
+    /// The result was detected in the baseline but not in the current run.
+    Absent,
+}
+
+/// A result produced by an analysis tool.
+///
+/// See [SARIF specification §3.27](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317638)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Result<'s> {
+    /// The stable, unique identifier of the rule, if any, to which this result is relevant.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule_id: Option<Cow<'s, str>>,
+
+    /// The index within the rules of the tool component of the [`ReportingDescriptor`] to which
+    /// this result is relevant.
+    ///
+    /// [`ReportingDescriptor`]: crate::schema::ReportingDescriptor
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule_index: Option<usize>,
+
+    /// The kind of the result.
+    ///
+    /// If absent, the kind is [`ResultKind::Fail`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub kind: Option<ResultKind>,
+
+    /// The severity of the result.
+    ///
+    /// If absent, the level is determined by the configuration of the rule, falling back to
+    /// [`Level::Warning`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<Level>,
+
+    /// A message that describes the result.
+    ///
+    /// The first sentence of the message only will be displayed when visible space is limited.
+    ///
+    /// See [SARIF specification §3.27.11](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317649)
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: Message<'s>,
+
+    /// Identifies the artifact that the analysis tool was instructed to scan.
+    ///
+    /// This need not be the same as the artifact where the result actually occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub analysis_target: Option<ArtifactLocation<'s>>,
+
+    /// The locations where the result was detected.
+    ///
+    /// Only one location should be specified unless the problem indicated by the result can
+    /// only be corrected by making a change at every specified location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub locations: Vec<Location<'s>>,
+
+    /// A stable, unique identifier for the result in the form of a GUID.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// A stable, unique identifier for the equivalence class of logically identical results to
+    /// which this result belongs, in the form of a GUID.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub correlation_guid: Option<Uuid>,
+
+    /// The number of times this logically unique result was observed in this run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub occurrence_count: Option<usize>,
+
+    /// A set of strings that contribute to the stable, unique identity of the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub partial_fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// A set of strings each of which individually defines a stable, unique identity for the
+    /// result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// A set of locations relevant to this result.
+    ///
+    /// These can be referenced from the [`message`] through embedded links.
+    ///
+    /// [`message`]: Self::message
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub related_locations: Vec<Location<'s>>,
+
+    /// The state of the result with respect to a previous baseline run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub baseline_state: Option<BaselineState>,
+
+    /// An absolute URI at which the result can be viewed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub hosted_viewer_uri: Option<Url>,
+
+    /// Key/value pairs that provide additional information about the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Result<'s> {
+    /// Creates a new `Result` with the given message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable: `x`"));
+    ///
+    /// assert_eq!(result.message.text.unwrap(), "unused variable: `x`");
+    /// ```
+    #[must_use]
+    pub const fn new(message: Message<'s>) -> Self {
+        Self {
+            rule_id: None,
+            rule_index: None,
+            kind: None,
+            level: None,
+            message,
+            analysis_target: None,
+            locations: Vec::new(),
+            guid: None,
+            correlation_guid: None,
+            occurrence_count: None,
+            partial_fingerprints: BTreeMap::new(),
+            fingerprints: BTreeMap::new(),
+            related_locations: Vec::new(),
+            baseline_state: None,
+            hosted_viewer_uri: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the identifier of the rule this result is relevant to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable: `x`")).with_rule_id("unused_variables");
+    ///
+    /// assert_eq!(result.rule_id.unwrap(), "unused_variables");
+    /// ```
+    #[must_use]
+    pub fn with_rule_id(mut self, rule_id: impl Into<Cow<'s, str>>) -> Self {
+        self.rule_id = Some(rule_id.into());
+        self
+    }
+
+    /// Sets the index of the rule this result is relevant to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable: `x`")).with_rule_index(0);
+    ///
+    /// assert_eq!(result.rule_index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_rule_index(mut self, rule_index: usize) -> Self {
+        self.rule_index = Some(rule_index);
+        self
+    }
+
+    /// Sets the kind of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, ResultKind};
+    ///
+    /// let result = Result::new(Message::new("no unsafe code found")).with_kind(ResultKind::Pass);
+    ///
+    /// assert_eq!(result.kind, Some(ResultKind::Pass));
+    /// ```
+    #[must_use]
+    pub const fn with_kind(mut self, kind: ResultKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Sets the severity of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Level, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_level(Level::Error);
+    ///
+    /// assert_eq!(result.level, Some(Level::Error));
+    /// ```
+    #[must_use]
+    pub const fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Sets the artifact the analysis tool was instructed to scan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"))
+    ///     .with_analysis_target(ArtifactLocation::new("src/lib.rs"));
+    ///
+    /// assert_eq!(result.analysis_target.unwrap().uri.unwrap(), "src/lib.rs");
+    /// ```
+    #[must_use]
+    pub fn with_analysis_target(mut self, analysis_target: ArtifactLocation<'s>) -> Self {
+        self.analysis_target = Some(analysis_target);
+        self
+    }
+
+    /// Adds a location where the result was detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, Message, PhysicalLocation, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_location(
+    ///     Location::new()
+    ///         .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))),
+    /// );
+    ///
+    /// assert_eq!(result.locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Sets the GUID of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    /// use uuid::Uuid;
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_guid(Uuid::nil());
+    ///
+    /// assert_eq!(result.guid, Some(Uuid::nil()));
+    /// ```
+    #[must_use]
+    pub const fn with_guid(mut self, guid: Uuid) -> Self {
+        self.guid = Some(guid);
+        self
+    }
+
+    /// Sets the GUID of the equivalence class this result belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    /// use uuid::Uuid;
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_correlation_guid(Uuid::nil());
+    ///
+    /// assert_eq!(result.correlation_guid, Some(Uuid::nil()));
+    /// ```
+    #[must_use]
+    pub const fn with_correlation_guid(mut self, correlation_guid: Uuid) -> Self {
+        self.correlation_guid = Some(correlation_guid);
+        self
+    }
+
+    /// Sets the number of times this result was observed in the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_occurrence_count(3);
+    ///
+    /// assert_eq!(result.occurrence_count, Some(3));
+    /// ```
+    #[must_use]
+    pub const fn with_occurrence_count(mut self, occurrence_count: usize) -> Self {
+        self.occurrence_count = Some(occurrence_count);
+        self
+    }
+
+    /// Adds a partial fingerprint contributing to the identity of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"))
+    ///     .with_partial_fingerprint("primaryLocationLineHash/v1", "39fa2ee980eb94b0:1");
+    ///
+    /// assert_eq!(
+    ///     result.partial_fingerprints["primaryLocationLineHash/v1"],
+    ///     "39fa2ee980eb94b0:1"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_partial_fingerprint(
+        mut self,
+        key: impl Into<Cow<'s, str>>,
+        fingerprint: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.partial_fingerprints
+            .insert(key.into(), fingerprint.into());
+        self
+    }
+
+    /// Adds a fingerprint defining the identity of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"))
+    ///     .with_fingerprint("stableResultHash/v1", "c5d0fe8f2a1b7d44");
+    ///
+    /// assert_eq!(
+    ///     result.fingerprints["stableResultHash/v1"],
+    ///     "c5d0fe8f2a1b7d44"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_fingerprint(
+        mut self,
+        key: impl Into<Cow<'s, str>>,
+        fingerprint: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.fingerprints.insert(key.into(), fingerprint.into());
+        self
+    }
+
+    /// Adds a location relevant to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"))
+    ///     .with_related_location(Location::new().with_id(0));
+    ///
+    /// assert_eq!(result.related_locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_related_location(mut self, location: Location<'s>) -> Self {
+        self.related_locations.push(location);
+        self
+    }
+
+    /// Sets the state of the result with respect to a previous baseline run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{BaselineState, Message, Result};
+    ///
+    /// let result =
+    ///     Result::new(Message::new("mismatched types")).with_baseline_state(BaselineState::New);
+    ///
+    /// assert_eq!(result.baseline_state, Some(BaselineState::New));
+    /// ```
+    #[must_use]
+    pub const fn with_baseline_state(mut self, baseline_state: BaselineState) -> Self {
+        self.baseline_state = Some(baseline_state);
+        self
+    }
+
+    /// Sets the URI at which the result can be viewed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    /// use url::Url;
+    ///
+    /// let uri = Url::parse("https://example.com/results/1").unwrap();
+    /// let result = Result::new(Message::new("mismatched types")).with_hosted_viewer_uri(uri.clone());
+    ///
+    /// assert_eq!(result.hosted_viewer_uri, Some(uri));
+    /// ```
+    #[must_use]
+    pub fn with_hosted_viewer_uri(mut self, hosted_viewer_uri: Url) -> Self {
+        self.hosted_viewer_uri = Some(hosted_viewer_uri);
+        self
+    }
+
+    /// Returns the severity of the result, applying the defaults of the SARIF specification if
+    /// [`level`] is absent.
+    ///
+    /// A result without a level has the level [`Level::Warning`] if its kind is
+    /// [`ResultKind::Fail`] or absent, and [`Level::None`] otherwise. Defaults provided by the
+    /// configuration of the rule are not taken into account.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Level, Message, Result, ResultKind};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"));
+    /// assert_eq!(result.effective_level(), Level::Warning);
+    ///
+    /// let result = result.with_kind(ResultKind::Pass);
+    /// assert_eq!(result.effective_level(), Level::None);
+    ///
+    /// let result = result.with_level(Level::Note);
+    /// assert_eq!(result.effective_level(), Level::Note);
+    /// ```
+    ///
+    /// [`level`]: Self::level
+    #[must_use]
+    pub const fn effective_level(&self) -> Level {
+        match (self.level, self.kind) {
+            (Some(level), _) => level,
+            (None, None | Some(ResultKind::Fail)) => Level::Warning,
+            (None, Some(_)) => Level::None,
+        }
+    }
+
+    /// Returns all artifact locations referenced by the result.
+    pub(crate) fn artifact_locations_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut ArtifactLocation<'s>> {
+        self.analysis_target.iter_mut().chain(
+            self.locations
+                .iter_mut()
+                .chain(&mut self.related_locations)
+                .filter_map(|location| location.physical_location.as_mut())
+                .filter_map(|location| location.artifact_location.as_mut()),
+        )
+    }
+
+    /// Returns all logical locations referenced by the result.
+    pub(crate) fn logical_locations_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut LogicalLocation<'s>> {
+        self.locations
+            .iter_mut()
+            .chain(&mut self.related_locations)
+            .flat_map(|location| &mut location.logical_locations)
+    }
+
+    /// Sets the properties of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, PropertyBag, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"))
+    ///     .with_properties(PropertyBag::new().with_tag("correctness"));
+    ///
+    /// assert!(result.properties.tags.contains("correctness"));
+    /// ```
+    #[must_use]
+    pub fn with_properties(mut self, properties: PropertyBag<'s>) -> Self {
+        self.properties = properties;
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+    use url::Url;
+    use uuid::Uuid;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, BaselineState, Level, Location, Message,
+        PhysicalLocation, Region, Result, ResultKind, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent,
+    };
+
+    fn create_log(result: Result) -> SarifLog {
+        SarifLog::new(SchemaVersion::V2_1_0)
+            .with_run(Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(result))
+    }
+
+    #[test]
+    fn minimal() {
+        validate_schema(&create_log(Result::new(Message::new("mismatched types"))));
+    }
+
+    #[test]
+    fn full() {
+        let result = Result::new(Message::new("mismatched types"))
+            .with_rule_id("E0308")
+            .with_rule_index(0)
+            .with_kind(ResultKind::Fail)
+            .with_level(Level::Error)
+            .with_analysis_target(ArtifactLocation::new("src/lib.rs"))
+            .with_location(
+                Location::new().with_physical_location(
+                    PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))
+                        .with_region(Region::new().with_start_line(15)),
+                ),
+            )
+            .with_guid(Uuid::from_u128(0x9D4C_A2E0_8F3A_4C6B_9A1D_5E7F_0B2C_4D6E))
+            .with_correlation_guid(Uuid::from_u128(0x1F2E_3D4C_5B6A_4789_8A9B_0C1D_2E3F_4A5B))
+            .with_occurrence_count(1)
+            .with_partial_fingerprint("primaryLocationLineHash/v1", "39fa2ee980eb94b0:1")
+            .with_fingerprint("stableResultHash/v1", "c5d0fe8f2a1b7d44")
+            .with_related_location(Location::new().with_id(0))
+            .with_baseline_state(BaselineState::Unchanged)
+            .with_hosted_viewer_uri(
+                Url::parse("https://example.com/results/1").expect("valid URL"),
+            );
+
+        validate_schema(&create_log(result));
+    }
+
+    #[test]
+    fn kinds() {
+        for kind in [
+            ResultKind::Pass,
+            ResultKind::Fail,
+            ResultKind::Review,
+            ResultKind::Open,
+            ResultKind::NotApplicable,
+            ResultKind::Informational,
+        ] {
+            validate_schema(&create_log(
+                Result::new(Message::new("mismatched types")).with_kind(kind),
+            ));
+        }
+    }
+
+    #[test]
+    fn levels() {
+        for level in [Level::None, Level::Note, Level::Warning, Level::Error] {
+            validate_schema(&create_log(
+                Result::new(Message::new("mismatched types")).with_level(level),
+            ));
+        }
+    }
+
+    #[test]
+    fn level_order() {
+        assert!(Level::None < Level::Note);
+        assert!(Level::Note < Level::Warning);
+        assert!(Level::Warning < Level::Error);
+    }
+
+    #[test]
+    fn baseline_states() {
+        for state in [
+            BaselineState::New,
+            BaselineState::Unchanged,
+            BaselineState::Updated,
+            BaselineState::Absent,
+        ] {
+            validate_schema(&create_log(
+                Result::new(Message::new("mismatched types")).with_baseline_state(state),
+            ));
+        }
+    }
+}
@@ -1,7 +1,11 @@
+use alloc::{borrow::ToOwned, collections::BTreeMap, vec, vec::Vec};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::schema::Tool;
+use crate::schema::{
+    Artifact, BaselineState, Invocation, Level, LogicalLocation, ReportingDescriptor, Result, Tool,
+};
 
 /// Describes a single run of an analysis tool, and contains the reported output of that run.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,6 +23,38 @@ pub struct Run<'s> {
     /// command-line arguments and the like) is identical for all aggregated files.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub tool: Tool<'s>,
+
+    /// Describes the invocations of the analysis tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub invocations: Vec<Invocation<'s>>,
+
+    /// The artifacts relevant to the run.
+    ///
+    /// Results refer to an artifact by its index in this array.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub artifacts: Vec<Artifact<'s>>,
+
+    /// The logical locations relevant to the run.
+    ///
+    /// Results refer to a logical location by its index in this array.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub logical_locations: Vec<LogicalLocation<'s>>,
+
+    /// The results produced by the analysis tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub results: Vec<Result<'s>>,
 }
 
 impl<'s> Run<'s> {
@@ -35,17 +71,441 @@ impl<'s> Run<'s> {
     /// ```
     #[must_use]
     pub const fn new(tool: Tool<'s>) -> Self {
-        Self { tool }
+        Self {
+            tool,
+            invocations: Vec::new(),
+            artifacts: Vec::new(),
+            logical_locations: Vec::new(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Adds an invocation of the analysis tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Run, Tool, ToolComponent};
+    ///
+    /// let run =
+    ///     Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(Invocation::new(true));
+    ///
+    /// assert_eq!(run.invocations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_invocation(mut self, invocation: Invocation<'s>) -> Self {
+        self.invocations.push(invocation);
+        self
+    }
+
+    /// Adds an artifact relevant to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs")));
+    ///
+    /// assert_eq!(run.artifacts.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_artifact(mut self, artifact: Artifact<'s>) -> Self {
+        self.artifacts.push(artifact);
+        self
+    }
+
+    /// Adds a logical location relevant to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{LogicalLocation, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_logical_location(LogicalLocation::new().with_name("main"));
+    ///
+    /// assert_eq!(run.logical_locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_logical_location(mut self, logical_location: LogicalLocation<'s>) -> Self {
+        self.logical_locations.push(logical_location);
+        self
+    }
+
+    /// Adds a result produced by the analysis tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_result(Result::new(Message::new("unused variable: `x`")));
+    ///
+    /// assert_eq!(run.results.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_result(mut self, result: Result<'s>) -> Self {
+        self.results.push(result);
+        self
+    }
+
+    /// Adds results produced by the analysis tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([
+    ///     Result::new(Message::new("unused variable: `x`")),
+    ///     Result::new(Message::new("unused variable: `y`")),
+    /// ]);
+    ///
+    /// assert_eq!(run.results.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_results(mut self, results: impl IntoIterator<Item = Result<'s>>) -> Self {
+        self.results.extend(results);
+        self
+    }
+
+    /// Returns the rule of the driver the result is relevant to.
+    ///
+    /// The rule is looked up by [`Result::rule_index`] if present, otherwise by
+    /// [`Result::rule_id`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, ReportingDescriptor, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(
+    ///     ToolComponent::new("rustc").with_rule(ReportingDescriptor::new("E0308")),
+    /// ));
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_rule_id("E0308");
+    ///
+    /// assert_eq!(run.rule(&result).unwrap().id, "E0308");
+    /// ```
+    #[must_use]
+    pub fn rule(&self, result: &Result<'_>) -> Option<&ReportingDescriptor<'s>> {
+        let rules = &self.tool.driver.rules;
+
+        match (result.rule_index, &result.rule_id) {
+            (Some(index), _) => rules.get(index),
+            (None, Some(rule_id)) => rules.iter().find(|rule| rule.id == *rule_id),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the identifier of the rule the result is relevant to.
+    ///
+    /// If the result does not specify [`Result::rule_id`], the rule is looked up by
+    /// [`Result::rule_index`] in the rules of the driver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, ReportingDescriptor, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(
+    ///     ToolComponent::new("rustc").with_rule(ReportingDescriptor::new("E0308")),
+    /// ));
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_rule_index(0);
+    ///
+    /// assert_eq!(run.rule_id(&result), Some("E0308"));
+    /// ```
+    #[must_use]
+    pub fn rule_id<'r>(&'r self, result: &'r Result<'s>) -> Option<&'r str> {
+        resolve_rule_id(&self.tool.driver.rules, result)
+    }
+
+    /// Retains only the results for which `predicate` returns `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let mut run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([
+    ///     Result::new(Message::new("unused variable: `x`")),
+    ///     Result::new(Message::new("unused variable: `_y`")),
+    /// ]);
+    ///
+    /// run.retain_results(|result| !result.message.text.as_ref().unwrap().contains('_'));
+    ///
+    /// assert_eq!(run.results.len(), 1);
+    /// ```
+    pub fn retain_results(&mut self, predicate: impl FnMut(&Result<'s>) -> bool) {
+        self.results.retain(predicate);
+    }
+
+    /// Retains only the results which are at least as severe as `minimum`.
+    ///
+    /// The severity of a result is determined by [`Result::effective_level`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Level, Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let mut run = Run::new(Tool::new(ToolComponent::new("rustc"))).with_results([
+    ///     Result::new(Message::new("mismatched types")).with_level(Level::Error),
+    ///     Result::new(Message::new("unused variable: `x`")).with_level(Level::Warning),
+    ///     Result::new(Message::new("consider using `let`")).with_level(Level::Note),
+    /// ]);
+    ///
+    /// run.retain_level(Level::Warning);
+    ///
+    /// assert_eq!(run.results.len(), 2);
+    /// ```
+    pub fn retain_level(&mut self, minimum: Level) {
+        self.results
+            .retain(|result| result.effective_level() >= minimum);
+    }
+
+    /// Retains only the results which are relevant to one of the rules in `rule_ids`.
+    ///
+    /// The rule of a result is determined by [`Run::rule_id`]. Results without a rule are
+    /// removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let mut run = Run::new(Tool::new(ToolComponent::new("rustc"))).with_results([
+    ///     Result::new(Message::new("mismatched types")).with_rule_id("E0308"),
+    ///     Result::new(Message::new("unused variable: `x`")).with_rule_id("unused_variables"),
+    /// ]);
+    ///
+    /// run.retain_rule_ids(&["E0308"]);
+    ///
+    /// assert_eq!(run.results.len(), 1);
+    /// ```
+    pub fn retain_rule_ids(&mut self, rule_ids: &[&str]) {
+        let rules = &self.tool.driver.rules;
+        self.results.retain(|result| {
+            resolve_rule_id(rules, result).is_some_and(|rule_id| rule_ids.contains(&rule_id))
+        });
+    }
+
+    /// Merges the content of another run of the same tool into this run.
+    ///
+    /// Invocations, artifacts, logical locations and results of `other` are appended to this
+    /// run. Indices into [`artifacts`] and [`logical_locations`] are adjusted accordingly. The
+    /// rules of `other` are expected to be identical to the rules of this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{
+    ///     Artifact, ArtifactLocation, Location, Message, PhysicalLocation, Result, Run, Tool,
+    ///     ToolComponent,
+    /// };
+    ///
+    /// let run = |path: &'static str| {
+    ///     Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///         .with_artifact(Artifact::new(ArtifactLocation::new(path)))
+    ///         .with_result(Result::new(Message::new("unused variable")).with_location(
+    ///             Location::new().with_physical_location(PhysicalLocation::new(
+    ///                 ArtifactLocation::new(path).with_index(0),
+    ///             )),
+    ///         ))
+    /// };
+    ///
+    /// let mut merged = run("src/lib.rs");
+    /// merged.merge(run("src/main.rs"));
+    ///
+    /// assert_eq!(merged.artifacts.len(), 2);
+    /// assert_eq!(
+    ///     merged.results[1].locations[0]
+    ///         .physical_location
+    ///         .as_ref()
+    ///         .and_then(|location| location.artifact_location.as_ref())
+    ///         .and_then(|location| location.index),
+    ///     Some(1)
+    /// );
+    /// ```
+    ///
+    /// [`artifacts`]: Self::artifacts
+    /// [`logical_locations`]: Self::logical_locations
+    pub fn merge(&mut self, mut other: Self) {
+        let artifact_offset = self.artifacts.len();
+        let logical_location_offset = self.logical_locations.len();
+
+        for artifact in &mut other.artifacts {
+            offset_index(&mut artifact.parent_index, artifact_offset);
+            if let Some(location) = &mut artifact.location {
+                offset_index(&mut location.index, artifact_offset);
+            }
+        }
+        for logical_location in &mut other.logical_locations {
+            offset_index(&mut logical_location.index, logical_location_offset);
+            offset_index(&mut logical_location.parent_index, logical_location_offset);
+        }
+        for result in &mut other.results {
+            for location in result.artifact_locations_mut() {
+                offset_index(&mut location.index, artifact_offset);
+            }
+            for logical_location in result.logical_locations_mut() {
+                offset_index(&mut logical_location.index, logical_location_offset);
+            }
+        }
+
+        self.invocations.append(&mut other.invocations);
+        self.artifacts.append(&mut other.artifacts);
+        self.logical_locations.append(&mut other.logical_locations);
+        self.results.append(&mut other.results);
+    }
+
+    /// Sets the [`baseline_state`] of every result by comparing it to the results of `baseline`.
+    ///
+    /// Results are matched by their rule and their [`partial_fingerprints`]:
+    ///
+    /// - A result without a match in `baseline` is [`BaselineState::New`]. This includes all
+    ///   results without partial fingerprints.
+    /// - A matched result is [`BaselineState::Unchanged`] if its message and [effective level] are
+    ///   the same as in `baseline`, otherwise it's [`BaselineState::Updated`].
+    /// - A result of `baseline` which was not matched is added to this run as
+    ///   [`BaselineState::Absent`]. As its locations refer to the baseline run, indices into
+    ///   [`artifacts`] and [`logical_locations`] are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{BaselineState, Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let result = |line: &'static str| {
+    ///     Result::new(Message::new("unused variable"))
+    ///         .with_rule_id("unused_variables")
+    ///         .with_partial_fingerprint("primaryLocationLineHash", line)
+    /// };
+    ///
+    /// let baseline =
+    ///     Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([result("1"), result("2")]);
+    /// let mut run =
+    ///     Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([result("2"), result("3")]);
+    ///
+    /// run.compare_with_baseline(&baseline);
+    ///
+    /// let states: Vec<_> = run
+    ///     .results
+    ///     .iter()
+    ///     .map(|result| result.baseline_state)
+    ///     .collect();
+    /// assert_eq!(states, [
+    ///     Some(BaselineState::Unchanged),
+    ///     Some(BaselineState::New),
+    ///     Some(BaselineState::Absent),
+    /// ]);
+    /// ```
+    ///
+    /// [`baseline_state`]: Result::baseline_state
+    /// [`partial_fingerprints`]: Result::partial_fingerprints
+    /// [effective level]: Result::effective_level
+    /// [`artifacts`]: Self::artifacts
+    /// [`logical_locations`]: Self::logical_locations
+    pub fn compare_with_baseline(&mut self, baseline: &Self) {
+        let mut matched = vec![false; baseline.results.len()];
+
+        let states: Vec<_> = {
+            // The candidates are stored in reverse order, so that `pop` returns the first one
+            let mut candidates = BTreeMap::<_, Vec<usize>>::new();
+            for (index, result) in baseline.results.iter().enumerate().rev() {
+                if !result.partial_fingerprints.is_empty() {
+                    candidates
+                        .entry((baseline.rule_id(result), &result.partial_fingerprints))
+                        .or_default()
+                        .push(index);
+                }
+            }
+
+            self.results
+                .iter()
+                .map(|result| {
+                    let Some(index) = candidates
+                        .get_mut(&(self.rule_id(result), &result.partial_fingerprints))
+                        .and_then(Vec::pop)
+                    else {
+                        return BaselineState::New;
+                    };
+                    matched[index] = true;
+
+                    let previous = &baseline.results[index];
+                    if previous.message == result.message
+                        && previous.effective_level() == result.effective_level()
+                    {
+                        BaselineState::Unchanged
+                    } else {
+                        BaselineState::Updated
+                    }
+                })
+                .collect()
+        };
+
+        for (result, state) in self.results.iter_mut().zip(states) {
+            result.baseline_state = Some(state);
+        }
+
+        for (result, matched) in baseline.results.iter().zip(matched) {
+            if matched {
+                continue;
+            }
+
+            let mut result = result.clone();
+            if result.rule_id.is_none() {
+                result.rule_id = baseline
+                    .rule_id(&result)
+                    .map(|rule_id| rule_id.to_owned().into());
+            }
+            result.rule_index = None;
+            for location in result.artifact_locations_mut() {
+                location.index = None;
+            }
+            for logical_location in result.logical_locations_mut() {
+                logical_location.index = None;
+            }
+            result.baseline_state = Some(BaselineState::Absent);
+
+            self.results.push(result);
+        }
+    }
+}
+
+fn resolve_rule_id<'r>(
+    rules: &'r [ReportingDescriptor<'_>],
+    result: &'r Result<'_>,
+) -> Option<&'r str> {
+    result.rule_id.as_deref().or_else(|| {
+        result
+            .rule_index
+            .and_then(|index| rules.get(index))
+            .map(|rule| rule.id.as_ref())
+    })
+}
+
+fn offset_index(index: &mut Option<usize>, offset: usize) {
+    if let Some(index) = index {
+        *index += offset;
     }
 }
 
 #[cfg(test)]
 #[cfg(feature = "serde")]
 pub(crate) mod tests {
+    use alloc::vec::Vec;
+
     use coverage_helper::test;
 
     use crate::schema::{
-        tests::validate_schema, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+        tests::validate_schema, Artifact, ArtifactLocation, BaselineState, Invocation, Level,
+        Location, LogicalLocation, Message, PhysicalLocation, ReportingDescriptor, Result,
+        ResultKind, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
     };
 
     #[test]
@@ -54,4 +514,190 @@ pub(crate) mod tests {
 
         validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
     }
+
+    #[test]
+    fn with_results() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_invocation(Invocation::new(true))
+            .with_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs")))
+            .with_logical_location(LogicalLocation::new().with_name("main"))
+            .with_results([
+                Result::new(Message::new("unused variable: `x`")),
+                Result::new(Message::from_id("default").with_arguments(["y"])),
+            ]);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    fn located_result(path: &'static str) -> Result<'static> {
+        Result::new(Message::new("unused variable"))
+            .with_analysis_target(ArtifactLocation::new(path).with_index(0))
+            .with_location(
+                Location::new()
+                    .with_physical_location(PhysicalLocation::new(
+                        ArtifactLocation::new(path).with_index(0),
+                    ))
+                    .with_logical_location(LogicalLocation::new().with_name("main").with_index(1)),
+            )
+    }
+
+    fn located_run(path: &'static str) -> Run<'static> {
+        Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_invocation(Invocation::new(true))
+            .with_artifact(Artifact::new(ArtifactLocation::new(path).with_index(0)))
+            .with_artifact(
+                Artifact::new(ArtifactLocation::new("src").with_index(1)).with_parent_index(0),
+            )
+            .with_logical_location(LogicalLocation::new().with_name("crate").with_index(0))
+            .with_logical_location(
+                LogicalLocation::new()
+                    .with_name("main")
+                    .with_index(1)
+                    .with_parent_index(0),
+            )
+            .with_result(located_result(path))
+    }
+
+    #[test]
+    fn merge() {
+        let mut run = located_run("src/lib.rs");
+        run.merge(located_run("src/main.rs"));
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run.clone()));
+
+        assert_eq!(run.invocations.len(), 2);
+        assert_eq!(run.artifacts.len(), 4);
+        assert_eq!(run.logical_locations.len(), 4);
+        assert_eq!(run.results.len(), 2);
+
+        let artifact = &run.artifacts[3];
+        assert_eq!(artifact.parent_index, Some(2));
+        assert_eq!(
+            artifact
+                .location
+                .as_ref()
+                .and_then(|location| location.index),
+            Some(3)
+        );
+        assert_eq!(run.logical_locations[3].index, Some(3));
+        assert_eq!(run.logical_locations[3].parent_index, Some(2));
+
+        let mut result = run.results[1].clone();
+        assert_eq!(
+            result
+                .artifact_locations_mut()
+                .map(|location| location.index)
+                .collect::<Vec<_>>(),
+            [Some(2), Some(2)]
+        );
+        assert_eq!(
+            result
+                .logical_locations_mut()
+                .map(|location| location.index)
+                .collect::<Vec<_>>(),
+            [Some(3)]
+        );
+        assert_eq!(run.results[0], located_result("src/lib.rs"));
+    }
+
+    #[test]
+    fn retain_level() {
+        let mut run = Run::new(Tool::new(ToolComponent::new("rustc"))).with_results([
+            Result::new(Message::new("mismatched types")).with_level(Level::Error),
+            Result::new(Message::new("unused variable: `x`")),
+            Result::new(Message::new("consider using `let`")).with_level(Level::Note),
+            Result::new(Message::new("all types match")).with_kind(ResultKind::Pass),
+        ]);
+
+        run.retain_level(Level::Warning);
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run.clone()));
+
+        assert_eq!(
+            run.results
+                .iter()
+                .map(Result::effective_level)
+                .collect::<Vec<_>>(),
+            [Level::Error, Level::Warning]
+        );
+    }
+
+    #[test]
+    fn retain_rule_ids() {
+        let mut run = Run::new(Tool::new(
+            ToolComponent::new("rustc")
+                .with_rule(ReportingDescriptor::new("E0308"))
+                .with_rule(ReportingDescriptor::new("unused_variables")),
+        ))
+        .with_results([
+            Result::new(Message::new("mismatched types")).with_rule_id("E0308"),
+            Result::new(Message::new("mismatched types")).with_rule_index(0),
+            Result::new(Message::new("unused variable: `x`")).with_rule_index(1),
+            Result::new(Message::new("internal compiler error")),
+        ]);
+
+        run.retain_rule_ids(&["E0308"]);
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run.clone()));
+
+        assert_eq!(run.results.len(), 2);
+        assert!(
+            run.results
+                .iter()
+                .all(|result| run.rule_id(result) == Some("E0308"))
+        );
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let tool = Tool::new(
+            ToolComponent::new("clippy").with_rule(ReportingDescriptor::new("unused_variables")),
+        );
+        let result = |message: &'static str, hash: &'static str| {
+            Result::new(Message::new(message))
+                .with_rule_index(0)
+                .with_partial_fingerprint("primaryLocationLineHash", hash)
+        };
+
+        let baseline = Run::new(tool.clone())
+            .with_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs")))
+            .with_results([
+                result("unused variable: `x`", "1"),
+                result("unused variable: `y`", "2"),
+                result("unused variable: `z`", "3")
+                    .with_analysis_target(ArtifactLocation::new("src/lib.rs").with_index(0)),
+            ]);
+        let mut run = Run::new(tool).with_results([
+            result("unused variable: `x`", "1"),
+            result("unused variable: `y` in `main`", "2"),
+            result("unused variable: `w`", "4"),
+            Result::new(Message::new("unused variable: `v`")).with_rule_index(0),
+        ]);
+
+        run.compare_with_baseline(&baseline);
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run.clone()));
+
+        assert_eq!(
+            run.results
+                .iter()
+                .map(|result| result.baseline_state)
+                .collect::<Vec<_>>(),
+            [
+                Some(BaselineState::Unchanged),
+                Some(BaselineState::Updated),
+                Some(BaselineState::New),
+                Some(BaselineState::New),
+                Some(BaselineState::Absent),
+            ]
+        );
+
+        let absent = &run.results[4];
+        assert_eq!(absent.rule_id.as_deref(), Some("unused_variables"));
+        assert_eq!(absent.rule_index, None);
+        assert_eq!(
+            absent
+                .analysis_target
+                .as_ref()
+                .and_then(|location| location.index),
+            None
+        );
+    }
 }
@@ -2,6 +2,7 @@ use alloc::{borrow::Cow, vec::Vec};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 use crate::schema::{PropertyBag, ReportingDescriptor};
 
@@ -155,6 +156,29 @@ pub struct ToolComponent<'s> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub name: Cow<'s, str>,
 
+    /// The name of the tool component along with its version and any other useful identifying
+    /// information, such as its locale.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub full_name: Option<Cow<'s, str>>,
+
+    /// The organization or company that produced the tool component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub organization: Option<Cow<'s, str>>,
+
+    /// The absolute URI at which information about this version of the tool component can be
+    /// found.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub information_uri: Option<Url>,
+
     /// The tool component version, in whatever format the component natively provides.
     #[cfg_attr(
         feature = "serde",
@@ -190,15 +214,75 @@ impl<'s> ToolComponent<'s> {
     ///
     /// assert_eq!(tool_component.name, "prettier");
     /// ```
+    #[must_use]
     pub fn new(name: impl Into<Cow<'s, str>>) -> Self {
         Self {
             name: name.into(),
+            full_name: None,
+            organization: None,
+            information_uri: None,
             version: None,
             semantic_version: None,
             rules: Vec::new(),
         }
     }
 
+    /// Set the full name of the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponent;
+    ///
+    /// let tool_component = ToolComponent::new("clippy").with_full_name("clippy 0.1.70 (en-US)");
+    ///
+    /// assert_eq!(
+    ///     tool_component.full_name,
+    ///     Some("clippy 0.1.70 (en-US)".into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_full_name(mut self, full_name: impl Into<Cow<'s, str>>) -> Self {
+        self.full_name = Some(full_name.into());
+        self
+    }
+
+    /// Set the organization that produced the tool component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponent;
+    ///
+    /// let tool_component = ToolComponent::new("clippy").with_organization("The Rust Project");
+    ///
+    /// assert_eq!(tool_component.organization, Some("The Rust Project".into()));
+    /// ```
+    #[must_use]
+    pub fn with_organization(mut self, organization: impl Into<Cow<'s, str>>) -> Self {
+        self.organization = Some(organization.into());
+        self
+    }
+
+    /// Set the URI at which information about the tool component can be found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ToolComponent;
+    /// use url::Url;
+    ///
+    /// let uri = Url::parse("https://github.com/rust-lang/rust-clippy").unwrap();
+    /// let tool_component = ToolComponent::new("clippy").with_information_uri(uri.clone());
+    ///
+    /// assert_eq!(tool_component.information_uri, Some(uri));
+    /// ```
+    #[must_use]
+    pub fn with_information_uri(mut self, information_uri: impl Into<Url>) -> Self {
+        self.information_uri = Some(information_uri.into());
+        self
+    }
+
     /// Set the version of the tool component.
     ///
     /// # Example
@@ -0,0 +1,354 @@
+//! Incremental serialization of SARIF logs.
+//!
+//! Serializing a [`SarifLog`] requires all results to be held in memory at once. For tools that
+//! report a large number of results, [`SarifWriter`] instead writes the log piece by piece: the
+//! log header is written on creation, each run is opened with [`SarifWriter::begin_run`], results
+//! are streamed through the returned [`RunWriter`], and the artifact table of the run is written
+//! once the run is finished.
+//!
+//! [`SarifLog`]: crate::schema::SarifLog
+
+use alloc::vec::Vec;
+use core::mem;
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::schema::{Artifact, Result, Run, SchemaVersion};
+
+/// Writes a SARIF log incrementally to an [`io::Write`].
+///
+/// The output is only a valid SARIF log after [`finish`] has been called.
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     schema::{Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent},
+///     writer::SarifWriter,
+/// };
+///
+/// let mut writer = SarifWriter::new(Vec::new(), SchemaVersion::V2_1_0)?;
+///
+/// let mut run = writer.begin_run(Run::new(Tool::new(ToolComponent::new("clippy"))))?;
+/// run.write_result(&Result::new(Message::new("unused variable: `x`")))?;
+/// run.finish()?;
+///
+/// let output = writer.finish()?;
+/// let log: SarifLog = serde_json::from_slice(&output)?;
+///
+/// assert_eq!(log.runs.unwrap()[0].results.len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`finish`]: Self::finish
+#[derive(Debug)]
+pub struct SarifWriter<W> {
+    writer: W,
+    has_runs: bool,
+}
+
+impl<W: Write> SarifWriter<W> {
+    /// Creates a new `SarifWriter` and writes the header of the log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn new(mut writer: W, version: SchemaVersion) -> io::Result<Self> {
+        writer.write_all(b"{\"$schema\":")?;
+        serde_json::to_writer(&mut writer, version.schema_id())?;
+        writer.write_all(b",\"version\":")?;
+        serde_json::to_writer(&mut writer, &version)?;
+        writer.write_all(b",\"runs\":[")?;
+
+        Ok(Self {
+            writer,
+            has_runs: false,
+        })
+    }
+
+    /// Opens a new run.
+    ///
+    /// Everything but the results and artifacts of `run` is written immediately. Results already
+    /// contained in `run` are written before any result passed to [`RunWriter::write_result`],
+    /// artifacts are added to the artifact table of the run.
+    ///
+    /// The run is closed by calling [`RunWriter::finish`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying writer fails.
+    pub fn begin_run<'s>(&mut self, mut run: Run<'s>) -> io::Result<RunWriter<'_, 's, W>> {
+        let artifacts = mem::take(&mut run.artifacts);
+        let results = mem::take(&mut run.results);
+
+        if self.has_runs {
+            self.writer.write_all(b",")?;
+        }
+        self.has_runs = true;
+
+        // `run` is written without its closing brace, so the results can be appended
+        let mut header = serde_json::to_vec(&run)?;
+        header.pop();
+        if header.len() > 1 {
+            header.push(b',');
+        }
+        self.writer.write_all(&header)?;
+        self.writer.write_all(b"\"results\":[")?;
+
+        let mut run_writer = RunWriter {
+            writer: &mut self.writer,
+            artifacts: Vec::new(),
+            has_results: false,
+        };
+
+        for artifact in artifacts {
+            run_writer.add_artifact(artifact);
+        }
+        for result in &results {
+            run_writer.write_result(result)?;
+        }
+
+        Ok(run_writer)
+    }
+
+    /// Closes the log and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to or flushing the underlying writer fails.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.write_all(b"]}")?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+/// Streams the results of a single run, created by [`SarifWriter::begin_run`].
+///
+/// Artifacts are collected while the results are written and emitted as the artifact table of
+/// the run in [`finish`]. Dropping a `RunWriter` without calling [`finish`] leaves the log in an
+/// invalid state.
+///
+/// [`finish`]: Self::finish
+#[derive(Debug)]
+#[must_use = "the run must be closed by calling `finish`"]
+pub struct RunWriter<'w, 's, W> {
+    writer: &'w mut W,
+    artifacts: Vec<Artifact<'s>>,
+    has_results: bool,
+}
+
+impl<'s, W: Write> RunWriter<'_, 's, W> {
+    /// Writes a single result to the run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying writer fails.
+    pub fn write_result(&mut self, result: &Result<'_>) -> io::Result<()> {
+        if self.has_results {
+            self.writer.write_all(b",")?;
+        }
+        self.has_results = true;
+
+        result.serialize(&mut serde_json::Serializer::new(&mut *self.writer))?;
+
+        Ok(())
+    }
+
+    /// Adds an artifact to the artifact table of the run and returns its index.
+    ///
+    /// If an artifact with the same location was already added, the index of the existing
+    /// artifact is returned instead. The returned index can be used for
+    /// [`ArtifactLocation::index`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     schema::{Artifact, ArtifactLocation, Run, SchemaVersion, Tool, ToolComponent},
+    ///     writer::SarifWriter,
+    /// };
+    ///
+    /// let mut writer = SarifWriter::new(Vec::new(), SchemaVersion::V2_1_0)?;
+    /// let mut run = writer.begin_run(Run::new(Tool::new(ToolComponent::new("clippy"))))?;
+    ///
+    /// let lib = run.add_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs")));
+    /// let main = run.add_artifact(Artifact::new(ArtifactLocation::new("src/main.rs")));
+    ///
+    /// assert_eq!(lib, 0);
+    /// assert_eq!(main, 1);
+    /// assert_eq!(
+    ///     run.add_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs"))),
+    ///     lib
+    /// );
+    /// # run.finish()?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`ArtifactLocation::index`]: crate::schema::ArtifactLocation::index
+    pub fn add_artifact(&mut self, artifact: Artifact<'s>) -> usize {
+        let existing = artifact.location.as_ref().and_then(|location| {
+            self.artifacts.iter().position(|existing| {
+                existing.location.as_ref().is_some_and(|existing| {
+                    existing.uri == location.uri && existing.uri_base_id == location.uri_base_id
+                })
+            })
+        });
+
+        existing.unwrap_or_else(|| {
+            self.artifacts.push(artifact);
+            self.artifacts.len() - 1
+        })
+    }
+
+    /// Writes the artifact table and closes the run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying writer fails.
+    pub fn finish(self) -> io::Result<()> {
+        self.writer.write_all(b"]")?;
+
+        if !self.artifacts.is_empty() {
+            self.writer.write_all(b",\"artifacts\":")?;
+            serde_json::to_writer(&mut *self.writer, &self.artifacts)?;
+        }
+
+        self.writer.write_all(b"}")
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloc::vec::Vec;
+
+    use coverage_helper::test;
+    use url::Url;
+    use uuid::Uuid;
+
+    use super::SarifWriter;
+    use crate::schema::{
+        tests::validate_schema, Artifact, ArtifactLocation, Level, Location, Message,
+        MultiformatMessageString, PhysicalLocation, Region, ReportingDescriptor, Result, Run,
+        SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    fn parse(output: &[u8]) -> SarifLog<'_> {
+        serde_json::from_slice(output).expect("writer produced an invalid SARIF log")
+    }
+
+    fn result(line: usize, artifact: usize) -> Result<'static> {
+        Result::new(Message::new("unused variable"))
+            .with_rule_id("unused_variables")
+            .with_level(Level::Warning)
+            .with_location(
+                Location::new().with_physical_location(
+                    PhysicalLocation::new(ArtifactLocation::new("src/lib.rs").with_index(artifact))
+                        .with_region(Region::new().with_start_line(line)),
+                ),
+            )
+    }
+
+    #[test]
+    fn empty() {
+        let output = SarifWriter::new(Vec::new(), SchemaVersion::V2_1_0)
+            .expect("could not write header")
+            .finish()
+            .expect("could not finish log");
+
+        let log = parse(&output);
+        validate_schema(&log);
+        assert_eq!(log, SarifLog::new(SchemaVersion::V2_1_0));
+    }
+
+    #[test]
+    fn streamed() {
+        let tool = Tool::new(
+            ToolComponent::new("clippy")
+                .with_full_name("clippy 0.1.70 (en-US)")
+                .with_organization("The Rust Project")
+                .with_information_uri(
+                    Url::parse("https://github.com/rust-lang/rust-clippy").expect("valid URL"),
+                )
+                .with_version("0.1.70")
+                .with_rule(
+                    ReportingDescriptor::new("unused_variables")
+                        .with_guid(Uuid::from_u128(0x9D4C_A2E0_8F3A_4C6B_9A1D_5E7F_0B2C_4D6E))
+                        .with_short_description(MultiformatMessageString::new(
+                            "detects variables which are not used in any way",
+                        )),
+                ),
+        );
+
+        let mut writer =
+            SarifWriter::new(Vec::new(), SchemaVersion::V2_1_0).expect("could not write header");
+
+        let mut run = writer
+            .begin_run(Run::new(tool.clone()).with_result(result(1, 0)))
+            .expect("could not begin run");
+        let index = run.add_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs")));
+        for line in 2..=100 {
+            run.write_result(&result(line, index))
+                .expect("could not write result");
+        }
+        run.finish().expect("could not finish run");
+
+        writer
+            .begin_run(Run::new(Tool::new(ToolComponent::new("rustfmt"))))
+            .expect("could not begin run")
+            .finish()
+            .expect("could not finish run");
+
+        let output = writer.finish().expect("could not finish log");
+        let log = parse(&output);
+        validate_schema(&log);
+
+        let expected = SarifLog::new(SchemaVersion::V2_1_0)
+            .with_run(
+                Run::new(tool)
+                    .with_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs")))
+                    .with_results((1..=100).map(|line| result(line, 0))),
+            )
+            .with_run(Run::new(Tool::new(ToolComponent::new("rustfmt"))));
+        assert_eq!(log, expected);
+    }
+
+    #[test]
+    fn deduplicated_artifacts() {
+        let mut writer =
+            SarifWriter::new(Vec::new(), SchemaVersion::V2_1_0).expect("could not write header");
+
+        let mut run = writer
+            .begin_run(
+                Run::new(Tool::new(ToolComponent::new("clippy")))
+                    .with_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs"))),
+            )
+            .expect("could not begin run");
+
+        assert_eq!(
+            run.add_artifact(Artifact::new(ArtifactLocation::new("src/main.rs"))),
+            1
+        );
+        assert_eq!(
+            run.add_artifact(Artifact::new(ArtifactLocation::new("src/lib.rs"))),
+            0
+        );
+        assert_eq!(
+            run.add_artifact(Artifact::new(
+                ArtifactLocation::new("src/lib.rs").with_uri_base_id("SRCROOT")
+            )),
+            2
+        );
+        run.finish().expect("could not finish run");
+
+        let output = writer.finish().expect("could not finish log");
+        let log = parse(&output);
+        validate_schema(&log);
+
+        let runs = log.runs.expect("no runs found");
+        assert_eq!(runs[0].artifacts.len(), 3);
+        assert!(runs[0].results.is_empty());
+    }
+}